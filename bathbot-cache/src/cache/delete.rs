@@ -7,9 +7,28 @@ use twilight_model::id::{
     Id,
 };
 
-use crate::{key::RedisKey, model::CacheChange, Cache};
+use crate::{
+    key::{RedisKey, ToCacheKey},
+    model::CacheChange,
+    Cache,
+};
 
 impl Cache {
+    /// Remove the entry of the given key. Returns whether there was an entry.
+    pub async fn delete<K>(&self, key: &K) -> Result<bool>
+    where
+        K: ToCacheKey + ?Sized,
+    {
+        let removed: usize = self
+            .connection()
+            .await?
+            .del(RedisKey::from(key))
+            .await
+            .wrap_err("Failed to delete entry")?;
+
+        Ok(removed > 0)
+    }
+
     pub(crate) async fn delete_channel(
         &self,
         guild: Option<Id<GuildMarker>>,
@@ -112,6 +131,13 @@ impl Cache {
         .await
         .wrap_err("Failed to remove guild channels")?;
 
+        let members: isize = conn
+            .scard(RedisKey::guild_members(guild))
+            .await
+            .wrap_err("Failed to get guild member cardinality")?;
+
+        change.members -= members;
+
         remove_ids(
             &mut conn,
             guild,
@@ -150,7 +176,8 @@ impl Cache {
             .await
             .wrap_err("Failed to delete member entry")?;
 
-        conn.srem(RedisKey::guild_members(guild), user.get())
+        let removed: isize = conn
+            .srem(RedisKey::guild_members(guild), user.get())
             .await
             .wrap_err("Failed to remove guild member entry")?;
 
@@ -159,7 +186,10 @@ impl Cache {
         // There's no stored structure in place that provides a way to remove
         // such user data but it shouldn't matter much anyway.
 
-        Ok(CacheChange::default())
+        Ok(CacheChange {
+            members: -removed,
+            ..Default::default()
+        })
     }
 
     pub(crate) async fn delete_role(
@@ -32,6 +32,23 @@ impl Cache {
             .wrap_err("Failed to fetch stored data")
     }
 
+    /// Remaining seconds until the entry of the given key expires.
+    ///
+    /// Returns `None` if there is no entry or it does not expire.
+    pub async fn ttl<K>(&self, key: &K) -> Result<Option<u64>>
+    where
+        K: ToCacheKey + ?Sized,
+    {
+        let ttl: i64 = self
+            .connection()
+            .await?
+            .ttl(RedisKey::from(key))
+            .await
+            .wrap_err("Failed to get ttl")?;
+
+        Ok(u64::try_from(ttl).ok())
+    }
+
     #[inline]
     pub async fn channel(
         &self,
@@ -276,7 +276,8 @@ impl Cache {
 
             let guild_key = RedisKey::guild_members(guild);
 
-            conn.sadd(guild_key, user.id.get())
+            let members_added: isize = conn
+                .sadd(guild_key, user.id.get())
                 .await
                 .wrap_err("Failed to add user as guild member")?;
 
@@ -286,6 +287,7 @@ impl Cache {
                 .wrap_err("Failed to add user as user id")?;
 
             Ok(CacheChange {
+                members: members_added,
                 users: added,
                 ..Default::default()
             })
@@ -347,7 +349,8 @@ impl Cache {
 
         let guild_key = RedisKey::guild_members(guild);
 
-        conn.sadd(guild_key, &member_ids)
+        let members_added: isize = conn
+            .sadd(guild_key, &member_ids)
             .await
             .wrap_err("Failed to add users as guild members")?;
 
@@ -357,6 +360,7 @@ impl Cache {
             .wrap_err("Failed to add users as user ids")?;
 
         Ok(CacheChange {
+            members: members_added,
             users: added,
             ..Default::default()
         })
@@ -3,8 +3,13 @@ use std::{
     sync::Mutex,
 };
 
-use bb8_redis::{bb8::Pool, redis::AsyncCommands, RedisConnectionManager};
+use bb8_redis::{
+    bb8::Pool,
+    redis::{pipe, AsyncCommands},
+    RedisConnectionManager,
+};
 use eyre::{Result, WrapErr};
+use twilight_model::id::Id;
 
 use crate::key::RedisKey;
 
@@ -12,6 +17,7 @@ use crate::key::RedisKey;
 pub struct CacheStats {
     pub channels: isize,
     pub guilds: isize,
+    pub members: isize,
     pub roles: isize,
     pub unavailable_guilds: isize,
     pub users: isize,
@@ -22,6 +28,7 @@ pub struct CacheStats {
 pub struct CacheChange {
     pub channels: isize,
     pub guilds: isize,
+    pub members: isize,
     pub roles: isize,
     pub unavailable_guilds: isize,
     pub users: isize,
@@ -44,6 +51,7 @@ impl AddAssign for CacheChange {
         let Self {
             channels,
             guilds,
+            members,
             roles,
             unavailable_guilds,
             users,
@@ -51,6 +59,7 @@ impl AddAssign for CacheChange {
 
         self.channels += channels;
         self.guilds += guilds;
+        self.members += members;
         self.roles += roles;
         self.unavailable_guilds += unavailable_guilds;
         self.users += users;
@@ -78,9 +87,28 @@ impl CacheStatsInternal {
             };
         }
 
+        // Members are only stored per guild so their count is the sum of all
+        // guild member sets
+        let guild_ids: Vec<u64> = conn
+            .smembers(RedisKey::guilds())
+            .await
+            .wrap_err("Failed to get guild ids")?;
+
+        let mut pipe = pipe();
+
+        for guild_id in guild_ids {
+            pipe.scard(RedisKey::guild_members(Id::new(guild_id)));
+        }
+
+        let member_counts: Vec<isize> = pipe
+            .query_async(&mut *conn)
+            .await
+            .wrap_err("Failed to get guild member cardinalities")?;
+
         let stats = CacheStats {
             channels: scard!(channels),
             guilds: scard!(guilds),
+            members: member_counts.into_iter().sum(),
             roles: scard!(roles),
             unavailable_guilds: scard!(unavailable_guilds),
             users: scard!(users),
@@ -96,6 +124,7 @@ impl CacheStatsInternal {
 
         unlocked.channels += change.channels;
         unlocked.guilds += change.guilds;
+        unlocked.members += change.members;
         unlocked.roles += change.roles;
         unlocked.unavailable_guilds += change.unavailable_guilds;
         unlocked.users += change.users;
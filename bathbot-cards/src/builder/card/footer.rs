@@ -8,7 +8,7 @@ use crate::{
         font::FontBuilder,
         paint::{Gradient, PaintBuilder},
     },
-    card::CardInner,
+    card::{CardInner, MapStats},
     error::FooterError,
     font::FontData,
     svg::Svg,
@@ -31,6 +31,14 @@ impl CardBuilder<'_> {
         draw_name(self, card.assets.clone())?;
         draw_date(self, card.date, font_data)?;
 
+        if card.extended {
+            draw_extended(self, font_data)?;
+        }
+
+        if let Some(ref map_stats) = card.map_stats {
+            draw_map_stats(self, map_stats, font_data)?;
+        }
+
         Ok(self)
     }
 }
@@ -125,3 +133,50 @@ fn draw_date(
 
     Ok(())
 }
+
+fn draw_extended(card: &mut CardBuilder<'_>, font_data: &FontData) -> Result<(), FooterError> {
+    let font = FontBuilder::build(200, Slant::Italic, font_data, 30.0)?;
+    let paint = PaintBuilder::rgb(255, 255, 255).build();
+    let pos_x = W - FOOTER_DATE_MARGIN_RIGHT;
+    let pos_y = H - FOOTER_H + 50;
+
+    card.canvas.draw_str_align(
+        "extended",
+        (pos_x as f32, pos_y as f32),
+        &font,
+        &paint,
+        Align::Right,
+    );
+
+    Ok(())
+}
+
+fn draw_map_stats(
+    card: &mut CardBuilder<'_>,
+    map_stats: &MapStats,
+    font_data: &FontData,
+) -> Result<(), FooterError> {
+    let font = FontBuilder::build(400, Slant::Upright, font_data, 26.0)?;
+    let paint = PaintBuilder::rgb(255, 255, 255).build();
+    let pos_x = W - FOOTER_DATE_MARGIN_RIGHT;
+    let pos_y = H - FOOTER_H + 150;
+
+    let secs = map_stats.avg_len.round() as u32;
+
+    let text = format!(
+        "Avg. length {}:{:0>2} • Avg. objects {}",
+        secs / 60,
+        secs % 60,
+        map_stats.avg_objects.round() as u32,
+    );
+
+    card.canvas.draw_str_align(
+        text,
+        (pos_x as f32, pos_y as f32),
+        &font,
+        &paint,
+        Align::Right,
+    );
+
+    Ok(())
+}
@@ -26,6 +26,57 @@ pub struct RequiredAttributes {
     pub od: f32,
 }
 
+/// Aggregated stats of the maps on which the scores were set.
+#[derive(Copy, Clone)]
+pub(crate) struct MapStats {
+    /// Average drain length in seconds, adjusted to the clock rate
+    pub(crate) avg_len: f32,
+    pub(crate) avg_objects: f32,
+}
+
+impl MapStats {
+    fn new<S>(scores: &[Score], maps: &Maps<S>) -> Option<Self>
+    where
+        S: BuildHasher,
+    {
+        let mut len_sum = 0.0;
+        let mut len_count = 0;
+        let mut objects_sum = 0;
+        let mut objects_count = 0;
+
+        for score in scores {
+            let Some(attrs) = maps.get(&score.map_id) else {
+                continue;
+            };
+
+            objects_sum += match attrs.difficulty {
+                DifficultyAttributes::Osu(ref attrs) => {
+                    attrs.n_circles + attrs.n_sliders + attrs.n_spinners
+                }
+                DifficultyAttributes::Mania(ref attrs) => attrs.n_objects,
+                ref attrs => attrs.max_combo(),
+            };
+
+            objects_count += 1;
+
+            if let Some(ref map) = score.map {
+                let clock_rate = score.mods.clock_rate().unwrap_or(1.0);
+                len_sum += map.seconds_drain as f32 / clock_rate;
+                len_count += 1;
+            }
+        }
+
+        if objects_count == 0 || len_count == 0 {
+            return None;
+        }
+
+        Some(Self {
+            avg_len: len_sum / len_count as f32,
+            avg_objects: objects_sum as f32 / objects_count as f32,
+        })
+    }
+}
+
 pub struct BathbotCard<'a, Status> {
     pub(crate) skills: Skills,
     pub(crate) title: CardTitle,
@@ -45,6 +96,9 @@ pub(crate) struct CardInner<'a> {
     pub(crate) flag: &'a [u8],
     pub(crate) date: &'a str,
     pub(crate) assets: PathBuf,
+    pub(crate) extended: bool,
+    /// Only drawn if available
+    pub(crate) map_stats: Option<MapStats>,
 }
 
 impl<'a, Status> BathbotCard<'a, Status> {
@@ -59,12 +113,16 @@ impl<'a> BathbotCard<'a, UserNext> {
     where
         S: BuildHasher,
     {
+        let map_stats = MapStats::new(scores, &maps);
         let skills = Skills::calculate(mode, scores, maps);
 
         Self {
             title: CardTitle::new(&skills, scores, legacy_scores),
             skills,
-            inner: CardInner::default(),
+            inner: CardInner {
+                map_stats,
+                ..Default::default()
+            },
             _phantom: PhantomData,
         }
     }
@@ -131,6 +189,14 @@ impl<'a> BathbotCard<'a, AssetsPathNext> {
 }
 
 impl BathbotCard<'_, ReadyToDraw> {
+    /// Label the card as extended i.e. its values are not only based on top
+    /// scores.
+    pub fn extended(&mut self, extended: bool) -> &mut Self {
+        self.inner.extended = extended;
+
+        self
+    }
+
     pub fn draw(&self) -> Result<Vec<u8>, CardError> {
         let fonts = FontData::new(self.inner.assets.clone())?;
         let mut surface = surfaces::raster_n32_premul((W, H)).ok_or(CardError::CreateSurface)?;
@@ -1,8 +1,8 @@
 use std::{collections::BTreeMap, fmt::Write};
 
 use bathbot_model::{
-    SnipeCountries, SnipeCountryListOrder, SnipeCountryPlayer, SnipeCountryStatistics, SnipePlayer,
-    SnipePlayerHistory, SnipeRecent, SnipeScore, SnipeScoreParams,
+    SnipeCountries, SnipeCountryListOrder, SnipeCountryPlayer, SnipeCountryStatistics,
+    SnipeMapScore, SnipePlayer, SnipePlayerHistory, SnipeRecent, SnipeScore, SnipeScoreParams,
 };
 use bathbot_util::{
     constants::HUISMETBENEN,
@@ -85,6 +85,23 @@ pub async fn get_country_statistics(
     })
 }
 
+pub async fn get_map_leaderboard(
+    client: &Client,
+    country: &str,
+    map_id: u32,
+) -> Result<Vec<SnipeMapScore>> {
+    let country = country.to_lowercase();
+    let url = format!("{HUISMETBENEN}beatmap/{country}/{map_id}/scores");
+
+    let bytes = client.make_get_request(url, Site::Huismetbenen).await?;
+
+    serde_json::from_slice(&bytes).wrap_err_with(|| {
+        let body = String::from_utf8_lossy(&bytes);
+
+        format!("Failed to deserialize huismetbenen map leaderboard: {body}")
+    })
+}
+
 pub async fn get_national_snipes(
     client: &Client,
     user_id: u32,
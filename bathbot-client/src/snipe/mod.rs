@@ -5,8 +5,9 @@ use std::{
 };
 
 use bathbot_model::{
-    SnipeCountries, SnipeCountryListOrder, SnipeCountryPlayer, SnipeCountryStatistics, SnipePlayer,
-    SnipePlayerListOrder, SnipeRecent, SnipeScore, SnipeScoreParams, SnipedPlayer, SnipedWeek,
+    SnipeCountries, SnipeCountryListOrder, SnipeCountryPlayer, SnipeCountryStatistics,
+    SnipeMapScore, SnipePlayer, SnipePlayerListOrder, SnipeRecent, SnipeScore, SnipeScoreParams,
+    SnipedPlayer, SnipedWeek,
 };
 use bathbot_util::IntHasher;
 use eyre::Result;
@@ -161,6 +162,16 @@ impl Client {
         }
     }
 
+    /// National leaderboard of a map, sorted by score. Only available for
+    /// osu!standard.
+    pub async fn get_snipe_map_leaderboard(
+        &self,
+        country_code: &str,
+        map_id: u32,
+    ) -> Result<Vec<SnipeMapScore>> {
+        huismetbenen::get_map_leaderboard(self, country_code, map_id).await
+    }
+
     pub async fn get_sniped_players(
         &self,
         user_id: u32,
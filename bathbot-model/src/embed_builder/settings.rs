@@ -21,8 +21,22 @@ pub struct ScoreEmbedSettings {
         skip_serializing_if = "super::is_true"
     )]
     pub show_sr_in_title: bool,
+    #[serde(
+        rename = "sv",
+        default = "ScoreEmbedSettings::default_show_score_version",
+        with = "bool_as_u8",
+        skip_serializing_if = "super::is_false"
+    )]
+    pub show_score_version: bool,
     #[serde(rename = "i")]
     pub image: SettingsImage,
+    /// Source of the thumbnail if [`SettingsImage::Thumbnail`] is chosen
+    #[serde(
+        rename = "t",
+        default,
+        skip_serializing_if = "SettingsThumbnail::is_default"
+    )]
+    pub thumbnail: SettingsThumbnail,
     #[serde(rename = "b")]
     pub buttons: SettingsButtons,
 }
@@ -35,6 +49,10 @@ impl ScoreEmbedSettings {
     fn default_show_sr_in_title() -> bool {
         true
     }
+
+    fn default_show_score_version() -> bool {
+        false
+    }
 }
 
 impl Default for ScoreEmbedSettings {
@@ -108,7 +126,9 @@ impl Default for ScoreEmbedSettings {
             ],
             show_artist: Self::default_show_artist(),
             show_sr_in_title: Self::default_show_sr_in_title(),
+            show_score_version: Self::default_show_score_version(),
             image: SettingsImage::default(),
+            thumbnail: SettingsThumbnail::default(),
             buttons: SettingsButtons::default(),
         }
     }
@@ -125,6 +145,22 @@ define_enum! {
     }
 }
 
+define_enum! {
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+    pub enum SettingsThumbnail {
+        #[default]
+        ListCover = 0,
+        Card = 1,
+        Avatar = 2,
+    }
+}
+
+impl SettingsThumbnail {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct SettingsButtons {
     #[serde(
@@ -86,6 +86,39 @@ bitflags::bitflags! {
     }
 }
 
+bitflags::bitflags! {
+    pub struct HintTypes: u8 {
+        const Title  = 1 << 0;
+        const Artist = 1 << 1;
+        const Length = 1 << 2;
+        const Region = 1 << 3;
+    }
+}
+
+impl Default for HintTypes {
+    #[inline]
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl FromStr for HintTypes {
+    type Err = String;
+
+    #[inline]
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let result = match value.cow_to_ascii_lowercase().as_ref() {
+            "t" | "title" => Self::Title,
+            "a" | "artist" => Self::Artist,
+            "l" | "len" | "length" => Self::Length,
+            "r" | "region" | "img" | "image" => Self::Region,
+            other => return Err(other.to_owned()),
+        };
+
+        Ok(result)
+    }
+}
+
 pub struct IntoIter<F> {
     flags: F,
     shift: usize,
@@ -153,3 +186,4 @@ macro_rules! bitflag_impls {
 
 bitflag_impls!(MapsetTags, 32);
 bitflag_impls!(Effects, 8);
+bitflag_impls!(HintTypes, 8);
@@ -27,7 +27,7 @@ use time::{
 use twilight_interactions::command::{CommandOption, CreateOption};
 
 use super::deser;
-use crate::KittenRoleplayCountries;
+use crate::{rkyv_util::DerefAsString, KittenRoleplayCountries};
 
 pub struct SnipeScoreParams {
     pub user_id: u32,
@@ -234,6 +234,20 @@ pub struct SnipeCountryPlayer {
     pub count_first: u32,
 }
 
+/// A national leaderboard entry of a map.
+#[derive(Debug, Deserialize, Archive, RkyvDeserialize, RkyvSerialize)]
+pub struct SnipeMapScore {
+    #[serde(rename = "player_id")]
+    pub user_id: u32,
+    #[serde(rename = "player_name")]
+    #[with(DerefAsString)]
+    pub username: Username,
+    pub score: u32,
+    pub pp: Option<f32>,
+    #[serde(with = "deser::adjust_acc")]
+    pub accuracy: f32,
+}
+
 #[derive(Debug)]
 pub struct SnipeRecent {
     pub map_id: u32,
@@ -184,6 +184,7 @@ impl From<UserStatsEntries> for RankingEntries {
 pub enum RankingKind {
     BgScores {
         global: bool,
+        mode: GameMode,
         scores: Vec<BgGameScore>,
     },
     Commands {
@@ -236,13 +237,16 @@ impl EmbedHeader {
 impl RankingKind {
     pub fn embed_header(&self) -> EmbedHeader {
         match self {
-            Self::BgScores { global, .. } => {
-                let text = if *global {
-                    "Global leaderboard for correct guesses"
-                } else {
-                    "Server leaderboard for correct guesses"
+            Self::BgScores { global, mode, .. } => {
+                let scope = if *global { "Global" } else { "Server" };
+
+                let mode = match mode {
+                    GameMode::Mania => "mania",
+                    _ => "osu!",
                 };
 
+                let text = format!("{scope} leaderboard for correct guesses ({mode})");
+
                 EmbedHeader::Author(AuthorBuilder::new(text))
             }
             Self::Commands { .. } => {
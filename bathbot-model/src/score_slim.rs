@@ -1,3 +1,5 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
 use bathbot_util::{ScoreExt, ScoreHasEndedAt, ScoreHasMode};
 use rosu_v2::{
     model::score::LegacyScoreStatistics,
@@ -19,6 +21,8 @@ pub struct ScoreSlim {
     /// Note that this is the *new* kind of score id
     pub score_id: u64,
     pub legacy_id: Option<u64>,
+    /// Only available for scores set on lazer
+    pub build_id: Option<u32>,
     pub statistics: LegacyScoreStatistics,
 }
 
@@ -36,10 +40,22 @@ impl ScoreSlim {
             classic_score: score.classic_score,
             score_id: score.id,
             legacy_id: score.legacy_score_id,
+            build_id: score.build_id,
             statistics: score.statistics.as_legacy(score.mode),
         }
     }
 
+    /// The client on which the score was set; `None` if unknown.
+    pub fn version(&self) -> Option<ScoreVersion> {
+        if self.legacy_id.is_some() {
+            Some(ScoreVersion::Stable)
+        } else if self.build_id.is_some() {
+            Some(ScoreVersion::Lazer)
+        } else {
+            None
+        }
+    }
+
     pub fn total_hits(&self) -> u32 {
         self.statistics.total_hits(self.mode)
     }
@@ -52,6 +68,21 @@ impl ScoreSlim {
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ScoreVersion {
+    Stable,
+    Lazer,
+}
+
+impl Display for ScoreVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Stable => f.write_str("osu!stable"),
+            Self::Lazer => f.write_str("osu!lazer"),
+        }
+    }
+}
+
 #[rustfmt::skip]
 impl ScoreExt for ScoreSlim {
     #[inline] fn count_miss(&self) -> u32 { self.statistics.count_miss }
@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN cooldowns;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN cooldowns BYTEA;
\ No newline at end of file
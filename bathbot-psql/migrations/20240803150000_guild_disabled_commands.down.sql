@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN disabled_commands;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN disabled_commands BYTEA;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN channel_restrictions;
\ No newline at end of file
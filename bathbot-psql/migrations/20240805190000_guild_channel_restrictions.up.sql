@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN channel_restrictions BYTEA;
\ No newline at end of file
@@ -0,0 +1,4 @@
+DELETE FROM bggame_scores WHERE gamemode <> 0;
+ALTER TABLE bggame_scores DROP CONSTRAINT bggame_scores_pkey;
+ALTER TABLE bggame_scores DROP COLUMN gamemode;
+ALTER TABLE bggame_scores ADD PRIMARY KEY (discord_id);
\ No newline at end of file
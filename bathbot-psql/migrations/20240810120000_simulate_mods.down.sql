@@ -0,0 +1,3 @@
+DROP TABLE user_simulate_mods;
+
+ALTER TABLE user_configs DROP COLUMN remember_simulate_mods;
@@ -0,0 +1,8 @@
+ALTER TABLE user_configs ADD COLUMN remember_simulate_mods BOOL;
+
+CREATE TABLE user_simulate_mods (
+    discord_id INT8 NOT NULL,
+    gamemode   INT2 NOT NULL,
+    mods       VARCHAR(128) NOT NULL,
+    PRIMARY KEY (discord_id, gamemode)
+);
@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN allow_foreign_interactions BOOL;
@@ -0,0 +1,2 @@
+ALTER TABLE guild_configs DROP COLUMN pagination_timeout;
+ALTER TABLE user_configs DROP COLUMN pagination_timeout;
@@ -0,0 +1,2 @@
+ALTER TABLE user_configs ADD COLUMN pagination_timeout INT2;
+ALTER TABLE guild_configs ADD COLUMN pagination_timeout INT2;
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS role_assigns (
+    guild_id   INT8 NOT NULL,
+    channel_id INT8 NOT NULL,
+    message_id INT8 NOT NULL,
+    emoji_id   INT8 NOT NULL,
+    role_id    INT8 NOT NULL,
+    PRIMARY KEY (message_id, emoji_id)
+);
@@ -0,0 +1,3 @@
+DELETE FROM role_assigns WHERE emoji !~ '^[0-9]+$';
+ALTER TABLE role_assigns RENAME COLUMN emoji TO emoji_id;
+ALTER TABLE role_assigns ALTER COLUMN emoji_id TYPE INT8 USING emoji_id::INT8;
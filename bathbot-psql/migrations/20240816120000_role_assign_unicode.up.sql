@@ -0,0 +1,2 @@
+ALTER TABLE role_assigns ALTER COLUMN emoji_id TYPE VARCHAR(64) USING emoji_id::VARCHAR;
+ALTER TABLE role_assigns RENAME COLUMN emoji_id TO emoji;
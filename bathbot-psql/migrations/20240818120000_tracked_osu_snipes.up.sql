@@ -0,0 +1,5 @@
+CREATE TABLE IF NOT EXISTS tracked_osu_snipes (
+    channel_id INT8 NOT NULL,
+    user_id    INT4 NOT NULL,
+    PRIMARY KEY (channel_id, user_id)
+);
@@ -0,0 +1 @@
+ALTER TABLE tracked_osu_users DROP COLUMN min_rank_gains;
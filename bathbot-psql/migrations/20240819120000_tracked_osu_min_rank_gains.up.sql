@@ -0,0 +1 @@
+ALTER TABLE tracked_osu_users ADD COLUMN min_rank_gains BYTEA;
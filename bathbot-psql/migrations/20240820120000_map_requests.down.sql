@@ -0,0 +1,2 @@
+DROP TABLE map_request_hosts;
+DROP TABLE map_requests;
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS map_requests (
+    host_id      INT8 NOT NULL,
+    requester_id INT8 NOT NULL,
+    map_id       INT4 NOT NULL,
+    insert_date  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (host_id, map_id)
+);
+
+CREATE TABLE IF NOT EXISTS map_request_hosts (
+    host_id INT8 NOT NULL PRIMARY KEY
+);
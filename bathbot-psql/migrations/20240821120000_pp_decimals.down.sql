@@ -0,0 +1 @@
+ALTER TABLE user_configs DROP COLUMN pp_decimals;
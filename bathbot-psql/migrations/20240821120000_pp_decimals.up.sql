@@ -0,0 +1 @@
+ALTER TABLE user_configs ADD COLUMN pp_decimals INT2;
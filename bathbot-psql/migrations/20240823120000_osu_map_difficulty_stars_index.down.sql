@@ -0,0 +1 @@
+DROP INDEX osu_map_difficulty_mods_stars_index;
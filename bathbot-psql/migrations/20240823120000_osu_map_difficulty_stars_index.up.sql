@@ -0,0 +1 @@
+CREATE INDEX osu_map_difficulty_mods_stars_index ON osu_map_difficulty (mods, stars);
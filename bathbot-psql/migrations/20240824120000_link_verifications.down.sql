@@ -0,0 +1,3 @@
+DROP TABLE osu_link_verifications;
+
+ALTER TABLE guild_configs DROP COLUMN require_verification;
\ No newline at end of file
@@ -0,0 +1,9 @@
+ALTER TABLE guild_configs ADD COLUMN require_verification BOOL;
+
+-- Links whose ownership was verified, either through osu! OAuth or a code
+-- on the osu! profile. Only valid while the user is still linked to `osu_id`.
+CREATE TABLE osu_link_verifications (
+    discord_id INT8 NOT NULL,
+    osu_id     INT4 NOT NULL,
+    PRIMARY KEY (discord_id)
+);
\ No newline at end of file
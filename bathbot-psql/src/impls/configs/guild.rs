@@ -30,7 +30,15 @@ SELECT
   render_button, 
   allow_custom_skins, 
   hide_medal_solution, 
-  score_data 
+  score_data, 
+  cooldowns, 
+  disabled_commands, 
+  channel_restrictions, 
+  allow_foreign_interactions, 
+  pagination_timeout, 
+  gamemode, 
+  channel_modes, 
+  require_verification 
 FROM 
   guild_configs"#
         );
@@ -47,6 +55,48 @@ FROM
         Ok(configs)
     }
 
+    pub async fn select_guild_config(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Option<GuildConfig>> {
+        let query = sqlx::query_as!(
+            DbGuildConfig,
+            r#"
+SELECT
+  guild_id,
+  authorities,
+  prefixes,
+  allow_songs,
+  retries,
+  osu_track_limit,
+  list_size,
+  render_button,
+  allow_custom_skins,
+  hide_medal_solution,
+  score_data,
+  cooldowns,
+  disabled_commands,
+  channel_restrictions,
+  allow_foreign_interactions,
+  pagination_timeout,
+  gamemode,
+  channel_modes,
+  require_verification
+FROM
+  guild_configs
+WHERE
+  guild_id = $1"#,
+            guild_id.get() as i64
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt.map(GuildConfig::from))
+    }
+
     pub async fn upsert_guild_config(
         &self,
         guild_id: Id<GuildMarker>,
@@ -63,6 +113,14 @@ FROM
             allow_custom_skins,
             hide_medal_solution,
             score_data,
+            cooldowns,
+            disabled_commands,
+            channel_restrictions,
+            allow_foreign_interactions,
+            pagination_timeout,
+            mode,
+            channel_modes,
+            require_verification,
         } = config;
 
         let authorities =
@@ -71,18 +129,57 @@ FROM
         let prefixes =
             rkyv::to_bytes::<_, 32>(prefixes).wrap_err("failed to serialize prefixes")?;
 
+        let cooldowns = if cooldowns.is_empty() {
+            None
+        } else {
+            let bytes =
+                rkyv::to_bytes::<_, 1>(cooldowns).wrap_err("failed to serialize cooldowns")?;
+
+            Some(bytes)
+        };
+
+        let disabled_commands = if disabled_commands.is_empty() {
+            None
+        } else {
+            let bytes = rkyv::to_bytes::<_, 64>(disabled_commands)
+                .wrap_err("failed to serialize disabled commands")?;
+
+            Some(bytes)
+        };
+
+        let channel_restrictions = if channel_restrictions.is_empty() {
+            None
+        } else {
+            let bytes = rkyv::to_bytes::<_, 64>(channel_restrictions)
+                .wrap_err("failed to serialize channel restrictions")?;
+
+            Some(bytes)
+        };
+
+        let channel_modes = if channel_modes.is_empty() {
+            None
+        } else {
+            let bytes = rkyv::to_bytes::<_, 64>(channel_modes)
+                .wrap_err("failed to serialize channel modes")?;
+
+            Some(bytes)
+        };
+
         let query = sqlx::query!(
             r#"
 INSERT INTO guild_configs (
   guild_id, authorities, prefixes, allow_songs, 
   retries, osu_track_limit, list_size, 
   render_button, allow_custom_skins, 
-  hide_medal_solution, score_data
+  hide_medal_solution, score_data, cooldowns, 
+  disabled_commands, channel_restrictions, 
+  allow_foreign_interactions, pagination_timeout, 
+  gamemode, channel_modes, require_verification
 ) 
 VALUES 
   (
     $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, 
-    $11
+    $11, $12, $13, $14, $15, $16, $17, $18, $19
   ) ON CONFLICT (guild_id) DO 
 UPDATE 
 SET 
@@ -95,7 +192,15 @@ SET
   render_button = $8, 
   allow_custom_skins = $9, 
   hide_medal_solution = $10, 
-  score_data = $11"#,
+  score_data = $11, 
+  cooldowns = $12, 
+  disabled_commands = $13, 
+  channel_restrictions = $14, 
+  allow_foreign_interactions = $15, 
+  pagination_timeout = $16, 
+  gamemode = $17, 
+  channel_modes = $18, 
+  require_verification = $19"#,
             guild_id.get() as i64,
             &authorities as &[u8],
             &prefixes as &[u8],
@@ -107,6 +212,14 @@ SET
             *allow_custom_skins,
             hide_medal_solution.map(i16::from),
             score_data.map(i16::from),
+            cooldowns.as_deref(),
+            disabled_commands.as_deref(),
+            channel_restrictions.as_deref(),
+            *allow_foreign_interactions,
+            pagination_timeout.map(|secs| secs as i16),
+            mode.map(|mode| mode as i16) as Option<i16>,
+            channel_modes.as_deref(),
+            *require_verification,
         );
 
         query
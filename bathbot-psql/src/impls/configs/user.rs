@@ -28,7 +28,9 @@ SELECT
   twitch_id, 
   timezone_seconds, 
   render_button, 
-  score_data 
+  score_data, 
+  pagination_timeout, 
+  pp_decimals 
 FROM 
   user_configs 
 WHERE 
@@ -262,6 +264,8 @@ FROM
             timezone,
             render_button,
             score_data,
+            pagination_timeout,
+            pp_decimals,
         } = config;
 
         let query = sqlx::query!(
@@ -269,10 +273,11 @@ FROM
 INSERT INTO user_configs (
   discord_id, osu_id, gamemode, twitch_id, 
   retries, score_embed, list_size, 
-  timezone_seconds, render_button, score_data
+  timezone_seconds, render_button, score_data, 
+  pagination_timeout, pp_decimals
 ) 
 VALUES 
-  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) ON CONFLICT (discord_id) DO 
+  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12) ON CONFLICT (discord_id) DO 
 UPDATE 
 SET 
   osu_id = $2, 
@@ -283,7 +288,9 @@ SET
   list_size = $7, 
   timezone_seconds = $8, 
   render_button = $9, 
-  score_data = $10"#,
+  score_data = $10, 
+  pagination_timeout = $11, 
+  pp_decimals = $12"#,
             user_id.get() as i64,
             osu.map(|id| id as i32),
             mode.map(|mode| mode as i16) as Option<i16>,
@@ -294,6 +301,8 @@ SET
             timezone.map(UtcOffset::whole_seconds),
             *render_button,
             score_data.map(i16::from),
+            pagination_timeout.map(|secs| secs as i16),
+            pp_decimals.map(i16::from),
         );
 
         query
@@ -402,4 +411,186 @@ WHERE
 
         Ok(row_opt.and_then(|row| row.gamemode.map(|mode| GameMode::from(mode as u8))))
     }
+
+    pub async fn select_pagination_timeout(&self, user_id: Id<UserMarker>) -> Result<Option<u16>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  pagination_timeout 
+FROM 
+  user_configs 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt.and_then(|row| row.pagination_timeout.map(|secs| secs as u16)))
+    }
+
+    /// Remembered simulate mods of the user for the given mode. Only returns
+    /// mods if the user enabled remembering them.
+    pub async fn select_simulate_mods(
+        &self,
+        user_id: Id<UserMarker>,
+        mode: GameMode,
+    ) -> Result<Option<String>> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  sim.mods
+FROM
+  user_simulate_mods AS sim
+  JOIN user_configs AS configs ON sim.discord_id = configs.discord_id
+WHERE
+  sim.discord_id = $1
+  AND sim.gamemode = $2
+  AND configs.remember_simulate_mods"#,
+            user_id.get() as i64,
+            mode as i16,
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt.map(|row| row.mods))
+    }
+
+    /// Store the simulate mods of the user for the given mode. Does nothing if
+    /// the user did not enable remembering them.
+    pub async fn upsert_simulate_mods(
+        &self,
+        user_id: Id<UserMarker>,
+        mode: GameMode,
+        mods: &str,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO user_simulate_mods (discord_id, gamemode, mods)
+SELECT
+  $1::INT8, $2::INT2, $3::VARCHAR
+WHERE
+  EXISTS (
+    SELECT
+      1
+    FROM
+      user_configs
+    WHERE
+      discord_id = $1
+      AND remember_simulate_mods
+  ) ON CONFLICT (discord_id, gamemode) DO
+UPDATE
+SET
+  mods = $3"#,
+            user_id.get() as i64,
+            mode as i16,
+            mods,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn update_remember_simulate_mods(
+        &self,
+        user_id: Id<UserMarker>,
+        remember: bool,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO user_configs (discord_id, remember_simulate_mods)
+VALUES
+  ($1, $2) ON CONFLICT (discord_id) DO
+UPDATE
+SET
+  remember_simulate_mods = $2"#,
+            user_id.get() as i64,
+            remember,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn delete_simulate_mods(&self, user_id: Id<UserMarker>) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  user_simulate_mods
+WHERE
+  discord_id = $1"#,
+            user_id.get() as i64,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Mark the user's link to the osu! user as verified.
+    pub async fn upsert_osu_link_verification(
+        &self,
+        user_id: Id<UserMarker>,
+        osu_id: u32,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_link_verifications (discord_id, osu_id)
+VALUES
+  ($1, $2) ON CONFLICT (discord_id) DO
+UPDATE
+SET
+  osu_id = $2"#,
+            user_id.get() as i64,
+            osu_id as i32,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Of the given discord ids, return those whose current osu! link is
+    /// verified.
+    pub async fn select_verified_discord_ids(&self, discord_ids: &[i64]) -> Result<Vec<i64>> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  configs.discord_id
+FROM
+  user_configs AS configs
+  JOIN osu_link_verifications AS verifications ON configs.discord_id = verifications.discord_id
+  AND configs.osu_id = verifications.osu_id
+WHERE
+  configs.discord_id = ANY($1)"#,
+            discord_ids,
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("failed to fetch all")?;
+
+        Ok(rows.into_iter().map(|row| row.discord_id).collect())
+    }
 }
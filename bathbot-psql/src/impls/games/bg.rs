@@ -1,28 +1,36 @@
 use std::mem;
 
-use bathbot_model::BgGameScore;
+use bathbot_model::{BgGameScore, MapsetTags};
 use eyre::{Result, WrapErr};
 use rosu_v2::prelude::GameMode;
 
 use crate::{
-    model::games::{DbBgGameScore, DbMapTagEntry, DbMapTagsParams},
+    model::games::{DbBgGameScore, DbMapTagEntry, DbMapTagsParams, DbMapsetTags},
     Database,
 };
 
 impl Database {
-    pub async fn increment_bggame_scores(&self, user_ids: &[i64], amounts: &[i32]) -> Result<()> {
+    pub async fn increment_bggame_scores(
+        &self,
+        user_ids: &[i64],
+        amounts: &[i32],
+        mode: GameMode,
+    ) -> Result<()> {
         let query = sqlx::query!(
             r#"
-INSERT INTO bggame_scores (discord_id, score) 
+INSERT INTO bggame_scores (discord_id, score, gamemode) 
 SELECT
-  *
+  discord_id, 
+  score, 
+  $3::INT2 
 FROM
-  UNNEST($1::INT8[], $2::INT4[]) ON CONFLICT (discord_id) DO 
+  UNNEST($1::INT8[], $2::INT4[]) AS scores(discord_id, score) ON CONFLICT (discord_id, gamemode) DO 
 UPDATE 
 SET 
   score = bggame_scores.score + excluded.score"#,
             user_ids,
             amounts,
+            mode as i16,
         );
 
         query
@@ -33,7 +41,7 @@ SET
         Ok(())
     }
 
-    pub async fn select_bggame_scores(&self) -> Result<Vec<BgGameScore>> {
+    pub async fn select_bggame_scores(&self, mode: GameMode) -> Result<Vec<BgGameScore>> {
         let query = sqlx::query_as!(
             DbBgGameScore,
             r#"
@@ -41,7 +49,10 @@ SELECT
   discord_id, 
   score 
 FROM 
-  bggame_scores"#
+  bggame_scores 
+WHERE 
+  gamemode = $1"#,
+            mode as i16
         );
 
         let scores = query
@@ -82,6 +93,142 @@ SET
         Ok(())
     }
 
+    pub async fn select_map_tag_entry(&self, mapset_id: u32) -> Result<Option<DbMapTagEntry>> {
+        let query = sqlx::query_as!(
+            DbMapTagEntry,
+            r#"
+SELECT 
+  mapset_id, 
+  image_filename 
+FROM 
+  map_tags 
+WHERE 
+  mapset_id = $1"#,
+            mapset_id as i32
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")
+    }
+
+    pub async fn select_mapset_tags(&self, mapset_id: u32) -> Result<Option<MapsetTags>> {
+        let query = sqlx::query_as!(
+            DbMapsetTags,
+            r#"
+SELECT 
+  farm, 
+  streams, 
+  alternate, 
+  old, 
+  meme, 
+  hardname, 
+  easy, 
+  hard, 
+  tech, 
+  weeb, 
+  bluesky, 
+  english, 
+  kpop 
+FROM 
+  map_tags 
+WHERE 
+  mapset_id = $1"#,
+            mapset_id as i32
+        );
+
+        let tags_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(tags_opt.map(MapsetTags::from))
+    }
+
+    /// Overwrite all tags of the mapset. Returns `false` if the mapset is not
+    /// stored.
+    pub async fn update_mapset_tags(&self, mapset_id: u32, tags: MapsetTags) -> Result<bool> {
+        let DbMapsetTags {
+            farm,
+            streams,
+            alternate,
+            old,
+            meme,
+            hardname,
+            easy,
+            hard,
+            tech,
+            weeb,
+            bluesky,
+            english,
+            kpop,
+        } = DbMapsetTags::from(tags);
+
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  map_tags 
+SET 
+  farm = $2, 
+  streams = $3, 
+  alternate = $4, 
+  old = $5, 
+  meme = $6, 
+  hardname = $7, 
+  easy = $8, 
+  hard = $9, 
+  tech = $10, 
+  weeb = $11, 
+  bluesky = $12, 
+  english = $13, 
+  kpop = $14 
+WHERE 
+  mapset_id = $1"#,
+            mapset_id as i32,
+            farm,
+            streams,
+            alternate,
+            old,
+            meme,
+            hardname,
+            easy,
+            hard,
+            tech,
+            weeb,
+            bluesky,
+            english,
+            kpop,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Remove the mapset and return its filename and mode if it was stored.
+    pub async fn delete_map_tag(&self, mapset_id: u32) -> Result<Option<(String, GameMode)>> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  map_tags 
+WHERE 
+  mapset_id = $1 RETURNING image_filename, 
+  gamemode"#,
+            mapset_id as i32
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt.map(|row| (row.image_filename, GameMode::from(row.gamemode as u8))))
+    }
+
     pub async fn select_map_tags(&self, params: DbMapTagsParams) -> Result<Vec<DbMapTagEntry>> {
         let query = params.into_query();
 
@@ -0,0 +1,270 @@
+use eyre::{Result, WrapErr};
+use futures::StreamExt;
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{database::Database, model::map_request::MapRequest};
+
+impl Database {
+    /// Returns the requests of the host, oldest first
+    pub async fn select_map_requests(&self, host_id: Id<UserMarker>) -> Result<Vec<MapRequest>> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  requests.requester_id,
+  requests.map_id,
+  requests.insert_date,
+  maps.mapset_id,
+  maps.map_version,
+  mapsets.artist,
+  mapsets.title
+FROM
+  (
+    SELECT
+      requester_id,
+      map_id,
+      insert_date
+    FROM
+      map_requests
+    WHERE
+      host_id = $1
+  ) AS requests
+  JOIN (
+    SELECT
+      map_id,
+      mapset_id,
+      map_version
+    FROM
+      osu_maps
+  ) AS maps ON requests.map_id = maps.map_id
+  JOIN (
+    SELECT
+      mapset_id,
+      artist,
+      title
+    FROM
+      osu_mapsets
+  ) AS mapsets ON maps.mapset_id = mapsets.mapset_id
+ORDER BY
+  requests.insert_date ASC"#,
+            host_id.get() as i64
+        );
+
+        let mut rows = query.fetch(self);
+        let mut requests = Vec::new();
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("Failed to fetch next")?;
+
+            requests.push(MapRequest {
+                requester_id: Id::new(row.requester_id as u64),
+                map_id: row.map_id as u32,
+                mapset_id: row.mapset_id as u32,
+                artist: row.artist.into_boxed_str(),
+                title: row.title.into_boxed_str(),
+                version: row.map_version.into_boxed_str(),
+                insert_date: row.insert_date,
+            });
+        }
+
+        Ok(requests)
+    }
+
+    /// Returns whether the request was inserted i.e. `false` if the host's
+    /// queue is not open, the map was already requested, the queue is full, or
+    /// the requester already has too many requests in the queue.
+    pub async fn insert_map_request(
+        &self,
+        host_id: Id<UserMarker>,
+        requester_id: Id<UserMarker>,
+        map_id: u32,
+        max_requests: usize,
+        max_per_requester: usize,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO map_requests (host_id, requester_id, map_id)
+SELECT
+  $1::INT8, $2::INT8, $3::INT4
+WHERE
+  EXISTS (
+    SELECT
+      1
+    FROM
+      map_request_hosts
+    WHERE
+      host_id = $1
+  )
+  AND (
+    SELECT
+      COUNT(*)
+    FROM
+      map_requests
+    WHERE
+      host_id = $1
+  ) < $4
+  AND (
+    SELECT
+      COUNT(*)
+    FROM
+      map_requests
+    WHERE
+      host_id = $1
+      AND requester_id = $2
+  ) < $5 ON CONFLICT (host_id, map_id) DO NOTHING"#,
+            host_id.get() as i64,
+            requester_id.get() as i64,
+            map_id as i32,
+            max_requests as i64,
+            max_per_requester as i64,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Removes and returns the oldest request of the host.
+    ///
+    /// Rows that are currently being popped by a concurrent call are skipped
+    /// so that no request is returned twice.
+    pub async fn pop_map_request(&self, host_id: Id<UserMarker>) -> Result<Option<MapRequest>> {
+        let query = sqlx::query!(
+            r#"
+WITH popped AS (
+  DELETE FROM
+    map_requests
+  WHERE
+    (host_id, map_id) = (
+      SELECT
+        host_id,
+        map_id
+      FROM
+        map_requests
+      WHERE
+        host_id = $1
+      ORDER BY
+        insert_date ASC
+      LIMIT
+        1 FOR
+      UPDATE
+        SKIP LOCKED
+    ) RETURNING requester_id,
+    map_id,
+    insert_date
+)
+SELECT
+  popped.requester_id,
+  popped.map_id,
+  popped.insert_date,
+  maps.mapset_id,
+  maps.map_version,
+  mapsets.artist,
+  mapsets.title
+FROM
+  popped
+  JOIN osu_maps AS maps ON popped.map_id = maps.map_id
+  JOIN osu_mapsets AS mapsets ON maps.mapset_id = mapsets.mapset_id"#,
+            host_id.get() as i64
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("Failed to fetch optional")?;
+
+        let request = row_opt.map(|row| MapRequest {
+            requester_id: Id::new(row.requester_id as u64),
+            map_id: row.map_id as u32,
+            mapset_id: row.mapset_id as u32,
+            artist: row.artist.into_boxed_str(),
+            title: row.title.into_boxed_str(),
+            version: row.map_version.into_boxed_str(),
+            insert_date: row.insert_date,
+        });
+
+        Ok(request)
+    }
+
+    /// Returns the amount of deleted entries
+    pub async fn delete_map_requests(&self, host_id: Id<UserMarker>) -> Result<u64> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  map_requests
+WHERE
+  host_id = $1"#,
+            host_id.get() as i64
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected())
+    }
+
+    /// Returns whether the host's queue accepts requests
+    pub async fn select_map_request_host(&self, host_id: Id<UserMarker>) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  EXISTS (
+    SELECT
+      1
+    FROM
+      map_request_hosts
+    WHERE
+      host_id = $1
+  ) AS "open!""#,
+            host_id.get() as i64
+        );
+
+        let row = query
+            .fetch_one(self)
+            .await
+            .wrap_err("Failed to fetch one")?;
+
+        Ok(row.open)
+    }
+
+    /// Returns whether the queue was opened i.e. `false` if it was open already
+    pub async fn insert_map_request_host(&self, host_id: Id<UserMarker>) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO map_request_hosts (host_id)
+VALUES
+  ($1) ON CONFLICT (host_id) DO NOTHING"#,
+            host_id.get() as i64
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Returns whether the queue was closed i.e. `false` if it was not open
+    pub async fn delete_map_request_host(&self, host_id: Id<UserMarker>) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  map_request_hosts
+WHERE
+  host_id = $1"#,
+            host_id.get() as i64
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
@@ -1,5 +1,7 @@
 mod bookmarks;
 mod configs;
 mod games;
+mod map_requests;
 mod osu;
+mod role_assigns;
 mod tracked_streams;
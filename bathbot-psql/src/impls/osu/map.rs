@@ -8,11 +8,13 @@ use rosu_pp::{
 };
 use rosu_v2::prelude::{BeatmapExtended, GameMode};
 use sqlx::{Postgres, Transaction};
+use time::OffsetDateTime;
 
 use crate::{
     model::osu::{
-        DbBeatmap, DbBeatmapset, DbCatchDifficultyAttributes, DbManiaDifficultyAttributes,
-        DbMapFilename, DbOsuDifficultyAttributes, DbTaikoDifficultyAttributes, MapVersion,
+        DbBeatmap, DbBeatmapset, DbCatchDifficultyAttributes, DbFarmMap,
+        DbManiaDifficultyAttributes, DbMapFilename, DbOsuDifficultyAttributes,
+        DbTaikoDifficultyAttributes, MapVersion,
     },
     Database,
 };
@@ -340,6 +342,96 @@ WHERE
         Ok(attrs)
     }
 
+    /// Ranked or approved osu!standard maps within the star range whose
+    /// difficulty attributes for the given mods are stored.
+    ///
+    /// If there are more maps than the limit, the ones closest to the center of
+    /// the star range are picked.
+    pub async fn select_farm_maps(
+        &self,
+        mods: u32,
+        min_stars: f64,
+        max_stars: f64,
+        limit: usize,
+    ) -> Result<Vec<DbFarmMap>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  map.map_id, 
+  map.mapset_id, 
+  mapset.artist, 
+  mapset.title, 
+  map.map_version, 
+  map.seconds_drain, 
+  attrs.aim, 
+  attrs.speed, 
+  attrs.flashlight, 
+  attrs.slider_factor, 
+  attrs.speed_note_count, 
+  attrs.ar, 
+  attrs.od, 
+  attrs.hp, 
+  attrs.n_circles, 
+  attrs.n_sliders, 
+  attrs.n_spinners, 
+  attrs.stars, 
+  attrs.max_combo 
+FROM 
+  osu_map_difficulty AS attrs 
+  JOIN osu_maps AS map ON attrs.map_id = map.map_id 
+  JOIN osu_mapsets AS mapset ON map.mapset_id = mapset.mapset_id 
+WHERE 
+  attrs.mods = $1 
+  AND attrs.stars BETWEEN $2 
+  AND $3 
+  AND mapset.rank_status IN (1, 2) 
+ORDER BY 
+  ABS(attrs.stars - ($2 + $3) / 2), 
+  map.map_id 
+LIMIT 
+  $4"#,
+            mods as i32,
+            min_stars,
+            max_stars,
+            limit as i64,
+        );
+
+        let mut rows = query.fetch(self);
+        let mut maps = Vec::new();
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("failed to fetch next")?;
+
+            let attrs = DbOsuDifficultyAttributes {
+                aim: row.aim,
+                speed: row.speed,
+                flashlight: row.flashlight,
+                slider_factor: row.slider_factor,
+                speed_note_count: row.speed_note_count,
+                ar: row.ar,
+                od: row.od,
+                hp: row.hp,
+                n_circles: row.n_circles,
+                n_sliders: row.n_sliders,
+                n_spinners: row.n_spinners,
+                stars: row.stars,
+                max_combo: row.max_combo,
+            };
+
+            maps.push(DbFarmMap {
+                map_id: row.map_id as u32,
+                mapset_id: row.mapset_id as u32,
+                artist: row.artist,
+                title: row.title,
+                version: row.map_version,
+                seconds_drain: row.seconds_drain as u32,
+                attrs: attrs.into(),
+            });
+        }
+
+        Ok(maps)
+    }
+
     pub async fn select_beatmap_file(&self, map_id: u32) -> Result<Option<Box<str>>> {
         let query = sqlx::query!(
             r#"
@@ -359,6 +451,106 @@ WHERE
             .map(|row_opt| row_opt.map(|row| row.map_filepath.into_boxed_str()))
     }
 
+    pub async fn select_osu_map_last_update(&self, map_id: u32) -> Result<Option<OffsetDateTime>> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  last_update
+FROM
+  osu_maps
+WHERE
+  map_id = $1"#,
+            map_id as i32
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt.map(|row| row.last_update))
+    }
+
+    /// Delete the map, its file entry, and all of its difficulty attributes so
+    /// that it will be retrieved anew on the next request.
+    pub async fn delete_osu_map(&self, map_id: u32) -> Result<()> {
+        let mut tx = self.begin().await.wrap_err("failed to begin transaction")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  osu_maps
+WHERE
+  map_id = $1"#,
+            map_id as i32
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("failed to delete from osu_maps")?;
+
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  osu_map_files
+WHERE
+  map_id = $1"#,
+            map_id as i32
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("failed to delete from osu_map_files")?;
+
+        let queries = [
+            sqlx::query!(
+                r#"
+DELETE FROM
+  osu_map_difficulty
+WHERE
+  map_id = $1"#,
+                map_id as i32
+            ),
+            sqlx::query!(
+                r#"
+DELETE FROM
+  osu_map_difficulty_taiko
+WHERE
+  map_id = $1"#,
+                map_id as i32
+            ),
+            sqlx::query!(
+                r#"
+DELETE FROM
+  osu_map_difficulty_catch
+WHERE
+  map_id = $1"#,
+                map_id as i32
+            ),
+            sqlx::query!(
+                r#"
+DELETE FROM
+  osu_map_difficulty_mania
+WHERE
+  map_id = $1"#,
+                map_id as i32
+            ),
+        ];
+
+        for query in queries {
+            query
+                .execute(&mut *tx)
+                .await
+                .wrap_err("failed to delete from map difficulty")?;
+        }
+
+        tx.commit().await.wrap_err("failed to commit transaction")?;
+
+        Ok(())
+    }
+
     pub async fn select_map_versions_by_map_id(&self, map_id: u32) -> Result<Vec<MapVersion>> {
         let query = sqlx::query_as!(
             MapVersion,
@@ -4,5 +4,6 @@ pub mod name;
 pub mod rank_pp;
 pub mod render;
 pub mod score;
+pub mod tracked_snipes;
 pub mod tracked_users;
 pub mod user;
@@ -1,6 +1,7 @@
 use eyre::{Result, WrapErr};
 use rosu_v2::prelude::Username;
 use sqlx::{Executor, Postgres};
+use time::OffsetDateTime;
 use twilight_model::id::{marker::UserMarker, Id};
 
 use crate::database::Database;
@@ -82,6 +83,45 @@ WHERE
         Ok(row_opt.map(|row| row.username.into()))
     }
 
+    /// Ids of linked osu! users whose stored data was last updated before
+    /// `threshold`, oldest first.
+    pub async fn select_stale_linked_osu_ids(
+        &self,
+        threshold: OffsetDateTime,
+        limit: i64,
+    ) -> Result<Vec<u32>> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  configs.osu_id AS "osu_id!"
+FROM
+  (
+    SELECT
+      DISTINCT osu_id
+    FROM
+      user_configs
+    WHERE
+      osu_id IS NOT NULL
+  ) AS configs
+  JOIN osu_user_stats AS stats ON configs.osu_id = stats.user_id
+WHERE
+  stats.last_update < $1
+ORDER BY
+  stats.last_update ASC
+LIMIT
+  $2"#,
+            threshold,
+            limit,
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("failed to fetch all")?;
+
+        Ok(rows.into_iter().map(|row| row.osu_id as u32).collect())
+    }
+
     pub async fn delete_osu_username<'c, E>(executor: E, user_id: u32) -> Result<()>
     where
         E: Executor<'c, Database = Postgres>,
@@ -0,0 +1,113 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::BuildHasher,
+};
+
+use eyre::{Result, WrapErr};
+use futures::StreamExt;
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+use crate::database::Database;
+
+impl Database {
+    pub async fn select_tracked_osu_snipes<S>(
+        &self,
+    ) -> Result<HashMap<u32, Vec<Id<ChannelMarker>>, S>>
+    where
+        S: Default + BuildHasher,
+    {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  channel_id, 
+  user_id 
+FROM 
+  tracked_osu_snipes"#
+        );
+
+        let mut rows = query.fetch(self);
+        let mut tracks = HashMap::with_capacity_and_hasher(128, S::default());
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("failed to fetch next")?;
+            let channel_id = Id::new(row.channel_id as u64);
+            let user_id = row.user_id as u32;
+
+            // match instead of `.or_insert_with(...).push(...)` to avoid bounds check
+            match tracks.entry(user_id) {
+                Entry::Vacant(e) => {
+                    e.insert(vec![channel_id]);
+                }
+                Entry::Occupied(mut e) => e.get_mut().push(channel_id),
+            }
+        }
+
+        Ok(tracks)
+    }
+
+    /// Returns whether a new entry was inserted
+    pub async fn insert_tracked_osu_snipe(
+        &self,
+        channel: Id<ChannelMarker>,
+        user_id: u32,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO tracked_osu_snipes (channel_id, user_id) 
+VALUES 
+  ($1, $2) ON CONFLICT (channel_id, user_id) DO NOTHING"#,
+            channel.get() as i64,
+            user_id as i32,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    pub async fn delete_tracked_osu_snipes(&self, channel: Id<ChannelMarker>) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  tracked_osu_snipes 
+WHERE 
+  channel_id = $1"#,
+            channel.get() as i64,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_tracked_osu_snipe(
+        &self,
+        channel: Id<ChannelMarker>,
+        user_id: u32,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  tracked_osu_snipes 
+WHERE 
+  channel_id = $1 
+  AND user_id = $2"#,
+            channel.get() as i64,
+            user_id as i32,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
@@ -9,7 +9,7 @@ use rkyv::ser::{
 use rosu_v2::prelude::GameMode;
 
 use crate::{
-    model::osu::{Channels, DbTrackedOsuUser, TrackedOsuUserKey, TrackedOsuUserValue},
+    model::osu::{Channels, DbTrackedOsuUser, RankGains, TrackedOsuUserKey, TrackedOsuUserValue},
     Database,
 };
 
@@ -27,7 +27,8 @@ SELECT
   user_id, 
   gamemode, 
   channels, 
-  last_update 
+  last_update, 
+  min_rank_gains 
 FROM 
   tracked_osu_users"#
         );
@@ -71,22 +72,34 @@ WHERE
         user_id: u32,
         mode: GameMode,
         channels: &Channels<S>,
+        min_rank_gains: &RankGains<S>,
     ) -> Result<()> {
         let channels =
             rkyv::to_bytes::<_, 256>(channels).wrap_err("failed to serialize channels")?;
 
+        let min_rank_gains = if min_rank_gains.is_empty() {
+            None
+        } else {
+            let bytes = rkyv::to_bytes::<_, 64>(min_rank_gains)
+                .wrap_err("failed to serialize min rank gains")?;
+
+            Some(bytes)
+        };
+
         let query = sqlx::query!(
             r#"
 UPDATE 
   tracked_osu_users 
 SET 
-  channels = $3 
+  channels = $3, 
+  min_rank_gains = $4 
 WHERE 
   user_id = $1 
   AND gamemode = $2"#,
             user_id as i32,
             mode as i16,
             &channels as &[u8],
+            min_rank_gains.as_deref(),
         );
 
         query
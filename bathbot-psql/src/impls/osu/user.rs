@@ -831,6 +831,29 @@ SET
         Ok(())
     }
 
+    pub async fn select_osu_user_last_update(
+        &self,
+        user_id: u32,
+    ) -> Result<Option<OffsetDateTime>> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  last_update
+FROM
+  osu_user_stats
+WHERE
+  user_id = $1"#,
+            user_id as i32
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt.map(|row| row.last_update))
+    }
+
     pub async fn delete_osu_user_stats_and_scores(&self, user_id: u32) -> Result<()> {
         let mut conn = self
             .acquire()
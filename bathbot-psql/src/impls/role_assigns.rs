@@ -0,0 +1,107 @@
+use eyre::{Result, WrapErr};
+use futures::StreamExt;
+use twilight_model::id::{
+    marker::{GuildMarker, MessageMarker},
+    Id,
+};
+
+use crate::{
+    database::Database,
+    model::role_assign::{RoleAssign, RoleAssignEmoji},
+};
+
+impl Database {
+    pub async fn select_role_assigns(&self) -> Result<Vec<RoleAssign>> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  guild_id,
+  channel_id,
+  message_id,
+  emoji,
+  role_id
+FROM
+  role_assigns"#
+        );
+
+        let mut rows = query.fetch(self);
+        let mut role_assigns = Vec::new();
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("failed to fetch next")?;
+
+            role_assigns.push(RoleAssign {
+                guild_id: Id::new(row.guild_id as u64),
+                channel_id: Id::new(row.channel_id as u64),
+                message_id: Id::new(row.message_id as u64),
+                emoji: RoleAssignEmoji::from_db(row.emoji),
+                role_id: Id::new(row.role_id as u64),
+            });
+        }
+
+        Ok(role_assigns)
+    }
+
+    pub async fn upsert_role_assign(&self, role_assign: &RoleAssign) -> Result<()> {
+        let RoleAssign {
+            guild_id,
+            channel_id,
+            message_id,
+            emoji,
+            role_id,
+        } = role_assign;
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO role_assigns (
+  guild_id, channel_id, message_id, emoji,
+  role_id
+)
+VALUES
+  ($1, $2, $3, $4, $5) ON CONFLICT (message_id, emoji) DO
+UPDATE
+SET
+  role_id = $5"#,
+            guild_id.get() as i64,
+            channel_id.get() as i64,
+            message_id.get() as i64,
+            emoji.to_db(),
+            role_id.get() as i64,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_role_assign(
+        &self,
+        guild_id: Id<GuildMarker>,
+        message_id: Id<MessageMarker>,
+        emoji: &RoleAssignEmoji,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  role_assigns
+WHERE
+  guild_id = $1
+  AND message_id = $2
+  AND emoji = $3"#,
+            guild_id.get() as i64,
+            message_id.get() as i64,
+            emoji.to_db(),
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,84 @@
+use rkyv::{Archive, Deserialize, Infallible, Serialize};
+use rosu_v2::prelude::GameMode;
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+/// Default modes for specific channels of a guild.
+#[derive(Archive, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ChannelModes {
+    inner: Vec<ChannelMode>,
+}
+
+#[derive(Archive, Clone, Debug, Deserialize, Serialize)]
+pub struct ChannelMode {
+    channel: u64,
+    mode: u8,
+}
+
+impl ChannelMode {
+    pub fn channel(&self) -> Id<ChannelMarker> {
+        Id::new(self.channel)
+    }
+
+    pub fn mode(&self) -> GameMode {
+        GameMode::from(self.mode)
+    }
+}
+
+impl ChannelModes {
+    /// The maximum amount of channels with a mode.
+    pub const CHANNELS_LEN: usize = 50;
+
+    /// Returns the mode of the channel if one is set.
+    pub fn get(&self, channel: Id<ChannelMarker>) -> Option<GameMode> {
+        self.inner
+            .iter()
+            .find(|entry| entry.channel == channel.get())
+            .map(ChannelMode::mode)
+    }
+
+    /// Set the mode of the channel, overwriting the previous one.
+    ///
+    /// Returns `false` if the channel limit has been reached.
+    pub fn set(&mut self, channel: Id<ChannelMarker>, mode: GameMode) -> bool {
+        let channel = channel.get();
+
+        match self.inner.iter_mut().find(|entry| entry.channel == channel) {
+            Some(entry) => entry.mode = mode as u8,
+            None if self.inner.len() >= Self::CHANNELS_LEN => return false,
+            None => self.inner.push(ChannelMode {
+                channel,
+                mode: mode as u8,
+            }),
+        }
+
+        true
+    }
+
+    /// Remove the mode of the channel.
+    ///
+    /// Returns `false` if the channel had no mode in the first place.
+    pub fn remove(&mut self, channel: Id<ChannelMarker>) -> bool {
+        let len = self.inner.len();
+        self.inner.retain(|entry| entry.channel != channel.get());
+
+        self.inner.len() < len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ChannelMode> {
+        self.inner.iter()
+    }
+
+    /// # Safety
+    ///
+    /// The caller must ensure that the provided bytes are valid archived
+    /// channel modes
+    pub(crate) unsafe fn deserialize(bytes: &[u8]) -> Self {
+        let archived_modes = rkyv::archived_root::<Self>(bytes);
+
+        archived_modes.deserialize(&mut Infallible).unwrap()
+    }
+}
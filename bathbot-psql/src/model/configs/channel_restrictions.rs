@@ -0,0 +1,121 @@
+use rkyv::{Archive, Deserialize, Infallible, Serialize};
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+/// Commands that may only be used in specific channels of a guild.
+#[derive(Archive, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ChannelRestrictions {
+    inner: Vec<ChannelRestriction>,
+}
+
+#[derive(Archive, Clone, Debug, Deserialize, Serialize)]
+pub struct ChannelRestriction {
+    pub command: String,
+    channels: Vec<u64>,
+}
+
+impl ChannelRestriction {
+    pub fn channels(&self) -> impl Iterator<Item = Id<ChannelMarker>> + '_ {
+        self.channels.iter().copied().map(Id::new)
+    }
+}
+
+impl ChannelRestrictions {
+    /// The maximum amount of allowed channels per command.
+    pub const CHANNELS_LEN: usize = 10;
+    /// The maximum amount of restricted commands.
+    pub const COMMANDS_LEN: usize = 25;
+
+    /// Returns the allowed channels for the command if it is restricted.
+    pub fn get(&self, command: &str) -> Option<&ChannelRestriction> {
+        self.inner
+            .iter()
+            .find(|restriction| restriction.command == command)
+    }
+
+    /// Allow the command in the channel.
+    ///
+    /// Returns `false` if the channel was already allowed or a limit has been
+    /// reached.
+    pub fn allow(&mut self, command: &str, channel: Id<ChannelMarker>) -> bool {
+        let channel = channel.get();
+
+        match self
+            .inner
+            .iter_mut()
+            .find(|restriction| restriction.command == command)
+        {
+            Some(restriction) => {
+                if restriction.channels.len() >= Self::CHANNELS_LEN
+                    || restriction.channels.contains(&channel)
+                {
+                    return false;
+                }
+
+                restriction.channels.push(channel);
+            }
+            None if self.inner.len() >= Self::COMMANDS_LEN => return false,
+            None => self.inner.push(ChannelRestriction {
+                command: command.to_owned(),
+                channels: vec![channel],
+            }),
+        }
+
+        true
+    }
+
+    /// Disallow the command in the channel. If no allowed channel remains,
+    /// the command is no longer restricted.
+    ///
+    /// Returns `false` if the channel was not allowed in the first place.
+    pub fn disallow(&mut self, command: &str, channel: Id<ChannelMarker>) -> bool {
+        let channel = channel.get();
+
+        let Some(idx) = self
+            .inner
+            .iter()
+            .position(|restriction| restriction.command == command)
+        else {
+            return false;
+        };
+
+        let channels = &mut self.inner[idx].channels;
+        let len = channels.len();
+        channels.retain(|allowed| *allowed != channel);
+        let removed = channels.len() < len;
+
+        if channels.is_empty() {
+            self.inner.swap_remove(idx);
+        }
+
+        removed
+    }
+
+    /// Remove the restriction of the command.
+    ///
+    /// Returns `false` if the command was not restricted.
+    pub fn clear(&mut self, command: &str) -> bool {
+        let len = self.inner.len();
+        self.inner
+            .retain(|restriction| restriction.command != command);
+
+        self.inner.len() < len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ChannelRestriction> {
+        self.inner.iter()
+    }
+
+    /// # Safety
+    ///
+    /// The caller must ensure that the provided bytes are valid archived
+    /// channel restrictions
+    pub(crate) unsafe fn deserialize(bytes: &[u8]) -> Self {
+        let archived_restrictions = rkyv::archived_root::<Self>(bytes);
+
+        archived_restrictions.deserialize(&mut Infallible).unwrap()
+    }
+}
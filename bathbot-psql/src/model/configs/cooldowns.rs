@@ -0,0 +1,84 @@
+use rkyv::{Archive, Deserialize, Infallible, Serialize};
+
+/// Guild-specific overrides for the ratelimit of buckets.
+///
+/// Buckets are identified by their index so that the bot can
+/// decide which buckets may be adjusted.
+#[derive(Archive, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Cooldowns {
+    inner: Vec<Cooldown>,
+}
+
+/// Replaces the full ratelimit of a bucket, not only its delay.
+#[derive(Archive, Copy, Clone, Debug, Deserialize, Serialize)]
+pub struct Cooldown {
+    pub bucket: u8,
+    /// Minimum amount of seconds between two uses
+    pub seconds: u16,
+    /// Optional amount of uses within a timespan in seconds.
+    ///
+    /// If `None`, only `seconds` limits the usage.
+    pub limit: Option<(u16, u8)>,
+}
+
+impl Cooldown {
+    /// Whether all values are within their respective bounds.
+    pub fn is_valid(&self) -> bool {
+        let limit_valid = self.limit.map_or(true, |(timespan, uses)| {
+            timespan <= Cooldowns::MAX_SECONDS && (1..=Cooldowns::MAX_USES).contains(&uses)
+        });
+
+        self.seconds <= Cooldowns::MAX_SECONDS && limit_valid
+    }
+}
+
+impl Cooldowns {
+    /// The maximum amount of seconds a cooldown or timespan can be set to.
+    pub const MAX_SECONDS: u16 = 600;
+    /// The maximum amount of uses within a timespan.
+    pub const MAX_USES: u8 = 20;
+
+    pub fn get(&self, bucket: u8) -> Option<Cooldown> {
+        self.inner
+            .iter()
+            .find(|cooldown| cooldown.bucket == bucket)
+            .copied()
+    }
+
+    /// Set the cooldown of a bucket, overwriting the previous one.
+    ///
+    /// Returns `false` if the cooldown is not [valid](Cooldown::is_valid).
+    pub fn set(&mut self, cooldown: Cooldown) -> bool {
+        if !cooldown.is_valid() {
+            return false;
+        }
+
+        self.reset(cooldown.bucket);
+        self.inner.push(cooldown);
+
+        true
+    }
+
+    /// Reset the cooldown of a bucket to its default.
+    pub fn reset(&mut self, bucket: u8) {
+        self.inner.retain(|cooldown| cooldown.bucket != bucket);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Cooldown> + '_ {
+        self.inner.iter().copied()
+    }
+
+    /// # Safety
+    ///
+    /// The caller must ensure that the provided bytes are valid archived
+    /// cooldowns
+    pub(crate) unsafe fn deserialize(bytes: &[u8]) -> Self {
+        let archived_cooldowns = rkyv::archived_root::<Self>(bytes);
+
+        archived_cooldowns.deserialize(&mut Infallible).unwrap()
+    }
+}
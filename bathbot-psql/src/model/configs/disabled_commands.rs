@@ -0,0 +1,54 @@
+use rkyv::{Archive, Deserialize, Infallible, Serialize};
+
+/// Names of commands that cannot be used within a guild.
+#[derive(Archive, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DisabledCommands {
+    inner: Vec<String>,
+}
+
+impl DisabledCommands {
+    /// The maximum amount of commands that can be disabled.
+    pub const LEN: usize = 50;
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.inner.iter().any(|disabled| disabled == name)
+    }
+
+    /// Returns `false` if the command was already disabled or the maximum
+    /// amount of disabled commands is reached.
+    pub fn insert(&mut self, name: String) -> bool {
+        if self.inner.len() >= Self::LEN || self.contains(&name) {
+            return false;
+        }
+
+        self.inner.push(name);
+
+        true
+    }
+
+    /// Returns `false` if the command was not disabled.
+    pub fn remove(&mut self, name: &str) -> bool {
+        let len = self.inner.len();
+        self.inner.retain(|disabled| disabled != name);
+
+        self.inner.len() < len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.inner.iter().map(String::as_str)
+    }
+
+    /// # Safety
+    ///
+    /// The caller must ensure that the provided bytes are valid archived
+    /// disabled commands
+    pub(crate) unsafe fn deserialize(bytes: &[u8]) -> Self {
+        let archived_commands = rkyv::archived_root::<Self>(bytes);
+
+        archived_commands.deserialize(&mut Infallible).unwrap()
+    }
+}
@@ -1,4 +1,9 @@
-use super::{list_size::ListSize, Authorities, HideSolutions, Prefixes, Retries, ScoreData};
+use rosu_v2::prelude::GameMode;
+
+use super::{
+    list_size::ListSize, Authorities, ChannelModes, ChannelRestrictions, Cooldowns,
+    DisabledCommands, HideSolutions, Prefixes, Retries, ScoreData,
+};
 
 pub struct DbGuildConfig {
     pub guild_id: i64,
@@ -12,6 +17,14 @@ pub struct DbGuildConfig {
     pub allow_custom_skins: Option<bool>,
     pub hide_medal_solution: Option<i16>,
     pub score_data: Option<i16>,
+    pub cooldowns: Option<Vec<u8>>,
+    pub disabled_commands: Option<Vec<u8>>,
+    pub channel_restrictions: Option<Vec<u8>>,
+    pub allow_foreign_interactions: Option<bool>,
+    pub pagination_timeout: Option<i16>,
+    pub gamemode: Option<i16>,
+    pub channel_modes: Option<Vec<u8>>,
+    pub require_verification: Option<bool>,
 }
 
 #[derive(Clone, Default)]
@@ -26,6 +39,18 @@ pub struct GuildConfig {
     pub allow_custom_skins: Option<bool>,
     pub hide_medal_solution: Option<HideSolutions>,
     pub score_data: Option<ScoreData>,
+    pub cooldowns: Cooldowns,
+    pub disabled_commands: DisabledCommands,
+    pub channel_restrictions: ChannelRestrictions,
+    pub allow_foreign_interactions: Option<bool>,
+    /// Seconds until paginations time out
+    pub pagination_timeout: Option<u16>,
+    /// Mode for members that did not specify one themselves
+    pub mode: Option<GameMode>,
+    /// Modes for specific channels, taking precedence over the members' modes
+    pub channel_modes: ChannelModes,
+    /// Whether only verified links count for member-wide features
+    pub require_verification: Option<bool>,
 }
 
 impl From<DbGuildConfig> for GuildConfig {
@@ -43,12 +68,32 @@ impl From<DbGuildConfig> for GuildConfig {
             allow_custom_skins,
             hide_medal_solution,
             score_data,
+            cooldowns,
+            disabled_commands,
+            channel_restrictions,
+            allow_foreign_interactions,
+            pagination_timeout,
+            gamemode,
+            channel_modes,
+            require_verification,
         } = config;
 
         // SAFETY: The bytes originate from the DB which only provides valid archived
         // data
         let authorities = unsafe { Authorities::deserialize(&authorities) };
         let prefixes = unsafe { Prefixes::deserialize(&prefixes) };
+        let cooldowns = cooldowns
+            .map(|bytes| unsafe { Cooldowns::deserialize(&bytes) })
+            .unwrap_or_default();
+        let disabled_commands = disabled_commands
+            .map(|bytes| unsafe { DisabledCommands::deserialize(&bytes) })
+            .unwrap_or_default();
+        let channel_restrictions = channel_restrictions
+            .map(|bytes| unsafe { ChannelRestrictions::deserialize(&bytes) })
+            .unwrap_or_default();
+        let channel_modes = channel_modes
+            .map(|bytes| unsafe { ChannelModes::deserialize(&bytes) })
+            .unwrap_or_default();
 
         Self {
             authorities,
@@ -63,6 +108,14 @@ impl From<DbGuildConfig> for GuildConfig {
                 .map(HideSolutions::try_from)
                 .and_then(Result::ok),
             score_data: score_data.map(ScoreData::try_from).and_then(Result::ok),
+            cooldowns,
+            disabled_commands,
+            channel_restrictions,
+            allow_foreign_interactions,
+            pagination_timeout: pagination_timeout.map(|secs| secs as u16),
+            mode: gamemode.map(|mode| GameMode::from(mode as u8)),
+            channel_modes,
+            require_verification,
         }
     }
 }
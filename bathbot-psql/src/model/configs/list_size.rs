@@ -17,6 +17,16 @@ pub enum ListSize {
     Single = 2,
 }
 
+impl ListSize {
+    /// Resolve which list size to use.
+    ///
+    /// An explicitly given size takes precedence over the user's config which
+    /// in turn takes precedence over the guild's config and then the default.
+    pub fn resolve(arg: Option<Self>, user: Option<Self>, guild: Option<Self>) -> Self {
+        arg.or(user).or(guild).unwrap_or_default()
+    }
+}
+
 impl From<ListSize> for i16 {
     #[inline]
     fn from(list_size: ListSize) -> Self {
@@ -68,3 +78,37 @@ impl Default for ListSize {
         Self::Condensed
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_default() {
+        assert_eq!(ListSize::resolve(None, None, None), ListSize::default());
+    }
+
+    #[test]
+    fn resolve_guild_over_default() {
+        let guild = Some(ListSize::Single);
+
+        assert_eq!(ListSize::resolve(None, None, guild), ListSize::Single);
+    }
+
+    #[test]
+    fn resolve_user_over_guild() {
+        let user = Some(ListSize::Detailed);
+        let guild = Some(ListSize::Single);
+
+        assert_eq!(ListSize::resolve(None, user, guild), ListSize::Detailed);
+    }
+
+    #[test]
+    fn resolve_arg_over_user() {
+        let arg = Some(ListSize::Single);
+        let user = Some(ListSize::Detailed);
+        let guild = Some(ListSize::Condensed);
+
+        assert_eq!(ListSize::resolve(arg, user, guild), ListSize::Single);
+    }
+}
@@ -1,5 +1,9 @@
 pub use self::{
     authorities::{Authorities, Authority},
+    channel_modes::{ChannelMode, ChannelModes},
+    channel_restrictions::{ChannelRestriction, ChannelRestrictions},
+    cooldowns::{Cooldown, Cooldowns},
+    disabled_commands::DisabledCommands,
     guild::{DbGuildConfig, GuildConfig},
     hide_solutions::HideSolutions,
     list_size::ListSize,
@@ -11,6 +15,10 @@ pub use self::{
 };
 
 mod authorities;
+mod channel_modes;
+mod channel_restrictions;
+mod cooldowns;
+mod disabled_commands;
 mod guild;
 mod hide_solutions;
 mod list_size;
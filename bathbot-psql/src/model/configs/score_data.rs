@@ -16,6 +16,14 @@ impl ScoreData {
     pub fn is_legacy(self) -> bool {
         self == Self::Stable
     }
+
+    /// Resolve which score data to use.
+    ///
+    /// The user's config takes precedence over the guild's config which in
+    /// turn takes precedence over the default.
+    pub fn resolve(user: Option<Self>, guild: Option<Self>) -> Self {
+        user.or(guild).unwrap_or_default()
+    }
 }
 
 impl From<ScoreData> for i16 {
@@ -36,3 +44,32 @@ impl TryFrom<i16> for ScoreData {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_default() {
+        assert_eq!(ScoreData::resolve(None, None), ScoreData::default());
+    }
+
+    #[test]
+    fn resolve_guild_over_default() {
+        let guild = Some(ScoreData::Stable);
+
+        assert_eq!(ScoreData::resolve(None, guild), ScoreData::Stable);
+    }
+
+    #[test]
+    fn resolve_user_over_guild() {
+        let user = Some(ScoreData::LazerWithClassicScoring);
+        let guild = Some(ScoreData::Stable);
+
+        assert_eq!(
+            ScoreData::resolve(user, guild),
+            ScoreData::LazerWithClassicScoring
+        );
+        assert_eq!(ScoreData::resolve(user, None), user.unwrap());
+    }
+}
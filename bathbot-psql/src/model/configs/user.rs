@@ -15,6 +15,8 @@ pub struct DbUserConfig {
     pub timezone_seconds: Option<i32>,
     pub render_button: Option<bool>,
     pub score_data: Option<i16>,
+    pub pagination_timeout: Option<i16>,
+    pub pp_decimals: Option<i16>,
 }
 
 pub trait OsuId {
@@ -46,6 +48,10 @@ pub struct UserConfig<O: OsuId> {
     pub timezone: Option<UtcOffset>,
     pub render_button: Option<bool>,
     pub score_data: Option<ScoreData>,
+    /// Seconds until paginations time out
+    pub pagination_timeout: Option<u16>,
+    /// Amount of decimal places when displaying pp values
+    pub pp_decimals: Option<u8>,
 }
 
 impl<O: OsuId> Default for UserConfig<O> {
@@ -61,6 +67,8 @@ impl<O: OsuId> Default for UserConfig<O> {
             timezone: None,
             render_button: None,
             score_data: None,
+            pagination_timeout: None,
+            pp_decimals: None,
         }
     }
 }
@@ -78,6 +86,8 @@ impl From<DbUserConfig> for UserConfig<OsuUserId> {
             timezone_seconds,
             render_button,
             score_data,
+            pagination_timeout,
+            pp_decimals,
         } = config;
 
         Self {
@@ -92,6 +102,8 @@ impl From<DbUserConfig> for UserConfig<OsuUserId> {
                 .map(Result::unwrap),
             render_button,
             score_data: score_data.map(ScoreData::try_from).and_then(Result::ok),
+            pagination_timeout: pagination_timeout.map(|secs| secs as u16),
+            pp_decimals: pp_decimals.map(|decimals| decimals as u8),
         }
     }
 }
@@ -21,6 +21,65 @@ pub struct DbMapTagEntry {
     pub image_filename: String,
 }
 
+/// The tag columns of a single `map_tags` row.
+pub struct DbMapsetTags {
+    pub farm: bool,
+    pub streams: bool,
+    pub alternate: bool,
+    pub old: bool,
+    pub meme: bool,
+    pub hardname: bool,
+    pub easy: bool,
+    pub hard: bool,
+    pub tech: bool,
+    pub weeb: bool,
+    pub bluesky: bool,
+    pub english: bool,
+    pub kpop: bool,
+}
+
+macro_rules! convert_mapset_tags {
+    ( $( $field:ident: $variant:ident ,)* ) => {
+        impl From<DbMapsetTags> for MapsetTags {
+            fn from(tags: DbMapsetTags) -> Self {
+                let mut flags = MapsetTags::empty();
+
+                $(
+                    if tags.$field {
+                        flags |= MapsetTags::$variant;
+                    }
+                )*
+
+                flags
+            }
+        }
+
+        impl From<MapsetTags> for DbMapsetTags {
+            fn from(tags: MapsetTags) -> Self {
+                Self {
+                    $( $field: tags.contains(MapsetTags::$variant), )*
+                }
+            }
+        }
+    };
+}
+
+convert_mapset_tags! {
+    farm: Farm,
+    streams: Streams,
+    alternate: Alternate,
+    old: Old,
+    meme: Meme,
+    hardname: HardName,
+    easy: Easy,
+    hard: Hard,
+    tech: Tech,
+    weeb: Weeb,
+    bluesky: BlueSky,
+    english: English,
+    kpop: Kpop,
+}
+
 macro_rules! define_map_tags {
     ( $( $column:ident ,)* ) => {
         pub struct DbMapTagsParams {
@@ -0,0 +1,13 @@
+use time::OffsetDateTime;
+use twilight_model::id::{marker::UserMarker, Id};
+
+/// A map that was requested to be played by a host.
+pub struct MapRequest {
+    pub requester_id: Id<UserMarker>,
+    pub map_id: u32,
+    pub mapset_id: u32,
+    pub artist: Box<str>,
+    pub title: Box<str>,
+    pub version: Box<str>,
+    pub insert_date: OffsetDateTime,
+}
@@ -1,4 +1,6 @@
 pub mod configs;
 pub mod games;
+pub mod map_request;
 pub mod osu;
 pub mod render;
+pub mod role_assign;
@@ -17,6 +17,17 @@ pub struct DbBeatmap {
     pub bpm: f32,
 }
 
+/// A ranked osu!standard map alongside its difficulty attributes.
+pub struct DbFarmMap {
+    pub map_id: u32,
+    pub mapset_id: u32,
+    pub artist: String,
+    pub title: String,
+    pub version: String,
+    pub seconds_drain: u32,
+    pub attrs: OsuDifficultyAttributes,
+}
+
 #[derive(Debug)]
 pub enum DbMapFilename {
     Present(Box<str>),
@@ -10,11 +10,15 @@ use time::OffsetDateTime;
 
 pub type Channels<S> = HashMap<NonZeroU64, u8, S>;
 
+/// Minimum global rank gain per channel
+pub type RankGains<S> = HashMap<NonZeroU64, u32, S>;
+
 pub struct DbTrackedOsuUser {
     pub user_id: i32,
     pub gamemode: i16,
     pub channels: Vec<u8>,
     pub last_update: OffsetDateTime,
+    pub min_rank_gains: Option<Vec<u8>>,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -27,6 +31,20 @@ pub struct TrackedOsuUserKey {
 pub struct TrackedOsuUserValue<S> {
     pub channels: Channels<S>,
     pub last_update: OffsetDateTime,
+    /// Notifications are only sent to these channels if a play improved the
+    /// user's global rank by at least the channel's amount
+    pub min_rank_gains: RankGains<S>,
+}
+
+impl<S: BuildHasher> TrackedOsuUserValue<S> {
+    /// Stop tracking in the channel.
+    ///
+    /// Returns whether the user was tracked in the channel.
+    pub fn remove_channel(&mut self, channel: NonZeroU64) -> bool {
+        self.min_rank_gains.remove(&channel);
+
+        self.channels.remove(&channel).is_some()
+    }
 }
 
 impl<S> From<DbTrackedOsuUser> for (TrackedOsuUserKey, TrackedOsuUserValue<S>)
@@ -40,6 +58,7 @@ where
             gamemode,
             channels,
             last_update,
+            min_rank_gains,
         } = user;
 
         // SAFETY: The bytes originate from the DB which only provides valid archived
@@ -47,6 +66,16 @@ where
         let archived_channels = unsafe { rkyv::archived_root::<Channels<S>>(&channels) };
         let channels = archived_channels.deserialize(&mut Infallible).unwrap();
 
+        let min_rank_gains = match min_rank_gains {
+            Some(bytes) => {
+                // SAFETY: Same as above
+                let archived_gains = unsafe { rkyv::archived_root::<RankGains<S>>(&bytes) };
+
+                archived_gains.deserialize(&mut Infallible).unwrap()
+            }
+            None => RankGains::default(),
+        };
+
         let key = TrackedOsuUserKey {
             user_id: user_id as u32,
             mode: (gamemode as u8).into(),
@@ -55,6 +84,7 @@ where
         let value = TrackedOsuUserValue {
             channels,
             last_update,
+            min_rank_gains,
         };
 
         (key, value)
@@ -0,0 +1,147 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use twilight_model::{
+    channel::message::ReactionType,
+    id::{
+        marker::{ChannelMarker, EmojiMarker, GuildMarker, MessageMarker, RoleMarker},
+        Id,
+    },
+};
+
+/// Reacting with the emoji on the message assigns the role.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoleAssign {
+    pub guild_id: Id<GuildMarker>,
+    pub channel_id: Id<ChannelMarker>,
+    pub message_id: Id<MessageMarker>,
+    pub emoji: RoleAssignEmoji,
+    pub role_id: Id<RoleMarker>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RoleAssignEmoji {
+    Custom(Id<EmojiMarker>),
+    Unicode(Box<str>),
+}
+
+impl RoleAssignEmoji {
+    /// Whether the reaction was made with this emoji.
+    pub fn matches(&self, reaction: &ReactionType) -> bool {
+        match (self, reaction) {
+            (Self::Custom(emoji_id), ReactionType::Custom { id, .. }) => emoji_id == id,
+            (Self::Unicode(emoji), ReactionType::Unicode { name }) => same_unicode(emoji, name),
+            _ => false,
+        }
+    }
+
+    /// Whether both emojis are the same, ignoring variation selectors.
+    pub fn is_same(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Custom(a), Self::Custom(b)) => a == b,
+            (Self::Unicode(a), Self::Unicode(b)) => same_unicode(a, b),
+            _ => false,
+        }
+    }
+
+    pub(crate) fn from_db(emoji: String) -> Self {
+        match emoji.parse().ok().and_then(Id::new_checked) {
+            Some(id) => Self::Custom(id),
+            None => Self::Unicode(emoji.into_boxed_str()),
+        }
+    }
+
+    pub(crate) fn to_db(&self) -> String {
+        match self {
+            Self::Custom(id) => id.to_string(),
+            Self::Unicode(emoji) => emoji.to_string(),
+        }
+    }
+}
+
+fn same_unicode(a: &str, b: &str) -> bool {
+    // Clients are inconsistent on whether they send variation selectors
+    let strip = |s: &str| s.replace('\u{fe0f}', "");
+
+    strip(a) == strip(b)
+}
+
+impl Display for RoleAssignEmoji {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Custom(id) => write!(f, "<:_:{id}>"),
+            Self::Unicode(emoji) => f.write_str(emoji),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_custom() {
+        let emoji = RoleAssignEmoji::Custom(Id::new(123));
+
+        let same = ReactionType::Custom {
+            animated: false,
+            id: Id::new(123),
+            name: Some("name".to_owned()),
+        };
+
+        let other = ReactionType::Custom {
+            animated: false,
+            id: Id::new(456),
+            name: Some("name".to_owned()),
+        };
+
+        let unicode = ReactionType::Unicode {
+            name: "123".to_owned(),
+        };
+
+        assert!(emoji.matches(&same));
+        assert!(!emoji.matches(&other));
+        assert!(!emoji.matches(&unicode));
+    }
+
+    #[test]
+    fn matches_unicode() {
+        let emoji = RoleAssignEmoji::Unicode("❤️".into());
+
+        let same = ReactionType::Unicode {
+            name: "❤️".to_owned(),
+        };
+
+        let without_selector = ReactionType::Unicode {
+            name: "❤".to_owned(),
+        };
+
+        let other = ReactionType::Unicode {
+            name: "👍".to_owned(),
+        };
+
+        assert!(emoji.matches(&same));
+        assert!(emoji.matches(&without_selector));
+        assert!(!emoji.matches(&other));
+    }
+
+    #[test]
+    fn same_ignoring_selector() {
+        let with_selector = RoleAssignEmoji::Unicode("❤️".into());
+        let without_selector = RoleAssignEmoji::Unicode("❤".into());
+        let other = RoleAssignEmoji::Unicode("👍".into());
+
+        assert!(with_selector.is_same(&without_selector));
+        assert!(without_selector.is_same(&with_selector));
+        assert!(!with_selector.is_same(&other));
+        assert!(!with_selector.is_same(&RoleAssignEmoji::Custom(Id::new(123))));
+    }
+
+    #[test]
+    fn db_roundtrip() {
+        let custom = RoleAssignEmoji::Custom(Id::new(123));
+        let unicode = RoleAssignEmoji::Unicode("👍".into());
+
+        assert_eq!(RoleAssignEmoji::from_db(custom.to_db()), custom);
+        assert_eq!(RoleAssignEmoji::from_db(unicode.to_db()), unicode);
+    }
+}
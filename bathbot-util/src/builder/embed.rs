@@ -1,4 +1,4 @@
-use std::num::NonZeroU32;
+use std::{fmt::Write, num::NonZeroU32};
 
 use time::OffsetDateTime;
 use twilight_model::{
@@ -8,7 +8,9 @@ use twilight_model::{
 
 use super::footer::IntoFooterBuilder;
 use crate::{
-    constants::{DARK_GREEN, RED},
+    constants::{
+        DARK_GREEN, DESCRIPTION_SIZE, EMBED_FIELD_COUNT, EMBED_SIZE, FIELD_VALUE_SIZE, RED,
+    },
     AuthorBuilder, FooterBuilder,
 };
 
@@ -20,6 +22,7 @@ pub struct EmbedBuilder {
     pub fields: Vec<EmbedField>,
     pub footer: Option<FooterBuilder>,
     pub image_url: Option<String>,
+    pub max_fields: Option<usize>,
     pub thumbnail_url: Option<String>,
     pub timestamp: Option<Timestamp>,
     pub title: Option<String>,
@@ -31,7 +34,9 @@ impl EmbedBuilder {
         Self::default()
     }
 
-    pub fn build(self) -> Embed {
+    pub fn build(mut self) -> Embed {
+        self.truncate_to_limits();
+
         Embed {
             author: self.author.map(AuthorBuilder::build),
             color: Some(self.color.map_or(DARK_GREEN, NonZeroU32::get)),
@@ -59,6 +64,82 @@ impl EmbedBuilder {
         }
     }
 
+    /// Cut off excess fields and lines so that discord won't reject the embed.
+    /// Whatever is cut off will be replaced by an "…and N more" note.
+    fn truncate_to_limits(&mut self) {
+        if let Some(ref mut description) = self.description {
+            truncate_lines(description, DESCRIPTION_SIZE);
+        }
+
+        for field in self.fields.iter_mut() {
+            truncate_lines(&mut field.value, FIELD_VALUE_SIZE);
+        }
+
+        let max_fields = self
+            .max_fields
+            .map_or(EMBED_FIELD_COUNT, |max| max.clamp(1, EMBED_FIELD_COUNT));
+
+        if self.fields.len() > max_fields {
+            // The last field is replaced by the note
+            let remaining = self.fields.len() - (max_fields - 1);
+            self.fields.truncate(max_fields - 1);
+
+            self.fields.push(more_field(remaining));
+        }
+
+        self.truncate_to_total();
+    }
+
+    /// Make sure the combined size stays within [`EMBED_SIZE`] by first
+    /// dropping fields and then shortening the description.
+    fn truncate_to_total(&mut self) {
+        // Enough space for the note that replaces dropped fields
+        const NOTE_RESERVED: usize = 32;
+
+        let fields_len = |fields: &[EmbedField]| -> usize {
+            fields
+                .iter()
+                .map(|field| field.name.len() + field.value.len())
+                .sum()
+        };
+
+        let other_len = self.title.as_ref().map_or(0, String::len)
+            + self.description.as_ref().map_or(0, String::len)
+            + self.footer.as_ref().map_or(0, |footer| footer.text.len())
+            + self.author.as_ref().map_or(0, |author| author.name.len());
+
+        if other_len + fields_len(&self.fields) <= EMBED_SIZE {
+            return;
+        }
+
+        let mut kept_len = other_len + NOTE_RESERVED;
+
+        let keep = self
+            .fields
+            .iter()
+            .take_while(|field| {
+                kept_len += field.name.len() + field.value.len();
+
+                kept_len <= EMBED_SIZE
+            })
+            .count();
+
+        if keep < self.fields.len() {
+            let remaining = self.fields.len() - keep;
+            self.fields.truncate(keep);
+            self.fields.push(more_field(remaining));
+        }
+
+        let total_len = other_len + fields_len(&self.fields);
+
+        if total_len > EMBED_SIZE {
+            if let Some(ref mut description) = self.description {
+                let max_len = description.len().saturating_sub(total_len - EMBED_SIZE);
+                truncate_lines(description, max_len);
+            }
+        }
+    }
+
     pub fn author(mut self, author: AuthorBuilder) -> Self {
         self.author = Some(author);
 
@@ -98,6 +179,14 @@ impl EmbedBuilder {
         self
     }
 
+    /// Limit the amount of fields, defaults to and is capped at discord's
+    /// limit of [`EMBED_FIELD_COUNT`].
+    pub fn max_fields(mut self, max_fields: usize) -> Self {
+        self.max_fields = Some(max_fields);
+
+        self
+    }
+
     pub fn push_field(&mut self, field: EmbedField) {
         self.fields.push(field);
     }
@@ -146,3 +235,164 @@ impl EmbedBuilder {
         self
     }
 }
+
+/// Truncate the text to full lines so that it fits into `max_len` bytes,
+/// including a note about how many lines were cut off.
+fn more_field(remaining: usize) -> EmbedField {
+    EmbedField {
+        inline: false,
+        name: "\u{2026}".to_owned(),
+        value: format!("\u{2026}and {remaining} more"),
+    }
+}
+
+fn truncate_lines(text: &mut String, max_len: usize) {
+    // Enough space for the closing code block and the note
+    const RESERVED: usize = 32;
+
+    if text.len() <= max_len {
+        return;
+    }
+
+    let mut cut = max_len.saturating_sub(RESERVED);
+
+    while !text.is_char_boundary(cut) {
+        cut -= 1;
+    }
+
+    if let Some(newline) = text[..cut].rfind('\n') {
+        cut = newline;
+    }
+
+    let remaining = text[cut..]
+        .lines()
+        .filter(|line| !line.is_empty() && *line != "```")
+        .count();
+    text.truncate(cut);
+
+    // Don't leave a code block unclosed
+    if text.matches("```").count() % 2 == 1 {
+        text.push_str("\n```");
+    }
+
+    let _ = write!(text, "\n\u{2026}and {remaining} more");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(i: usize) -> EmbedField {
+        EmbedField {
+            inline: true,
+            name: format!("Field #{i}"),
+            value: i.to_string(),
+        }
+    }
+
+    #[test]
+    fn truncates_oversized_field_list() {
+        let fields = (0..40).map(field).collect();
+        let embed = EmbedBuilder::new().fields(fields).build();
+
+        assert_eq!(embed.fields.len(), EMBED_FIELD_COUNT);
+        assert_eq!(embed.fields[EMBED_FIELD_COUNT - 2].value, "23");
+        assert_eq!(
+            embed.fields[EMBED_FIELD_COUNT - 1].value,
+            "\u{2026}and 16 more"
+        );
+    }
+
+    #[test]
+    fn respects_custom_max_fields() {
+        let fields = (0..10).map(field).collect();
+        let embed = EmbedBuilder::new().fields(fields).max_fields(5).build();
+
+        assert_eq!(embed.fields.len(), 5);
+        assert_eq!(embed.fields[4].value, "\u{2026}and 6 more");
+
+        let fields = (0..30).map(field).collect();
+        let embed = EmbedBuilder::new().fields(fields).max_fields(100).build();
+
+        assert_eq!(embed.fields.len(), EMBED_FIELD_COUNT);
+    }
+
+    #[test]
+    fn keeps_small_embeds_untouched() {
+        let fields: Vec<_> = (0..EMBED_FIELD_COUNT).map(field).collect();
+
+        let embed = EmbedBuilder::new()
+            .description("line 1\nline 2")
+            .fields(fields.clone())
+            .build();
+
+        assert_eq!(embed.description.as_deref(), Some("line 1\nline 2"));
+        assert_eq!(embed.fields, fields);
+    }
+
+    #[test]
+    fn truncates_oversized_description() {
+        let description: String = (0..1000).map(|i| format!("Entry #{i}\n")).collect();
+        let embed = EmbedBuilder::new().description(description).build();
+        let description = embed.description.unwrap();
+
+        assert!(description.len() <= DESCRIPTION_SIZE);
+        assert!(description.starts_with("Entry #0\n"));
+
+        let (kept, note) = description.rsplit_once('\n').unwrap();
+        let kept_lines = kept.lines().count();
+
+        assert_eq!(note, format!("\u{2026}and {} more", 1000 - kept_lines));
+    }
+
+    #[test]
+    fn closes_code_block_of_oversized_field() {
+        let mut value = String::from("```\n");
+        value.extend((0..200).map(|i| format!("{i:>4} | entry\n")));
+        value.push_str("```");
+
+        let fields = vec![EmbedField {
+            inline: false,
+            name: "List".to_owned(),
+            value,
+        }];
+
+        let embed = EmbedBuilder::new().fields(fields).build();
+        let value = &embed.fields[0].value;
+
+        assert!(value.len() <= FIELD_VALUE_SIZE);
+        assert_eq!(value.matches("```").count(), 2);
+        assert!(value.ends_with("more"));
+    }
+
+    #[test]
+    fn respects_total_size() {
+        let value: String = (0..100).map(|i| format!("{i:>4} | entry\n")).collect();
+        let fields = (0..10)
+            .map(|i| EmbedField {
+                inline: false,
+                name: format!("Field #{i}"),
+                value: value[..FIELD_VALUE_SIZE - 100].to_owned(),
+            })
+            .collect();
+
+        let description: String = (0..400).map(|i| format!("Entry #{i}\n")).collect();
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .fields(fields)
+            .footer(FooterBuilder::new("footer"))
+            .build();
+
+        let total_len = embed.description.as_ref().map_or(0, String::len)
+            + embed
+                .fields
+                .iter()
+                .map(|field| field.name.len() + field.value.len())
+                .sum::<usize>()
+            + embed.footer.as_ref().map_or(0, |footer| footer.text.len());
+
+        assert!(total_len <= EMBED_SIZE);
+        assert!(embed.fields.last().unwrap().value.ends_with("more"));
+    }
+}
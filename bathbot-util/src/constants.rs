@@ -7,6 +7,9 @@ pub const RED: u32 = 0xE74C3C;
 // Message field sizes
 pub const DESCRIPTION_SIZE: usize = 4096;
 pub const FIELD_VALUE_SIZE: usize = 1024;
+pub const EMBED_FIELD_COUNT: usize = 25;
+/// Combined size of title, description, fields, footer, and author
+pub const EMBED_SIZE: usize = 6000;
 
 // osu!
 pub const OSU_BASE: &str = "https://osu.ppy.sh/";
@@ -37,6 +40,7 @@ pub const INVALID_ACTION_FOR_CHANNEL_TYPE: u64 = 50024;
 pub const MESSAGE_TOO_OLD_TO_BULK_DELETE: u64 = 50034;
 
 pub const UNKNOWN_CHANNEL: u64 = 10003;
+pub const UNKNOWN_MEMBER: u64 = 10007;
 
 // Misc
 pub const INVITE_LINK: &str = "https://discord.com/api/oauth2/authorize?client_id=297073686916366336&permissions=309238025216&scope=bot%20applications.commands";
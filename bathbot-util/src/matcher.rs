@@ -2,7 +2,7 @@ use std::{borrow::Cow, sync::OnceLock};
 
 use rosu_v2::prelude::{GameMode, GameModsIntermode, UserId as OsuUserId};
 use twilight_model::id::{
-    marker::{RoleMarker, UserMarker},
+    marker::{EmojiMarker, MessageMarker, RoleMarker, UserMarker},
     Id,
 };
 
@@ -16,6 +16,30 @@ pub fn is_custom_emote(msg: &str) -> bool {
     EMOJI_MATCHER.get().is_match(msg)
 }
 
+/// Parse a custom emote of the form `<:name:id>` or `<a:name:id>` into its id
+/// and name.
+pub fn get_custom_emote(msg: &str) -> Option<(Id<EmojiMarker>, &str)> {
+    let captures = EMOJI_MATCHER.get().captures(msg)?;
+    let name = captures.get(2)?.as_str();
+    let id = captures.get(3)?.as_str().parse().ok()?;
+
+    Id::new_checked(id).map(|id| (id, name))
+}
+
+/// Parse either a message id or a message link.
+pub fn get_message_id(msg: &str) -> Option<Id<MessageMarker>> {
+    if let Ok(id) = msg.parse() {
+        return Id::new_checked(id);
+    }
+
+    MESSAGE_LINK_MATCHER
+        .get()
+        .captures(msg)
+        .and_then(|c| c.get(1))
+        .and_then(|c| c.as_str().parse().ok())
+        .and_then(Id::new_checked)
+}
+
 enum MentionType {
     Role,
     User,
@@ -193,6 +217,8 @@ define_regex! {
 
     EMOJI_MATCHER: r"<(a?):([^:\n]+):(\d+)>";
 
+    MESSAGE_LINK_MATCHER: r"https://(?:\w+\.)?discord(?:app)?\.com/channels/(?:\d+|@me)/\d+/(\d+)";
+
     SEVEN_TWO_SEVEN: "(?P<num>7[.,]?2[.,]?7)";
 
     OSU_SCORE_URL_MATCHER: r"https://osu.ppy.sh/scores/(osu|taiko|mania|fruits)/(\d+)";
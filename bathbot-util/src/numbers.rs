@@ -8,6 +8,13 @@ pub fn round(n: f32) -> f32 {
     (100.0 * n).round() / 100.0
 }
 
+/// Round the number to the given amount of decimal places.
+pub fn round_to(n: f32, decimals: u8) -> f32 {
+    let factor = 10_f32.powi(decimals as i32);
+
+    (factor * n).round() / factor
+}
+
 pub struct WithComma<N> {
     num: N,
 }
@@ -306,6 +313,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_round_to() {
+        assert_eq!(round_to(3.1615, 0), 3.0);
+        assert_eq!(round_to(3.1615, 1), 3.2);
+        assert_eq!(round_to(3.1615, 2), 3.16);
+    }
+
     #[test]
     fn test_with_comma_int() {
         assert_eq!(
@@ -1,4 +1,5 @@
 use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
     iter::{self, Copied, Map},
     slice::Iter,
 };
@@ -126,6 +127,40 @@ pub fn flag_url_svg(country_code: &str) -> String {
     url
 }
 
+/// Displays the discord emoji of a country's flag e.g. `:flag_de:`.
+///
+/// Codes without a flag emoji, like osu!'s `XX` for unknown countries, are
+/// displayed as `:pirate_flag:`.
+pub struct FlagEmote<'c> {
+    country_code: &'c str,
+}
+
+impl<'c> FlagEmote<'c> {
+    pub fn new(country_code: &'c str) -> Self {
+        Self { country_code }
+    }
+}
+
+impl Display for FlagEmote<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.country_code.as_bytes() {
+            [byte0, byte1]
+                if byte0.is_ascii_alphabetic()
+                    && byte1.is_ascii_alphabetic()
+                    && !self.country_code.eq_ignore_ascii_case("xx") =>
+            {
+                write!(
+                    f,
+                    ":flag_{}{}:",
+                    byte0.to_ascii_lowercase() as char,
+                    byte1.to_ascii_lowercase() as char
+                )
+            }
+            _ => f.write_str(":pirate_flag:"),
+        }
+    }
+}
+
 pub trait ExtractablePp {
     fn extract_pp(&self) -> Vec<f32>;
 }
@@ -61,6 +61,15 @@ impl ActiveMessagesBuilder {
 
             let components = active_msg.build_components();
 
+            let configurable =
+                active_msg.until_timeout().is_some() && active_msg.configurable_timeout();
+
+            let configured_timeout = if configurable {
+                orig.pagination_timeout().await
+            } else {
+                None
+            };
+
             let mut builder = MessageBuilder::new().embed(embed).components(components);
 
             if let Some(ref content) = content {
@@ -87,6 +96,8 @@ impl ActiveMessagesBuilder {
             let (activity_tx, activity_rx) = watch::channel(());
 
             if let Some(until_timeout) = active_msg.until_timeout() {
+                let until_timeout = configured_timeout.unwrap_or(until_timeout);
+
                 ActiveMessagesBuilder::spawn_timeout(activity_rx, response, until_timeout);
 
                 let full = FullActiveMessage {
@@ -6,7 +6,8 @@ use std::{
 use bathbot_macros::PaginationBuilder;
 use bathbot_model::{OsekaiBadge, OsekaiBadgeOwner};
 use bathbot_util::{
-    constants::OSU_BASE, datetime::DATE_FORMAT, fields, CowUtils, EmbedBuilder, FooterBuilder,
+    constants::OSU_BASE, datetime::DATE_FORMAT, fields, osu::FlagEmote, CowUtils, EmbedBuilder,
+    FooterBuilder,
 };
 use eyre::{Result, WrapErr};
 use futures::future::BoxFuture;
@@ -88,8 +89,8 @@ impl BadgesPagination {
             } else {
                 writeln!(
                     owners_str,
-                    ":flag_{code}: [{name}]({OSU_BASE}u/{user_id})",
-                    code = owner.country_code.to_ascii_lowercase(),
+                    "{flag} [{name}]({OSU_BASE}u/{user_id})",
+                    flag = FlagEmote::new(owner.country_code.as_str()),
                     name = owner.username.cow_escape_markdown(),
                     user_id = owner.user_id
                 )
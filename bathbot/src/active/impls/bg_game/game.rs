@@ -1,6 +1,6 @@
 use std::{collections::VecDeque, sync::RwLock};
 
-use bathbot_model::Effects;
+use bathbot_model::{Effects, HintTypes};
 use bathbot_psql::model::games::MapsetTagsEntries;
 use bathbot_util::{constants::OSU_BASE, CowUtils};
 use eyre::{Result, WrapErr};
@@ -17,7 +17,12 @@ use twilight_model::id::{
 };
 use twilight_standby::future::WaitForMessageStream;
 
-use super::{hints::Hints, img_reveal::ImageReveal, mapset::GameMapset, util};
+use super::{
+    hints::{Hint, Hints},
+    img_reveal::ImageReveal,
+    mapset::GameMapset,
+    util,
+};
 use crate::{commands::fun::GameDifficulty, core::BotConfig, util::ChannelExt, Context};
 
 pub struct Game {
@@ -32,10 +37,11 @@ impl Game {
         entries: &MapsetTagsEntries,
         previous_ids: &mut VecDeque<i32>,
         effects: Effects,
+        hint_types: HintTypes,
         difficulty: GameDifficulty,
     ) -> (Self, Vec<u8>) {
         loop {
-            match Game::new_(entries, previous_ids, effects, difficulty).await {
+            match Game::new_(entries, previous_ids, effects, hint_types, difficulty).await {
                 Ok(game) => {
                     let sub_image_result = { game.reveal.read().unwrap().sub_image() };
 
@@ -61,6 +67,7 @@ impl Game {
         entries: &MapsetTagsEntries,
         previous_ids: &mut VecDeque<i32>,
         effects: Effects,
+        hint_types: HintTypes,
         difficulty: GameDifficulty,
     ) -> Result<Self> {
         let mut path = BotConfig::get().paths.backgrounds.clone();
@@ -121,7 +128,7 @@ impl Game {
         let (mapset_, img) = tokio::try_join!(GameMapset::new(mapset.mapset_id as u32), img_fut)?;
 
         Ok(Self {
-            hints: RwLock::new(Hints::new(mapset_.title())),
+            hints: RwLock::new(Hints::new(mapset_.title(), hint_types)),
             difficulty: difficulty.factor(),
             mapset: mapset_,
             reveal: RwLock::new(ImageReveal::new(img)),
@@ -135,10 +142,27 @@ impl Game {
         reveal.sub_image()
     }
 
-    pub fn hint(&self) -> String {
-        let mut hints = self.hints.write().unwrap();
+    pub fn hint(&self, kind: Option<HintTypes>) -> Result<HintContent> {
+        let hint = {
+            let mut hints = self.hints.write().unwrap();
+
+            hints.get(kind, self.mapset.title(), self.mapset.artist())
+        };
 
-        hints.get(self.mapset.title(), self.mapset.artist())
+        match hint {
+            Hint::Text(content) => Ok(HintContent {
+                content,
+                image: None,
+            }),
+            Hint::Region => {
+                let image = self.reveal.read().unwrap().blurred_region()?;
+
+                Ok(HintContent {
+                    content: "Here's a blurred region of the background".to_owned(),
+                    image: Some(image),
+                })
+            }
+        }
     }
 
     pub fn mapset_id(&self) -> u32 {
@@ -164,6 +188,11 @@ impl Game {
     }
 }
 
+pub struct HintContent {
+    pub content: String,
+    pub image: Option<Vec<u8>>,
+}
+
 #[derive(Clone, Copy)]
 pub enum LoopResult {
     Winner(Id<UserMarker>),
@@ -4,7 +4,7 @@ use std::{
     sync::Arc,
 };
 
-use bathbot_model::Effects;
+use bathbot_model::{Effects, HintTypes};
 use bathbot_psql::model::games::MapsetTagsEntries;
 use bathbot_util::{constants::OSU_BASE, IntHasher, MessageBuilder};
 use eyre::Result;
@@ -20,7 +20,7 @@ use twilight_model::{
     id::{marker::ChannelMarker, Id},
 };
 
-use super::game::{game_loop, Game, LoopResult};
+use super::game::{game_loop, Game, HintContent, LoopResult};
 use crate::{commands::fun::GameDifficulty, util::ChannelExt, Context};
 
 const GAME_LEN: Duration = Duration::from_secs(180);
@@ -36,6 +36,7 @@ impl BackgroundGame {
         channel: Id<ChannelMarker>,
         entries: MapsetTagsEntries,
         effects: Effects,
+        hint_types: HintTypes,
         difficulty: GameDifficulty,
     ) -> Self {
         let (tx, mut rx) = mpsc::unbounded_channel();
@@ -47,7 +48,8 @@ impl BackgroundGame {
         let mut scores = HashMap::with_hasher(IntHasher);
 
         // Initialize game
-        let (game, mut img) = Game::new(&entries, &mut previous_ids, effects, difficulty).await;
+        let (game, mut img) =
+            Game::new(&entries, &mut previous_ids, effects, hint_types, difficulty).await;
         let game = Arc::new(RwLock::new(game));
         let game_clone = Arc::clone(&game);
 
@@ -100,7 +102,10 @@ impl BackgroundGame {
                         }
 
                         // Store score for winners
-                        if let Err(err) = Context::games().bggame_increment_scores(&scores).await {
+                        let increment_fut =
+                            Context::games().bggame_increment_scores(&scores, entries.mode);
+
+                        if let Err(err) = increment_fut.await {
                             warn!("{err:?}");
                         }
 
@@ -117,7 +122,7 @@ impl BackgroundGame {
 
                 // Initialize next game
                 let (game, img_) =
-                    Game::new(&entries, &mut previous_ids, effects, difficulty).await;
+                    Game::new(&entries, &mut previous_ids, effects, hint_types, difficulty).await;
                 img = img_;
                 *game_clone.write().await = game;
             }
@@ -146,11 +151,11 @@ impl BackgroundGame {
             .sub_image()
     }
 
-    pub async fn hint(&self) -> Result<String> {
+    pub async fn hint(&self, kind: Option<HintTypes>) -> Result<HintContent> {
         let game = timeout(Duration::from_secs(1), self.game.read())
             .await
             .map_err(|_| eyre!("timeout while waiting for write"))?;
 
-        Ok(game.hint())
+        game.hint(kind)
     }
 }
@@ -1,16 +1,38 @@
 use std::iter;
 
+use bathbot_model::HintTypes;
 use rand::seq::SliceRandom;
 
+/// How many title hints can be given per background
+const MAX_TITLE_HINTS: u8 = 8;
+
+/// How many artist hints can be given per background
+const MAX_ARTIST_HINTS: u8 = 1;
+
+/// How many length hints can be given per background
+const MAX_LENGTH_HINTS: u8 = 1;
+
+/// How many blurred regions can be revealed per background
+const MAX_REGIONS: u8 = 2;
+
 pub struct Hints {
     pub artist_guessed: bool,
-    hint_level: u8,
+    allowed: HintTypes,
+    title_given: u8,
+    artist_given: u8,
+    length_given: u8,
+    regions_given: u8,
     title_mask: Box<[bool]>,
     indices: Vec<usize>,
 }
 
+pub enum Hint {
+    Text(String),
+    Region,
+}
+
 impl Hints {
-    pub fn new(title: &str) -> Self {
+    pub fn new(title: &str, allowed: HintTypes) -> Self {
         // Indices of chars that still need to be revealed
         let mut indices: Vec<_> = title
             .chars()
@@ -29,40 +51,117 @@ impl Hints {
 
         Self {
             artist_guessed: false,
-            hint_level: 0,
+            allowed,
+            title_given: 0,
+            artist_given: 0,
+            length_given: 0,
+            regions_given: 0,
             title_mask,
             indices,
         }
     }
 
-    pub fn get(&mut self, title: &str, artist: &str) -> String {
-        self.hint_level = self.hint_level.saturating_add(1);
+    /// Get a hint of the given type or, if none is specified, the next
+    /// available one.
+    pub fn get(&mut self, kind: Option<HintTypes>, title: &str, artist: &str) -> Hint {
+        if self.allowed.is_empty() {
+            return Hint::Text("Hints are disabled for this game".to_owned());
+        }
 
-        if self.hint_level == 1 {
+        let kind = match kind {
+            Some(kind) if !self.allowed.contains(kind) => {
+                let content = format!(
+                    "That hint type is disabled for this game, available: {}",
+                    self.allowed.join(", ")
+                );
+
+                return Hint::Text(content);
+            }
+            Some(kind) => kind,
+            None => match self.next_kind() {
+                Some(kind) => kind,
+                None => return Hint::Text("No hints left for this background".to_owned()),
+            },
+        };
+
+        let (given, limit) = self.counter(kind);
+
+        if given >= limit {
+            let content = format!(
+                "Already gave {limit} {kind:?} hint{plural} for this background",
+                plural = if limit != 1 { "s" } else { "" },
+            );
+
+            return Hint::Text(content);
+        }
+
+        if kind == HintTypes::Artist {
+            self.artist(artist)
+        } else if kind == HintTypes::Length {
+            self.length(title)
+        } else if kind == HintTypes::Region {
+            self.region()
+        } else {
+            self.title(title)
+        }
+    }
+
+    /// The amount of given hints of the kind and the kind's limit.
+    fn counter(&self, kind: HintTypes) -> (u8, u8) {
+        if kind == HintTypes::Artist {
+            (self.artist_given, MAX_ARTIST_HINTS)
+        } else if kind == HintTypes::Length {
+            (self.length_given, MAX_LENGTH_HINTS)
+        } else if kind == HintTypes::Region {
+            (self.regions_given, MAX_REGIONS)
+        } else {
+            (self.title_given, MAX_TITLE_HINTS)
+        }
+    }
+
+    fn available(&self, kind: HintTypes) -> bool {
+        let (given, limit) = self.counter(kind);
+
+        self.allowed.contains(kind) && given < limit
+    }
+
+    /// The next hint type that is allowed and not used up yet.
+    fn next_kind(&self) -> Option<HintTypes> {
+        let artist_available = self.available(HintTypes::Artist) && !self.artist_guessed;
+
+        if self.available(HintTypes::Title) && self.title_given == 0 {
+            Some(HintTypes::Title)
+        } else if artist_available {
+            Some(HintTypes::Artist)
+        } else if self.available(HintTypes::Title) {
+            Some(HintTypes::Title)
+        } else if self.available(HintTypes::Length) {
+            Some(HintTypes::Length)
+        } else if self.available(HintTypes::Region) {
+            Some(HintTypes::Region)
+        } else {
+            None
+        }
+    }
+
+    fn title(&mut self, title: &str) -> Hint {
+        self.title_given += 1;
+
+        if self.title_given == 1 {
             let word_count = title.split(' ').count();
 
-            format!(
+            let content = format!(
                 "Let me give you a hint: The title has {amount} \
                 word{plural} and the starting letter is `{first}`",
                 amount = word_count,
                 plural = if word_count != 1 { "s" } else { "" },
                 first = title.chars().next().unwrap(),
-            )
-        } else if self.hint_level == 2 && !self.artist_guessed {
-            let mut artist_hint = "Here's my second hint: The artist looks like `".to_owned();
-            artist_hint.reserve(3 * artist.len() - 1);
-
-            let mut artist_iter = artist.chars();
+            );
 
-            if let Some(c) = artist_iter.next() {
-                artist_hint.push(c);
-                artist_hint.extend(artist_iter.map(|c| if c == ' ' { c } else { '▢' }));
-            }
-
-            artist_hint.push('`');
+            return Hint::Text(content);
+        }
 
-            artist_hint
-        } else if let Some(i) = self.indices.pop() {
+        let content = if let Some(i) = self.indices.pop() {
             self.title_mask[i] = true;
 
             let mut title_hint = "Slowly constructing the title: `".to_owned();
@@ -79,6 +178,53 @@ impl Hints {
             title_hint
         } else {
             format!("Bruh the title is literally `{title}` xd")
+        };
+
+        Hint::Text(content)
+    }
+
+    fn artist(&mut self, artist: &str) -> Hint {
+        if self.artist_guessed {
+            return Hint::Text(
+                "The artist was already guessed, it's the title you need".to_owned(),
+            );
+        }
+
+        self.artist_given += 1;
+
+        let mut artist_hint = "Here's a hint: The artist looks like `".to_owned();
+        artist_hint.reserve(3 * artist.len() - 1);
+
+        let mut artist_iter = artist.chars();
+
+        if let Some(c) = artist_iter.next() {
+            artist_hint.push(c);
+            artist_hint.extend(artist_iter.map(|c| if c == ' ' { c } else { '▢' }));
         }
+
+        artist_hint.push('`');
+
+        Hint::Text(artist_hint)
+    }
+
+    fn length(&mut self, title: &str) -> Hint {
+        self.length_given += 1;
+
+        let len = title.chars().filter(|c| *c != ' ').count();
+        let word_count = title.split(' ').count();
+
+        let content = format!(
+            "The title consists of {len} character{plural} across {word_count} word{word_plural}",
+            plural = if len != 1 { "s" } else { "" },
+            word_plural = if word_count != 1 { "s" } else { "" },
+        );
+
+        Hint::Text(content)
+    }
+
+    fn region(&mut self) -> Hint {
+        self.regions_given += 1;
+
+        Hint::Region
     }
 }
@@ -33,6 +33,28 @@ impl ImageReveal {
         self.radius += 75;
     }
 
+    /// Crop a random region of the image and blur it.
+    pub fn blurred_region(&self) -> Result<Vec<u8>> {
+        const SIZE: u32 = 200;
+
+        let (w, h) = self.dim;
+        let size_w = SIZE.min(w);
+        let size_h = SIZE.min(h);
+        let mut rng = rand::thread_rng();
+        let cx = rng.next_u32() % (w - size_w + 1);
+        let cy = rng.next_u32() % (h - size_h + 1);
+
+        let region = self.original.crop_imm(cx, cy, size_w, size_h).blur(6.0);
+        let png_bytes: Vec<u8> = Vec::with_capacity((size_w * size_h) as usize);
+
+        let mut cursor = Cursor::new(png_bytes);
+        region
+            .write_to(&mut cursor, Png)
+            .wrap_err("Failed to encode image")?;
+
+        Ok(cursor.into_inner())
+    }
+
     pub fn sub_image(&self) -> Result<Vec<u8>> {
         let cx = self.x.saturating_sub(self.radius);
         let cy = self.y.saturating_sub(self.radius);
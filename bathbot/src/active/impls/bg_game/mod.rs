@@ -1,4 +1,4 @@
-use bathbot_model::{Effects, MapsetTags};
+use bathbot_model::{Effects, HintTypes, MapsetTags};
 use bathbot_psql::model::games::DbMapTagsParams;
 use bathbot_util::{constants::GENERAL_ISSUE, fields, EmbedBuilder, FooterBuilder, MessageBuilder};
 use eyre::{Report, Result};
@@ -17,7 +17,7 @@ use twilight_model::{
 
 pub use self::game_wrapper::BackgroundGame;
 use crate::{
-    active::{BuildPage, ComponentResult, IActiveMessage},
+    active::{can_interact, BuildPage, ComponentResult, IActiveMessage},
     commands::fun::GameDifficulty,
     core::Context,
     util::{interaction::InteractionComponent, Authored, ComponentExt},
@@ -35,6 +35,7 @@ pub struct BackgroundGameSetup {
     effects: Effects,
     excluded: MapsetTags,
     included: MapsetTags,
+    hint_types: HintTypes,
     state: SetupState,
     msg_owner: Id<UserMarker>,
 }
@@ -66,6 +67,10 @@ impl IActiveMessage for BackgroundGameSetup {
             fields![fields { "Effects", self.effects.join(", "), false }];
         }
 
+        if self.hint_types != HintTypes::all() {
+            fields![fields { "Hints", self.hint_types.join(", "), false }];
+        }
+
         let embed = EmbedBuilder::new().description(description).fields(fields);
 
         BuildPage::new(embed, false).boxed()
@@ -260,6 +265,50 @@ impl IActiveMessage for BackgroundGameSetup {
             components: vec![Component::SelectMenu(effects_menu)],
         };
 
+        let hint_types = vec![
+            SelectMenuOption {
+                default: self.hint_types.contains(HintTypes::Title),
+                description: Some("Reveal letters of the title".to_owned()),
+                emoji: None,
+                label: "Title".to_owned(),
+                value: "title".to_owned(),
+            },
+            SelectMenuOption {
+                default: self.hint_types.contains(HintTypes::Artist),
+                description: Some("Reveal the artist's first letter".to_owned()),
+                emoji: None,
+                label: "Artist".to_owned(),
+                value: "artist".to_owned(),
+            },
+            SelectMenuOption {
+                default: self.hint_types.contains(HintTypes::Length),
+                description: Some("Reveal the length of the title".to_owned()),
+                emoji: None,
+                label: "Length".to_owned(),
+                value: "length".to_owned(),
+            },
+            SelectMenuOption {
+                default: self.hint_types.contains(HintTypes::Region),
+                description: Some("Reveal a blurred region of the background".to_owned()),
+                emoji: None,
+                label: "Region".to_owned(),
+                value: "region".to_owned(),
+            },
+        ];
+
+        let hints_menu = SelectMenu {
+            custom_id: "bg_setup_hints".to_owned(),
+            disabled: false,
+            max_values: Some(hint_types.len() as u8),
+            min_values: Some(0),
+            options: hint_types,
+            placeholder: Some("Select which hints are allowed".to_owned()),
+        };
+
+        let hints_row = ActionRow {
+            components: vec![Component::SelectMenu(hints_menu)],
+        };
+
         let start_button = Button {
             custom_id: Some("bg_start_button".to_owned()),
             disabled: false,
@@ -289,6 +338,7 @@ impl IActiveMessage for BackgroundGameSetup {
             Component::ActionRow(include_row),
             Component::ActionRow(exclude_row),
             Component::ActionRow(effects_row),
+            Component::ActionRow(hints_row),
             Component::ActionRow(button_row),
         ]
     }
@@ -297,37 +347,47 @@ impl IActiveMessage for BackgroundGameSetup {
         &'a mut self,
         component: &'a mut InteractionComponent,
     ) -> BoxFuture<'a, ComponentResult> {
+        Box::pin(self.async_handle_component(component))
+    }
+}
+
+impl BackgroundGameSetup {
+    async fn async_handle_component(
+        &mut self,
+        component: &mut InteractionComponent,
+    ) -> ComponentResult {
         let user_id = match component.user_id() {
             Ok(user_id) => user_id,
-            Err(err) => return ComponentResult::Err(err).boxed(),
+            Err(err) => return ComponentResult::Err(err),
         };
 
-        if user_id != self.msg_owner {
-            return ComponentResult::Ignore.boxed();
+        match can_interact(user_id, component.guild_id, self.msg_owner).await {
+            Ok(true) => {}
+            Ok(false) => return ComponentResult::NotOwner,
+            Err(err) => return ComponentResult::Err(err),
         }
 
         match component.data.custom_id.as_str() {
             "bg_setup_include" => self.included = MapsetTags::from(&*component),
             "bg_setup_exclude" => self.excluded = MapsetTags::from(&*component),
             "bg_setup_effects" => self.effects = Effects::from(&*component),
+            "bg_setup_hints" => self.hint_types = HintTypes::from(&*component),
             "bg_start_button" => {
                 self.state = SetupState::Ready {
                     channel: component.channel_id,
                 }
             }
-            "bg_cancel_button" => return Box::pin(self.cancel(component)),
+            "bg_cancel_button" => return self.cancel(component).await,
             other => {
                 warn!(name = %other, ?component, "Unknown background game setup component");
 
-                return ComponentResult::Ignore.boxed();
+                return ComponentResult::Ignore;
             }
         }
 
-        ComponentResult::BuildPage.boxed()
+        ComponentResult::BuildPage
     }
-}
 
-impl BackgroundGameSetup {
     pub fn new(difficulty: GameDifficulty, msg_owner: Id<UserMarker>) -> Self {
         Self {
             difficulty,
@@ -335,6 +395,7 @@ impl BackgroundGameSetup {
             effects: Effects::empty(),
             excluded: MapsetTags::empty(),
             included: MapsetTags::empty(),
+            hint_types: HintTypes::all(),
             state: SetupState::Ongoing,
         }
     }
@@ -406,7 +467,13 @@ impl BackgroundGameSetup {
                 "Starting game"
             );
 
-            let game_fut = BackgroundGame::new(channel, entries, self.effects, self.difficulty);
+            let game_fut = BackgroundGame::new(
+                channel,
+                entries,
+                self.effects,
+                self.hint_types,
+                self.difficulty,
+            );
 
             let game = game_fut.await;
             Context::bg_games().own(channel).await.insert(game);
@@ -487,6 +554,29 @@ impl From<&InteractionComponent> for Effects {
     }
 }
 
+impl From<&InteractionComponent> for HintTypes {
+    fn from(component: &InteractionComponent) -> Self {
+        component
+            .data
+            .values
+            .iter()
+            .fold(Self::empty(), |hint_types, value| {
+                hint_types
+                    | match value.as_str() {
+                        "title" => Self::Title,
+                        "artist" => Self::Artist,
+                        "length" => Self::Length,
+                        "region" => Self::Region,
+                        _ => {
+                            warn!(%value, "Unknown hint type");
+
+                            return hint_types;
+                        }
+                    }
+            })
+    }
+}
+
 #[derive(Copy, Clone)]
 enum SetupState {
     Ongoing,
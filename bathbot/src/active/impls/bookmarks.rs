@@ -285,7 +285,7 @@ impl BookmarksPagination {
         };
 
         if owner != self.msg_owner {
-            return ComponentResult::Ignore;
+            return ComponentResult::NotOwner;
         }
 
         if let Err(err) = component.defer().await {
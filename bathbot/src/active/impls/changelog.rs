@@ -14,7 +14,7 @@ use twilight_model::{
 };
 
 use crate::{
-    active::{BuildPage, ComponentResult, IActiveMessage},
+    active::{can_interact, BuildPage, ComponentResult, IActiveMessage},
     commands::utility::ChangelogTagPages,
     util::{interaction::InteractionComponent, Authored, ComponentExt, Emote},
 };
@@ -157,8 +157,10 @@ impl ChangelogPagination {
             Err(err) => return ComponentResult::Err(err),
         };
 
-        if user_id != self.msg_owner {
-            return ComponentResult::Ignore;
+        match can_interact(user_id, component.guild_id, self.msg_owner).await {
+            Ok(true) => {}
+            Ok(false) => return ComponentResult::NotOwner,
+            Err(err) => return ComponentResult::Err(err),
         }
 
         match component.data.custom_id.as_str() {
@@ -89,6 +89,7 @@ impl IActiveMessage for CompareScoresPagination {
                 entry,
                 self.score_data,
                 MarkIndex::Skip,
+                self.user.avatar_url(),
             );
 
             if page == 1 {
@@ -6,7 +6,7 @@ use std::{
 use bathbot_model::{
     embed_builder::{
         ComboValue, EmoteTextValue, HitresultsValue, MapperValue, PpValue, ScoreEmbedSettings,
-        SettingValue, SettingsButtons, SettingsImage, Value,
+        SettingValue, SettingsButtons, SettingsImage, SettingsThumbnail, Value,
     },
     rosu_v2::user::User,
 };
@@ -75,7 +75,7 @@ impl ScoreEmbedBuilderActive {
         };
 
         if user_id != self.msg_owner {
-            return ComponentResult::Ignore;
+            return ComponentResult::NotOwner;
         }
 
         match component.data.custom_id.as_str() {
@@ -198,11 +198,14 @@ impl ScoreEmbedBuilderActive {
                 self.inner.settings.values = default.values;
                 self.inner.settings.show_artist = default.show_artist;
                 self.inner.settings.show_sr_in_title = default.show_sr_in_title;
+                self.inner.settings.show_score_version = default.show_score_version;
             }
             "embed_builder_show_artist_button" => self.inner.settings.show_artist = true,
             "embed_builder_hide_artist_button" => self.inner.settings.show_artist = false,
             "embed_builder_show_sr_title" => self.inner.settings.show_sr_in_title = true,
             "embed_builder_hide_sr_title" => self.inner.settings.show_sr_in_title = false,
+            "embed_builder_show_version" => self.inner.settings.show_score_version = true,
+            "embed_builder_hide_version" => self.inner.settings.show_score_version = false,
             "embed_builder_value_left" => {
                 let Some(idx) = self
                     .inner
@@ -624,6 +627,26 @@ impl ScoreEmbedBuilderActive {
                     }
                 }
             }
+            "embed_builder_thumbnail" => {
+                let Some(value) = component.data.values.first() else {
+                    return ComponentResult::Err(eyre!(
+                        "Missing value for builder component {}",
+                        component.data.custom_id
+                    ));
+                };
+
+                self.inner.settings.thumbnail = match value.as_str() {
+                    "list_cover" => SettingsThumbnail::ListCover,
+                    "card" => SettingsThumbnail::Card,
+                    "avatar" => SettingsThumbnail::Avatar,
+                    _ => {
+                        return ComponentResult::Err(eyre!(
+                            "Unknown value `{value}` for builder component {}",
+                            component.data.custom_id
+                        ))
+                    }
+                }
+            }
             "embed_builder_buttons" => {
                 let mut pagination = false;
                 let mut render = false;
@@ -965,6 +988,28 @@ impl IActiveMessage for ScoreEmbedBuilderActive {
                     }
                     ValueKind::Score => {
                         components.push(show_hide_row(idx));
+
+                        components.push(Component::ActionRow(ActionRow {
+                            components: vec![
+                                Component::Button(Button {
+                                    custom_id: Some("embed_builder_show_version".to_owned()),
+                                    disabled: self.inner.settings.show_score_version,
+                                    emoji: None,
+                                    label: Some("Show version in footer".to_owned()),
+                                    style: ButtonStyle::Secondary,
+                                    url: None,
+                                }),
+                                Component::Button(Button {
+                                    custom_id: Some("embed_builder_hide_version".to_owned()),
+                                    disabled: !self.inner.settings.show_score_version,
+                                    emoji: None,
+                                    label: Some("Hide version in footer".to_owned()),
+                                    style: ButtonStyle::Secondary,
+                                    url: None,
+                                }),
+                            ],
+                        }));
+
                         components.push(arrow_row(idx));
                     }
                     ValueKind::Accuracy => {
@@ -1429,6 +1474,45 @@ impl IActiveMessage for ScoreEmbedBuilderActive {
                         placeholder: None,
                     })],
                 }));
+
+                if self.inner.settings.image == SettingsImage::Thumbnail {
+                    let thumbnail = self.inner.settings.thumbnail;
+
+                    let options = vec![
+                        SelectMenuOption {
+                            default: thumbnail == SettingsThumbnail::ListCover,
+                            description: None,
+                            emoji: None,
+                            label: "Thumbnail: Map cover".to_owned(),
+                            value: "list_cover".to_owned(),
+                        },
+                        SelectMenuOption {
+                            default: thumbnail == SettingsThumbnail::Card,
+                            description: None,
+                            emoji: None,
+                            label: "Thumbnail: Map card".to_owned(),
+                            value: "card".to_owned(),
+                        },
+                        SelectMenuOption {
+                            default: thumbnail == SettingsThumbnail::Avatar,
+                            description: None,
+                            emoji: None,
+                            label: "Thumbnail: User avatar".to_owned(),
+                            value: "avatar".to_owned(),
+                        },
+                    ];
+
+                    components.push(Component::ActionRow(ActionRow {
+                        components: vec![Component::SelectMenu(SelectMenu {
+                            custom_id: "embed_builder_thumbnail".to_owned(),
+                            disabled: false,
+                            max_values: None,
+                            min_values: None,
+                            options,
+                            placeholder: None,
+                        })],
+                    }));
+                }
             }
             EmbedSection::Buttons => {
                 let options = vec![
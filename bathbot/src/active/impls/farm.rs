@@ -0,0 +1,113 @@
+use std::fmt::Write;
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_util::{
+    constants::OSU_BASE, datetime::SecToMinSec, numbers::round, CowUtils, EmbedBuilder,
+    FooterBuilder,
+};
+use eyre::Result;
+use futures::future::BoxFuture;
+use rosu_v2::prelude::GameMods;
+use twilight_model::{
+    channel::message::Component,
+    id::{marker::UserMarker, Id},
+};
+
+use crate::{
+    active::{
+        pagination::{handle_pagination_component, handle_pagination_modal, Pages},
+        BuildPage, ComponentResult, IActiveMessage,
+    },
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+pub struct FarmEntry {
+    pub map_id: u32,
+    pub artist: String,
+    pub title: String,
+    pub version: String,
+    pub seconds_drain: u32,
+    pub stars: f32,
+    pub pp: f32,
+}
+
+impl FarmEntry {
+    pub fn pp_per_star(&self) -> f32 {
+        self.pp / self.stars.max(f32::EPSILON)
+    }
+}
+
+#[derive(PaginationBuilder)]
+pub struct FarmPagination {
+    #[pagination(per_page = 10)]
+    entries: Box<[FarmEntry]>,
+    stars: f32,
+    mods: GameMods,
+    sample_size: usize,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for FarmPagination {
+    fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let pages = &self.pages;
+        let idx = pages.index();
+        let entries = &self.entries[idx..self.entries.len().min(idx + pages.per_page())];
+
+        let mut description = String::with_capacity(entries.len() * 160);
+
+        for (entry, i) in entries.iter().zip(idx + 1..) {
+            let _ = writeln!(
+                description,
+                "**#{i} [{artist} - {title} [{version}]]({OSU_BASE}b/{map_id})**\n\
+                [{stars}★] • **{pp}pp** • {pp_per_star}pp/★ • `{len}`",
+                artist = entry.artist.cow_escape_markdown(),
+                title = entry.title.cow_escape_markdown(),
+                version = entry.version.cow_escape_markdown(),
+                map_id = entry.map_id,
+                stars = round(entry.stars),
+                pp = round(entry.pp),
+                pp_per_star = round(entry.pp_per_star()),
+                len = SecToMinSec::new(entry.seconds_drain),
+            );
+        }
+
+        let mut title = format!("Farm maps around {}★", round(self.stars));
+
+        if !self.mods.is_empty() {
+            let _ = write!(title, " +{}", self.mods);
+        }
+
+        let footer = format!(
+            "Page {}/{} • pp for a 99% full combo • {} maps considered",
+            pages.curr_page(),
+            pages.last_page(),
+            self.sample_size,
+        );
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(FooterBuilder::new(footer))
+            .title(title);
+
+        BuildPage::new(embed, false).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(component, self.msg_owner, false, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages)
+    }
+}
@@ -13,7 +13,7 @@ use twilight_model::{
 };
 
 use crate::{
-    active::{BuildPage, ComponentResult, IActiveMessage},
+    active::{can_interact, BuildPage, ComponentResult, IActiveMessage},
     core::commands::interaction::{
         twilight_command::Command, InteractionCommandKind, InteractionCommands,
     },
@@ -143,28 +143,37 @@ impl IActiveMessage for HelpInteractionCommand {
         &'a mut self,
         component: &'a mut InteractionComponent,
     ) -> BoxFuture<'a, ComponentResult> {
+        Box::pin(self.async_handle_component(component))
+    }
+}
+
+impl HelpInteractionCommand {
+    async fn async_handle_component(
+        &mut self,
+        component: &mut InteractionComponent,
+    ) -> ComponentResult {
         let user_id = match component.user_id() {
             Ok(user_id) => user_id,
-            Err(err) => return ComponentResult::Err(err).boxed(),
+            Err(err) => return ComponentResult::Err(err),
         };
 
-        if user_id != self.msg_owner {
-            return ComponentResult::Ignore.boxed();
+        match can_interact(user_id, component.guild_id, self.msg_owner).await {
+            Ok(true) => {}
+            Ok(false) => return ComponentResult::NotOwner,
+            Err(err) => return ComponentResult::Err(err),
         }
 
         match component.data.custom_id.as_str() {
-            "help_menu" => self.handle_menu(component).boxed(),
-            "help_back" => self.handle_back().boxed(),
+            "help_menu" => self.handle_menu(component),
+            "help_back" => self.handle_back(),
             other => {
                 warn!(name = %other, ?component, "Unknown interaction help component");
 
-                ComponentResult::Ignore.boxed()
+                ComponentResult::Ignore
             }
         }
     }
-}
 
-impl HelpInteractionCommand {
     pub fn new(command: String, msg_owner: Id<UserMarker>) -> Self {
         Self {
             next_title: command,
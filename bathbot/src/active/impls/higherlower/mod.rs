@@ -21,7 +21,7 @@ use twilight_model::{
 
 use self::state::{ButtonState, HigherLowerState};
 use crate::{
-    active::{response::ActiveResponse, BuildPage, ComponentResult, IActiveMessage},
+    active::{can_interact, response::ActiveResponse, BuildPage, ComponentResult, IActiveMessage},
     core::Context,
     util::{interaction::InteractionComponent, Authored, ComponentExt, Emote},
 };
@@ -63,26 +63,7 @@ impl IActiveMessage for HigherLowerGame {
         &'a mut self,
         component: &'a mut InteractionComponent,
     ) -> BoxFuture<'a, ComponentResult> {
-        let user_id = match component.user_id() {
-            Ok(user_id) => user_id,
-            Err(err) => return ComponentResult::Err(err).boxed(),
-        };
-
-        if user_id != self.msg_owner {
-            return ComponentResult::Ignore.boxed();
-        }
-
-        match component.data.custom_id.as_str() {
-            "higher_button" => Box::pin(self.handle_higherlower(component, HlGuess::Higher)),
-            "lower_button" => Box::pin(self.handle_higherlower(component, HlGuess::Lower)),
-            "next_higherlower" => Box::pin(self.handle_next(component)),
-            "try_again_button" => Box::pin(self.handle_try_again(component)),
-            other => {
-                warn!(name = %other, ?component, "Unknown higherlower component");
-
-                ComponentResult::Ignore.boxed()
-            }
-        }
+        Box::pin(self.async_handle_component(component))
     }
 
     fn on_timeout(&mut self, response: ActiveResponse) -> BoxFuture<'_, Result<()>> {
@@ -96,9 +77,41 @@ impl IActiveMessage for HigherLowerGame {
             ButtonState::TryAgain { .. } => Some(Duration::from_secs(30)),
         }
     }
+
+    fn configurable_timeout(&self) -> bool {
+        false
+    }
 }
 
 impl HigherLowerGame {
+    async fn async_handle_component(
+        &mut self,
+        component: &mut InteractionComponent,
+    ) -> ComponentResult {
+        let user_id = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        match can_interact(user_id, component.guild_id, self.msg_owner).await {
+            Ok(true) => {}
+            Ok(false) => return ComponentResult::NotOwner,
+            Err(err) => return ComponentResult::Err(err),
+        }
+
+        match component.data.custom_id.as_str() {
+            "higher_button" => self.handle_higherlower(component, HlGuess::Higher).await,
+            "lower_button" => self.handle_higherlower(component, HlGuess::Lower).await,
+            "next_higherlower" => self.handle_next(component).await,
+            "try_again_button" => self.handle_try_again(component).await,
+            other => {
+                warn!(name = %other, ?component, "Unknown higherlower component");
+
+                ComponentResult::Ignore
+            }
+        }
+    }
+
     pub async fn new_score_pp(mode: GameMode, msg_owner: Id<UserMarker>) -> Result<Self> {
         let game_fut = HigherLowerState::start_score_pp(mode);
         let highscore_fut = Context::games().higherlower_highscore(msg_owner, HlVersion::ScorePp);
@@ -4,6 +4,7 @@ use bathbot_model::rosu_v2::ranking::ArchivedRankingsUser;
 use bathbot_util::{
     constants::OSU_BASE,
     numbers::{round, WithComma},
+    osu::FlagEmote,
     EmbedBuilder, ModsFormatter,
 };
 use eyre::{Result, WrapErr};
@@ -243,15 +244,16 @@ impl ScorePp {
             username,
         } = user;
 
-        let country_code = country_code.to_lowercase();
-
         Self {
             user_id,
             avatar_url,
             map_id: map.map_id(),
             mapset_id: map.mapset_id(),
-            player_string: format!(":flag_{country_code}: {username} (#{global_rank})")
-                .into_boxed_str(),
+            player_string: format!(
+                "{flag} {username} (#{global_rank})",
+                flag = FlagEmote::new(country_code.as_str())
+            )
+            .into_boxed_str(),
             map_string: format!(
                 "[{artist} - {title} [{version}]]({OSU_BASE}b/{map_id})",
                 artist = map.artist(),
@@ -0,0 +1,174 @@
+use std::time::Duration;
+
+use bathbot_util::{constants::OSU_BASE, EmbedBuilder};
+use eyre::{Report, Result};
+use futures::future::BoxFuture;
+use rosu_v2::prelude::{UserExtended, Username};
+use twilight_model::{
+    channel::message::{
+        component::{ActionRow, Button, ButtonStyle},
+        Component,
+    },
+    id::{marker::UserMarker, Id},
+};
+
+use crate::{
+    active::{BuildPage, ComponentResult, IActiveMessage},
+    core::Context,
+    util::{interaction::InteractionComponent, Authored, ComponentExt},
+};
+
+pub struct LinkVerification {
+    osu_id: u32,
+    username: Username,
+    code: Box<str>,
+    state: VerificationState,
+    msg_owner: Id<UserMarker>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum VerificationState {
+    Pending,
+    NotFound,
+    Verified,
+}
+
+impl LinkVerification {
+    pub fn new(osu_id: u32, username: Username, code: Box<str>, msg_owner: Id<UserMarker>) -> Self {
+        Self {
+            osu_id,
+            username,
+            code,
+            state: VerificationState::Pending,
+            msg_owner,
+        }
+    }
+
+    async fn handle_verify(&mut self, component: &InteractionComponent) -> ComponentResult {
+        let owner = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if owner != self.msg_owner {
+            return ComponentResult::NotOwner;
+        }
+
+        if let Err(err) = component.defer().await {
+            return ComponentResult::Err(Report::new(err).wrap_err("Failed to defer component"));
+        }
+
+        // Fetch directly from the API so that cached profiles don't hide a
+        // freshly added code
+        let user = match Context::osu().user(self.osu_id).await {
+            Ok(user) => user,
+            Err(err) => {
+                let wrap = "Failed to get user for link verification";
+
+                return ComponentResult::Err(Report::new(err).wrap_err(wrap));
+            }
+        };
+
+        if !contains_code(&user, &self.code) {
+            self.state = VerificationState::NotFound;
+
+            return ComponentResult::BuildPage;
+        }
+
+        if let Err(err) = Context::user_config().link_osu(owner, self.osu_id).await {
+            return ComponentResult::Err(err);
+        }
+
+        Context::osu_user().store(&user, user.mode).await;
+        self.username = user.username;
+        self.state = VerificationState::Verified;
+
+        ComponentResult::BuildPage
+    }
+}
+
+impl IActiveMessage for LinkVerification {
+    fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let profile = format!("[{}]({OSU_BASE}u/{})", self.username, self.osu_id);
+
+        let description = match self.state {
+            VerificationState::Pending | VerificationState::NotFound => {
+                let mut description = format!(
+                    "To prove that you own the osu! profile {profile}, put the code \
+                    `{code}` into its location, interests, or occupation field \
+                    via the [account settings]({OSU_BASE}home/account/edit).\n\
+                    Once you're done, press the `Verify` button below.\n\
+                    You can remove the code again after the verification.",
+                    code = self.code,
+                );
+
+                if self.state == VerificationState::NotFound {
+                    description.push_str(
+                        "\n\n:x: The code could not be found on your profile yet. \
+                        Note that it may take a moment until profile changes are visible.",
+                    );
+                }
+
+                description
+            }
+            VerificationState::Verified => {
+                format!("Successfully verified and linked to the osu! profile {profile}")
+            }
+        };
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .title("Profile verification");
+
+        let defer = self.state != VerificationState::Pending;
+
+        BuildPage::new(embed, defer).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        if self.state == VerificationState::Verified {
+            return Vec::new();
+        }
+
+        let verify = Button {
+            custom_id: Some("link_verify".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Verify".to_owned()),
+            style: ButtonStyle::Success,
+            url: None,
+        };
+
+        let components = vec![Component::Button(verify)];
+
+        vec![Component::ActionRow(ActionRow { components })]
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        match component.data.custom_id.as_str() {
+            "link_verify" => Box::pin(self.handle_verify(component)),
+            other => {
+                ComponentResult::Err(eyre!("Unknown link verification component `{other}`")).boxed()
+            }
+        }
+    }
+
+    fn until_timeout(&self) -> Option<Duration> {
+        Some(Duration::from_secs(600))
+    }
+
+    fn configurable_timeout(&self) -> bool {
+        false
+    }
+}
+
+/// Check whether the code appears in one of the user's editable profile fields.
+fn contains_code(user: &UserExtended, code: &str) -> bool {
+    [&user.location, &user.interests, &user.occupation]
+        .into_iter()
+        .flatten()
+        .any(|field| field.contains(code))
+}
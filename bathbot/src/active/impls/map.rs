@@ -10,7 +10,7 @@ use bathbot_util::{
 };
 use eyre::{Result, WrapErr};
 use futures::future::BoxFuture;
-use rosu_pp::Difficulty;
+use rosu_pp::{any::DifficultyAttributes, model::beatmap::BeatmapAttributes, Beatmap, Difficulty};
 use rosu_v2::prelude::{
     BeatmapExtended, BeatmapsetExtended, GameMode, GameModsIntermode, Username,
 };
@@ -43,6 +43,7 @@ pub struct MapPagination {
     attrs: CustomAttrs,
     origin: MessageOrigin,
     content: Box<str>,
+    svg_graph: bool,
     msg_owner: Id<UserMarker>,
     pages: Pages,
 }
@@ -119,36 +120,9 @@ impl MapPagination {
         let creator_fut = creator_name(map, &self.mapset);
         let (map_res, gd_creator) = tokio::join!(map_fut, creator_fut);
 
-        let mut rosu_map = map_res.wrap_err("Failed to get pp map")?;
-
+        let rosu_map = map_res.wrap_err("Failed to get pp map")?;
         let mod_bits = self.mods.bits();
-
-        if let Some(ar_) = self.attrs.ar {
-            rosu_map.ar = ar_ as f32;
-        }
-
-        if let Some(cs_) = self.attrs.cs {
-            rosu_map.cs = cs_ as f32;
-        }
-
-        if let Some(hp_) = self.attrs.hp {
-            rosu_map.hp = hp_ as f32;
-        }
-
-        if let Some(od_) = self.attrs.od {
-            rosu_map.od = od_ as f32;
-        }
-
-        let map_attrs = rosu_map
-            .attributes()
-            .mods(mod_bits)
-            .clock_rate(clock_rate as f64)
-            .build();
-
-        let mut attrs = Difficulty::new()
-            .mods(mod_bits)
-            .clock_rate(clock_rate as f64)
-            .calculate(&rosu_map);
+        let (map_attrs, mut attrs) = adjusted_attrs(rosu_map, &self.mods, &self.attrs);
 
         let stars = attrs.stars();
         const ACCS: [f32; 4] = [95.0, 97.0, 99.0, 100.0];
@@ -287,8 +261,6 @@ impl MapPagination {
 
         let footer = FooterBuilder::new(footer_text);
 
-        let image = attachment("map_graph.png");
-
         let mut description = format!(
             ":musical_note: [Song preview](https://b.ppy.sh/preview/{mapset_id}.mp3) \
             :frame_photo: [Full background](https://assets.ppy.sh/beatmaps/{mapset_id}/covers/raw.jpg)",
@@ -314,16 +286,20 @@ impl MapPagination {
             GameMode::Catch => {}
         }
 
-        let embed = EmbedBuilder::new()
+        let mut embed = EmbedBuilder::new()
             .author(author)
             .description(description)
             .fields(fields)
             .footer(footer)
-            .image(image)
             .timestamp(timestamp)
             .title(title)
             .url(map.url.as_str());
 
+        // Discord can't display svg images inside embeds
+        if !self.svg_graph {
+            embed = embed.image(attachment("map_graph.png"));
+        }
+
         let build = BuildPage::new(embed, true).content(self.content.clone());
 
         Ok(build)
@@ -334,6 +310,49 @@ impl MapPagination {
     }
 }
 
+/// Map attributes and difficulty of the map adjusted to both the mods and
+/// the custom attributes.
+///
+/// Called for every page so that each map of the mapset is adjusted, not
+/// just the initially shown one.
+fn adjusted_attrs(
+    mut map: Beatmap,
+    mods: &GameModsIntermode,
+    custom: &CustomAttrs,
+) -> (BeatmapAttributes, DifficultyAttributes) {
+    let mod_bits = mods.bits();
+    let clock_rate = f64::from(mods.legacy_clock_rate());
+
+    if let Some(ar) = custom.ar {
+        map.ar = ar as f32;
+    }
+
+    if let Some(cs) = custom.cs {
+        map.cs = cs as f32;
+    }
+
+    if let Some(hp) = custom.hp {
+        map.hp = hp as f32;
+    }
+
+    if let Some(od) = custom.od {
+        map.od = od as f32;
+    }
+
+    let map_attrs = map
+        .attributes()
+        .mods(mod_bits)
+        .clock_rate(clock_rate)
+        .build();
+
+    let attrs = Difficulty::new()
+        .mods(mod_bits)
+        .clock_rate(clock_rate)
+        .calculate(&map);
+
+    (map_attrs, attrs)
+}
+
 async fn creator_name(map: &BeatmapExtended, mapset: &BeatmapsetExtended) -> Option<Username> {
     if map.creator_id == mapset.creator_id {
         return None;
@@ -357,3 +376,86 @@ async fn creator_name(map: &BeatmapExtended, mapset: &BeatmapsetExtended) -> Opt
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "osu file format v14
+
+[General]
+Mode: 0
+
+[Difficulty]
+HPDrainRate:5
+CircleSize:4
+OverallDifficulty:8
+ApproachRate:9
+SliderMultiplier:1.4
+SliderTickRate:1
+
+[TimingPoints]
+0,400,4,2,0,100,1,0
+
+[HitObjects]
+64,64,1000,1,0,0:0:0:0:
+448,64,1200,1,0,0:0:0:0:
+448,320,1400,1,0,0:0:0:0:
+64,320,1600,1,0,0:0:0:0:
+256,192,1800,2,0,L|256:32,1,140
+128,192,2400,1,0,0:0:0:0:
+384,192,2600,1,0,0:0:0:0:
+256,64,2800,1,0,0:0:0:0:
+256,320,3000,1,0,0:0:0:0:
+";
+
+    fn mapset() -> Vec<Beatmap> {
+        let easier = MAP
+            .replace("OverallDifficulty:8", "OverallDifficulty:5")
+            .replace("ApproachRate:9", "ApproachRate:6");
+
+        [MAP, easier.as_str()]
+            .into_iter()
+            .map(|map| Beatmap::from_bytes(map.as_bytes()).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn double_time_applies_to_every_page() {
+        let nomod = GameModsIntermode::new();
+        let dt = GameModsIntermode::from_bits(64);
+        let custom = CustomAttrs::default();
+
+        for map in mapset() {
+            let (nm_attrs, nm_diff) = adjusted_attrs(map.clone(), &nomod, &custom);
+            let (dt_attrs, dt_diff) = adjusted_attrs(map, &dt, &custom);
+
+            assert!(dt_attrs.ar > nm_attrs.ar);
+            assert!(dt_attrs.od > nm_attrs.od);
+            assert_eq!(dt_attrs.cs, nm_attrs.cs);
+            assert_eq!(dt_attrs.hp, nm_attrs.hp);
+            assert!(dt_diff.stars() > nm_diff.stars());
+        }
+    }
+
+    #[test]
+    fn custom_attrs_apply_to_every_page() {
+        let dt = GameModsIntermode::from_bits(64);
+
+        let custom = CustomAttrs {
+            ar: Some(5.0),
+            ..Default::default()
+        };
+
+        let maps = mapset();
+
+        let adjusted: Vec<_> = maps
+            .into_iter()
+            .map(|map| adjusted_attrs(map, &dt, &custom).0)
+            .collect();
+
+        // Both maps have the same custom AR so DT must adjust them equally
+        assert_eq!(adjusted[0].ar, adjusted[1].ar);
+        assert!(adjusted[0].ar > 5.0);
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt::Write;
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_model::rosu_v2::user::User;
+use bathbot_util::{
+    constants::OSU_BASE, datetime::HowLongAgoDynamic, numbers::round, CowUtils, EmbedBuilder,
+    FooterBuilder,
+};
+use eyre::Result;
+use futures::future::BoxFuture;
+use rosu_v2::prelude::{BeatmapExtended, BeatmapsetExtended, Score};
+use twilight_model::{
+    channel::message::Component,
+    id::{marker::UserMarker, Id},
+};
+
+use crate::{
+    active::{
+        pagination::{handle_pagination_component, handle_pagination_modal, Pages},
+        BuildPage, ComponentResult, IActiveMessage,
+    },
+    embeds::ComboFormatter,
+    manager::redis::RedisData,
+    util::{
+        interaction::{InteractionComponent, InteractionModal},
+        osu::GradeCompletionFormatter,
+        Emote,
+    },
+};
+
+pub struct MapsetScoresEntry {
+    pub map: BeatmapExtended,
+    pub score: Option<Score>,
+}
+
+#[derive(PaginationBuilder)]
+pub struct MapsetScoresPagination {
+    user: RedisData<User>,
+    mapset: BeatmapsetExtended,
+    #[pagination(per_page = 10)]
+    entries: Box<[MapsetScoresEntry]>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for MapsetScoresPagination {
+    fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let pages = &self.pages;
+        let end_idx = self.entries.len().min(pages.index() + pages.per_page());
+        let entries = &self.entries[pages.index()..end_idx];
+
+        let mut description = String::with_capacity(entries.len() * 160);
+
+        for MapsetScoresEntry { map, score } in entries {
+            let _ = write!(
+                description,
+                "{mode} **[{version}]({OSU_BASE}b/{map_id})** [{stars:.2}★]",
+                mode = Emote::from(map.mode),
+                version = map.version.as_str().cow_escape_markdown(),
+                map_id = map.map_id,
+                stars = map.stars,
+            );
+
+            let Some(score) = score else {
+                description.push_str("\n—\n");
+
+                continue;
+            };
+
+            let n_objects = map.count_circles + map.count_sliders + map.count_spinners;
+
+            let _ = writeln!(
+                description,
+                "\n{grade}\t**{pp}pp**\t[ {combo} ]\t({acc}%)\t{ago}",
+                grade = GradeCompletionFormatter::new(score, map.mode, n_objects),
+                pp = score
+                    .pp
+                    .map_or_else(|| "-".to_owned(), |pp| round(pp).to_string()),
+                combo = ComboFormatter::new(score.max_combo, map.max_combo),
+                acc = round(score.accuracy),
+                ago = HowLongAgoDynamic::new(&score.ended_at),
+            );
+        }
+
+        let played = self
+            .entries
+            .iter()
+            .filter(|entry| entry.score.is_some())
+            .count();
+
+        let footer = format!(
+            "Page {}/{} • Played {played}/{} difficulties",
+            pages.curr_page(),
+            pages.last_page(),
+            self.entries.len(),
+        );
+
+        let title = format!(
+            "{} - {} by {}",
+            self.mapset.artist.as_str().cow_escape_markdown(),
+            self.mapset.title.as_str().cow_escape_markdown(),
+            self.mapset.creator_name.as_str().cow_escape_markdown(),
+        );
+
+        let embed = EmbedBuilder::new()
+            .author(self.user.author_builder())
+            .description(description)
+            .footer(FooterBuilder::new(footer))
+            .thumbnail(self.mapset.covers.list.as_str())
+            .title(title)
+            .url(format!("{OSU_BASE}s/{}", self.mapset.mapset_id));
+
+        BuildPage::new(embed, false).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(component, self.msg_owner, false, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages)
+    }
+}
@@ -18,7 +18,10 @@ use crate::{
         pagination::{handle_pagination_component, handle_pagination_modal, Pages},
         BuildPage, ComponentResult, IActiveMessage,
     },
-    commands::osu::{MatchCostDisplay, MatchResult, TeamResult, UserMatchCostEntry},
+    commands::osu::{
+        MatchCostDisplay, MatchCostFormula, MatchResult, TeamResult, UserMatchCostEntry,
+    },
+    embeds::attachment,
     util::interaction::{InteractionComponent, InteractionModal},
 };
 
@@ -26,6 +29,9 @@ pub struct MatchCostPagination {
     result: MatchResult,
     osu_match: OsuMatch,
     display: MatchCostDisplay,
+    hide_mvp_avatar: bool,
+    formula: MatchCostFormula,
+    graph: bool,
     content: Box<str>,
     msg_owner: Id<UserMarker>,
     pages: Pages,
@@ -69,6 +75,9 @@ pub struct MatchCostPaginationBuilder {
     result: Option<MatchResult>,
     osu_match: Option<OsuMatch>,
     display: Option<MatchCostDisplay>,
+    hide_mvp_avatar: bool,
+    formula: MatchCostFormula,
+    graph: bool,
     content: Option<Box<str>>,
     msg_owner: Option<Id<UserMarker>>,
 }
@@ -91,6 +100,9 @@ impl MatchCostPaginationBuilder {
             result,
             osu_match,
             display,
+            hide_mvp_avatar: self.hide_mvp_avatar,
+            formula: self.formula,
+            graph: self.graph,
             content,
             msg_owner,
             pages,
@@ -115,6 +127,25 @@ impl MatchCostPaginationBuilder {
         self
     }
 
+    pub fn hide_mvp_avatar(mut self, hide_mvp_avatar: bool) -> Self {
+        self.hide_mvp_avatar = hide_mvp_avatar;
+
+        self
+    }
+
+    pub fn formula(mut self, formula: MatchCostFormula) -> Self {
+        self.formula = formula;
+
+        self
+    }
+
+    /// Whether a graph is attached to the message
+    pub fn graph(mut self, graph: bool) -> Self {
+        self.graph = graph;
+
+        self
+    }
+
     pub fn content(mut self, content: Box<str>) -> Self {
         self.content = Some(content);
 
@@ -129,6 +160,8 @@ impl MatchCostPaginationBuilder {
 }
 
 impl MatchCostPagination {
+    pub const IMAGE_NAME: &'static str = "matchcost_graph.png";
+
     pub fn builder() -> MatchCostPaginationBuilder {
         MatchCostPaginationBuilder::default()
     }
@@ -152,10 +185,7 @@ impl MatchCostPagination {
 
                 validate_description_len(&mut description);
 
-                embed = match self.display {
-                    MatchCostDisplay::Compact => embed.thumbnail(mvp_avatar_url.as_ref()),
-                    MatchCostDisplay::Full => embed.footer(FooterBuilder::new(FULL_FOOTER)),
-                };
+                embed = self.decorate(embed, mvp_avatar_url);
 
                 embed.description(description)
             }
@@ -165,19 +195,40 @@ impl MatchCostPagination {
             } => {
                 let description = self.description_head_to_head(players);
 
-                embed = match self.display {
-                    MatchCostDisplay::Compact => embed.thumbnail(mvp_avatar_url.as_ref()),
-                    MatchCostDisplay::Full => embed.footer(FooterBuilder::new(FULL_FOOTER)),
-                };
+                embed = self.decorate(embed, mvp_avatar_url);
 
                 embed.description(description)
             }
             MatchResult::NoGames { description } => embed.description(description.as_str()),
         };
 
+        if self.graph {
+            embed = embed.image(attachment(Self::IMAGE_NAME));
+        }
+
         Ok(BuildPage::new(embed, false).content(self.content.clone()))
     }
 
+    /// Add the MVP thumbnail or the footer depending on the display
+    fn decorate(&self, embed: EmbedBuilder, mvp_avatar_url: &str) -> EmbedBuilder {
+        let embed = match self.display {
+            MatchCostDisplay::Compact if self.hide_mvp_avatar => embed,
+            MatchCostDisplay::Compact => embed.thumbnail(mvp_avatar_url),
+            MatchCostDisplay::Full => embed,
+        };
+
+        let footer = match (self.display, self.formula) {
+            (MatchCostDisplay::Compact, MatchCostFormula::Score) => return embed,
+            (MatchCostDisplay::Compact, MatchCostFormula::Accuracy) => ACC_FOOTER.to_owned(),
+            (MatchCostDisplay::Full, MatchCostFormula::Score) => FULL_FOOTER.to_owned(),
+            (MatchCostDisplay::Full, MatchCostFormula::Accuracy) => {
+                format!("{FULL_FOOTER}\n{ACC_FOOTER}")
+            }
+        };
+
+        embed.footer(FooterBuilder::new(footer))
+    }
+
     fn description_team_vs(&self, blue: &TeamResult, red: &TeamResult) -> String {
         let mut description = String::new();
 
@@ -302,6 +353,8 @@ impl MatchCostPagination {
 
 const FULL_FOOTER: &str =
     "matchcost = (performance * participation * mods) + tiebreaker | average score";
+const ACC_FOOTER: &str =
+    "performance = 50% score / average score + 50% accuracy / average accuracy";
 const UNKNOWN_NAME: &str = "<unknown name>";
 
 #[derive(Default)]
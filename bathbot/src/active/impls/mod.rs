@@ -1,6 +1,8 @@
 // Used for tracking notifs
 #[allow(unused)]
 pub use self::single_score::MarkIndex;
+#[cfg(feature = "osutracking")]
+pub use self::track_preview::{TrackPreview, TrackPreviewEntry};
 pub use self::{
     badges::BadgesPagination,
     bg_game::{BackgroundGame, BackgroundGameSetup},
@@ -8,11 +10,14 @@ pub use self::{
     changelog::ChangelogPagination,
     compare::{CompareMostPlayedPagination, CompareScoresPagination, CompareTopPagination},
     embed_builder::ScoreEmbedBuilderActive,
+    farm::{FarmEntry, FarmPagination},
     help::{HelpInteractionCommand, HelpPrefixMenu},
     higherlower::HigherLowerGame,
     leaderboard::LeaderboardPagination,
+    link_verification::LinkVerification,
     map::MapPagination,
     map_search::MapSearchPagination,
+    mapset_scores::{MapsetScoresEntry, MapsetScoresPagination},
     match_compare::MatchComparePagination,
     match_costs::MatchCostPagination,
     medals::{
@@ -24,12 +29,14 @@ pub use self::{
     osekai::{MedalCountPagination, MedalRarityPagination},
     osustats::{OsuStatsBestPagination, OsuStatsPlayersPagination, OsuStatsScoresPagination},
     profile::ProfileMenu,
+    profile_badges::ProfileBadgesPagination,
     ranking::RankingPagination,
     ranking_countries::RankingCountriesPagination,
     recent_list::RecentListPagination,
     region_top::RegionTopPagination,
     render::{CachedRender, CachedRenderData, RenderSettingsActive, SettingsImport},
     scores::{ScoresMapPagination, ScoresServerPagination, ScoresUserPagination},
+    shards::{ShardEntry, ShardsPagination},
     simulate::{SimulateAttributes, SimulateComponents, SimulateData, SimulateMap, TopOldVersion},
     single_score::{SingleScoreContent, SingleScorePagination},
     skins::SkinsPagination,
@@ -37,6 +44,8 @@ pub use self::{
     snipe::{SnipeCountryListPagination, SnipeDifferencePagination, SnipePlayerListPagination},
     top::TopPagination,
     top_if::TopIfPagination,
+    top_monthly::{TopMonthlyEntry, TopMonthlyPagination},
+    unlink::UnlinkConfirm,
 };
 
 mod badges;
@@ -45,11 +54,14 @@ mod bookmarks;
 mod changelog;
 mod compare;
 mod embed_builder;
+mod farm;
 mod help;
 mod higherlower;
 mod leaderboard;
+mod link_verification;
 mod map;
 mod map_search;
+mod mapset_scores;
 mod match_compare;
 mod match_costs;
 mod medals;
@@ -58,12 +70,14 @@ mod nochoke;
 mod osekai;
 mod osustats;
 mod profile;
+mod profile_badges;
 mod ranking;
 mod ranking_countries;
 mod recent_list;
 mod region_top;
 mod render;
 mod scores;
+mod shards;
 mod simulate;
 mod single_score;
 mod skins;
@@ -71,3 +85,9 @@ mod slash_commands;
 mod snipe;
 mod top;
 mod top_if;
+mod top_monthly;
+
+#[cfg(feature = "osutracking")]
+mod track_preview;
+
+mod unlink;
@@ -2,7 +2,9 @@ use std::fmt::Write;
 
 use bathbot_macros::PaginationBuilder;
 use bathbot_model::{OsekaiMedal, OsekaiUserEntry};
-use bathbot_util::{constants::OSU_BASE, numbers::round, CowUtils, EmbedBuilder, FooterBuilder};
+use bathbot_util::{
+    constants::OSU_BASE, numbers::round, osu::FlagEmote, CowUtils, EmbedBuilder, FooterBuilder,
+};
 use eyre::Result;
 use futures::future::BoxFuture;
 use twilight_model::{
@@ -48,14 +50,17 @@ impl IActiveMessage for MedalCountPagination {
                 }
             };
 
-            let _ =
-                writeln!(
+            let _ = writeln!(
                 description,
-                "**{i}.** :flag_{country}: [{author}**{user}**{author}]({OSU_BASE}u/{user_id}): \
+                "**{i}.** {flag} [{author}**{user}**{author}]({OSU_BASE}u/{user_id}): \
                 `{count}` (`{percent}%`) ▸ [{medal}]({medal_url})",
                 i = idx + 1,
-                country = entry.country_code.to_ascii_lowercase(),
-                author = if self.author_idx == Some(idx) { "__" } else { "" },
+                flag = FlagEmote::new(entry.country_code.as_str()),
+                author = if self.author_idx == Some(idx) {
+                    "__"
+                } else {
+                    ""
+                },
                 user = entry.username.cow_escape_markdown(),
                 user_id = entry.user_id,
                 count = entry.medal_count,
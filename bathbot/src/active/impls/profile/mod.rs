@@ -9,7 +9,7 @@ use bathbot_util::{
     datetime::{HowLongAgoText, SecToMinSec, NAIVE_DATETIME_FORMAT},
     fields,
     numbers::{round, MinMaxAvg, Number, WithComma},
-    osu::BonusPP,
+    osu::{BonusPP, FlagEmote},
     EmbedBuilder, FooterBuilder, MessageOrigin,
 };
 use eyre::Result;
@@ -38,8 +38,9 @@ use self::{
     top100_stats::Top100Stats,
 };
 use crate::{
-    active::{BuildPage, ComponentResult, IActiveMessage},
+    active::{can_interact, BuildPage, ComponentResult, IActiveMessage},
     commands::osu::ProfileKind,
+    embeds::attachment,
     manager::redis::RedisData,
     util::{interaction::InteractionComponent, osu::grade_emote, Authored, ComponentExt, Emote},
 };
@@ -63,6 +64,7 @@ pub struct ProfileMenu {
     kind: ProfileKind,
     origin: MessageOrigin,
     msg_owner: Id<UserMarker>,
+    with_graph: bool,
 }
 
 impl IActiveMessage for ProfileMenu {
@@ -151,8 +153,10 @@ impl IActiveMessage for ProfileMenu {
                 Err(err) => return ComponentResult::Err(err),
             };
 
-            if user_id != msg_owner {
-                return ComponentResult::Ignore;
+            match can_interact(user_id, component.guild_id, msg_owner).await {
+                Ok(true) => {}
+                Ok(false) => return ComponentResult::NotOwner,
+                Err(err) => return ComponentResult::Err(err),
             }
 
             let value = component.data.values.pop();
@@ -182,6 +186,9 @@ impl IActiveMessage for ProfileMenu {
 }
 
 impl ProfileMenu {
+    /// Name of the attached playcount graph
+    pub const GRAPH_NAME: &'static str = "profile_graph.png";
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         user: RedisData<User>,
@@ -192,6 +199,7 @@ impl ProfileMenu {
         kind: ProfileKind,
         origin: MessageOrigin,
         msg_owner: Id<UserMarker>,
+        with_graph: bool,
     ) -> Self {
         Self {
             user,
@@ -207,6 +215,15 @@ impl ProfileMenu {
             mapper_names: Availability::NotRequested,
             origin,
             top100stats: None,
+            with_graph,
+        }
+    }
+
+    fn add_graph(&self, embed: EmbedBuilder) -> EmbedBuilder {
+        if self.with_graph {
+            embed.image(attachment(Self::GRAPH_NAME))
+        } else {
+            embed
         }
     }
 
@@ -239,13 +256,14 @@ impl ProfileMenu {
         let mut description = format!(
             "Accuracy: [`{acc:.2}%`]({origin} \"{acc}\") • Level: `{level:.2}`\n\
             Playcount: `{playcount}` (`{playtime} hrs`)\n\
-            {mode} • Medals: `{medals}`",
+            {flag} {mode} • Medals: `{medals}`",
             acc = stats.accuracy(),
             origin = self.origin,
             level = stats.level().float(),
             playcount = WithComma::new(stats.playcount()),
             playtime = stats.playtime() / 60 / 60,
             mode = Emote::from(mode),
+            flag = FlagEmote::new(self.user.country_code()),
         );
 
         if let Some(skin_url) = skin_url {
@@ -265,13 +283,33 @@ impl ProfileMenu {
             );
         }
 
+        let grades = stats.grade_counts();
+
+        // Users without any plays in the mode have no grades worth showing
+        if grades.ssh + grades.ss + grades.sh + grades.s + grades.a > 0 {
+            let _ = write!(
+                description,
+                "\n{}`{}` {}`{}` {}`{}` {}`{}` {}`{}`",
+                grade_emote(Grade::XH),
+                grades.ssh,
+                grade_emote(Grade::X),
+                grades.ss,
+                grade_emote(Grade::SH),
+                grades.sh,
+                grade_emote(Grade::S),
+                grades.s,
+                grade_emote(Grade::A),
+                grades.a,
+            );
+        }
+
         let embed = EmbedBuilder::new()
             .author(self.user.author_builder())
             .description(description)
             .footer(self.footer())
             .thumbnail(self.user.avatar_url());
 
-        Ok(BuildPage::new(embed, true))
+        Ok(BuildPage::new(self.add_graph(embed), true))
     }
 
     async fn user_stats(&mut self) -> Result<BuildPage> {
@@ -487,7 +525,7 @@ impl ProfileMenu {
             .footer(self.footer())
             .thumbnail(self.user.avatar_url());
 
-        Ok(BuildPage::new(embed, true))
+        Ok(BuildPage::new(self.add_graph(embed), true))
     }
 
     async fn top100_stats(&mut self) -> Result<BuildPage> {
@@ -670,7 +708,7 @@ impl ProfileMenu {
             .description(description)
             .thumbnail(self.user.avatar_url());
 
-        Ok(BuildPage::new(embed, true))
+        Ok(BuildPage::new(self.add_graph(embed), true))
     }
 
     async fn top100_mods(&mut self) -> Result<BuildPage> {
@@ -773,7 +811,7 @@ impl ProfileMenu {
             .fields(fields)
             .thumbnail(self.user.avatar_url());
 
-        Ok(BuildPage::new(embed, true))
+        Ok(BuildPage::new(self.add_graph(embed), true))
     }
 
     async fn top100_mappers(&mut self) -> Result<BuildPage> {
@@ -841,7 +879,7 @@ impl ProfileMenu {
             .description(description)
             .thumbnail(self.user.avatar_url());
 
-        Ok(BuildPage::new(embed, true))
+        Ok(BuildPage::new(self.add_graph(embed), true))
     }
 
     async fn mapper_stats(&mut self) -> Result<BuildPage> {
@@ -949,7 +987,7 @@ impl ProfileMenu {
             .fields(fields)
             .thumbnail(self.user.avatar_url());
 
-        Ok(BuildPage::new(embed, true))
+        Ok(BuildPage::new(self.add_graph(embed), true))
     }
 
     async fn own_maps_in_top100(&mut self) -> Option<usize> {
@@ -0,0 +1,93 @@
+use std::fmt::Write;
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_model::rosu_v2::user::{Badge, User};
+use bathbot_util::{CowUtils, EmbedBuilder, FooterBuilder};
+use eyre::Result;
+use futures::future::BoxFuture;
+use twilight_model::{
+    channel::message::Component,
+    id::{marker::UserMarker, Id},
+};
+
+use crate::{
+    active::{
+        pagination::{handle_pagination_component, handle_pagination_modal, Pages},
+        BuildPage, ComponentResult, IActiveMessage,
+    },
+    manager::redis::RedisData,
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+#[derive(PaginationBuilder)]
+pub struct ProfileBadgesPagination {
+    user: RedisData<User>,
+    #[pagination(per_page = 10)]
+    badges: Box<[Badge]>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for ProfileBadgesPagination {
+    fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let pages = &self.pages;
+        let idx = pages.index();
+        let badges = &self.badges[idx..self.badges.len().min(idx + pages.per_page())];
+
+        let mut description = String::with_capacity(badges.len() * 100);
+
+        for (badge, i) in badges.iter().zip(idx + 1..) {
+            let badge_description = badge.description.cow_escape_markdown();
+
+            let _ = if badge.url.is_empty() {
+                write!(description, "**{i}.** {badge_description}")
+            } else {
+                write!(
+                    description,
+                    "**{i}.** [{badge_description}]({url})",
+                    url = badge.url
+                )
+            };
+
+            let _ = writeln!(
+                description,
+                " • <t:{}:d>",
+                badge.awarded_at.unix_timestamp()
+            );
+        }
+
+        let page = pages.curr_page();
+        let last_page = pages.last_page();
+        let footer_text = format!("Page {page}/{last_page} • {} badges", self.badges.len());
+
+        let mut embed = EmbedBuilder::new()
+            .author(self.user.author_builder())
+            .description(description)
+            .footer(FooterBuilder::new(footer_text))
+            .title("Profile badges:");
+
+        if let Some(badge) = badges.first() {
+            embed = embed.thumbnail(badge.image_url.as_ref());
+        }
+
+        BuildPage::new(embed, false).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(component, self.msg_owner, false, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages)
+    }
+}
@@ -10,6 +10,7 @@ use bathbot_macros::PaginationBuilder;
 use bathbot_model::{BgGameScore, EmbedHeader, RankingEntries, RankingEntry, RankingKind};
 use bathbot_util::{
     numbers::{round, WithComma},
+    osu::FlagEmote,
     EmbedBuilder,
 };
 use eyre::{Result, WrapErr};
@@ -435,7 +436,7 @@ impl<V> Display for CountryFormatter<'_, V> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         if let Some(ref country) = self.entry.country {
-            write!(f, ":flag_{}:", country.to_ascii_lowercase())
+            write!(f, "{}", FlagEmote::new(country.as_str()))
         } else {
             f.write_str(" ")
         }
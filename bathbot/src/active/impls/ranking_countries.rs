@@ -1,7 +1,7 @@
 use std::{collections::BTreeMap, fmt::Write};
 
 use bathbot_macros::PaginationBuilder;
-use bathbot_util::{numbers::WithComma, EmbedBuilder, FooterBuilder};
+use bathbot_util::{numbers::WithComma, osu::FlagEmote, EmbedBuilder, FooterBuilder};
 use eyre::{Result, WrapErr};
 use futures::future::BoxFuture;
 use rosu_v2::prelude::{CountryRanking, GameMode};
@@ -140,10 +140,10 @@ impl RankingCountriesPagination {
 
             let _ = write!(
                 description,
-                "`#{idx:<idx_len$}` :flag_{code}: `{name:<name_len$}` `{pp:>pp_len$}pp`",
+                "`#{idx:<idx_len$}` {flag} `{name:<name_len$}` `{pp:>pp_len$}pp`",
                 idx = idx,
                 idx_len = idx_len,
-                code = country.country_code.to_ascii_lowercase(),
+                flag = FlagEmote::new(country.country_code.as_str()),
                 name = country.country,
                 name_len = name_len,
                 pp = buf,
@@ -282,7 +282,7 @@ impl IActiveMessage for SettingsImport {
         };
 
         if owner != self.msg_owner {
-            return ComponentResult::Ignore.boxed();
+            return ComponentResult::NotOwner.boxed();
         }
 
         let input = TextInputBuilder::new("input", "Yuna embed text")
@@ -574,7 +574,7 @@ impl IActiveMessage for RenderSettingsActive {
         };
 
         if user_id != self.msg_owner {
-            return ComponentResult::Ignore.boxed();
+            return ComponentResult::NotOwner.boxed();
         }
 
         match component.data.custom_id.as_str() {
@@ -0,0 +1,96 @@
+use std::fmt::Write;
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_util::{numbers::WithComma, EmbedBuilder, FooterBuilder};
+use eyre::Result;
+use futures::future::BoxFuture;
+use twilight_model::{
+    channel::message::Component,
+    id::{marker::UserMarker, Id},
+};
+
+use crate::{
+    active::{
+        pagination::{handle_pagination_component, handle_pagination_modal, Pages},
+        BuildPage, ComponentResult, IActiveMessage,
+    },
+    core::ShardStatus,
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+pub struct ShardEntry {
+    pub shard_id: u64,
+    pub status: Option<ShardStatus>,
+    pub guilds: usize,
+}
+
+#[derive(PaginationBuilder)]
+pub struct ShardsPagination {
+    #[pagination(per_page = 15)]
+    entries: Box<[ShardEntry]>,
+    total_guilds: isize,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for ShardsPagination {
+    fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let idx = self.pages.index();
+        let entries = &self.entries[idx..self.entries.len().min(idx + self.pages.per_page())];
+
+        let mut description = String::with_capacity(entries.len() * 48);
+
+        for entry in entries {
+            let _ = write!(description, "`#{}` ", entry.shard_id);
+
+            match entry.status {
+                Some(ShardStatus { state, latency }) => {
+                    let _ = write!(description, "**{}** • ", state.as_str());
+
+                    match latency {
+                        Some(latency) => {
+                            let _ = write!(description, "{}ms", latency.as_millis());
+                        }
+                        None => description.push_str("-ms"),
+                    }
+                }
+                None => description.push_str("**No events yet**"),
+            }
+
+            let _ = writeln!(description, " • {} guilds", WithComma::new(entry.guilds));
+        }
+
+        let footer = format!(
+            "Page {}/{} • {} shards • {} guilds in total",
+            self.pages.curr_page(),
+            self.pages.last_page(),
+            self.entries.len(),
+            WithComma::new(self.total_guilds),
+        );
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(FooterBuilder::new(footer))
+            .title("Shard status");
+
+        BuildPage::new(embed, false).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(component, self.msg_owner, false, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages)
+    }
+}
@@ -1,3 +1,7 @@
+use rosu_pp::{
+    catch::CatchPerformanceAttributes, mania::ManiaPerformanceAttributes,
+    osu::OsuPerformanceAttributes, taiko::TaikoPerformanceAttributes,
+};
 use rosu_v2::{
     mods,
     prelude::{GameMod, GameMods},
@@ -25,6 +29,8 @@ pub struct SimulateData {
     pub version: TopOldVersion,
     pub attrs: SimulateAttributes,
     pub max_combo: u32,
+    /// Whether the individual pp components should be shown
+    pub verbose: bool,
 }
 
 impl SimulateData {
@@ -65,6 +71,7 @@ impl SimulateData {
                     map: map.pp_map().unchecked_as_converted();
                     arg: as_owned();
                     max_new: attrs;
+                    breakdown: components;
                 }
             };
             (
@@ -75,6 +82,7 @@ impl SimulateData {
                 $( arg: $arg:ident(); )?
                 max_new: $max_new:tt;
                 $( max_post: $max_post:ident; )?
+                $( breakdown: $breakdown:ident; )?
             ) => {{
                 let map = $map;
                 let arg = simulate!(@MAP map $( .$arg() )?);
@@ -103,14 +111,22 @@ impl SimulateData {
                     .calculate()
                     .pp;
 
-                (stars, pp, max_pp)
+                let breakdown = simulate!(@BREAKDOWN attrs $( $breakdown )?);
+
+                (stars, pp, max_pp, breakdown)
             }};
             (@MAP $map:ident $( .$fn:ident() )? ) => {
                 $map $( .$fn() )?
             };
+            (@BREAKDOWN $attrs:ident) => {
+                None
+            };
+            (@BREAKDOWN $attrs:ident $breakdown:ident) => {
+                Some(PpBreakdown::from(&$attrs))
+            };
         }
 
-        let (stars, pp, max_pp) = match self.version {
+        let (stars, pp, max_pp, breakdown) = match self.version {
             TopOldVersion::Osu(TopOldOsuVersion::May14July14) => simulate! {
                 rosu_pp_older::osu_2014_may::OsuPP {
                     combo: combo,
@@ -352,11 +368,25 @@ impl SimulateData {
             stars: stars as f32,
             pp: pp as f32,
             max_pp: max_pp as f32,
+            breakdown: breakdown.filter(|_| self.verbose),
             clock_rate,
             combo_ratio,
             score_state,
         }
     }
+
+    /// Simulate the same score but with maximum combo and return its pp.
+    ///
+    /// Returns `None` if the combo is already at its maximum.
+    pub(super) fn simulate_max_combo(&mut self, map: &SimulateMap) -> Option<f32> {
+        let combo = self.combo.filter(|&combo| combo < self.max_combo)?;
+
+        self.combo = None;
+        let SimulateValues { pp, .. } = self.simulate(map);
+        self.combo = Some(combo);
+
+        Some(pp)
+    }
 }
 
 fn score_multiplier(mods: &GameMods) -> f32 {
@@ -397,11 +427,57 @@ pub(super) struct SimulateValues {
     pub stars: f32,
     pub pp: f32,
     pub max_pp: f32,
+    pub breakdown: Option<PpBreakdown>,
     pub clock_rate: Option<f32>,
     pub combo_ratio: ComboOrRatio,
     pub score_state: StateOrScore,
 }
 
+/// The pp values of each skill that make up the total pp.
+///
+/// Only available for the current pp versions.
+pub(super) struct PpBreakdown {
+    pub components: Vec<(&'static str, f64)>,
+}
+
+impl From<&OsuPerformanceAttributes> for PpBreakdown {
+    fn from(attrs: &OsuPerformanceAttributes) -> Self {
+        Self {
+            components: vec![
+                ("Aim", attrs.pp_aim),
+                ("Speed", attrs.pp_speed),
+                ("Acc", attrs.pp_acc),
+                ("FL", attrs.pp_flashlight),
+            ],
+        }
+    }
+}
+
+impl From<&TaikoPerformanceAttributes> for PpBreakdown {
+    fn from(attrs: &TaikoPerformanceAttributes) -> Self {
+        Self {
+            components: vec![("Difficulty", attrs.pp_difficulty), ("Acc", attrs.pp_acc)],
+        }
+    }
+}
+
+impl From<&CatchPerformanceAttributes> for PpBreakdown {
+    fn from(_: &CatchPerformanceAttributes) -> Self {
+        // catch pp are not split into components
+        Self {
+            components: Vec::new(),
+        }
+    }
+}
+
+impl From<&ManiaPerformanceAttributes> for PpBreakdown {
+    fn from(attrs: &ManiaPerformanceAttributes) -> Self {
+        Self {
+            components: vec![("Difficulty", attrs.pp_difficulty)],
+        }
+    }
+}
+
 pub(super) enum StateOrScore {
     Score(u32),
     State(ScoreState),
@@ -27,16 +27,21 @@ use rosu_v2::{
         GameMod, GameMods,
     },
     mods,
-    prelude::{GameMode, GameModsIntermode, Grade},
+    prelude::{GameModIntermode, GameMode, GameModsIntermode, Grade},
 };
 use twilight_model::{
-    channel::message::{embed::EmbedField, Component},
+    channel::message::{
+        component::{ActionRow, SelectMenu, SelectMenuOption},
+        embed::EmbedField,
+        Component,
+    },
     id::{marker::UserMarker, Id},
 };
 
 pub use self::{attrs::SimulateAttributes, data::SimulateData, top_old::TopOldVersion};
 use crate::{
     active::{
+        can_interact,
         impls::simulate::data::{ComboOrRatio, SimulateValues, StateOrScore},
         BuildPage, ComponentResult, IActiveMessage,
     },
@@ -135,11 +140,17 @@ impl IActiveMessage for SimulateComponents {
             stars,
             pp,
             max_pp,
+            breakdown,
             clock_rate,
             combo_ratio,
             score_state,
         } = self.data.simulate(&self.map);
 
+        let max_combo_pp = match combo_ratio {
+            ComboOrRatio::Combo { .. } => self.data.simulate_max_combo(&self.map),
+            ComboOrRatio::Ratio(_) | ComboOrRatio::Neither => None,
+        };
+
         let mods = self
             .data
             .mods
@@ -232,7 +243,28 @@ impl IActiveMessage for SimulateComponents {
             fields.push(combo);
         }
 
-        fields![fields { "PP", PpFormatter::new(Some(pp), Some(max_pp)).to_string(), true; }];
+        let mut pp_value = PpFormatter::new(Some(pp), Some(max_pp)).to_string();
+
+        if let Some(max_combo_pp) = max_combo_pp {
+            let _ = write!(
+                pp_value,
+                "\nMax combo: **{:.2}pp** (-{:.2})",
+                max_combo_pp,
+                (max_combo_pp - pp).max(0.0)
+            );
+        }
+
+        fields![fields { "PP", pp_value, true; }];
+
+        if let Some(breakdown) = breakdown.filter(|breakdown| !breakdown.components.is_empty()) {
+            let mut value = String::with_capacity(breakdown.components.len() * 24);
+
+            for (name, pp) in breakdown.components {
+                let _ = writeln!(value, "{name}: **{pp:.2}pp**");
+            }
+
+            fields![fields { "PP breakdown", value, true; }];
+        }
 
         if let Some(clock_rate) = clock_rate {
             fields![fields { "Clock rate", format!("{clock_rate:.2}"), true }];
@@ -266,21 +298,124 @@ impl IActiveMessage for SimulateComponents {
     }
 
     fn build_components(&self) -> Vec<Component> {
-        self.data.version.components()
+        let mut components = self.data.version.components();
+        components.push(mods_menu(self.map.mode(), self.data.mods.as_ref()));
+
+        components
     }
 
     fn handle_component<'a>(
         &'a mut self,
-
         component: &'a mut InteractionComponent,
     ) -> BoxFuture<'a, ComponentResult> {
+        Box::pin(self.async_handle_component(component))
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        Box::pin(self.async_handle_modal(modal))
+    }
+}
+
+impl SimulateComponents {
+    pub fn new(map: SimulateMap, data: SimulateData, msg_owner: Id<UserMarker>) -> Self {
+        Self {
+            map,
+            data,
+            msg_owner,
+        }
+    }
+
+    async fn handle_topold_menu(
+        &mut self,
+
+        component: &mut InteractionComponent,
+    ) -> ComponentResult {
+        let Some(version) = component.data.values.first() else {
+            return ComponentResult::Err(eyre!("Missing simulate version"));
+        };
+
+        let Some(version) = TopOldVersion::from_menu_str(version) else {
+            return ComponentResult::Err(eyre!("Unknown TopOldVersion `{version}`"));
+        };
+
+        if let Err(err) = component.defer().await.map_err(Report::new) {
+            return ComponentResult::Err(err.wrap_err("Failed to defer component"));
+        }
+
+        self.data.version = version;
+
+        ComponentResult::BuildPage
+    }
+
+    /// Toggle the mods of the menu while keeping all other typed mods.
+    async fn handle_mods_menu(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        let mode = self.map.mode();
+
+        let selected = match component.data.values.concat().parse::<GameModsIntermode>() {
+            Ok(selected) => selected,
+            Err(_) => return ComponentResult::Err(eyre!("Unknown simulate mods menu values")),
+        };
+
+        if let Err(err) = component.defer().await.map_err(Report::new) {
+            return ComponentResult::Err(err.wrap_err("Failed to defer component"));
+        }
+
+        let prev = self.data.mods.as_ref();
+
+        let mut mods: GameMods = prev
+            .into_iter()
+            .flat_map(GameMods::iter)
+            .filter(|gamemod| match menu_mod(mode, gamemod.intermode()) {
+                Some(menu_mod) => selected.contains(menu_mod),
+                None => true,
+            })
+            .cloned()
+            .collect();
+
+        let added: GameModsIntermode = selected
+            .iter()
+            .filter(|&selected| {
+                !mods
+                    .iter()
+                    .any(|gamemod| menu_mod(mode, gamemod.intermode()) == Some(selected))
+            })
+            .collect();
+
+        let Some(added) = added.try_with_mode(mode) else {
+            return ComponentResult::Err(eyre!("Invalid simulate menu mods for {mode:?}"));
+        };
+
+        mods = mods.iter().chain(added.iter()).cloned().collect();
+
+        if !mods.is_valid() {
+            // Conflicts with a typed mod; keep the previous mods and let the
+            // rebuilt menu reflect them again.
+            debug!("Incompatible mods {mods}");
+
+            return ComponentResult::BuildPage;
+        }
+
+        self.data.mods = (!mods.is_empty()).then_some(mods);
+
+        ComponentResult::BuildPage
+    }
+
+    async fn async_handle_component(
+        &mut self,
+        component: &mut InteractionComponent,
+    ) -> ComponentResult {
         let user_id = match component.user_id() {
             Ok(user_id) => user_id,
-            Err(err) => return ComponentResult::Err(err).boxed(),
+            Err(err) => return ComponentResult::Err(err),
         };
 
-        if user_id != self.msg_owner {
-            return ComponentResult::Ignore.boxed();
+        match can_interact(user_id, component.guild_id, self.msg_owner).await {
+            Ok(true) => {}
+            Ok(false) => return ComponentResult::NotOwner,
+            Err(err) => return ComponentResult::Err(err),
         }
 
         let modal = match component.data.custom_id.as_str() {
@@ -394,59 +529,21 @@ impl IActiveMessage for SimulateComponents {
                     .input(od)
             }
             "sim_osu_version" | "sim_taiko_version" | "sim_catch_version" | "sim_mania_version" => {
-                return Box::pin(self.handle_topold_menu(component));
+                return self.handle_topold_menu(component).await;
             }
+            "sim_mods_menu" => return self.handle_mods_menu(component).await,
             other => {
                 warn!(name = %other, ?component, "Unknown simulate component");
 
-                return ComponentResult::Ignore.boxed();
+                return ComponentResult::Ignore;
             }
         };
 
-        ComponentResult::CreateModal(modal).boxed()
-    }
-
-    fn handle_modal<'a>(
-        &'a mut self,
-        modal: &'a mut InteractionModal,
-    ) -> BoxFuture<'a, Result<()>> {
-        Box::pin(self.async_handle_modal(modal))
-    }
-}
-
-impl SimulateComponents {
-    pub fn new(map: SimulateMap, data: SimulateData, msg_owner: Id<UserMarker>) -> Self {
-        Self {
-            map,
-            data,
-            msg_owner,
-        }
-    }
-
-    async fn handle_topold_menu(
-        &mut self,
-
-        component: &mut InteractionComponent,
-    ) -> ComponentResult {
-        let Some(version) = component.data.values.first() else {
-            return ComponentResult::Err(eyre!("Missing simulate version"));
-        };
-
-        let Some(version) = TopOldVersion::from_menu_str(version) else {
-            return ComponentResult::Err(eyre!("Unknown TopOldVersion `{version}`"));
-        };
-
-        if let Err(err) = component.defer().await.map_err(Report::new) {
-            return ComponentResult::Err(err.wrap_err("Failed to defer component"));
-        }
-
-        self.data.version = version;
-
-        ComponentResult::BuildPage
+        ComponentResult::CreateModal(modal)
     }
 
     async fn async_handle_modal(&mut self, modal: &mut InteractionModal) -> Result<()> {
-        if modal.user_id()? != self.msg_owner {
+        if !can_interact(modal.user_id()?, modal.guild_id, self.msg_owner).await? {
             return Ok(());
         }
 
@@ -595,6 +692,73 @@ impl SimulateComponents {
     }
 }
 
+/// Mods that can be toggled through the simulate mods menu.
+fn menu_mods(mode: GameMode) -> &'static [(GameModIntermode, &'static str)] {
+    const WITH_HR: &[(GameModIntermode, &str)] = &[
+        (GameModIntermode::Hidden, "Hidden"),
+        (GameModIntermode::HardRock, "HardRock"),
+        (GameModIntermode::DoubleTime, "DoubleTime"),
+        (GameModIntermode::Flashlight, "Flashlight"),
+    ];
+
+    const WITHOUT_HR: &[(GameModIntermode, &str)] = &[
+        (GameModIntermode::Hidden, "Hidden"),
+        (GameModIntermode::DoubleTime, "DoubleTime"),
+        (GameModIntermode::Flashlight, "Flashlight"),
+    ];
+
+    match mode {
+        GameMode::Osu | GameMode::Taiko | GameMode::Catch => WITH_HR,
+        GameMode::Mania => WITHOUT_HR,
+    }
+}
+
+/// The menu mod that a given mod is toggled through, if any.
+fn menu_mod(mode: GameMode, gamemod: GameModIntermode) -> Option<GameModIntermode> {
+    let gamemod = match gamemod {
+        GameModIntermode::Nightcore => GameModIntermode::DoubleTime,
+        other => other,
+    };
+
+    menu_mods(mode)
+        .iter()
+        .any(|(menu_mod, _)| *menu_mod == gamemod)
+        .then_some(gamemod)
+}
+
+fn mods_menu(mode: GameMode, mods: Option<&GameMods>) -> Component {
+    let options: Vec<_> = menu_mods(mode)
+        .iter()
+        .map(|(gamemod, label)| {
+            let default = mods.is_some_and(|mods| {
+                mods.iter()
+                    .any(|m| menu_mod(mode, m.intermode()) == Some(*gamemod))
+            });
+
+            SelectMenuOption {
+                default,
+                description: None,
+                emoji: None,
+                label: (*label).to_owned(),
+                value: gamemod.acronym().as_str().to_owned(),
+            }
+        })
+        .collect();
+
+    let menu = SelectMenu {
+        custom_id: "sim_mods_menu".to_owned(),
+        disabled: false,
+        max_values: Some(options.len() as u8),
+        min_values: Some(0),
+        options,
+        placeholder: Some("Toggle mods".to_owned()),
+    };
+
+    Component::ActionRow(ActionRow {
+        components: vec![Component::SelectMenu(menu)],
+    })
+}
+
 fn parse_attr(modal: &InteractionModal, component_id: &str) -> Option<f32> {
     modal
         .data
@@ -3,7 +3,7 @@ use std::{borrow::Cow, fmt::Write, time::Duration};
 use bathbot_model::{
     embed_builder::{
         EmoteTextValue, HitresultsValue, MapperValue, ScoreEmbedSettings, SettingValue,
-        SettingsImage, Value,
+        SettingsImage, SettingsThumbnail, Value,
     },
     rosu_v2::user::User,
 };
@@ -18,10 +18,7 @@ use bathbot_util::{
 use eyre::{Report, Result};
 use futures::future::BoxFuture;
 use rosu_pp::model::beatmap::BeatmapAttributes;
-use rosu_v2::{
-    model::{GameMode, Grade},
-    prelude::RankStatus,
-};
+use rosu_v2::{model::GameMode, prelude::RankStatus};
 use time::OffsetDateTime;
 use twilight_model::{
     channel::message::{
@@ -37,6 +34,7 @@ use twilight_model::{
 
 use crate::{
     active::{
+        can_interact,
         impls::{embed_builder::ValueKind, CachedRender, CachedRenderData},
         pagination::{async_handle_pagination_component, handle_pagination_modal, Pages},
         ActiveMessages, BuildPage, ComponentResult, IActiveMessage,
@@ -50,7 +48,7 @@ use crate::{
     manager::{redis::RedisData, OwnedReplayScore, ReplayScore},
     util::{
         interaction::{InteractionComponent, InteractionModal},
-        osu::{GradeFormatter, ScoreFormatter},
+        osu::{fail_completion, GradeFormatter, ScoreFormatter},
         Authored, Emote, MessageExt,
     },
 };
@@ -60,6 +58,7 @@ pub struct SingleScorePagination {
     scores: Box<[ScoreEmbedDataWrap]>,
     score_data: ScoreData,
     username: Box<str>,
+    avatar_url: Box<str>,
     msg_owner: Id<UserMarker>,
     pages: Pages,
 
@@ -85,6 +84,7 @@ impl SingleScorePagination {
             scores,
             score_data,
             username: Box::from(user.username()),
+            avatar_url: Box::from(user.avatar_url()),
             msg_owner,
             pages,
             author: user.author_builder(),
@@ -103,7 +103,13 @@ impl SingleScorePagination {
     ) -> Result<BuildPage> {
         let score = &*self.scores[self.pages.index()].get_mut().await?;
 
-        let embed = Self::apply_settings(&self.settings, score, self.score_data, mark_idx);
+        let embed = Self::apply_settings(
+            &self.settings,
+            score,
+            self.score_data,
+            mark_idx,
+            &self.avatar_url,
+        );
 
         let url = format!("{OSU_BASE}b/{}", score.map.map_id());
 
@@ -154,8 +160,9 @@ impl SingleScorePagination {
         data: &ScoreEmbedData,
         score_data: ScoreData,
         mark_idx: MarkIndex,
+        avatar_url: &str,
     ) -> EmbedBuilder {
-        apply_settings(settings, data, score_data, mark_idx)
+        apply_settings(settings, data, score_data, mark_idx, avatar_url)
     }
 
     async fn async_handle_component(
@@ -167,8 +174,10 @@ impl SingleScorePagination {
             Err(err) => return ComponentResult::Err(err),
         };
 
-        if user_id != self.msg_owner {
-            return ComponentResult::Ignore;
+        match can_interact(user_id, component.guild_id, self.msg_owner).await {
+            Ok(true) => {}
+            Ok(false) => return ComponentResult::NotOwner,
+            Err(err) => return ComponentResult::Err(err),
         }
 
         match component.data.custom_id.as_str() {
@@ -521,6 +530,7 @@ fn apply_settings(
     data: &ScoreEmbedData,
     score_data: ScoreData,
     mark_idx: MarkIndex,
+    avatar_url: &str,
 ) -> EmbedBuilder {
     const SEP_NAME: &str = "\t";
     const SEP_VALUE: &str = " • ";
@@ -935,7 +945,13 @@ fn apply_settings(
     let mut builder = EmbedBuilder::new().fields(fields).title(title);
 
     match settings.image {
-        SettingsImage::Thumbnail => builder = builder.thumbnail(data.map.thumbnail()),
+        SettingsImage::Thumbnail => {
+            builder = match settings.thumbnail {
+                SettingsThumbnail::ListCover => builder.thumbnail(data.map.thumbnail()),
+                SettingsThumbnail::Card => builder.thumbnail(data.map.card()),
+                SettingsThumbnail::Avatar => builder.thumbnail(avatar_url),
+            }
+        }
         SettingsImage::Image => builder = builder.image(data.map.cover()),
         SettingsImage::ImageWithStrains => {
             builder = builder.image(attachment(SingleScorePagination::IMAGE_NAME));
@@ -943,6 +959,15 @@ fn apply_settings(
         SettingsImage::Hide => {}
     }
 
+    // Scores of unknown version don't get a label
+    if let Some(version) = data.score.version().filter(|_| settings.show_score_version) {
+        if !footer_text.is_empty() {
+            footer_text.push_str(SEP_VALUE);
+        }
+
+        let _ = write!(footer_text, "{version}");
+    }
+
     if !footer_text.is_empty() {
         let emote = Emote::from(data.score.mode).url();
         let footer = FooterBuilder::new(footer_text).icon_url(emote);
@@ -979,20 +1004,14 @@ fn write_value(
                 )
             };
 
-            // The completion is very hard to calculate for `Catch` because
-            // `n_objects` is not correct due to juicestreams so we won't
-            // show it for that mode.
-            let is_fail = data.score.grade == Grade::F && data.score.mode != GameMode::Catch;
-
-            if is_fail {
-                let n_objects = data.map.n_objects();
-
-                let completion = if n_objects != 0 {
-                    100 * data.score.total_hits() / n_objects
-                } else {
-                    100
-                };
+            let completion = fail_completion(
+                data.score.grade,
+                data.score.mode,
+                data.score.total_hits(),
+                data.map.n_objects(),
+            );
 
+            if let Some(completion) = completion {
                 let _ = write!(writer, "@{completion}%");
             }
         }
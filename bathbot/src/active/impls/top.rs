@@ -5,7 +5,7 @@ use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
     constants::OSU_BASE,
     datetime::HowLongAgoDynamic,
-    numbers::{round, WithComma},
+    numbers::{round, round_to, WithComma},
     CowUtils, EmbedBuilder, FooterBuilder, ModsFormatter, ScoreExt,
 };
 use eyre::Result;
@@ -42,6 +42,9 @@ pub struct TopPagination {
     sort_by: TopScoreOrder,
     condensed_list: bool,
     score_data: ScoreData,
+    weighted: bool,
+    unchoked: Option<Box<[Option<f32>]>>,
+    pp_decimals: u8,
     content: Box<str>,
     msg_owner: Id<UserMarker>,
     pages: Pages,
@@ -56,11 +59,49 @@ impl TopPagination {
             sort_by: None,
             condensed_list: None,
             score_data: None,
+            weighted: false,
+            unchoked: None,
+            pp_decimals: 2,
             content: None,
             msg_owner: None,
         }
     }
 
+    /// The pp that the score contributes to the user's total if weighting
+    /// should be shown, empty otherwise.
+    fn weighted_pp(&self, entry: &ScoreEmbedDataHalf) -> WeightedPp {
+        let idx = if self.weighted {
+            entry
+                .original_idx
+                .or(entry.pb_idx.as_ref().and_then(|idx| idx.idx))
+        } else {
+            None
+        };
+
+        WeightedPp {
+            pp: entry.score.pp,
+            idx,
+            decimals: self.pp_decimals,
+        }
+    }
+
+    /// The unchoked pp of the entry at the given index if unchoked pp should be
+    /// shown and the score is not already an FC.
+    fn unchoked_pp(&self, idx: usize) -> UnchokedPp {
+        let pp = self.entries[idx].get_half().score.pp;
+
+        let fc_pp = self
+            .unchoked
+            .as_deref()
+            .and_then(|unchoked| unchoked.get(idx).copied().flatten());
+
+        UnchokedPp {
+            pp,
+            fc_pp,
+            decimals: self.pp_decimals,
+        }
+    }
+
     fn build_condensed(&self) -> BuildPage {
         let pages = &self.pages;
         let end_idx = self.entries.len().min(pages.index() + pages.per_page());
@@ -92,7 +133,8 @@ impl TopPagination {
     fn condensed_description(&self, entries: &[ScoreEmbedDataWrap]) -> String {
         let mut description = String::with_capacity(1024);
 
-        for entry in entries {
+        for (i, entry) in entries.iter().enumerate() {
+            let unchoked = self.unchoked_pp(self.pages.index() + i);
             let entry = entry.get_half();
 
             let ScoreEmbedDataHalf {
@@ -108,13 +150,14 @@ impl TopPagination {
             let _ = writeln!(
                 description,
                 "**#{idx} [{map}]({OSU_BASE}b/{map_id})** [{stars}★]\n\
-                {grade} **{pp}pp** ({acc}%) [**{combo}x**/{max_combo}x] {miss}**+{mods}** {appendix}",
+                {grade} **{pp}pp**{weighted}{unchoked} ({acc}%) [**{combo}x**/{max_combo}x] {miss}**+{mods}** {appendix}",
                 idx = original_idx.or(pb_idx.as_ref().and_then(|idx| idx.idx)).expect("missing idx") + 1,
+                weighted = self.weighted_pp(entry),
                 map = MapFormat::new(map),
                 map_id = map.map_id(),
                 stars = round(*stars),
                 grade = GradeFormatter::new(score.grade, Some(score.score_id), score.is_legacy()),
-                pp = round(score.pp),
+                pp = round_to(score.pp, self.pp_decimals),
                 acc = round(score.accuracy),
                 combo = score.max_combo,
                 miss = MissFormat(score.statistics.count_miss),
@@ -129,7 +172,8 @@ impl TopPagination {
     fn condensed_description_mania(&self, entries: &[ScoreEmbedDataWrap]) -> String {
         let mut description = String::with_capacity(1024);
 
-        for entry in entries {
+        for (i, entry) in entries.iter().enumerate() {
+            let unchoked = self.unchoked_pp(self.pages.index() + i);
             let entry = entry.get_half();
 
             let ScoreEmbedDataHalf {
@@ -146,13 +190,14 @@ impl TopPagination {
             let _ = writeln!(
                 description,
                 "**#{idx} [{map}]({OSU_BASE}b/{map_id})** [{stars}★]\n\
-                {grade} **{pp}pp** {acc}% `{score}` {{{n320}/{n300}/../{miss}}} **+{mods}** {appendix}",
+                {grade} **{pp}pp**{weighted}{unchoked} {acc}% `{score}` {{{n320}/{n300}/../{miss}}} **+{mods}** {appendix}",
                 idx = original_idx.or(pb_idx.as_ref().and_then(|idx| idx.idx)).expect("missing idx") + 1,
+                weighted = self.weighted_pp(entry),
                 map = MapFormat::new(map),
                 map_id = map.map_id(),
                 stars = round(*stars),
                 grade = GradeFormatter::new(score.grade, Some(score.score_id), score.is_legacy()),
-                pp = round(score.pp),
+                pp = round_to(score.pp, self.pp_decimals),
                 acc = round(score.accuracy),
                 // currently ignoring classic scoring, should it be considered for mania?
                 score = ScoreFormat(score.score),
@@ -175,7 +220,8 @@ impl TopPagination {
 
         let mut description = String::with_capacity(512);
 
-        for entry in scores {
+        for (i, entry) in scores.iter().enumerate() {
+            let unchoked = self.unchoked_pp(self.pages.index() + i);
             let entry = entry.get_half();
 
             let ScoreEmbedDataHalf {
@@ -192,7 +238,7 @@ impl TopPagination {
             let _ = writeln!(
                 description,
                 "**#{idx} [{title} [{version}]]({OSU_BASE}b/{id}) +{mods}** [{stars:.2}★]\n\
-                {grade} {pp} • {acc}% • {score}\n[ {combo} ] • {hits} • {appendix}",
+                {grade} {pp}{weighted}{unchoked} • {acc}% • {score}\n[ {combo} ] • {hits} • {appendix}",
                 idx = original_idx
                     .or(pb_idx.as_ref().and_then(|idx| idx.idx))
                     .expect("missing idx")
@@ -202,7 +248,8 @@ impl TopPagination {
                 id = map.map_id(),
                 mods = ModsFormatter::new(&score.mods),
                 grade = GradeFormatter::new(score.grade, Some(score.score_id), score.is_legacy()),
-                pp = PpFormatter::new(Some(score.pp), Some(*max_pp)),
+                pp = PpFormatter::new(Some(score.pp), Some(*max_pp)).decimals(self.pp_decimals),
+                weighted = self.weighted_pp(entry),
                 acc = round(score.accuracy),
                 score = ScoreFormatter::new(score, self.score_data),
                 combo = ComboFormatter::new(score.max_combo, Some(*max_combo)),
@@ -271,6 +318,9 @@ pub struct TopPaginationBuilder {
     sort_by: Option<TopScoreOrder>,
     condensed_list: Option<bool>,
     score_data: Option<ScoreData>,
+    weighted: bool,
+    unchoked: Option<Box<[Option<f32>]>>,
+    pp_decimals: u8,
     content: Option<Box<str>>,
     msg_owner: Option<Id<UserMarker>>,
 }
@@ -299,6 +349,9 @@ impl TopPaginationBuilder {
             sort_by,
             condensed_list,
             score_data,
+            weighted: self.weighted,
+            unchoked: self.unchoked.take(),
+            pp_decimals: self.pp_decimals,
             content,
             msg_owner,
             pages,
@@ -341,6 +394,26 @@ impl TopPaginationBuilder {
         self
     }
 
+    pub fn weighted(&mut self, weighted: bool) -> &mut Self {
+        self.weighted = weighted;
+
+        self
+    }
+
+    /// FC pp for each entry, `None` for entries that are already FCs.
+    pub fn unchoked(&mut self, unchoked: Option<Box<[Option<f32>]>>) -> &mut Self {
+        self.unchoked = unchoked;
+
+        self
+    }
+
+    /// Amount of decimal places for pp values, defaults to 2.
+    pub fn pp_decimals(&mut self, pp_decimals: u8) -> &mut Self {
+        self.pp_decimals = pp_decimals;
+
+        self
+    }
+
     pub fn content(&mut self, content: Box<str>) -> &mut Self {
         self.content = Some(content);
 
@@ -363,6 +436,43 @@ fn mode_str(mode: GameMode) -> &'static str {
     }
 }
 
+struct WeightedPp {
+    pp: f32,
+    idx: Option<usize>,
+    decimals: u8,
+}
+
+impl Display for WeightedPp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let Some(idx) = self.idx else { return Ok(()) };
+
+        let weighted = self.pp * 0.95_f32.powi(idx as i32);
+
+        write!(f, " (~{}pp weighted)", round_to(weighted, self.decimals))
+    }
+}
+
+struct UnchokedPp {
+    pp: f32,
+    fc_pp: Option<f32>,
+    decimals: u8,
+}
+
+impl Display for UnchokedPp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let Some(fc_pp) = self.fc_pp else {
+            return Ok(());
+        };
+
+        write!(
+            f,
+            " (FC: {}pp, +{})",
+            round_to(fc_pp, self.decimals),
+            round_to((fc_pp - self.pp).max(0.0), self.decimals)
+        )
+    }
+}
+
 struct MapFormat<'m> {
     map: &'m OsuMap,
 }
@@ -0,0 +1,122 @@
+use std::fmt::Write;
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_model::rosu_v2::user::User;
+use bathbot_util::{
+    constants::OSU_BASE, numbers::round, CowUtils, EmbedBuilder, FooterBuilder, ModsFormatter,
+};
+use eyre::Result;
+use futures::future::BoxFuture;
+use rosu_v2::prelude::Score;
+use time::Month;
+use twilight_model::{
+    channel::message::Component,
+    id::{marker::UserMarker, Id},
+};
+
+use crate::{
+    active::{
+        pagination::{handle_pagination_component, handle_pagination_modal, Pages},
+        BuildPage, ComponentResult, IActiveMessage,
+    },
+    manager::redis::RedisData,
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+pub struct TopMonthlyEntry {
+    pub year: i32,
+    pub month: Month,
+    /// Index in the top100 and the highest-pp score of the month
+    pub best: Option<(usize, Score)>,
+}
+
+#[derive(PaginationBuilder)]
+pub struct TopMonthlyPagination {
+    user: RedisData<User>,
+    #[pagination(per_page = 12)]
+    entries: Box<[TopMonthlyEntry]>,
+    content: Box<str>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for TopMonthlyPagination {
+    fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let pages = &self.pages;
+        let idx = pages.index();
+        let entries = &self.entries[idx..self.entries.len().min(idx + pages.per_page())];
+
+        let mut description = String::with_capacity(entries.len() * 100);
+
+        for entry in entries {
+            let _ = write!(
+                description,
+                "**{month} {year}**: ",
+                month = &entry.month.to_string()[..3],
+                year = entry.year,
+            );
+
+            let Some((i, ref score)) = entry.best else {
+                description.push_str("*No top play*\n");
+
+                continue;
+            };
+
+            match (&score.map, &score.mapset) {
+                (Some(map), Some(mapset)) => {
+                    let _ = write!(
+                        description,
+                        "[{title} [{version}]]({OSU_BASE}b/{map_id})",
+                        title = mapset.title.cow_escape_markdown(),
+                        version = map.version.cow_escape_markdown(),
+                        map_id = map.map_id,
+                    );
+                }
+                _ => {
+                    let _ = write!(description, "[Map]({OSU_BASE}b/{})", score.map_id);
+                }
+            }
+
+            let _ = writeln!(
+                description,
+                " +{mods} • **{pp}pp** • #{idx}",
+                mods = ModsFormatter::new(&score.mods),
+                pp = round(score.pp.unwrap_or(0.0)),
+                idx = i + 1,
+            );
+        }
+
+        let page = pages.curr_page();
+        let pages = pages.last_page();
+        let footer_text = format!("Page {page}/{pages}");
+
+        let embed = EmbedBuilder::new()
+            .author(self.user.author_builder())
+            .description(description)
+            .footer(FooterBuilder::new(footer_text))
+            .thumbnail(self.user.avatar_url())
+            .title("Best top play per month:");
+
+        BuildPage::new(embed, false)
+            .content(self.content.clone())
+            .boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(component, self.msg_owner, false, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages)
+    }
+}
@@ -0,0 +1,213 @@
+use std::{fmt::Write, future::ready};
+
+use bathbot_util::{constants::OSU_BASE, CowUtils, EmbedBuilder, ModsFormatter};
+use eyre::{Report, Result};
+use futures::future::BoxFuture;
+use rosu_v2::prelude::{Score, Username};
+use twilight_model::{
+    channel::message::{
+        component::{ActionRow, Button, ButtonStyle},
+        Component,
+    },
+    id::{
+        marker::{ChannelMarker, UserMarker},
+        Id,
+    },
+};
+
+use crate::{
+    active::{BuildPage, ComponentResult, IActiveMessage},
+    commands::tracking::{store_tracks, TrackOptions},
+    embeds::EmbedData,
+    util::{interaction::InteractionComponent, Authored, ComponentExt},
+};
+
+pub struct TrackPreviewEntry {
+    pub username: Username,
+    pub user_id: u32,
+    pub scores: Vec<Score>,
+}
+
+pub struct TrackPreview {
+    entries: Vec<TrackPreviewEntry>,
+    options: TrackOptions,
+    channel: Id<ChannelMarker>,
+    state: TrackPreviewState,
+    msg_owner: Id<UserMarker>,
+}
+
+enum TrackPreviewState {
+    Pending,
+    Cancelled,
+    Tracked(EmbedBuilder),
+}
+
+impl TrackPreview {
+    pub const SCORES_PER_USER: usize = 5;
+
+    pub fn new(
+        entries: Vec<TrackPreviewEntry>,
+        options: TrackOptions,
+        channel: Id<ChannelMarker>,
+        msg_owner: Id<UserMarker>,
+    ) -> Self {
+        Self {
+            entries,
+            options,
+            channel,
+            state: TrackPreviewState::Pending,
+            msg_owner,
+        }
+    }
+
+    async fn handle_confirm(&mut self, component: &InteractionComponent) -> ComponentResult {
+        match component.user_id() {
+            Ok(user_id) if user_id == self.msg_owner => {}
+            Ok(_) => return ComponentResult::NotOwner,
+            Err(err) => return ComponentResult::Err(err),
+        }
+
+        if let Err(err) = component.defer().await {
+            return ComponentResult::Err(Report::new(err).wrap_err("Failed to defer component"));
+        }
+
+        let users = self
+            .entries
+            .iter()
+            .map(|entry| (entry.username.clone(), entry.user_id))
+            .collect();
+
+        let embed = store_tracks(self.channel, users, self.options).await;
+        self.state = TrackPreviewState::Tracked(embed.build());
+
+        ComponentResult::BuildPage
+    }
+
+    fn handle_cancel(&mut self, component: &InteractionComponent) -> ComponentResult {
+        match component.user_id() {
+            Ok(user_id) if user_id == self.msg_owner => {}
+            Ok(_) => return ComponentResult::NotOwner,
+            Err(err) => return ComponentResult::Err(err),
+        }
+
+        self.state = TrackPreviewState::Cancelled;
+
+        ComponentResult::BuildPage
+    }
+
+    fn preview_embed(&self) -> EmbedBuilder {
+        let mut description = String::with_capacity(self.entries.len() * 512);
+
+        for entry in self.entries.iter() {
+            let _ = writeln!(
+                description,
+                "**[{name}]({OSU_BASE}u/{user_id})**",
+                name = entry.username.cow_escape_markdown(),
+                user_id = entry.user_id,
+            );
+
+            if entry.scores.is_empty() {
+                description.push_str("No top plays yet\n");
+            }
+
+            for (i, score) in entry.scores.iter().enumerate() {
+                let (Some(map), Some(mapset)) = (&score.map, &score.mapset) else {
+                    continue;
+                };
+
+                let _ = writeln!(
+                    description,
+                    "`#{idx}` [{title} [{version}]]({OSU_BASE}b/{map_id}) +{mods} • {pp:.2}pp",
+                    idx = i + 1,
+                    title = mapset.title.cow_escape_markdown(),
+                    version = map.version.cow_escape_markdown(),
+                    map_id = map.map_id,
+                    mods = ModsFormatter::new(&score.mods),
+                    pp = score.pp.unwrap_or(0.0),
+                );
+            }
+
+            description.push('\n');
+        }
+
+        description.push_str("Do you want to track these users in this channel?");
+
+        let TrackOptions {
+            mode,
+            limit,
+            snipes,
+            min_rank_gain,
+        } = self.options;
+
+        let mut title = format!("Track preview | mode={mode} | limit={limit}");
+
+        if snipes {
+            title.push_str(" | snipes");
+        }
+
+        if let Some(gain @ 1..) = min_rank_gain {
+            let _ = write!(title, " | min rank gain={gain}");
+        }
+
+        EmbedBuilder::new().description(description).title(title)
+    }
+}
+
+impl IActiveMessage for TrackPreview {
+    fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let (embed, defer) = match self.state {
+            TrackPreviewState::Pending => (self.preview_embed(), false),
+            TrackPreviewState::Cancelled => {
+                let embed = EmbedBuilder::new()
+                    .description("Cancelled, no users were tracked")
+                    .title("Track preview");
+
+                (embed, false)
+            }
+            TrackPreviewState::Tracked(ref embed) => (embed.clone(), true),
+        };
+
+        BuildPage::new(embed, defer).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        if !matches!(self.state, TrackPreviewState::Pending) {
+            return Vec::new();
+        }
+
+        let confirm = Button {
+            custom_id: Some("track_confirm".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Track".to_owned()),
+            style: ButtonStyle::Success,
+            url: None,
+        };
+
+        let cancel = Button {
+            custom_id: Some("track_cancel".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Cancel".to_owned()),
+            style: ButtonStyle::Secondary,
+            url: None,
+        };
+
+        let components = vec![Component::Button(confirm), Component::Button(cancel)];
+
+        vec![Component::ActionRow(ActionRow { components })]
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        match component.data.custom_id.as_str() {
+            "track_confirm" => Box::pin(self.handle_confirm(component)),
+            "track_cancel" => Box::pin(ready(self.handle_cancel(component))),
+            other => {
+                ComponentResult::Err(eyre!("Unknown track preview component `{other}`")).boxed()
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use std::future::ready;
+
+use bathbot_util::{constants::OSU_BASE, EmbedBuilder};
+use eyre::{Report, Result};
+use futures::future::BoxFuture;
+use rosu_v2::prelude::Username;
+use twilight_model::{
+    channel::message::{
+        component::{ActionRow, Button, ButtonStyle},
+        Component,
+    },
+    id::{marker::UserMarker, Id},
+};
+
+use crate::{
+    active::{BuildPage, ComponentResult, IActiveMessage},
+    core::Context,
+    util::{interaction::InteractionComponent, Authored, ComponentExt},
+};
+
+pub struct UnlinkConfirm {
+    osu_id: u32,
+    username: Option<Username>,
+    reset_preferences: bool,
+    state: UnlinkState,
+    msg_owner: Id<UserMarker>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum UnlinkState {
+    Pending,
+    Cancelled,
+    Unlinked,
+}
+
+impl UnlinkConfirm {
+    pub fn new(
+        osu_id: u32,
+        username: Option<Username>,
+        reset_preferences: bool,
+        msg_owner: Id<UserMarker>,
+    ) -> Self {
+        Self {
+            osu_id,
+            username,
+            reset_preferences,
+            state: UnlinkState::Pending,
+            msg_owner,
+        }
+    }
+
+    async fn handle_confirm(&mut self, component: &InteractionComponent) -> ComponentResult {
+        let owner = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if owner != self.msg_owner {
+            return ComponentResult::NotOwner;
+        }
+
+        if let Err(err) = component.defer().await {
+            return ComponentResult::Err(Report::new(err).wrap_err("Failed to defer component"));
+        }
+
+        let unlink_fut = Context::user_config().unlink_osu(owner, self.reset_preferences);
+
+        if let Err(err) = unlink_fut.await {
+            return ComponentResult::Err(err);
+        }
+
+        self.state = UnlinkState::Unlinked;
+
+        ComponentResult::BuildPage
+    }
+
+    fn handle_cancel(&mut self, component: &InteractionComponent) -> ComponentResult {
+        match component.user_id() {
+            Ok(user_id) if user_id == self.msg_owner => {}
+            Ok(_) => return ComponentResult::Ignore,
+            Err(err) => return ComponentResult::Err(err),
+        }
+
+        self.state = UnlinkState::Cancelled;
+
+        ComponentResult::BuildPage
+    }
+
+    fn profile(&self) -> String {
+        match self.username {
+            Some(ref name) => format!("[{name}]({OSU_BASE}u/{})", self.osu_id),
+            None => format!("[user id {id}]({OSU_BASE}u/{id})", id = self.osu_id),
+        }
+    }
+}
+
+impl IActiveMessage for UnlinkConfirm {
+    fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let profile = self.profile();
+
+        let (description, defer) = match self.state {
+            UnlinkState::Pending => {
+                let mut description =
+                    format!("Are you sure you want to unlink from the osu! profile {profile}?\n");
+
+                if self.reset_preferences {
+                    description.push_str(
+                        "Your stored preferences such as mode, list size, or score embed \
+                        settings will be **reset** as well.",
+                    );
+                } else {
+                    description.push_str(
+                        "Your stored preferences such as mode, list size, or score embed \
+                        settings will be kept.",
+                    );
+                }
+
+                (description, false)
+            }
+            UnlinkState::Cancelled => ("Cancelled, your link remains as is".to_owned(), false),
+            UnlinkState::Unlinked => {
+                let mut description = format!("Removed the link to the osu! profile {profile}");
+
+                if self.reset_preferences {
+                    description.push_str("\nAll your stored preferences have been reset");
+                }
+
+                description.push_str(
+                    "\nYour twitch link, bookmarks, and channel trackings were not affected",
+                );
+
+                (description, true)
+            }
+        };
+
+        let embed = EmbedBuilder::new().description(description).title("Unlink");
+
+        BuildPage::new(embed, defer).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        if self.state != UnlinkState::Pending {
+            return Vec::new();
+        }
+
+        let confirm = Button {
+            custom_id: Some("unlink_confirm".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Unlink".to_owned()),
+            style: ButtonStyle::Danger,
+            url: None,
+        };
+
+        let cancel = Button {
+            custom_id: Some("unlink_cancel".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Cancel".to_owned()),
+            style: ButtonStyle::Secondary,
+            url: None,
+        };
+
+        let components = vec![Component::Button(confirm), Component::Button(cancel)];
+
+        vec![Component::ActionRow(ActionRow { components })]
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        match component.data.custom_id.as_str() {
+            "unlink_confirm" => Box::pin(self.handle_confirm(component)),
+            "unlink_cancel" => Box::pin(ready(self.handle_cancel(component))),
+            other => ComponentResult::Err(eyre!("Unknown unlink component `{other}`")).boxed(),
+        }
+    }
+}
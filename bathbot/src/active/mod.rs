@@ -11,7 +11,10 @@ use futures::future::BoxFuture;
 use tokio::sync::watch::Sender;
 use twilight_model::{
     channel::message::Component,
-    id::{marker::MessageMarker, Id},
+    id::{
+        marker::{GuildMarker, MessageMarker, UserMarker},
+        Id,
+    },
 };
 
 pub use self::origin::ActiveMessageOriginError;
@@ -21,21 +24,23 @@ use self::{
         BackgroundGameSetup, BadgesPagination, BookmarksPagination, CachedRender,
         ChangelogPagination, CompareMostPlayedPagination, CompareScoresPagination,
         CompareTopPagination, HelpInteractionCommand, HelpPrefixMenu, HigherLowerGame,
-        LeaderboardPagination, MapPagination, MapSearchPagination, MatchComparePagination,
-        MatchCostPagination, MedalCountPagination, MedalRarityPagination, MedalsCommonPagination,
-        MedalsListPagination, MedalsMissingPagination, MedalsRecentPagination,
-        MostPlayedPagination, NoChokePagination, OsuStatsBestPagination, OsuStatsPlayersPagination,
-        OsuStatsScoresPagination, ProfileMenu, RankingCountriesPagination, RankingPagination,
-        RecentListPagination, RegionTopPagination, RenderSettingsActive, ScoreEmbedBuilderActive,
-        ScoresMapPagination, ScoresServerPagination, ScoresUserPagination, SettingsImport,
-        SimulateComponents, SingleScorePagination, SkinsPagination, SlashCommandsPagination,
+        LeaderboardPagination, LinkVerification, MapPagination, MapSearchPagination,
+        MatchComparePagination, MatchCostPagination, MedalCountPagination, MedalRarityPagination,
+        MedalsCommonPagination, MedalsListPagination, MedalsMissingPagination,
+        MedalsRecentPagination, MostPlayedPagination, NoChokePagination, OsuStatsBestPagination,
+        OsuStatsPlayersPagination, OsuStatsScoresPagination, ProfileMenu,
+        RankingCountriesPagination, RankingPagination, RecentListPagination, RegionTopPagination,
+        RenderSettingsActive, ScoreEmbedBuilderActive, ScoresMapPagination, ScoresServerPagination,
+        ScoresUserPagination, SettingsImport, ShardsPagination, SimulateComponents,
+        SingleScorePagination, SkinsPagination, SlashCommandsPagination,
         SnipeCountryListPagination, SnipeDifferencePagination, SnipePlayerListPagination,
-        TopIfPagination, TopPagination,
+        TopIfPagination, TopMonthlyPagination, TopPagination, UnlinkConfirm,
     },
     response::ActiveResponse,
+    router::ComponentRoute,
 };
 use crate::{
-    core::{BotMetrics, Context, EventKind},
+    core::{commands::checks::check_authority, BotMetrics, Context, EventKind},
     util::{
         interaction::{InteractionComponent, InteractionModal},
         ComponentExt, ModalExt,
@@ -48,6 +53,7 @@ mod builder;
 mod origin;
 mod pagination;
 mod response;
+mod router;
 
 #[enum_dispatch(IActiveMessage)]
 pub enum ActiveMessage {
@@ -63,6 +69,7 @@ pub enum ActiveMessage {
     HelpPrefixMenu,
     HigherLowerGame,
     LeaderboardPagination,
+    LinkVerification,
     MapPagination,
     MapSearchPagination,
     MatchComparePagination,
@@ -89,6 +96,7 @@ pub enum ActiveMessage {
     ScoresServerPagination,
     ScoresUserPagination,
     SettingsImport,
+    ShardsPagination,
     SimulateComponents,
     SingleScorePagination,
     SkinsPagination,
@@ -98,6 +106,10 @@ pub enum ActiveMessage {
     SnipePlayerListPagination,
     TopPagination,
     TopIfPagination,
+    TopMonthlyPagination,
+    #[cfg(feature = "osutracking")]
+    TrackPreview,
+    UnlinkConfirm,
 }
 
 struct FullActiveMessage {
@@ -141,11 +153,15 @@ impl ActiveMessages {
             activity_tx,
         }) = guard.get_mut()
         else {
-            return error!(
-                name = %component.data.custom_id,
-                ?component,
-                "Unknown message component",
-            );
+            // Not an active message (anymore) so route by custom id instead
+            return match ComponentRoute::parse(&component.data.custom_id) {
+                Some(route) => route.handle_inactive(&component).await,
+                None => error!(
+                    name = %component.data.custom_id,
+                    ?component,
+                    "Unknown message component",
+                ),
+            };
         };
 
         async fn handle_component_inner(
@@ -226,6 +242,11 @@ impl ActiveMessages {
                         "Failed to process component",
                     )
                 }
+                ComponentResult::NotOwner => {
+                    if let Err(err) = component.reply_ephemeral(NOT_OWNER).await {
+                        warn!(?err, "Failed to reply to foreign component");
+                    }
+                }
                 ComponentResult::Ignore => {}
             }
         }
@@ -332,6 +353,46 @@ impl ActiveMessages {
     }
 }
 
+const NOT_OWNER: &str = "This is not your interaction";
+
+/// Bounds in seconds for the configurable duration until paginations time out.
+pub const MIN_PAGINATION_TIMEOUT: u16 = 15;
+pub const MAX_PAGINATION_TIMEOUT: u16 = 300;
+
+/// Whether `user` may interact with the components of an active message owned
+/// by `msg_owner`.
+///
+/// Aside from the owner, authorities of the guild are always allowed to
+/// interact. Guilds may also allow anyone to interact.
+pub async fn can_interact(
+    user: Id<UserMarker>,
+    guild: Option<Id<GuildMarker>>,
+    msg_owner: Id<UserMarker>,
+) -> Result<bool> {
+    if user == msg_owner {
+        return Ok(true);
+    }
+
+    let Some(guild_id) = guild else {
+        return Ok(false);
+    };
+
+    let allow_anyone = Context::guild_config()
+        .peek(guild_id, |config| {
+            config.allow_foreign_interactions.unwrap_or(false)
+        })
+        .await;
+
+    if allow_anyone {
+        return Ok(true);
+    }
+
+    check_authority(user, guild)
+        .await
+        .map(|content| content.is_none())
+        .wrap_err("Failed to check authority")
+}
+
 #[enum_dispatch]
 pub trait IActiveMessage {
     /// The content of responses.
@@ -398,6 +459,14 @@ pub trait IActiveMessage {
     fn until_timeout(&self) -> Option<Duration> {
         Some(Duration::from_secs(60))
     }
+
+    /// Whether members and guilds may adjust the duration until timeout
+    /// through their config.
+    ///
+    /// Defaults to `true`.
+    fn configurable_timeout(&self) -> bool {
+        true
+    }
 }
 
 #[derive(Clone, Default)]
@@ -439,6 +508,8 @@ pub enum ComponentResult {
     BuildPage,
     Err(Report),
     Ignore,
+    /// The user is not allowed to interact with the message
+    NotOwner,
 }
 
 impl ComponentResult {
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use bathbot_util::{constants::CANNOT_DM_USER, MessageBuilder};
 use eyre::Report;
 use twilight_http::{
@@ -10,8 +12,9 @@ use twilight_model::{
     id::{marker::ChannelMarker, Id},
 };
 
+use super::{MAX_PAGINATION_TIMEOUT, MIN_PAGINATION_TIMEOUT};
 use crate::{
-    core::commands::CommandOrigin,
+    core::{commands::CommandOrigin, Context},
     util::{interaction::InteractionCommand, ChannelExt},
 };
 
@@ -62,6 +65,40 @@ impl ActiveMessageOrigin<'_> {
                 .map_err(ActiveMessageOriginError::Report),
         }
     }
+
+    /// The duration until paginations time out as configured by the user or,
+    /// if the user has no preference, by the guild.
+    pub(super) async fn pagination_timeout(&self) -> Option<Duration> {
+        let Self::Command(orig) = self else {
+            return None;
+        };
+
+        let user_secs = match orig.user_id() {
+            Ok(user_id) => match Context::user_config().pagination_timeout(user_id).await {
+                Ok(secs) => secs,
+                Err(err) => {
+                    warn!(?err, "Failed to get pagination timeout of user");
+
+                    None
+                }
+            },
+            Err(_) => None,
+        };
+
+        let secs = match (user_secs, orig.guild_id()) {
+            (Some(secs), _) => secs,
+            (None, Some(guild_id)) => {
+                Context::guild_config()
+                    .peek(guild_id, |config| config.pagination_timeout)
+                    .await?
+            }
+            (None, None) => return None,
+        };
+
+        let secs = secs.clamp(MIN_PAGINATION_TIMEOUT, MAX_PAGINATION_TIMEOUT);
+
+        Some(Duration::from_secs(u64::from(secs)))
+    }
 }
 
 impl<'d> From<CommandOrigin<'d>> for ActiveMessageOrigin<'d> {
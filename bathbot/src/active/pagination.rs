@@ -12,7 +12,7 @@ use twilight_model::{
     id::{marker::UserMarker, Id},
 };
 
-use super::ComponentResult;
+use super::{can_interact, ComponentResult};
 use crate::util::{
     interaction::{InteractionComponent, InteractionModal},
     Authored, ComponentExt, Emote, ModalExt,
@@ -146,8 +146,8 @@ pub async fn async_handle_pagination_component(
     defer: bool,
     pages: &mut Pages,
 ) -> Result<ComponentResult> {
-    if component.user_id()? != msg_owner {
-        return Ok(ComponentResult::Ignore);
+    if !can_interact(component.user_id()?, component.guild_id, msg_owner).await? {
+        return Ok(ComponentResult::NotOwner);
     }
 
     match component.data.custom_id.as_str() {
@@ -231,7 +231,7 @@ async fn async_handle_pagination_modal(
     defer: bool,
     pages: &mut Pages,
 ) -> Result<()> {
-    if modal.user_id()? != msg_owner {
+    if !can_interact(modal.user_id()?, modal.guild_id, msg_owner).await? {
         return Ok(());
     }
 
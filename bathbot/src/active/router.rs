@@ -0,0 +1,225 @@
+use crate::util::{interaction::InteractionComponent, ComponentExt};
+
+/// The subsystem that owns a message component, determined by the prefix of
+/// the component's custom id.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ComponentRoute {
+    BackgroundGame,
+    Bookmarks,
+    Changelog,
+    EmbedBuilder,
+    Help,
+    HigherLower,
+    Link,
+    Pagination,
+    Profile,
+    Render,
+    Score,
+    Simulate,
+    Track,
+}
+
+impl ComponentRoute {
+    /// Custom ids that don't share a prefix with the other ids of their
+    /// subsystem and must therefore match exactly.
+    const EXACT: &'static [(&'static str, Self)] = &[
+        ("edit_menu", Self::Render),
+        ("group_menu", Self::Render),
+        ("import", Self::Render),
+        ("send_link", Self::Render),
+        ("higher_button", Self::HigherLower),
+        ("lower_button", Self::HigherLower),
+        ("next_higherlower", Self::HigherLower),
+        ("try_again_button", Self::HigherLower),
+        ("miss_analyzer", Self::Score),
+        ("render", Self::Score),
+    ];
+    /// Custom id prefixes and the subsystem they belong to.
+    ///
+    /// Prefixes must be unique so that no custom id matches multiple entries.
+    const PREFIXES: &'static [(&'static str, Self)] = &[
+        ("bg_", Self::BackgroundGame),
+        ("bookmarks_", Self::Bookmarks),
+        ("changelog_", Self::Changelog),
+        ("embed_builder_", Self::EmbedBuilder),
+        ("help_", Self::Help),
+        ("link_", Self::Link),
+        ("unlink_", Self::Link),
+        ("pagination_", Self::Pagination),
+        ("profile_", Self::Profile),
+        ("render_", Self::Render),
+        ("sim_", Self::Simulate),
+        ("track_", Self::Track),
+    ];
+
+    /// Determine the subsystem of a custom id. Returns `None` for unknown ids.
+    pub fn parse(custom_id: &str) -> Option<Self> {
+        Self::EXACT
+            .iter()
+            .find(|(id, _)| custom_id == *id)
+            .or_else(|| {
+                Self::PREFIXES
+                    .iter()
+                    .find(|(prefix, _)| custom_id.starts_with(prefix))
+            })
+            .map(|(_, route)| *route)
+    }
+
+    /// Handle a component whose message is no longer an active message e.g.
+    /// because it timed out or the bot restarted in the meantime.
+    pub async fn handle_inactive(self, component: &InteractionComponent) {
+        let content = match self {
+            Self::BackgroundGame | Self::HigherLower => {
+                "This game is no longer active, start a new one to keep playing"
+            }
+            Self::Help => "This help menu is no longer active, use the help command again",
+            Self::Bookmarks
+            | Self::Changelog
+            | Self::EmbedBuilder
+            | Self::Link
+            | Self::Pagination
+            | Self::Profile
+            | Self::Render
+            | Self::Score
+            | Self::Simulate
+            | Self::Track => {
+                "This message is no longer active, use the command again to interact with it"
+            }
+        };
+
+        if let Err(err) = component.reply_ephemeral(content).await {
+            warn!(route = ?self, ?err, "Failed to reply to inactive component");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ComponentRoute;
+
+    /// Every custom id of a message component that is emitted by an active
+    /// message. When adding a new component, add its id here too.
+    const EMITTED_IDS: &[&str] = &[
+        "bg_cancel_button",
+        "bg_setup_effects",
+        "bg_setup_exclude",
+        "bg_setup_hints",
+        "bg_setup_include",
+        "bg_start_button",
+        "bookmarks_confirm_remove",
+        "bookmarks_remove",
+        "changelog_menu",
+        "edit_menu",
+        "embed_builder_bpm_emote",
+        "embed_builder_bpm_text",
+        "embed_builder_buttons",
+        "embed_builder_combo",
+        "embed_builder_hide_artist_button",
+        "embed_builder_hide_button",
+        "embed_builder_hide_sr_title",
+        "embed_builder_hide_version",
+        "embed_builder_hitresults_full",
+        "embed_builder_hitresults_misses",
+        "embed_builder_image",
+        "embed_builder_mapper",
+        "embed_builder_objects_emote",
+        "embed_builder_objects_text",
+        "embed_builder_pp",
+        "embed_builder_reset_button",
+        "embed_builder_section",
+        "embed_builder_show_artist_button",
+        "embed_builder_show_button",
+        "embed_builder_show_sr_title",
+        "embed_builder_show_version",
+        "embed_builder_sliders_emote",
+        "embed_builder_sliders_text",
+        "embed_builder_spinners_emote",
+        "embed_builder_spinners_text",
+        "embed_builder_thumbnail",
+        "embed_builder_value",
+        "embed_builder_value_down",
+        "embed_builder_value_left",
+        "embed_builder_value_right",
+        "embed_builder_value_up",
+        "group_menu",
+        "help_back",
+        "help_category",
+        "help_menu",
+        "higher_button",
+        "import",
+        "link_verify",
+        "lower_button",
+        "miss_analyzer",
+        "next_higherlower",
+        "pagination_back",
+        "pagination_custom",
+        "pagination_end",
+        "pagination_start",
+        "pagination_step",
+        "profile_menu",
+        "render",
+        "render_anyway",
+        "send_link",
+        "sim_acc",
+        "sim_attrs",
+        "sim_catch_version",
+        "sim_clock_rate",
+        "sim_combo",
+        "sim_geki",
+        "sim_katu",
+        "sim_mania_version",
+        "sim_miss",
+        "sim_mods",
+        "sim_mods_menu",
+        "sim_n100",
+        "sim_n300",
+        "sim_n50",
+        "sim_osu_version",
+        "sim_score",
+        "sim_taiko_version",
+        "track_cancel",
+        "track_confirm",
+        "try_again_button",
+        "unlink_cancel",
+        "unlink_confirm",
+    ];
+
+    #[test]
+    fn all_emitted_ids_route() {
+        for custom_id in EMITTED_IDS {
+            assert!(
+                ComponentRoute::parse(custom_id).is_some(),
+                "custom id `{custom_id}` has no route"
+            );
+        }
+    }
+
+    #[test]
+    fn ids_match_at_most_one_entry() {
+        for custom_id in EMITTED_IDS {
+            let exact = ComponentRoute::EXACT
+                .iter()
+                .filter(|(id, _)| custom_id == id)
+                .count();
+
+            let prefixed = ComponentRoute::PREFIXES
+                .iter()
+                .filter(|(prefix, _)| custom_id.starts_with(prefix))
+                .count();
+
+            assert!(
+                exact + prefixed <= 1,
+                "custom id `{custom_id}` is ambiguous"
+            );
+        }
+    }
+
+    #[test]
+    fn single_score_ids() {
+        assert_eq!(ComponentRoute::parse("render"), Some(ComponentRoute::Score));
+        assert_eq!(
+            ComponentRoute::parse("render_anyway"),
+            Some(ComponentRoute::Render)
+        );
+    }
+}
@@ -5,7 +5,9 @@ use twilight_model::{channel::Message, guild::Permissions};
 use crate::{core::buckets::BucketName, util::ChannelExt, Context};
 
 pub async fn bigger(msg: &Message, permissions: Option<Permissions>) -> Result<()> {
-    if let Some(cooldown) = Context::check_ratelimit(msg.author.id, BucketName::BgBigger) {
+    if let Some(cooldown) =
+        Context::check_guild_ratelimit(msg.author.id, msg.guild_id, BucketName::BgBigger).await
+    {
         trace!(
             "Ratelimiting user {} on bucket `BgBigger` for {cooldown} seconds",
             msg.author.id
@@ -0,0 +1,102 @@
+use std::{borrow::Cow, str::FromStr};
+
+use bathbot_model::MapsetTags;
+use bathbot_psql::model::games::DbMapTagsParams;
+use bathbot_util::{constants::GENERAL_ISSUE, fields, EmbedBuilder, MessageBuilder};
+use eyre::Result;
+use rosu_v2::prelude::GameMode;
+use twilight_model::{channel::Message, guild::Permissions};
+
+use crate::{util::ChannelExt, Context};
+
+/// Count how many stored backgrounds match the given tags.
+///
+/// Arguments are tag names; tags prefixed with `-` are excluded. A `mania`
+/// argument counts mania backgrounds instead of osu!standard ones.
+pub async fn count<'a>(
+    msg: &Message,
+    args: impl Iterator<Item = Cow<'a, str>>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    let mut mode = GameMode::Osu;
+    let mut included = MapsetTags::empty();
+    let mut excluded = MapsetTags::empty();
+
+    for arg in args {
+        match arg.as_ref() {
+            "mania" | "mna" | "m" => mode = GameMode::Mania,
+            "osu" | "std" | "standard" | "o" => mode = GameMode::Osu,
+            arg => {
+                let (tags, name) = match arg.strip_prefix('-') {
+                    Some(name) => (&mut excluded, name),
+                    None => (&mut included, arg.strip_prefix('+').unwrap_or(arg)),
+                };
+
+                match MapsetTags::from_str(name) {
+                    Ok(tag) => tags.insert(tag),
+                    Err(name) => {
+                        let content = format!(
+                            "Unknown tag `{name}`. Available tags: {}",
+                            MapsetTags::all().join(", ")
+                        );
+
+                        msg.error(content).await?;
+
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+
+    if included.intersects(excluded) {
+        let content = "Tags cannot be both included and excluded";
+        msg.error(content).await?;
+
+        return Ok(());
+    }
+
+    let mut params = DbMapTagsParams::new(mode);
+    params.include(included);
+    params.exclude(excluded);
+
+    let entries = match Context::games().bggame_tags(params).await {
+        Ok(entries) => entries,
+        Err(err) => {
+            let _ = msg.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to get background game tags"));
+        }
+    };
+
+    let include_value = if !included.is_empty() {
+        included.join('\n')
+    } else if excluded.is_empty() {
+        "Any".to_owned()
+    } else {
+        "None".to_owned()
+    };
+
+    let excluded_value = if !excluded.is_empty() {
+        excluded.join('\n')
+    } else {
+        "None".to_owned()
+    };
+
+    let fields = fields![
+        "Included", include_value, true;
+        "Excluded", excluded_value, true;
+    ];
+
+    let title = format!("Selected tags ({} backgrounds, {mode})", entries.tags.len());
+    let mut embed = EmbedBuilder::new().fields(fields).title(title);
+
+    if entries.tags.is_empty() {
+        embed = embed.description("No stored backgrounds match these tags, try different ones");
+    }
+
+    let builder = MessageBuilder::new().embed(embed);
+    msg.create_message(builder, permissions).await?;
+
+    Ok(())
+}
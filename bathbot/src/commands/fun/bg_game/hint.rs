@@ -1,11 +1,32 @@
+use std::{borrow::Cow, str::FromStr};
+
+use bathbot_model::HintTypes;
 use bathbot_util::{constants::GENERAL_ISSUE, MessageBuilder};
 use eyre::Result;
 use twilight_model::{channel::Message, guild::Permissions};
 
 use crate::{core::buckets::BucketName, util::ChannelExt, Context};
 
-pub async fn hint(msg: &Message, permissions: Option<Permissions>) -> Result<()> {
-    let ratelimit = Context::check_ratelimit(msg.author.id, BucketName::BgHint);
+pub async fn hint(
+    msg: &Message,
+    kind: Option<Cow<'_, str>>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    let kind = match kind.as_deref().map(HintTypes::from_str).transpose() {
+        Ok(kind) => kind,
+        Err(kind) => {
+            let content = format!(
+                "Unknown hint type `{kind}`. Available types: {}",
+                HintTypes::all().join(", ")
+            );
+            msg.error(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    let ratelimit =
+        Context::check_guild_ratelimit(msg.author.id, msg.guild_id, BucketName::BgHint).await;
 
     if let Some(cooldown) = ratelimit {
         trace!(
@@ -17,9 +38,14 @@ pub async fn hint(msg: &Message, permissions: Option<Permissions>) -> Result<()>
     }
 
     match Context::bg_games().read(&msg.channel_id).await.get() {
-        Some(game) => match game.hint().await {
+        Some(game) => match game.hint(kind).await {
             Ok(hint) => {
-                let builder = MessageBuilder::new().content(hint);
+                let mut builder = MessageBuilder::new().content(hint.content);
+
+                if let Some(image) = hint.image {
+                    builder = builder.attachment("bg_hint.png", image);
+                }
+
                 msg.create_message(builder, permissions).await?;
             }
             Err(err) => {
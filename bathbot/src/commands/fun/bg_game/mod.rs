@@ -1,5 +1,5 @@
 use bathbot_macros::{command, SlashCommand};
-use bathbot_model::{command_fields::ThreadChannel, Effects};
+use bathbot_model::{command_fields::ThreadChannel, Effects, HintTypes};
 use bathbot_psql::model::games::DbMapTagsParams;
 use bathbot_util::{
     constants::{GENERAL_ISSUE, INVALID_ACTION_FOR_CHANNEL_TYPE, THREADS_UNAVAILABLE},
@@ -14,7 +14,7 @@ use twilight_model::{
     guild::Permissions,
 };
 
-use self::{bigger::*, hint::*, rankings::*, skip::*, stop::*};
+use self::{bigger::*, count::*, hint::*, rankings::*, skip::*, stop::*};
 use crate::{
     active::{
         impls::{BackgroundGame, BackgroundGameSetup},
@@ -28,6 +28,7 @@ use crate::{
 };
 
 mod bigger;
+mod count;
 mod hint;
 mod rankings;
 mod skip;
@@ -55,12 +56,15 @@ pub async fn prefix_backgroundgame(
                 Use these prefix commands to initiate with the game:\n\
                 • `<bg s[kip]` / `<bg r[esolve]`: Resolve the current background and \
                 give a new one with the same tag specs.\n\
-                • `<bg h[int]`: Receive a hint (can be used multiple times).\n\
+                • `<bg h[int] [title/artist/length/region]`: Receive a hint (can be used multiple times). \
+                Optionally specify which kind of hint you want, each kind is limited per background.\n\
                 • `<bg b[igger]`: Increase the radius of the displayed image (can be used multiple times).\n\
                 • `<bg stop`: Resolve the current background and stop the game.
-                • `<bg l[eaderboard] s[erver]`: Check out the global leaderboard for \
+                • `<bg c[ount] [mania] [tags]`: Check how many backgrounds match the given tags. \
+                Prefix a tag with `-` to exclude it instead, e.g. `<bg count old -meme`.
+                • `<bg l[eaderboard] s[erver] [mania]`: Check out the global leaderboard for \
                 amount of correct guesses. If `server` or `s` is added at the end, \
-                I will only show members of this server.";
+                I will only show members of this server. Add `mania` to see the mania leaderboard.";
 
             let builder = MessageBuilder::new().embed(content);
             msg.create_message(builder, permissions).await?;
@@ -68,16 +72,23 @@ pub async fn prefix_backgroundgame(
             Ok(())
         }
         Some("s" | "skip" | "r" | "resolve" | "start") => skip(msg).await,
-        Some("h" | "hint") => hint(msg, permissions).await,
+        Some("h" | "hint") => hint(msg, args.next(), permissions).await,
         Some("b" | "bigger" | "enhance") => bigger(msg, permissions).await,
         Some("stop" | "end" | "quit") => stop(msg).await,
+        Some("c" | "count" | "tags") => count(msg, args, permissions).await,
         Some("l" | "lb" | "leaderboard") => {
-            let arg = args.next();
-
-            match arg.as_ref().map(|arg| arg.as_ref()) {
-                Some("s" | "server") => leaderboard(msg, false).await,
-                _ => leaderboard(msg, true).await,
+            let mut global = true;
+            let mut mode = GameMode::Osu;
+
+            for arg in args {
+                match arg.as_ref() {
+                    "s" | "server" => global = false,
+                    "m" | "mna" | "mania" => mode = GameMode::Mania,
+                    _ => {}
+                }
             }
+
+            leaderboard(msg, global, mode).await
         }
         _ => {
             let prefix = Context::guild_config().first_prefix(msg.guild_id).await;
@@ -102,12 +113,15 @@ pub async fn prefix_backgroundgame(
     Use these prefix commands to initiate with the game:\n\
     • `<bg s[kip]` / `<bg r[esolve]`: Resolve the current background and \
     give a new one with the same tag specs.\n\
-    • `<bg h[int]`: Receive a hint (can be used multiple times).\n\
+    • `<bg h[int] [title/artist/length/region]`: Receive a hint (can be used multiple times). \
+    Optionally specify which kind of hint you want, each kind is limited per background.\n\
     • `<bg b[igger]`: Increase the radius of the displayed image (can be used multiple times).\n\
     • `<bg stop`: Resolve the current background and stop the game.
-    • `<bg l[eaderboard] s[erver]`: Check out the global leaderboard for \
+    • `<bg c[ount] [mania] [tags]`: Check how many backgrounds match the given tags. \
+    Prefix a tag with `-` to exclude it instead, e.g. `<bg count old -meme`.
+    • `<bg l[eaderboard] s[erver] [mania]`: Check out the global leaderboard for \
     amount of correct guesses. If `server` or `s` is added at the end, \
-    I will only show members of this server."
+    I will only show members of this server. Add `mania` to see the mania leaderboard."
 )]
 #[flags(SKIP_DEFER)]
 pub struct Bg {
@@ -313,7 +327,13 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
                 command.callback(builder, false).await?;
             }
 
-            let game_fut = BackgroundGame::new(channel, entries, Effects::empty(), difficulty);
+            let game_fut = BackgroundGame::new(
+                channel,
+                entries,
+                Effects::empty(),
+                HintTypes::all(),
+                difficulty,
+            );
 
             Context::bg_games()
                 .own(channel)
@@ -4,6 +4,7 @@ use bathbot_model::{RankingEntries, RankingEntry, RankingKind};
 use bathbot_util::{constants::GENERAL_ISSUE, IntHasher};
 use eyre::Result;
 use hashbrown::HashSet;
+use rosu_v2::prelude::GameMode;
 use twilight_model::{channel::Message, id::Id};
 
 use crate::{
@@ -12,10 +13,10 @@ use crate::{
     Context,
 };
 
-pub async fn leaderboard(msg: &Message, global: bool) -> Result<()> {
+pub async fn leaderboard(msg: &Message, global: bool, mode: GameMode) -> Result<()> {
     let cache = Context::cache();
 
-    let mut scores = match Context::games().bggame_leaderboard().await {
+    let mut scores = match Context::games().bggame_leaderboard(mode).await {
         Ok(scores) => scores,
         Err(err) => {
             let _ = msg.error(GENERAL_ISSUE).await;
@@ -82,7 +83,11 @@ pub async fn leaderboard(msg: &Message, global: bool) -> Result<()> {
     // Prepare initial page
     let total = scores.len();
     let global = guild.is_none() || global;
-    let data = RankingKind::BgScores { global, scores };
+    let data = RankingKind::BgScores {
+        global,
+        mode,
+        scores,
+    };
 
     let pagination = RankingPagination::builder()
         .entries(entries)
@@ -5,7 +5,9 @@ use twilight_model::channel::Message;
 use crate::{core::buckets::BucketName, util::ChannelExt, Context};
 
 pub async fn skip(msg: &Message) -> Result<()> {
-    if let Some(cooldown) = Context::check_ratelimit(msg.author.id, BucketName::BgSkip) {
+    if let Some(cooldown) =
+        Context::check_guild_ratelimit(msg.author.id, msg.guild_id, BucketName::BgSkip).await
+    {
         trace!(
             "Ratelimiting user {} on bucket `BgSkip` for {cooldown} seconds",
             msg.author.id
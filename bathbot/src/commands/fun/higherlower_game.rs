@@ -15,6 +15,7 @@ use crate::{
         impls::{HigherLowerGame, RankingPagination},
         ActiveMessages,
     },
+    commands::osu::resolve_mode,
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
     Context,
 };
@@ -57,10 +58,11 @@ async fn slash_higherlower(mut command: InteractionCommand) -> Result<()> {
         HigherLower::ScorePp(args) => {
             let mode = match args.mode.map(GameMode::from) {
                 Some(mode) => mode,
-                None => Context::user_config()
-                    .mode(user)
-                    .await?
-                    .unwrap_or(GameMode::Osu),
+                None => {
+                    let config_mode = Context::user_config().mode(user).await?;
+
+                    resolve_mode(command.guild_id, command.channel_id, None, config_mode).await
+                }
             };
 
             HigherLowerGame::new_score_pp(mode, user).await
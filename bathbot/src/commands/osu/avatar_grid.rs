@@ -0,0 +1,245 @@
+use std::{cell::RefCell, rc::Rc};
+
+use bathbot_macros::{command, SlashCommand};
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    MessageBuilder,
+};
+use eyre::{Report, Result, WrapErr};
+use futures::{stream::FuturesOrdered, StreamExt};
+use image::DynamicImage;
+use plotters::{
+    prelude::*,
+    style::text_anchor::{HPos, Pos, VPos},
+};
+use plotters_skia::SkiaBackend;
+use rosu_v2::prelude::{GameMode, OsuError};
+use skia_safe::{surfaces, EncodedImageFormat};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use super::BitMapElement;
+use crate::{
+    core::commands::CommandOrigin,
+    manager::redis::osu::UserArgs,
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+const MAX_USERS: usize = 16;
+
+const AVATAR_SIZE: u32 = 128;
+const CELL_PADDING: u32 = 12;
+const NAME_H: u32 = 24;
+const CELL_W: u32 = AVATAR_SIZE + 2 * CELL_PADDING;
+const CELL_H: u32 = AVATAR_SIZE + NAME_H + 2 * CELL_PADDING;
+
+const BACKGROUND: RGBColor = RGBColor(19, 43, 33);
+const PLACEHOLDER: RGBColor = RGBColor(60, 60, 60);
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "avatargrid",
+    desc = "Display the osu! profile pictures of multiple users in one image",
+    help = "Display the osu! profile pictures of multiple users in one image.\n\
+    The usernames must be separated by commas.\n\
+    Up to 16 users can be specified."
+)]
+pub struct AvatarGrid {
+    #[command(desc = "Specify usernames separated by commas")]
+    names: String,
+}
+
+async fn slash_avatargrid(mut command: InteractionCommand) -> Result<()> {
+    let args = AvatarGrid::from_interaction(command.input_data())?;
+
+    let names = args
+        .names
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    avatar_grid((&mut command).into(), names).await
+}
+
+#[command]
+#[desc("Display the osu! profile pictures of multiple users in one image")]
+#[help(
+    "Display the osu! profile pictures of multiple users in one image.\n\
+    Up to 16 users can be specified.\n\
+    Usernames containing whitespace must be encased in quotes."
+)]
+#[usage("[username1] [username2] ...")]
+#[example("badewanne3 \"freddie benson\" peppy")]
+#[aliases("pfpgrid", "avatars")]
+#[group(AllModes)]
+async fn prefix_avatargrid(msg: &Message, args: Args<'_>) -> Result<()> {
+    avatar_grid(msg.into(), args.collect()).await
+}
+
+async fn avatar_grid(orig: CommandOrigin<'_>, args: Vec<&str>) -> Result<()> {
+    let mut names: Vec<&str> = Vec::with_capacity(args.len());
+
+    for name in args {
+        if !names.iter().any(|prev| prev.eq_ignore_ascii_case(name)) {
+            names.push(name);
+        }
+    }
+
+    if names.is_empty() {
+        return orig.error("You must specify at least one username").await;
+    } else if names.len() > MAX_USERS {
+        let content = format!("You can specify at most {MAX_USERS} users");
+
+        return orig.error(content).await;
+    }
+
+    let mut user_futs: FuturesOrdered<_> = names
+        .iter()
+        .map(|name| async move {
+            let user_args = UserArgs::username(name, GameMode::Osu).await;
+
+            Context::redis().osu_user(user_args).await
+        })
+        .collect();
+
+    let mut users = Vec::with_capacity(names.len());
+
+    while let Some(user_res) = user_futs.next().await {
+        match user_res {
+            Ok(user) => users.push(user),
+            Err(OsuError::NotFound) => {
+                let content = format!(
+                    "User `{}` was not found",
+                    names.get(users.len()).copied().unwrap_or_default()
+                );
+
+                return orig.error(content).await;
+            }
+            Err(err) => {
+                let _ = orig.error(OSU_API_ISSUE).await;
+                let err = Report::new(err).wrap_err("Failed to get user");
+
+                return Err(err);
+            }
+        }
+    }
+
+    let client = Context::client();
+
+    let avatars: Vec<_> = users
+        .iter()
+        .map(|user| client.get_avatar(user.avatar_url()))
+        .collect::<FuturesOrdered<_>>()
+        .collect()
+        .await;
+
+    let entries: Vec<_> = users
+        .iter()
+        .zip(avatars)
+        .map(|(user, avatar_res)| {
+            let avatar = avatar_res
+                .and_then(|bytes| {
+                    image::load_from_memory(&bytes).wrap_err("Failed to load avatar from memory")
+                })
+                .map(|img| img.thumbnail_exact(AVATAR_SIZE, AVATAR_SIZE));
+
+            let avatar = match avatar {
+                Ok(avatar) => Some(avatar),
+                Err(err) => {
+                    warn!(?err, name = user.username(), "Failed to get avatar");
+
+                    None
+                }
+            };
+
+            (user.username(), avatar)
+        })
+        .collect();
+
+    let bytes = match draw_grid(entries) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to draw avatar grid"));
+        }
+    };
+
+    let builder = MessageBuilder::new().attachment("avatars.png", bytes);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+/// Tile the avatars into a roughly square grid with each username below its
+/// avatar. Missing avatars are replaced by a placeholder.
+fn draw_grid(entries: Vec<(&str, Option<DynamicImage>)>) -> Result<Vec<u8>> {
+    let len = entries.len() as u32;
+    let cols = (len as f32).sqrt().ceil() as u32;
+    let rows = len.div_ceil(cols);
+
+    let w = cols * CELL_W;
+    let h = rows * CELL_H;
+
+    let mut surface =
+        surfaces::raster_n32_premul((w as i32, h as i32)).wrap_err("Failed to create surface")?;
+
+    {
+        let backend = Rc::new(RefCell::new(SkiaBackend::new(surface.canvas(), w, h)));
+        let root = DrawingArea::from(&backend);
+
+        root.fill(&BACKGROUND)
+            .wrap_err("Failed to fill background")?;
+
+        let name_style = FontDesc::new(FontFamily::SansSerif, 18.0, FontStyle::Bold)
+            .color(&WHITE)
+            .pos(Pos::new(HPos::Center, VPos::Top));
+
+        let placeholder_style = FontDesc::new(FontFamily::SansSerif, 64.0, FontStyle::Bold)
+            .color(&WHITE.mix(0.5))
+            .pos(Pos::new(HPos::Center, VPos::Center));
+
+        for (i, (name, avatar)) in entries.into_iter().enumerate() {
+            let col = i as u32 % cols;
+            let row = i as u32 / cols;
+
+            let x = (col * CELL_W + CELL_PADDING) as i32;
+            let y = (row * CELL_H + CELL_PADDING) as i32;
+
+            match avatar {
+                Some(avatar) => {
+                    let elem = BitMapElement::new(avatar, (x, y));
+                    root.draw(&elem).wrap_err("Failed to draw avatar")?;
+                }
+                None => {
+                    let size = AVATAR_SIZE as i32;
+                    let rect = Rectangle::new([(x, y), (x + size, y + size)], PLACEHOLDER.filled());
+                    root.draw(&rect).wrap_err("Failed to draw placeholder")?;
+
+                    let center = (x + size / 2, y + size / 2);
+                    root.draw_text("?", &placeholder_style, center)
+                        .wrap_err("Failed to draw placeholder text")?;
+                }
+            }
+
+            let name_pos = (
+                x + AVATAR_SIZE as i32 / 2,
+                y + AVATAR_SIZE as i32 + CELL_PADDING as i32 / 2,
+            );
+
+            root.draw_text(name, &name_style, name_pos)
+                .wrap_err("Failed to draw username")?;
+        }
+
+        root.present().wrap_err("Failed to present grid")?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(png_bytes)
+}
@@ -47,6 +47,22 @@ pub struct Bws<'a> {
         If none is specified, it defaults to the current amount + 2."
     )]
     badges: Option<usize>,
+    #[command(
+        min_value = 1,
+        max_value = 10_000_000,
+        desc = "Pretend the user is at this rank",
+        help = "Instead of the user's current global rank, calculate the bws value as if \
+        the user were at this rank."
+    )]
+    current_rank: Option<u32>,
+    #[command(
+        min_value = 0,
+        max_value = 100,
+        desc = "Pretend the user has this many badges",
+        help = "Instead of the user's current amount of badges, calculate the bws value as if \
+        the user had this many badges."
+    )]
+    current_badges: Option<usize>,
     #[command(
         desc = "Specify a linked discord user",
         help = "Instead of specifying an osu! username with the `name` option, \
@@ -62,8 +78,10 @@ impl<'m> Bws<'m> {
         let mut discord = None;
         let mut rank = None;
         let mut badges = None;
+        let mut current_rank = None;
+        let mut current_badges = None;
 
-        for arg in args.take(3) {
+        for arg in args.take(5) {
             if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
                 let key = &arg[..idx];
                 let value = arg[idx + 1..].trim_end();
@@ -85,9 +103,32 @@ impl<'m> Bws<'m> {
                             return Err(content.into());
                         }
                     },
+                    "current_rank" | "cr" => match value.parse::<u32>() {
+                        Ok(num @ 1..=MAX_RANK) => current_rank = Some(num),
+                        _ => {
+                            let content = format!(
+                                "Failed to parse `current_rank`. \
+                                Must be an integer between 1 and {MAX_RANK}."
+                            );
+
+                            return Err(content.into());
+                        }
+                    },
+                    "current_badges" | "cb" => match value.parse::<usize>() {
+                        Ok(num @ 0..=MAX_BADGES) => current_badges = Some(num),
+                        _ => {
+                            let content = format!(
+                                "Failed to parse `current_badges`. \
+                                Must be an integer between 0 and {MAX_BADGES}."
+                            );
+
+                            return Err(content.into());
+                        }
+                    },
                     _ => {
                         let content = format!(
-                            "Unrecognized option `{key}`.\nAvailable options are: `rank` or `badges`."
+                            "Unrecognized option `{key}`.\nAvailable options are: \
+                            `rank`, `badges`, `current_rank`, or `current_badges`."
                         );
 
                         return Err(content.into());
@@ -104,6 +145,8 @@ impl<'m> Bws<'m> {
             name,
             rank,
             badges,
+            current_rank,
+            current_badges,
             discord,
         })
     }
@@ -123,10 +166,19 @@ async fn slash_bws(mut command: InteractionCommand) -> Result<()> {
     Next to the player's username, you can specify `rank=integer` \
     to show how the bws value progresses towards that rank.\n\
     Similarly, you can specify `badges=integer` to show how the value \
-    progresses towards that badge amount."
+    progresses towards that badge amount.\n\
+    To answer \"what would my bws be at rank X with Y badges\", you can override the \
+    user's current values with `current_rank=integer` and `current_badges=integer`."
+)]
+#[usage(
+    "[username] [rank=integer] [badges=integer] [current_rank=integer] [current_badges=integer]"
+)]
+#[examples(
+    "badewanne3",
+    "badewanne3 rank=1234 badges=10",
+    "badewanne3 badges=3",
+    "badewanne3 current_rank=5000 current_badges=4"
 )]
-#[usage("[username] [rank=integer] [badges=integer]")]
-#[examples("badewanne3", "badewanne3 rank=1234 badges=10", "badewanne3 badges=3")]
 #[group(Osu)]
 async fn prefix_bws(msg: &Message, args: Args<'_>) -> Result<()> {
     match Bws::args(args) {
@@ -140,6 +192,8 @@ async fn prefix_bws(msg: &Message, args: Args<'_>) -> Result<()> {
 }
 
 const MIN_BADGES_OFFSET: usize = 2;
+const MAX_RANK: u32 = 10_000_000;
+const MAX_BADGES: usize = 100;
 
 async fn bws(orig: CommandOrigin<'_>, args: Bws<'_>) -> Result<()> {
     let user_id = match user_id!(orig, args) {
@@ -155,7 +209,13 @@ async fn bws(orig: CommandOrigin<'_>, args: Bws<'_>) -> Result<()> {
         },
     };
 
-    let Bws { rank, badges, .. } = args;
+    let Bws {
+        rank,
+        badges,
+        current_rank,
+        current_badges,
+        ..
+    } = args;
 
     let user_args = UserArgs::rosu_id(&user_id, GameMode::Osu).await;
 
@@ -174,7 +234,7 @@ async fn bws(orig: CommandOrigin<'_>, args: Bws<'_>) -> Result<()> {
         }
     };
 
-    let badges_curr = match user {
+    let badges_curr = current_badges.unwrap_or_else(|| match user {
         RedisData::Original(ref user) => {
             let badges = user.badges.iter().map(|badge| &badge.description);
 
@@ -185,7 +245,10 @@ async fn bws(orig: CommandOrigin<'_>, args: Bws<'_>) -> Result<()> {
 
             TourneyBadges::count(badges)
         }
-    };
+    });
+
+    let global_rank = current_rank.unwrap_or_else(|| user.stats().global_rank());
+    let what_if = current_rank.is_some() || current_badges.is_some();
 
     let (badges_min, badges_max) = match badges {
         Some(num) => {
@@ -203,7 +266,15 @@ async fn bws(orig: CommandOrigin<'_>, args: Bws<'_>) -> Result<()> {
         None => (badges_curr, badges_curr + MIN_BADGES_OFFSET),
     };
 
-    let embed_data = BWSEmbed::new(&user, badges_curr, badges_min, badges_max, rank);
+    let embed_data = BWSEmbed::new(
+        &user,
+        global_rank,
+        badges_curr,
+        badges_min,
+        badges_max,
+        rank,
+        what_if,
+    );
     let embed = embed_data.build();
     let builder = MessageBuilder::new().embed(embed);
     orig.create_message(builder).await?;
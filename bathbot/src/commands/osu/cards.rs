@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use bathbot_cards::{BathbotCard, RequiredAttributes};
 use bathbot_macros::{HasName, SlashCommand};
@@ -8,20 +8,27 @@ use bathbot_util::{
     constants::{GENERAL_ISSUE, OSEKAI_ISSUE, OSU_API_ISSUE},
     datetime::DATE_FORMAT,
     osu::flag_url_size,
-    EmbedBuilder, IntHasher, MessageBuilder,
+    EmbedBuilder, FooterBuilder, IntHasher, MessageBuilder,
 };
 use eyre::{Report, Result, WrapErr};
-use futures::{stream::FuturesUnordered, TryStreamExt};
-use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
+use futures::{stream::FuturesOrdered, TryStreamExt};
+use rosu_v2::{
+    model::GameMode,
+    prelude::{OsuError, Score},
+    request::UserId,
+};
 use time::OffsetDateTime;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{marker::UserMarker, Id};
 
-use super::{require_link, user_not_found};
+use super::{require_link, resolve_mode, user_not_found};
 use crate::{
     core::{commands::CommandOrigin, BotConfig, Context},
     embeds::attachment,
-    manager::redis::{osu::UserArgs, RedisData},
+    manager::redis::{
+        osu::{UserArgs, UserArgsSlim},
+        RedisData,
+    },
     util::{interaction::InteractionCommand, InteractionCommandExt},
 };
 
@@ -34,7 +41,8 @@ use crate::{
     Only the accuracy values for [catch](https://www.desmos.com/calculator/cg59pywpry) \
     and [mania](https://www.desmos.com/calculator/b30p1awwft) come from custom formulas \
     that are based on score accuracy, map OD, object count, and star rating.\n\
-    Note that only the user's top100 is considered while calculating card values.\n\
+    Note that only the user's top100 is considered while calculating card values \
+    unless the `extended` option is enabled.\n\
     Titles consist of three parts: **prefix**, **descriptions**, and **suffix**.\n\n\
     - The **prefix** is determined by checking the highest skill value \
     for thresholds:\n\
@@ -88,6 +96,13 @@ pub struct Card {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(
+        desc = "Also consider recent scores besides the top100",
+        help = "Also consider recent passes besides the top100, including those on unranked maps.\n\
+        Useful for players whose top100 is not yet filled. \
+        The card will be labeled as `extended`."
+    )]
+    extended: Option<bool>,
 }
 
 async fn slash_card(mut command: InteractionCommand) -> Result<()> {
@@ -108,11 +123,13 @@ async fn slash_card(mut command: InteractionCommand) -> Result<()> {
         },
     };
 
-    let mode = args
-        .mode
-        .map(GameMode::from)
-        .or(config.mode)
-        .unwrap_or(GameMode::Osu);
+    let mode = resolve_mode(
+        orig.guild_id(),
+        orig.channel_id(),
+        args.mode.map(GameMode::from),
+        config.mode,
+    )
+    .await;
 
     let legacy_scores = match config.score_data {
         Some(score_data) => score_data.is_legacy(),
@@ -159,6 +176,30 @@ async fn slash_card(mut command: InteractionCommand) -> Result<()> {
         }
     };
 
+    let extended = args.extended.unwrap_or(false);
+
+    let mut scores = if extended {
+        let user_args = UserArgsSlim::user_id(user.user_id()).mode(mode);
+
+        let recent_fut = Context::osu_scores()
+            .recent(legacy_scores)
+            .limit(100)
+            .include_fails(false)
+            .exec(user_args);
+
+        match recent_fut.await {
+            Ok(recent) => extend_scores(scores, recent),
+            Err(err) => {
+                let _ = orig.error(OSU_API_ISSUE).await;
+                let err = Report::new(err).wrap_err("failed to get recent scores");
+
+                return Err(err);
+            }
+        }
+    } else {
+        scores
+    };
+
     if scores.is_empty() {
         let content = "Looks like they don't have any scores on that mode";
         orig.error(content).await?;
@@ -166,7 +207,7 @@ async fn slash_card(mut command: InteractionCommand) -> Result<()> {
         return Ok(());
     }
 
-    let maps: HashMap<_, _, IntHasher> = scores
+    let entries: Vec<_> = scores
         .iter()
         .map(|score| async {
             let map = Context::osu_map()
@@ -174,23 +215,39 @@ async fn slash_card(mut command: InteractionCommand) -> Result<()> {
                 .await
                 .wrap_err("failed to get pp map")?;
 
-            let difficulty = Context::pp_parsed(&map, score.map_id, mode)
-                .mods(&score.mods)
-                .difficulty()
-                .await
-                .to_owned();
+            let mut calc = Context::pp_parsed(&map, score.map_id, mode).mods(&score.mods);
+            let difficulty = calc.difficulty().await.to_owned();
+
+            // Recent scores, e.g. on loved maps, don't necessarily come with pp
+            let pp = match score.pp {
+                Some(pp) => pp,
+                None => calc.score(score).performance().await.pp() as f32,
+            };
 
             let attrs = RequiredAttributes {
                 difficulty,
                 od: map.od,
             };
 
-            Ok::<_, Report>((score.map_id, attrs))
+            Ok::<_, Report>((score.map_id, attrs, pp))
         })
-        .collect::<FuturesUnordered<_>>()
+        .collect::<FuturesOrdered<_>>()
         .try_collect()
         .await?;
 
+    let mut maps = HashMap::with_capacity_and_hasher(entries.len(), IntHasher);
+
+    for (score, (map_id, attrs, pp)) in scores.iter_mut().zip(entries) {
+        score.pp = Some(pp);
+        maps.insert(map_id, attrs);
+    }
+
+    // Recent scores were appended after the top scores so the pp weighting
+    // requires them to be sorted again
+    if extended {
+        scores.sort_unstable_by(|a, b| b.pp.unwrap_or(0.0).total_cmp(&a.pp.unwrap_or(0.0)));
+    }
+
     let client = Context::client();
     let pfp_fut = client.get_avatar(user.avatar_url());
     let flag_url = flag_url_size(user.country_code(), 70);
@@ -229,6 +286,7 @@ async fn slash_card(mut command: InteractionCommand) -> Result<()> {
         .bytes(&pfp, &flag)
         .date(&today)
         .assets(BotConfig::get().paths.assets.clone())
+        .extended(extended)
         .draw();
 
     let bytes = match card_res {
@@ -240,10 +298,16 @@ async fn slash_card(mut command: InteractionCommand) -> Result<()> {
         }
     };
 
-    let embed = EmbedBuilder::new()
+    let mut embed = EmbedBuilder::new()
         .author(user.author_builder())
         .image(attachment("card.png"));
 
+    if extended {
+        embed = embed.footer(FooterBuilder::new(
+            "Extended card: recent passes were considered besides the top100",
+        ));
+    }
+
     let builder = MessageBuilder::new()
         .attachment("card.png", bytes)
         .embed(embed);
@@ -252,3 +316,19 @@ async fn slash_card(mut command: InteractionCommand) -> Result<()> {
 
     Ok(())
 }
+
+/// Append recent scores on maps that are not already covered by the top
+/// scores, keeping only the most recent score per map.
+///
+/// The result is not sorted by pp.
+fn extend_scores(mut top: Vec<Score>, recent: Vec<Score>) -> Vec<Score> {
+    let mut map_ids: HashSet<_, IntHasher> = top.iter().map(|score| score.map_id).collect();
+
+    top.extend(
+        recent
+            .into_iter()
+            .filter(|score| map_ids.insert(score.map_id)),
+    );
+
+    top
+}
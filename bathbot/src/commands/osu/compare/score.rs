@@ -5,6 +5,7 @@ use std::{
 
 use bathbot_macros::{command, HasMods, HasName, SlashCommand};
 use bathbot_model::{
+    command_fields::GameModeOption,
     embed_builder::{ScoreEmbedSettings, SettingsImage},
     ScoreSlim,
 };
@@ -42,7 +43,7 @@ use crate::{
         ActiveMessages,
     },
     commands::{
-        osu::{map_strain_graph, require_link, HasMods, ModsResult},
+        osu::{map_strain_graph, require_link, GraphPalette, HasMods, ModsResult},
         utility::{ScoreEmbedData, ScoreEmbedDataPersonalBest},
     },
     core::commands::{
@@ -68,7 +69,8 @@ use crate::{
 #[command(
     name = "cs",
     desc = "Compare a score",
-    help = "Given a user and a map, display the user's scores on the map"
+    help = "Given a user and a map, display all of the user's scores on the map.\n\
+    Scores are sorted by pp by default and each one shows its mods."
 )]
 #[allow(dead_code)]
 pub struct Cs<'a> {
@@ -86,6 +88,12 @@ pub struct Cs<'a> {
         desc = "Specify a difficulty name of the map's mapset"
     )]
     difficulty: Option<String>,
+    #[command(
+        desc = "Specify a convert mode for osu!standard maps",
+        help = "Specify a gamemode to compare scores on the map's convert.\n\
+        Only osu!standard maps can be converted to other modes."
+    )]
+    mode: Option<GameModeOption>,
     #[command(desc = "Choose how the scores should be ordered")]
     sort: Option<ScoreOrder>,
     #[command(
@@ -121,7 +129,8 @@ pub struct Cs<'a> {
 #[command(
     name = "score",
     desc = "Compare a score",
-    help = "Given a user and a map, display the user's scores on the map.\n\
+    help = "Given a user and a map, display all of the user's scores on the map.\n\
+    Scores are sorted by pp by default and each one shows its mods.\n\
     Its shorter alias is the `/cs` command."
 )]
 #[allow(dead_code)]
@@ -140,6 +149,12 @@ pub struct CompareScore_<'a> {
         desc = "Specify a difficulty name of the map's mapset"
     )]
     difficulty: Option<String>,
+    #[command(
+        desc = "Specify a convert mode for osu!standard maps",
+        help = "Specify a gamemode to compare scores on the map's convert.\n\
+        Only osu!standard maps can be converted to other modes."
+    )]
+    mode: Option<GameModeOption>,
     #[command(desc = "Choose how the scores should be ordered")]
     sort: Option<ScoreOrder>,
     #[command(
@@ -181,6 +196,7 @@ pub(super) struct CompareScoreArgs<'a> {
     name: Option<Cow<'a, str>>,
     map: Option<MapOrScore>,
     difficulty: Option<String>,
+    mode: Option<GameMode>,
     sort: Option<ScoreOrder>,
     mods: Option<Cow<'a, str>>,
     discord: Option<Id<UserMarker>>,
@@ -216,6 +232,7 @@ impl<'m> CompareScoreArgs<'m> {
             name,
             map,
             difficulty: None,
+            mode: None,
             sort: None,
             mods,
             discord,
@@ -258,6 +275,7 @@ impl<'a> TryFrom<CompareScoreAutocomplete<'a>> for CompareScoreArgs<'a> {
             name: args.name,
             map,
             difficulty,
+            mode: args.mode.map(GameMode::from),
             sort: args.sort,
             mods: args.mods,
             discord: args.discord,
@@ -360,17 +378,17 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
         },
     };
 
-    let score_data = match config.score_data {
-        Some(score_data) => score_data,
-        None => match orig.guild_id() {
-            Some(guild_id) => Context::guild_config()
+    let guild_score_data = match orig.guild_id() {
+        Some(guild_id) => {
+            Context::guild_config()
                 .peek(guild_id, |config| config.score_data)
                 .await
-                .unwrap_or_default(),
-            None => Default::default(),
-        },
+        }
+        None => None,
     };
 
+    let score_data = ScoreData::resolve(config.score_data, guild_score_data);
+
     let legacy_scores = score_data.is_legacy();
     let settings = config.score_embed.unwrap_or_default();
 
@@ -379,6 +397,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
         map,
         index,
         difficulty,
+        mode: convert_mode,
         ..
     } = args;
 
@@ -443,8 +462,26 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
     };
 
     // Retrieving the beatmap
+    let mut is_convert = false;
+
     let map = match Context::osu_map().map(map_id, None).await {
-        Ok(map) => map,
+        Ok(map) => match convert_mode {
+            Some(mode) if mode == map.mode() => map,
+            Some(mode) if map.mode() == GameMode::Osu => {
+                is_convert = true;
+
+                map.convert(mode)
+            }
+            Some(mode) => {
+                let content = format!(
+                    "There is no {mode:?} convert for this map; \
+                    only osu!standard maps can be converted to other modes"
+                );
+
+                return orig.error(content).await;
+            }
+            None => map,
+        },
         Err(MapError::NotFound) => {
             let content = format!(
                 "Could not find beatmap with id `{map_id}`. \
@@ -576,6 +613,15 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
         None => None,
     };
 
+    if is_convert && scores.is_empty() {
+        let content = format!(
+            "`{}` has no scores on the {mode:?} convert of this map",
+            user.username()
+        );
+
+        return orig.error(content).await;
+    }
+
     if let Some(ref selection) = mods {
         selection.filter_scores(&mut scores);
     }
@@ -584,6 +630,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
 
     let process_fut = process_scores(
         map_id,
+        mode,
         user.user_id(),
         scores,
         personal.as_deref(),
@@ -642,6 +689,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
 #[allow(clippy::too_many_arguments)]
 async fn process_scores(
     map_id: u32,
+    mode: GameMode,
     user_id: u32,
     scores: Vec<Score>,
     top100: Option<&[Score]>,
@@ -650,7 +698,7 @@ async fn process_scores(
     score_data: ScoreData,
     origin: &MessageOrigin,
 ) -> Result<Box<[ScoreEmbedData]>> {
-    let map = Context::osu_map().map(map_id, None).await?;
+    let map = Context::osu_map().map(map_id, None).await?.convert(mode);
 
     let mut entries = Vec::<ScoreEmbedData>::with_capacity(scores.len());
 
@@ -983,6 +1031,7 @@ async fn prepare_graph(entry: &ScoreEmbedData) -> Option<(String, Vec<u8>)> {
         &entry.map.pp_map,
         entry.score.mods.clone(),
         entry.map.cover(),
+        GraphPalette::default(),
     );
 
     match fut.await {
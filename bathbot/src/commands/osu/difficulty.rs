@@ -0,0 +1,396 @@
+use std::borrow::Cow;
+
+use bathbot_macros::{command, HasMods, SlashCommand};
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_BASE},
+    matcher,
+    osu::MapIdType,
+    EmbedBuilder, FooterBuilder, MessageBuilder,
+};
+use eyre::Result;
+use rosu_pp::{
+    any::DifficultyAttributes, catch::CatchDifficultyAttributes, mania::ManiaDifficultyAttributes,
+    osu::OsuDifficultyAttributes, taiko::TaikoDifficultyAttributes,
+};
+use rosu_v2::prelude::{GameMode, GameModsIntermode};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    channel::{
+        message::{embed::EmbedField, MessageType},
+        Message,
+    },
+    guild::Permissions,
+};
+
+use super::{leaderboard::get_map_id, HasMods, ModsResult};
+use crate::{
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::{MapError, Mods},
+    util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "difficulty",
+    desc = "Display all difficulty attributes of a map",
+    help = "Display all difficulty attributes of a map such as aim, speed, or slider factor.\n\
+    The attributes are adjusted to mods and differ between modes."
+)]
+pub struct Difficulty<'a> {
+    #[command(
+        desc = "Specify a map url or map id",
+        help = "Specify a map either by map url or map id.\n\
+        If none is specified, it will search in the recent channel history \
+        and pick the first map it can find."
+    )]
+    map: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify mods e.g. hdhr or nm",
+        help = "Specify mods either directly or through the explicit `+mods!` / `+mods` syntax e.g. `hdhr` or `+hdhr!`"
+    )]
+    mods: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a mode to convert an osu!standard map to")]
+    mode: Option<GameModeOption>,
+}
+
+#[derive(HasMods)]
+struct DifficultyArgs<'a> {
+    map: Option<MapIdType>,
+    mods: Option<Cow<'a, str>>,
+    mode: Option<GameMode>,
+}
+
+impl<'m> DifficultyArgs<'m> {
+    async fn args(msg: &Message, args: Args<'m>) -> Result<DifficultyArgs<'m>, String> {
+        let mut map = None;
+        let mut mods = None;
+
+        for arg in args.take(2) {
+            if let Some(id) = matcher::get_osu_map_id(arg)
+                .map(MapIdType::Map)
+                .or_else(|| matcher::get_osu_mapset_id(arg).map(MapIdType::Set))
+            {
+                map = Some(id);
+            } else if matcher::get_mods(arg).is_some() {
+                mods = Some(arg.into());
+            } else {
+                let content = format!(
+                    "Failed to parse `{arg}`.\n\
+                    Be sure you specify either a valid map id, map url, or mod combination."
+                );
+
+                return Err(content);
+            }
+        }
+
+        let reply = msg
+            .referenced_message
+            .as_deref()
+            .filter(|_| msg.kind == MessageType::Reply);
+
+        if let Some(reply) = reply {
+            if let Some(id) = Context::find_map_id_in_msg(reply).await {
+                map = Some(id);
+            }
+        }
+
+        Ok(Self {
+            map,
+            mods,
+            mode: None,
+        })
+    }
+}
+
+impl<'a> TryFrom<Difficulty<'a>> for DifficultyArgs<'a> {
+    type Error = &'static str;
+
+    fn try_from(args: Difficulty<'a>) -> Result<Self, Self::Error> {
+        let Difficulty { map, mods, mode } = args;
+
+        let map = match map.map(|arg| {
+            matcher::get_osu_map_id(&arg)
+                .map(MapIdType::Map)
+                .or_else(|| matcher::get_osu_mapset_id(&arg).map(MapIdType::Set))
+        }) {
+            Some(Some(id)) => Some(id),
+            Some(None) => {
+                let content =
+                    "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+
+                return Err(content);
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            map,
+            mods,
+            mode: mode.map(GameMode::from),
+        })
+    }
+}
+
+#[command]
+#[desc("Display all difficulty attributes of a map")]
+#[help(
+    "Display all difficulty attributes of a map such as aim, speed, or slider factor.\n\
+    If no map is specified by either url or id, I will choose the last map \
+    I can find in the embeds of this channel."
+)]
+#[usage("[map url / map id] [+mods]")]
+#[examples("2240404 +hddt", "https://osu.ppy.sh/beatmapsets/902425#osu/2240404")]
+#[aliases("diff", "diffattrs")]
+#[group(AllModes)]
+async fn prefix_difficulty(
+    msg: &Message,
+    args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    match DifficultyArgs::args(msg, args).await {
+        Ok(args) => difficulty(CommandOrigin::from_msg(msg, permissions), args).await,
+        Err(content) => {
+            msg.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+async fn slash_difficulty(mut command: InteractionCommand) -> Result<()> {
+    let args = Difficulty::from_interaction(command.input_data())?;
+
+    match DifficultyArgs::try_from(args) {
+        Ok(args) => difficulty((&mut command).into(), args).await,
+        Err(content) => {
+            command.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+async fn difficulty(orig: CommandOrigin<'_>, args: DifficultyArgs<'_>) -> Result<()> {
+    let mods = match args.mods() {
+        ModsResult::Mods(mods) => mods.into_mods(),
+        ModsResult::None => GameModsIntermode::new(),
+        ModsResult::Invalid => {
+            let content =
+                "Failed to parse mods. Be sure to specify a valid abbreviation e.g. `hdhr`.";
+
+            return orig.error(content).await;
+        }
+    };
+
+    let map_id = match get_map_id(&orig, args.map).await {
+        Ok(map_id) => map_id,
+        Err(content) => return orig.error(content).await,
+    };
+
+    let map = match Context::osu_map().map(map_id, None).await {
+        Ok(map) => map,
+        Err(MapError::NotFound) => {
+            let content = format!(
+                "Could not find beatmap with id `{map_id}`. \
+                Did you give me a mapset id instead of a map id?",
+            );
+
+            return orig.error(content).await;
+        }
+        Err(MapError::Report(err)) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let mode = match args.mode {
+        Some(mode) if mode != map.mode() && map.mode() != GameMode::Osu => {
+            let content = format!("A {:?} map cannot be converted to {mode:?}", map.mode());
+
+            return orig.error(content).await;
+        }
+        Some(mode) => mode,
+        None => map.mode(),
+    };
+
+    let mods = match mods.try_with_mode(mode) {
+        Some(mods) if mods.is_valid() => mods,
+        Some(mods) => {
+            let content =
+                format!("Looks like some mods in `{mods}` are incompatible with each other");
+
+            return orig.error(content).await;
+        }
+        None => {
+            let content = format!("The mods are incompatible with the mode {mode:?}");
+
+            return orig.error(content).await;
+        }
+    };
+
+    let attrs = Context::pp(&map)
+        .mode(mode)
+        .mods(Mods::from(&mods))
+        .difficulty()
+        .await
+        .to_owned();
+
+    let stars = attrs.stars();
+
+    let fields = match attrs {
+        DifficultyAttributes::Osu(attrs) => osu_fields(&attrs),
+        DifficultyAttributes::Taiko(attrs) => taiko_fields(&attrs),
+        DifficultyAttributes::Catch(attrs) => catch_fields(&attrs),
+        DifficultyAttributes::Mania(attrs) => mania_fields(&attrs),
+    };
+
+    let mut title = format!("{} - {} [{}]", map.artist(), map.title(), map.version());
+
+    if !mods.is_empty() {
+        title.push_str(" +");
+        title.push_str(&mods.to_string());
+    }
+
+    let footer = FooterBuilder::new(format!("{stars:.2}★ • {mode:?}"));
+
+    let embed = EmbedBuilder::new()
+        .fields(fields)
+        .footer(footer)
+        .thumbnail(map.thumbnail())
+        .title(title)
+        .url(format!("{OSU_BASE}b/{map_id}"));
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+fn field(name: &str, value: String) -> EmbedField {
+    EmbedField {
+        inline: true,
+        name: name.to_owned(),
+        value,
+    }
+}
+
+fn osu_fields(attrs: &OsuDifficultyAttributes) -> Vec<EmbedField> {
+    let OsuDifficultyAttributes {
+        aim,
+        speed,
+        flashlight,
+        slider_factor,
+        speed_note_count,
+        ar,
+        od,
+        hp,
+        n_circles,
+        n_sliders,
+        n_spinners,
+        stars: _,
+        max_combo,
+    } = attrs;
+
+    vec![
+        field(
+            "Skills",
+            format!("Aim: `{aim:.4}`\nSpeed: `{speed:.4}`\nFlashlight: `{flashlight:.4}`"),
+        ),
+        field(
+            "Factors",
+            format!(
+                "Slider factor: `{slider_factor:.4}`\n\
+                Speed note count: `{speed_note_count:.2}`"
+            ),
+        ),
+        field(
+            "Attributes",
+            format!("AR: `{ar:.2}`\nOD: `{od:.2}`\nHP: `{hp:.2}`"),
+        ),
+        field(
+            "Objects",
+            format!(
+                "Circles: `{n_circles}`\nSliders: `{n_sliders}`\n\
+                Spinners: `{n_spinners}`\nMax combo: `{max_combo}`"
+            ),
+        ),
+    ]
+}
+
+fn taiko_fields(attrs: &TaikoDifficultyAttributes) -> Vec<EmbedField> {
+    let TaikoDifficultyAttributes {
+        stamina,
+        rhythm,
+        color,
+        peak,
+        hit_window,
+        stars: _,
+        max_combo,
+        is_convert,
+    } = attrs;
+
+    vec![
+        field(
+            "Skills",
+            format!(
+                "Stamina: `{stamina:.4}`\nRhythm: `{rhythm:.4}`\n\
+                Color: `{color:.4}`\nPeak: `{peak:.4}`"
+            ),
+        ),
+        field(
+            "Attributes",
+            format!("Great hit window: `{hit_window:.2}ms`\nConvert: `{is_convert}`"),
+        ),
+        field("Objects", format!("Max combo: `{max_combo}`")),
+    ]
+}
+
+fn catch_fields(attrs: &CatchDifficultyAttributes) -> Vec<EmbedField> {
+    let CatchDifficultyAttributes {
+        stars: _,
+        ar,
+        n_fruits,
+        n_droplets,
+        n_tiny_droplets,
+        is_convert,
+    } = attrs;
+
+    vec![
+        field(
+            "Attributes",
+            format!("AR: `{ar:.2}`\nConvert: `{is_convert}`"),
+        ),
+        field(
+            "Objects",
+            format!(
+                "Fruits: `{n_fruits}`\nDroplets: `{n_droplets}`\n\
+                Tiny droplets: `{n_tiny_droplets}`\nMax combo: `{}`",
+                attrs.max_combo()
+            ),
+        ),
+    ]
+}
+
+fn mania_fields(attrs: &ManiaDifficultyAttributes) -> Vec<EmbedField> {
+    let ManiaDifficultyAttributes {
+        stars: _,
+        hit_window,
+        n_objects,
+        max_combo,
+        is_convert,
+    } = attrs;
+
+    vec![
+        field(
+            "Attributes",
+            format!("Great hit window: `{hit_window:.2}ms`\nConvert: `{is_convert}`"),
+        ),
+        field(
+            "Objects",
+            format!("Objects: `{n_objects}`\nMax combo: `{max_combo}`"),
+        ),
+    ]
+}
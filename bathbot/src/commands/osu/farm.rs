@@ -0,0 +1,202 @@
+use std::{borrow::Cow, cmp::Ordering};
+
+use bathbot_macros::{command, HasMods, SlashCommand};
+use bathbot_util::{numbers::round, osu::ModSelection};
+use eyre::Result;
+use rosu_pp::any::DifficultyAttributes;
+use rosu_v2::prelude::{GameMode, GameMods};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::channel::Message;
+
+use super::{HasMods, ModsResult};
+use crate::{
+    active::{
+        impls::{FarmEntry, FarmPagination},
+        ActiveMessages,
+    },
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::Mods,
+    util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
+    Context,
+};
+
+/// Maps within this many stars of the requested star rating are considered
+const STARS_SPREAD: f32 = 0.25;
+
+/// Accuracy for which the pp of each map are calculated
+const FARM_ACC: f64 = 99.0;
+
+#[derive(CommandModel, CreateCommand, HasMods, SlashCommand)]
+#[command(
+    name = "farm",
+    desc = "Find maps that give a lot of pp for their star rating",
+    help = "Find ranked osu!standard maps that give a lot of pp for their star rating.\n\
+    All maps within 0.25 stars of the given star rating are sorted by their pp per star \
+    for a 99% full combo.\n\
+    Only maps for which I already calculated the difficulty with the given mods are considered \
+    so results may differ from a complete list."
+)]
+pub struct Farm<'a> {
+    #[command(
+        min_value = 0.0,
+        max_value = 15.0,
+        desc = "Specify the star rating around which to search"
+    )]
+    stars: f32,
+    #[command(
+        desc = "Specify mods e.g. hdhr or nm",
+        help = "Specify mods either directly or through the explicit `+mods!` / `+mods` syntax, \
+        e.g. `hdhr` or `+hdhr!`"
+    )]
+    mods: Option<Cow<'a, str>>,
+}
+
+impl<'m> Farm<'m> {
+    fn args(args: Args<'m>) -> Result<Self, String> {
+        let mut stars = None;
+        let mut mods = None;
+
+        for arg in args.take(2) {
+            if let Ok(n) = arg.trim_end_matches('*').parse::<f32>() {
+                stars = Some(n);
+            } else {
+                mods = Some(arg.into());
+            }
+        }
+
+        let Some(stars) = stars else {
+            return Err("You must specify a star rating e.g. `6.5`".to_owned());
+        };
+
+        Ok(Self { stars, mods })
+    }
+}
+
+#[command]
+#[desc("Find maps that give a lot of pp for their star rating")]
+#[help(
+    "Find ranked osu!standard maps that give a lot of pp for their star rating.\n\
+    All maps within 0.25 stars of the given star rating are sorted by their pp per star \
+    for a 99% full combo.\n\
+    Only maps for which I already calculated the difficulty with the given mods are considered \
+    so results may differ from a complete list."
+)]
+#[usage("[stars] [+mods]")]
+#[examples("6.5", "5.8 +hd", "7 +dt")]
+#[group(Osu)]
+async fn prefix_farm(msg: &Message, args: Args<'_>) -> Result<()> {
+    match Farm::args(args) {
+        Ok(args) => farm(msg.into(), args).await,
+        Err(content) => {
+            msg.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+async fn slash_farm(mut command: InteractionCommand) -> Result<()> {
+    let args = Farm::from_interaction(command.input_data())?;
+
+    farm((&mut command).into(), args).await
+}
+
+async fn farm(orig: CommandOrigin<'_>, args: Farm<'_>) -> Result<()> {
+    let mods = match args.mods() {
+        ModsResult::Mods(ModSelection::Include(mods) | ModSelection::Exact(mods)) => {
+            match mods.try_with_mode(GameMode::Osu) {
+                Some(mods) => mods,
+                None => {
+                    let content = "Looks like some of the mods are not valid for osu!standard";
+
+                    return orig.error(content).await;
+                }
+            }
+        }
+        ModsResult::None => GameMods::new(),
+        ModsResult::Mods(ModSelection::Exclude(_)) | ModsResult::Invalid => {
+            let content = "Failed to parse mods.\n\
+            If you want to specify mods, do so e.g. as `+hdhr`.";
+
+            return orig.error(content).await;
+        }
+    };
+
+    let pp_mods = Mods::from(&mods);
+
+    if pp_mods.clock_rate.is_some() {
+        let content = "Custom clock rates are not supported for this command";
+
+        return orig.error(content).await;
+    }
+
+    let stars = args.stars;
+
+    let maps_fut =
+        Context::osu_map().farm_maps(pp_mods.bits, stars - STARS_SPREAD, stars + STARS_SPREAD);
+
+    let maps = match maps_fut.await {
+        Ok(maps) => maps,
+        Err(err) => {
+            let content = "Failed to gather maps, the map database seems to be unavailable.\n\
+                Please try again later.";
+            let _ = orig.error(content).await;
+
+            return Err(err);
+        }
+    };
+
+    if maps.is_empty() {
+        let content = format!(
+            "I don't know of any ranked maps around {}★ with those mods yet",
+            round(stars)
+        );
+
+        return orig.error(content).await;
+    }
+
+    let sample_size = maps.len();
+
+    let mut entries: Vec<_> = maps
+        .into_iter()
+        .map(|map| {
+            let map_stars = map.attrs.stars as f32;
+
+            let pp = DifficultyAttributes::Osu(map.attrs)
+                .performance()
+                .mods(pp_mods.bits)
+                .accuracy(FARM_ACC)
+                .calculate()
+                .pp() as f32;
+
+            FarmEntry {
+                map_id: map.map_id,
+                artist: map.artist,
+                title: map.title,
+                version: map.version,
+                seconds_drain: map.seconds_drain,
+                stars: map_stars,
+                pp,
+            }
+        })
+        .collect();
+
+    entries.sort_unstable_by(|a, b| {
+        b.pp_per_star()
+            .partial_cmp(&a.pp_per_star())
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let pagination = FarmPagination::builder()
+        .entries(entries.into_boxed_slice())
+        .stars(stars)
+        .mods(mods)
+        .sample_size(sample_size)
+        .msg_owner(orig.user_id()?)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
+}
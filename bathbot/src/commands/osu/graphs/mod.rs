@@ -24,6 +24,7 @@ use time::UtcOffset;
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::id::{marker::UserMarker, Id};
 
+pub use self::playcount_replays::profile_playcount_graph;
 use self::{
     medals::medals_graph,
     playcount_replays::{playcount_replays_graph, ProfileGraphFlags},
@@ -34,7 +35,7 @@ use self::{
     top_index::top_graph_index,
     top_time::top_graph_time,
 };
-use super::{require_link, user_not_found, SnipeGameMode};
+use super::{require_link, resolve_mode, user_not_found, SnipeGameMode};
 use crate::{
     core::{commands::CommandOrigin, Context},
     embeds::attachment,
@@ -161,6 +162,7 @@ pub struct GraphSnipeCount {
     name = "top",
     desc = "Display a user's top scores pp",
     help = "Display a user's top scores pp.\n\
+    The `Index` order additionally shades each score's weighted pp.\n\
     The timezone option is only relevant for the `Time` order."
 )]
 pub struct GraphTop {
@@ -289,11 +291,13 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph) -> Result<()> {
                 }
             };
 
-            let mode = args
-                .mode
-                .map(GameMode::from)
-                .or(config.mode)
-                .unwrap_or(GameMode::Osu);
+            let mode = resolve_mode(
+                orig.guild_id(),
+                orig.channel_id(),
+                args.mode.map(GameMode::from),
+                config.mode,
+            )
+            .await;
 
             let (user_id, no_user_specified) = match user_id!(orig, args) {
                 Some(user_id) => (user_id, false),
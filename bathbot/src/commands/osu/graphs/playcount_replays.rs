@@ -103,6 +103,18 @@ pub async fn playcount_replays_graph(
     Ok(Some((user, bytes)))
 }
 
+/// Playcount graph to be attached to a profile.
+///
+/// Returns `None` if the user does not have enough data points.
+pub async fn profile_playcount_graph(user: &mut RedisData<User>) -> Result<Option<Vec<u8>>> {
+    let params = ProfileGraphParams::new(user).flags(ProfileGraphFlags::PLAYCOUNT);
+
+    match graphs(params).await? {
+        GraphResult::Ok(graph) => Ok(Some(graph)),
+        GraphResult::NotEnoughDatapoints | GraphResult::NoBadges => Ok(None),
+    }
+}
+
 bitflags! {
     pub struct ProfileGraphFlags: u8 {
         const BADGES    = 1 << 0;
@@ -11,12 +11,26 @@ use skia_safe::{surfaces, EncodedImageFormat};
 
 use super::{H, W};
 
+/// Weight of the top score, each subsequent score's weight decreases by 5%.
+const WEIGHT_FACTOR: f32 = 0.95;
+
 pub async fn top_graph_index(caption: String, scores: &[Score]) -> Result<Vec<u8>> {
     let max = scores.first().and_then(|s| s.pp).unwrap_or(0.0);
     let max_adj = max + 5.0;
 
     let min = scores.last().and_then(|s| s.pp).unwrap_or(0.0);
-    let min_adj = (min - 5.0).max(0.0);
+
+    let weighted: Vec<_> = (1..)
+        .zip(scores)
+        .filter_map(|(i, s)| Some((i, s.pp? * WEIGHT_FACTOR.powi(i as i32 - 1))))
+        .collect();
+
+    let weighted_sum: f32 = weighted.iter().map(|(_, pp)| pp).sum();
+    let weighted_min = weighted.last().map_or(min, |(_, pp)| *pp);
+    let min_adj = (weighted_min - 5.0).max(0.0);
+
+    // A single score would otherwise result in an empty x-range
+    let x_max = scores.len().max(2);
 
     let mut surface =
         surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
@@ -36,7 +50,7 @@ pub async fn top_graph_index(caption: String, scores: &[Score]) -> Result<Vec<u8
             .margin_top(5_i32)
             .margin_right(15_i32)
             .caption(caption, caption_style)
-            .build_cartesian_2d(1..scores.len(), min_adj..max_adj)
+            .build_cartesian_2d(1..x_max, min_adj..max_adj)
             .wrap_err("failed to build chart")?;
 
         chart
@@ -60,6 +74,15 @@ pub async fn top_graph_index(caption: String, scores: &[Score]) -> Result<Vec<u8
             .label(format!("Max: {max}pp"))
             .legend(EmptyElement::at);
 
+        let weighted_style = RGBColor(0, 208, 138).mix(0.6).filled();
+        let series = AreaSeries::new(weighted.iter().copied(), 0.0, weighted_style);
+
+        chart
+            .draw_series(series)
+            .wrap_err("failed to draw weighted area")?
+            .label(format!("Weighted: {weighted_sum:.2}pp"))
+            .legend(EmptyElement::at);
+
         // Draw empty series for additional label
         let iter = (1..)
             .zip(scores)
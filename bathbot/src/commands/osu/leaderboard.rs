@@ -292,17 +292,17 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
         }
     };
 
-    let score_data = match config.score_data {
-        Some(score_data) => score_data,
-        None => match orig.guild_id() {
-            Some(guild_id) => Context::guild_config()
+    let guild_score_data = match orig.guild_id() {
+        Some(guild_id) => {
+            Context::guild_config()
                 .peek(guild_id, |config| config.score_data)
                 .await
-                .unwrap_or_default(),
-            None => Default::default(),
-        },
+        }
+        None => None,
     };
 
+    let score_data = ScoreData::resolve(config.score_data, guild_score_data);
+
     let legacy_scores = score_data.is_legacy();
 
     let specify_mods = match mods {
@@ -442,7 +442,10 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
         .await
 }
 
-async fn get_map_id(orig: &CommandOrigin<'_>, map: Option<MapIdType>) -> Result<u32, &'static str> {
+pub(super) async fn get_map_id(
+    orig: &CommandOrigin<'_>,
+    map: Option<MapIdType>,
+) -> Result<u32, &'static str> {
     match map {
         Some(MapIdType::Map(id)) => Ok(id),
         Some(MapIdType::Set(_)) => {
@@ -14,6 +14,7 @@ use crate::{
     desc = "Link your discord to an osu! profile",
     help = "Link your discord to an osu! profile.\n\
     To unlink, use the `/config` command.\n\
+    To link by placing a code on your osu! profile instead, use the `/linkprofile` command.\n\
     To link your discord to a twitch account you can also use the `/config` command."
 )]
 #[flags(EPHEMERAL)]
@@ -0,0 +1,83 @@
+use std::borrow::Cow;
+
+use bathbot_macros::SlashCommand;
+use bathbot_util::constants::{GENERAL_ISSUE, OSU_API_ISSUE};
+use eyre::{Report, Result};
+use rand::{distributions::Alphanumeric, Rng};
+use rosu_v2::prelude::OsuError;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    active::{impls::LinkVerification, ActiveMessages},
+    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
+    Context,
+};
+
+const CODE_LEN: usize = 8;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "linkprofile",
+    desc = "Link your discord to an osu! profile by verifying a code on it",
+    help = "Link your discord to an osu! profile without logging in through osu!.\n\
+    I will give you a code which you need to put into the location, interests, or occupation \
+    field of your osu! profile. Once the code shows up on your profile, \
+    the link is verified and stored.\n\
+    The regular way of linking is still the `/link` command."
+)]
+#[flags(EPHEMERAL)]
+pub struct LinkProfile<'a> {
+    #[command(desc = "Specify your osu! username")]
+    name: Cow<'a, str>,
+}
+
+async fn slash_linkprofile(mut command: InteractionCommand) -> Result<()> {
+    let args = LinkProfile::from_interaction(command.input_data())?;
+
+    link_profile(&mut command, args).await
+}
+
+async fn link_profile(command: &mut InteractionCommand, args: LinkProfile<'_>) -> Result<()> {
+    let owner = command.user_id()?;
+
+    let user = match Context::osu().user(args.name.as_ref()).await {
+        Ok(user) => user,
+        Err(OsuError::NotFound) => {
+            let content = format!("User `{}` was not found", args.name);
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error(OSU_API_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get user for link verification"));
+        }
+    };
+
+    match Context::user_config().osu_id(owner).await {
+        Ok(Some(osu_id)) if osu_id == user.user_id => {
+            let content = format!("You are already linked to `{}`", user.username);
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Ok(_) => {}
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    }
+
+    let code: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(CODE_LEN)
+        .map(char::from)
+        .collect();
+
+    let code = format!("bathbot-{code}").into_boxed_str();
+    let active_msg = LinkVerification::new(user.user_id, user.username, code, owner);
+
+    ActiveMessages::builder(active_msg).begin(command).await
+}
@@ -13,7 +13,7 @@ use plotters_skia::SkiaBackend;
 use rosu_pp::{any::Strains, Beatmap as PpMap, Difficulty};
 use rosu_v2::prelude::{GameMode, GameMods, GameModsIntermode, OsuError};
 use skia_safe::{surfaces, BlendMode, EncodedImageFormat};
-use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::{
     channel::{message::MessageType, Message},
     guild::Permissions,
@@ -58,13 +58,88 @@ pub struct Map<'a> {
     cs: Option<f64>,
     #[command(desc = "Specify an HP value to override the actual one")]
     hp: Option<f64>,
+    #[command(
+        desc = "Attach the strain graph as svg instead of png",
+        help = "Attach the strain graph as svg instead of png.\n\
+        The svg graph has no background image and discord won't display it inside the embed \
+        but it can be downloaded and scaled without loss."
+    )]
+    svg: Option<bool>,
+    #[command(
+        desc = "Choose the colors of the strain graph",
+        help = "Choose the colors of the strain graph.\n\
+        The colorblind palette uses colors that stay distinguishable \
+        for most kinds of color vision deficiency."
+    )]
+    palette: Option<GraphPalette>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption, Default)]
+pub enum GraphPalette {
+    #[default]
+    #[option(name = "Default", value = "default")]
+    Default,
+    #[option(name = "Colorblind", value = "colorblind")]
+    Colorblind,
+}
+
+/// Line colors of a strain graph
+struct StrainColors {
+    aim: RGBColor,
+    aim_no_sliders: RGBColor,
+    speed: RGBColor,
+    flashlight: RGBColor,
+    stamina: RGBColor,
+    color: RGBColor,
+    rhythm: RGBColor,
+    movement: RGBColor,
+    mania: RGBColor,
+}
+
+impl GraphPalette {
+    fn colors(self) -> StrainColors {
+        match self {
+            Self::Default => StrainColors {
+                aim: CYAN,
+                aim_no_sliders: GREEN,
+                speed: RED,
+                flashlight: MAGENTA,
+                stamina: RED,
+                color: YELLOW,
+                rhythm: CYAN,
+                movement: CYAN,
+                mania: MAGENTA,
+            },
+            // Okabe-Ito palette
+            Self::Colorblind => {
+                const SKY_BLUE: RGBColor = RGBColor(86, 180, 233);
+                const YELLOW: RGBColor = RGBColor(240, 228, 66);
+                const VERMILLION: RGBColor = RGBColor(213, 94, 0);
+                const REDDISH_PURPLE: RGBColor = RGBColor(204, 121, 167);
+
+                StrainColors {
+                    aim: SKY_BLUE,
+                    aim_no_sliders: YELLOW,
+                    speed: VERMILLION,
+                    flashlight: REDDISH_PURPLE,
+                    stamina: VERMILLION,
+                    color: YELLOW,
+                    rhythm: SKY_BLUE,
+                    movement: SKY_BLUE,
+                    mania: REDDISH_PURPLE,
+                }
+            }
+        }
+    }
 }
 
 #[derive(HasMods)]
-struct MapArgs<'a> {
-    map: Option<MapIdType>,
-    mods: Option<Cow<'a, str>>,
-    attrs: CustomAttrs,
+pub(super) struct MapArgs<'a> {
+    pub map: Option<MapIdType>,
+    pub mods: Option<Cow<'a, str>>,
+    pub attrs: CustomAttrs,
+    pub svg: bool,
+    pub palette: GraphPalette,
 }
 
 #[derive(Default)]
@@ -76,7 +151,7 @@ pub struct CustomAttrs {
 }
 
 impl CustomAttrs {
-    fn content(&self) -> Option<String> {
+    pub fn content(&self) -> Option<String> {
         self.ar.or(self.cs).or(self.hp).or(self.od)?;
 
         let mut content = "Custom attributes: ".to_owned();
@@ -121,9 +196,15 @@ impl<'m> MapArgs<'m> {
     async fn args(msg: &Message, args: Args<'m>) -> Result<MapArgs<'m>, String> {
         let mut map = None;
         let mut mods = None;
-
-        for arg in args.take(2) {
-            if let Some(id) = matcher::get_osu_map_id(arg)
+        let mut svg = false;
+        let mut palette = GraphPalette::Default;
+
+        for arg in args.take(4) {
+            if arg.eq_ignore_ascii_case("svg") {
+                svg = true;
+            } else if arg.eq_ignore_ascii_case("colorblind") {
+                palette = GraphPalette::Colorblind;
+            } else if let Some(id) = matcher::get_osu_map_id(arg)
                 .map(MapIdType::Map)
                 .or_else(|| matcher::get_osu_mapset_id(arg).map(MapIdType::Set))
             {
@@ -133,7 +214,7 @@ impl<'m> MapArgs<'m> {
             } else {
                 let content = format!(
                     "Failed to parse `{arg}`.\n\
-                    Be sure you specify either a valid map id, map url, or mod combination."
+                    Be sure you specify either a valid map id, map url, mod combination, `svg`, or `colorblind`."
                 );
 
                 return Err(content);
@@ -155,6 +236,8 @@ impl<'m> MapArgs<'m> {
             map,
             mods,
             attrs: CustomAttrs::default(),
+            svg,
+            palette,
         })
     }
 }
@@ -170,6 +253,8 @@ impl<'a> TryFrom<Map<'a>> for MapArgs<'a> {
             od,
             cs,
             hp,
+            svg,
+            palette,
         } = args;
 
         let map = match map.map(|arg| {
@@ -189,7 +274,13 @@ impl<'a> TryFrom<Map<'a>> for MapArgs<'a> {
 
         let attrs = CustomAttrs { ar, cs, hp, od };
 
-        Ok(Self { map, mods, attrs })
+        Ok(Self {
+            map,
+            mods,
+            attrs,
+            svg: svg.unwrap_or(false),
+            palette: palette.unwrap_or_default(),
+        })
     }
 }
 
@@ -200,9 +291,11 @@ impl<'a> TryFrom<Map<'a>> for MapArgs<'a> {
     If no map(set) is specified by either url or id, I will choose the last map \
     I can find in the embeds of this channel.\n\
     If the mapset is specified by id but there is some map with the same id, \
-    I will choose the latter."
+    I will choose the latter.\n\
+    To get the strain graph as svg instead of png, add `svg`.\n\
+    To use colorblind-friendly colors for the strain graph, add `colorblind`."
 )]
-#[usage("[map(set) url / map(set) id] [+mods]")]
+#[usage("[map(set) url / map(set) id] [+mods] [svg] [colorblind]")]
 #[examples("2240404 +hddt", "https://osu.ppy.sh/beatmapsets/902425 +hr")]
 #[aliases("m", "beatmap", "maps", "beatmaps", "mapinfo")]
 #[group(AllModes)]
@@ -234,7 +327,7 @@ const W: u32 = 590;
 const H: u32 = 170;
 const LEGEND_H: u32 = 25;
 
-async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
+pub(super) async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
     let mods = match args.mods() {
         ModsResult::Mods(mods) => Some(mods),
         ModsResult::None => None,
@@ -246,7 +339,13 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
         }
     };
 
-    let MapArgs { map, attrs, .. } = args;
+    let MapArgs {
+        map,
+        attrs,
+        svg,
+        palette,
+        ..
+    } = args;
 
     let map_id = if let Some(id) = map {
         id
@@ -352,14 +451,24 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
     };
 
     let graph = match Context::osu_map().pp_map(map_id).await {
-        Ok(map) => match map_strain_graph(&map, mods_with_mode, &mapset.covers.cover).await {
+        Ok(map) if svg => match map_strain_graph_svg(&map, mods_with_mode, palette) {
             Ok(graph) => Some(graph),
             Err(err) => {
-                warn!(?err, "Failed to create graph");
+                warn!(?err, "Failed to create svg graph");
 
                 None
             }
         },
+        Ok(map) => {
+            match map_strain_graph(&map, mods_with_mode, &mapset.covers.cover, palette).await {
+                Ok(graph) => Some(graph),
+                Err(err) => {
+                    warn!(?err, "Failed to create graph");
+
+                    None
+                }
+            }
+        }
         Err(err) => {
             warn!(?err, "Failed to get pp map");
 
@@ -378,14 +487,21 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
         .attrs(attrs)
         .origin(origin)
         .content(content.unwrap_or_default().into_boxed_str())
+        .svg_graph(svg)
         .msg_owner(orig.user_id()?)
         .build();
 
     pagination.set_index(map_idx);
 
+    let graph_filename = if svg {
+        "map_graph.svg"
+    } else {
+        "map_graph.png"
+    };
+
     ActiveMessages::builder(pagination)
         .start_by_update(true)
-        .attachment(graph.map(|bytes| ("map_graph.png".to_owned(), bytes)))
+        .attachment(graph.map(|bytes| (graph_filename.to_owned(), bytes)))
         .begin(orig)
         .await
 }
@@ -469,10 +585,92 @@ async fn get_cover(url: &str) -> Result<DynamicImage> {
     Ok(cover.thumbnail_exact(W, H))
 }
 
-pub async fn map_strain_graph(map: &PpMap, mods: GameMods, cover_url: &str) -> Result<Vec<u8>> {
+pub async fn map_strain_graph(
+    map: &PpMap,
+    mods: GameMods,
+    cover_url: &str,
+    palette: GraphPalette,
+) -> Result<Vec<u8>> {
     let cover_res = get_cover(cover_url).await;
     let strains = strain_values(map, mods)?;
 
+    let mut surface =
+        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
+
+    {
+        let backend = Rc::new(RefCell::new(SkiaBackend::new(surface.canvas(), W, H)));
+        let root = DrawingArea::from(&backend);
+
+        // Add background
+        match cover_res {
+            Ok(background) => {
+                let background = background.blur(2.0);
+                let elem = BitMapElement::new(background, (0, 0));
+                root.draw(&elem).wrap_err("Failed to draw background")?;
+
+                let rect = Rectangle::new([(0, 0), (W as i32, H as i32)], BLACK.mix(0.75).filled());
+                root.draw(&rect)
+                    .wrap_err("Failed to draw darkening rectangle")?;
+            }
+            Err(err) => {
+                warn!(?err, "Failed to get mapset cover");
+
+                root.fill(&GRAPH_BACKGROUND)
+                    .wrap_err("Failed to fill background")?;
+            }
+        }
+
+        let mut set_blend_mode =
+            |mode: Option<BlendMode>| backend.borrow_mut().set_blend_mode(mode);
+        draw_graph(&root, strains, palette, &mut set_blend_mode)?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(png_bytes)
+}
+
+/// Same graph as [`map_strain_graph`] but as SVG.
+///
+/// The mapset cover is not included since the strain areas can't be blended
+/// onto it.
+pub fn map_strain_graph_svg(map: &PpMap, mods: GameMods, palette: GraphPalette) -> Result<Vec<u8>> {
+    let strains = strain_values(map, mods)?;
+    let mut svg = String::new();
+
+    {
+        let root = SVGBackend::with_string(&mut svg, (W, H)).into_drawing_area();
+
+        root.fill(&GRAPH_BACKGROUND)
+            .wrap_err("Failed to fill background")?;
+
+        draw_graph(&root, strains, palette, &mut |_| {})?;
+
+        root.present().wrap_err("Failed to finish svg")?;
+    }
+
+    Ok(svg.into_bytes())
+}
+
+const GRAPH_BACKGROUND: RGBColor = RGBColor(19, 43, 33);
+
+type BlendModeSetter<'a> = dyn FnMut(Option<BlendMode>) + 'a;
+
+/// Draw the legend, mesh, and strains on top of an existing background.
+fn draw_graph<DB>(
+    root: &DrawingArea<DB, Shift>,
+    strains: GraphStrains,
+    palette: GraphPalette,
+    set_blend_mode: &mut BlendModeSetter<'_>,
+) -> Result<()>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
     let last_timestamp = ((NEW_STRAIN_COUNT - 2) as f64
         * strains.strains.section_len()
         * strains.strains_count as f64)
@@ -508,85 +706,62 @@ pub async fn map_strain_graph(map: &PpMap, mods: GameMods, cover_url: &str) -> R
         bail!("no non-zero strain point");
     }
 
-    let mut surface =
-        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
-
-    {
-        let backend = Rc::new(RefCell::new(SkiaBackend::new(surface.canvas(), W, H)));
-        let root = DrawingArea::from(&backend);
-
-        // Add background
-        match cover_res {
-            Ok(background) => {
-                let background = background.blur(2.0);
-                let elem = BitMapElement::new(background, (0, 0));
-                root.draw(&elem).wrap_err("Failed to draw background")?;
-
-                let rect = Rectangle::new([(0, 0), (W as i32, H as i32)], BLACK.mix(0.75).filled());
-                root.draw(&rect)
-                    .wrap_err("Failed to draw darkening rectangle")?;
-            }
-            Err(err) => {
-                warn!(?err, "Failed to get mapset cover");
-
-                root.fill(&RGBColor(19, 43, 33))
-                    .wrap_err("Failed to fill background")?;
+    let (legend_area, graph_area) = root.split_vertically(LEGEND_H);
+
+    let mut chart = ChartBuilder::on(&graph_area)
+        .x_label_area_size(17_i32)
+        .build_cartesian_2d(last_timestamp.min(1.0)..last_timestamp, 0.0_f64..max_strain)
+        .wrap_err("Failed to build chart")?;
+
+    // Mesh and labels
+    let text_style = FontDesc::new(FontFamily::SansSerif, 14.0, FontStyle::Bold).color(&WHITE);
+
+    chart
+        .configure_mesh()
+        .disable_y_mesh()
+        .disable_y_axis()
+        .set_all_tick_mark_size(3_i32)
+        .light_line_style(WHITE.mix(0.0)) // hide
+        .bold_line_style(WHITE.mix(0.75))
+        .x_labels(10)
+        .x_label_style(text_style.clone())
+        .axis_style(WHITE)
+        .x_label_formatter(&|timestamp| {
+            if timestamp.abs() < f64::EPSILON {
+                return String::new();
             }
-        }
-
-        let (legend_area, graph_area) = root.split_vertically(LEGEND_H);
-
-        let mut chart = ChartBuilder::on(&graph_area)
-            .x_label_area_size(17_i32)
-            .build_cartesian_2d(last_timestamp.min(1.0)..last_timestamp, 0.0_f64..max_strain)
-            .wrap_err("Failed to build chart")?;
-
-        // Mesh and labels
-        let text_style = FontDesc::new(FontFamily::SansSerif, 14.0, FontStyle::Bold).color(&WHITE);
-
-        chart
-            .configure_mesh()
-            .disable_y_mesh()
-            .disable_y_axis()
-            .set_all_tick_mark_size(3_i32)
-            .light_line_style(WHITE.mix(0.0)) // hide
-            .bold_line_style(WHITE.mix(0.75))
-            .x_labels(10)
-            .x_label_style(text_style.clone())
-            .axis_style(WHITE)
-            .x_label_formatter(&|timestamp| {
-                if timestamp.abs() < f64::EPSILON {
-                    return String::new();
-                }
 
-                let d = Duration::from_millis(*timestamp as u64);
-                let minutes = d.as_secs() / 60;
-                let seconds = d.as_secs() % 60;
+            let d = Duration::from_millis(*timestamp as u64);
+            let minutes = d.as_secs() / 60;
+            let seconds = d.as_secs() % 60;
 
-                format!("{minutes}:{seconds:0>2}")
-            })
-            .draw()
-            .wrap_err("Failed to draw mesh")?;
-
-        draw_mode_strains(&backend, &mut chart, strains, &legend_area, &text_style)?;
-    }
-
-    let png_bytes = surface
-        .image_snapshot()
-        .encode(None, EncodedImageFormat::PNG, None)
-        .wrap_err("Failed to encode image")?
-        .to_vec();
+            format!("{minutes}:{seconds:0>2}")
+        })
+        .draw()
+        .wrap_err("Failed to draw mesh")?;
 
-    Ok(png_bytes)
+    draw_mode_strains(
+        set_blend_mode,
+        &mut chart,
+        strains,
+        palette,
+        &legend_area,
+        &text_style,
+    )
 }
 
-fn draw_mode_strains(
-    backend: &Rc<RefCell<SkiaBackend<'_>>>,
-    chart: &mut ChartContext<'_, SkiaBackend<'_>, Cartesian2d<RangedCoordf64, RangedCoordf64>>,
+fn draw_mode_strains<DB>(
+    set_blend_mode: &mut BlendModeSetter<'_>,
+    chart: &mut ChartContext<'_, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>,
     strains: GraphStrains,
-    legend_area: &DrawingArea<SkiaBackend<'_>, Shift>,
+    palette: GraphPalette,
+    legend_area: &DrawingArea<DB, Shift>,
     text_style: &TextStyle<'_>,
-) -> Result<()> {
+) -> Result<()>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
     let GraphStrains {
         strains,
         strains_count,
@@ -607,24 +782,41 @@ fn draw_mode_strains(
 
     let factor = section_len * orig_count / new_count;
 
+    let colors = palette.colors();
+
     macro_rules! draw_line {
         ( $label:literal, $strains:expr, $color:ident ) => {{
-            draw_series(backend, chart, &$strains, $label, factor, $color)?;
-            draw_line(legend_area, $label, $color, text_style, &mut legend_x)?;
+            draw_series(
+                set_blend_mode,
+                chart,
+                &$strains,
+                $label,
+                factor,
+                colors.$color,
+            )?;
+            draw_line(
+                legend_area,
+                $label,
+                colors.$color,
+                text_style,
+                &mut legend_x,
+            )?;
         }};
     }
 
-    fn draw_series(
-        backend: &Rc<RefCell<SkiaBackend<'_>>>,
-        chart: &mut ChartContext<'_, SkiaBackend<'_>, Cartesian2d<RangedCoordf64, RangedCoordf64>>,
+    fn draw_series<DB>(
+        set_blend_mode: &mut BlendModeSetter<'_>,
+        chart: &mut ChartContext<'_, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>,
         strains: &[f64],
         label: &str,
         factor: f64,
         color: RGBColor,
-    ) -> Result<()> {
-        backend
-            .borrow_mut()
-            .set_blend_mode(Some(BlendMode::Lighten));
+    ) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        set_blend_mode(Some(BlendMode::Lighten));
 
         let timestamp_iter = strains
             .iter()
@@ -638,18 +830,22 @@ fn draw_mode_strains(
             .draw_series(series)
             .wrap_err_with(|| format!("Failed to draw {label} series"))?;
 
-        backend.borrow_mut().set_blend_mode(None);
+        set_blend_mode(None);
 
         Ok(())
     }
 
-    fn draw_line(
-        legend_area: &DrawingArea<SkiaBackend<'_>, Shift>,
+    fn draw_line<DB>(
+        legend_area: &DrawingArea<DB, Shift>,
         label: &str,
         color: RGBColor,
         text_style: &TextStyle<'_>,
         legend_x: &mut i32,
-    ) -> Result<()> {
+    ) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
         let rect = Rectangle::new(
             [
                 (*legend_x, (LEGEND_H as f32 * 0.42) as i32),
@@ -685,18 +881,18 @@ fn draw_mode_strains(
 
     match strains {
         Strains::Osu(strains) => {
-            draw_line!("Aim", strains.aim, CYAN);
-            draw_line!("Aim (Sliders)", strains.aim_no_sliders, GREEN);
-            draw_line!("Speed", strains.speed, RED);
-            draw_line!("Flashlight", strains.flashlight, MAGENTA);
+            draw_line!("Aim", strains.aim, aim);
+            draw_line!("Aim (Sliders)", strains.aim_no_sliders, aim_no_sliders);
+            draw_line!("Speed", strains.speed, speed);
+            draw_line!("Flashlight", strains.flashlight, flashlight);
         }
         Strains::Taiko(strains) => {
-            draw_line!("Stamina", strains.stamina, RED);
-            draw_line!("Color", strains.color, YELLOW);
-            draw_line!("Rhythm", strains.rhythm, CYAN);
+            draw_line!("Stamina", strains.stamina, stamina);
+            draw_line!("Color", strains.color, color);
+            draw_line!("Rhythm", strains.rhythm, rhythm);
         }
-        Strains::Catch(strains) => draw_line!("Movement", strains.movement, CYAN),
-        Strains::Mania(strains) => draw_line!("Strain", strains.strains, MAGENTA),
+        Strains::Catch(strains) => draw_line!("Movement", strains.movement, movement),
+        Strains::Mania(strains) => draw_line!("Strain", strains.strains, mania),
     }
 
     Ok(())
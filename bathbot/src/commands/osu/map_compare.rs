@@ -0,0 +1,400 @@
+use std::borrow::Cow;
+
+use bathbot_macros::{command, HasMods, SlashCommand};
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_BASE},
+    matcher,
+    numbers::round,
+    osu::ModSelection,
+    CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder,
+};
+use eyre::Result;
+use rosu_pp::{any::DifficultyAttributes, Difficulty};
+use rosu_v2::prelude::GameMods;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::channel::{message::embed::EmbedField, Message};
+
+use super::{HasMods, ModsResult};
+use crate::{
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::{MapError, Mods, OsuMap},
+    util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "mapcompare",
+    desc = "Compare the pp of two maps at the same accuracy",
+    help = "Compare the pp of two maps for a full combo at the same accuracy.\n\
+    Both maps must be of the same mode. Mods and attribute overrides apply to both maps."
+)]
+pub struct MapCompare<'a> {
+    #[command(desc = "Specify the first map url or map id")]
+    map1: Cow<'a, str>,
+    #[command(desc = "Specify the second map url or map id")]
+    map2: Cow<'a, str>,
+    #[command(min_value = 0.0, max_value = 100.0, desc = "Specify the accuracy")]
+    acc: f32,
+    #[command(
+        desc = "Specify mods e.g. hdhr or nm",
+        help = "Specify mods either directly or through the explicit `+mods!` / `+mods` syntax, \
+        e.g. `hdhr` or `+hdhr!`"
+    )]
+    mods: Option<Cow<'a, str>>,
+    #[command(
+        min_value = 0.0,
+        max_value = 11.0,
+        desc = "Overwrite the maps' approach rate"
+    )]
+    ar: Option<f32>,
+    #[command(
+        min_value = 0.0,
+        max_value = 10.0,
+        desc = "Overwrite the maps' circle size"
+    )]
+    cs: Option<f32>,
+    #[command(
+        min_value = 0.0,
+        max_value = 10.0,
+        desc = "Overwrite the maps' drain rate"
+    )]
+    hp: Option<f32>,
+    #[command(
+        min_value = 0.0,
+        max_value = 11.0,
+        desc = "Overwrite the maps' overall difficulty"
+    )]
+    od: Option<f32>,
+}
+
+#[derive(HasMods)]
+struct MapCompareArgs<'a> {
+    map1: u32,
+    map2: u32,
+    acc: f32,
+    mods: Option<Cow<'a, str>>,
+    attrs: AttributeOverrides,
+}
+
+/// Map attributes that replace those of both maps.
+#[derive(Copy, Clone, Default)]
+struct AttributeOverrides {
+    ar: Option<f32>,
+    cs: Option<f32>,
+    hp: Option<f32>,
+    od: Option<f32>,
+}
+
+impl AttributeOverrides {
+    fn is_empty(self) -> bool {
+        self.ar.is_none() && self.cs.is_none() && self.hp.is_none() && self.od.is_none()
+    }
+}
+
+impl<'m> MapCompareArgs<'m> {
+    fn args(args: Args<'m>) -> Result<Self, String> {
+        let mut maps = Vec::with_capacity(2);
+        let mut acc = None;
+        let mut mods = None;
+        let mut attrs = AttributeOverrides::default();
+
+        for arg in args.take(8) {
+            // Plain numbers are map ids until both maps are specified
+            if let Some(id) = matcher::get_osu_map_id(arg).filter(|_| maps.len() < 2) {
+                maps.push(id);
+
+                continue;
+            }
+
+            if let Some((key, value)) = arg.split_once('=') {
+                let Ok(value) = value.parse::<f32>() else {
+                    return Err(format!("Failed to parse value of `{key}` as a number"));
+                };
+
+                match key.cow_to_ascii_lowercase().as_ref() {
+                    "acc" | "accuracy" => acc = Some(value),
+                    "ar" => attrs.ar = Some(value),
+                    "cs" => attrs.cs = Some(value),
+                    "hp" => attrs.hp = Some(value),
+                    "od" => attrs.od = Some(value),
+                    _ => {
+                        let content = format!(
+                            "Unrecognized option `{key}`.\n\
+                            Available options are: `acc`, `ar`, `cs`, `hp`, or `od`."
+                        );
+
+                        return Err(content);
+                    }
+                }
+            } else if let Ok(n) = arg.trim_end_matches('%').parse::<f32>() {
+                acc = Some(n);
+            } else if matcher::get_mods(arg).is_some() {
+                mods = Some(arg.into());
+            } else {
+                let content = format!(
+                    "Failed to parse `{arg}`.\n\
+                    Must be either a map id, map url, accuracy, mods, or `key=value` option."
+                );
+
+                return Err(content);
+            }
+        }
+
+        let [map1, map2] = maps[..] else {
+            return Err("You must specify exactly two maps".to_owned());
+        };
+
+        let Some(acc) = acc else {
+            return Err("You must specify an accuracy e.g. `98.5`".to_owned());
+        };
+
+        Ok(Self {
+            map1,
+            map2,
+            acc: acc.clamp(0.0, 100.0),
+            mods,
+            attrs,
+        })
+    }
+}
+
+impl<'a> TryFrom<MapCompare<'a>> for MapCompareArgs<'a> {
+    type Error = &'static str;
+
+    fn try_from(args: MapCompare<'a>) -> Result<Self, Self::Error> {
+        const MAP_PARSE_FAIL: &str =
+            "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+
+        let map1 = matcher::get_osu_map_id(&args.map1).ok_or(MAP_PARSE_FAIL)?;
+        let map2 = matcher::get_osu_map_id(&args.map2).ok_or(MAP_PARSE_FAIL)?;
+
+        Ok(Self {
+            map1,
+            map2,
+            acc: args.acc,
+            mods: args.mods,
+            attrs: AttributeOverrides {
+                ar: args.ar,
+                cs: args.cs,
+                hp: args.hp,
+                od: args.od,
+            },
+        })
+    }
+}
+
+#[command]
+#[desc("Compare the pp of two maps at the same accuracy")]
+#[help(
+    "Compare the pp of two maps for a full combo at the same accuracy.\n\
+    Both maps must be of the same mode.\n\
+    Mods and the attribute overrides `ar=[number]`, `cs=[number]`, \
+    `hp=[number]`, and `od=[number]` apply to both maps."
+)]
+#[usage("[map url / map id] [map url / map id] [accuracy] [+mods] [ar=number] ...")]
+#[examples("2240404 1788363 98.5 +hd", "2240404 1788363 acc=99 +dt od=9")]
+#[alias("mapc")]
+#[group(AllModes)]
+async fn prefix_mapcompare(msg: &Message, args: Args<'_>) -> Result<()> {
+    match MapCompareArgs::args(args) {
+        Ok(args) => map_compare(msg.into(), args).await,
+        Err(content) => {
+            msg.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+async fn slash_mapcompare(mut command: InteractionCommand) -> Result<()> {
+    let args = MapCompare::from_interaction(command.input_data())?;
+
+    match MapCompareArgs::try_from(args) {
+        Ok(args) => map_compare((&mut command).into(), args).await,
+        Err(content) => {
+            command.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+async fn map_compare(orig: CommandOrigin<'_>, args: MapCompareArgs<'_>) -> Result<()> {
+    let mods = match args.mods() {
+        ModsResult::Mods(ModSelection::Include(mods) | ModSelection::Exact(mods)) => Some(mods),
+        ModsResult::None => None,
+        ModsResult::Mods(ModSelection::Exclude(_)) | ModsResult::Invalid => {
+            let content = "Failed to parse mods.\n\
+            If you want to specify mods, do so e.g. as `+hdhr`.";
+
+            return orig.error(content).await;
+        }
+    };
+
+    let maps_fut = tokio::join!(
+        Context::osu_map().map(args.map1, None),
+        Context::osu_map().map(args.map2, None),
+    );
+
+    let (map1, map2) = match maps_fut {
+        (Ok(map1), Ok(map2)) => (map1, map2),
+        (Err(MapError::NotFound), _) | (_, Err(MapError::NotFound)) => {
+            let content = "Could not find one of the beatmaps. \
+                Did you give me a mapset id instead of a map id?";
+
+            return orig.error(content).await;
+        }
+        (Err(MapError::Report(err)), _) | (_, Err(MapError::Report(err))) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let mode = map1.mode();
+
+    if map2.mode() != mode {
+        let content = format!(
+            "The maps are of different modes ({} and {}) so their pp can't be compared",
+            map1.mode(),
+            map2.mode()
+        );
+
+        return orig.error(content).await;
+    }
+
+    let mods = match mods.map(|mods| mods.try_with_mode(mode)) {
+        Some(Some(mods)) => mods,
+        None => GameMods::new(),
+        Some(None) => {
+            let content = format!(
+                "Looks like some mods in `{}` are not valid for {mode}",
+                args.mods.as_deref().unwrap_or_default()
+            );
+
+            return orig.error(content).await;
+        }
+    };
+
+    let acc = args.acc;
+    let (stars1, pp1) = pp_at_acc(&map1, &mods, args.attrs, acc).await;
+    let (stars2, pp2) = pp_at_acc(&map2, &mods, args.attrs, acc).await;
+
+    let map_field = |map: &OsuMap, stars: f32, pp: f32| {
+        let name = format!(
+            "{} [{}]",
+            map.title().cow_escape_markdown(),
+            map.version().cow_escape_markdown()
+        );
+
+        let value = format!(
+            "[Map link]({OSU_BASE}b/{map_id}) • {stars}★\n**{pp}pp**",
+            map_id = map.map_id(),
+            stars = round(stars),
+            pp = round(pp),
+        );
+
+        EmbedField {
+            inline: true,
+            name,
+            value,
+        }
+    };
+
+    let fields = vec![map_field(&map1, stars1, pp1), map_field(&map2, stars2, pp2)];
+
+    let diff = pp1 - pp2;
+
+    let description = if diff.abs() < 0.01 {
+        "Both maps give the same pp".to_owned()
+    } else {
+        let (more, less) = if diff > 0.0 { (1, pp2) } else { (2, pp1) };
+
+        format!(
+            "Map {more} gives **{diff}pp** more ({percent}%)",
+            diff = round(diff.abs()),
+            percent = round(100.0 * diff.abs() / less.max(f32::EPSILON)),
+        )
+    };
+
+    let mut footer = format!("Full combo with {}%", round(acc));
+
+    if !mods.is_empty() {
+        footer.push_str(&format!(" +{mods}"));
+    }
+
+    if !args.attrs.is_empty() {
+        let AttributeOverrides { ar, cs, hp, od } = args.attrs;
+
+        let overrides: Vec<_> = [("AR", ar), ("CS", cs), ("HP", hp), ("OD", od)]
+            .into_iter()
+            .filter_map(|(name, value)| Some(format!("{name} {}", round(value?))))
+            .collect();
+
+        footer.push_str(" • ");
+        footer.push_str(&overrides.join(" "));
+    }
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .fields(fields)
+        .footer(FooterBuilder::new(footer))
+        .title("Map pp comparison");
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+/// Stars and pp of a full combo with the given accuracy.
+async fn pp_at_acc(
+    map: &OsuMap,
+    mods: &GameMods,
+    overrides: AttributeOverrides,
+    acc: f32,
+) -> (f32, f32) {
+    let mods = Mods::from(mods);
+
+    let attrs: DifficultyAttributes = if overrides.is_empty() {
+        Context::pp(map).mods(mods).difficulty().await.to_owned()
+    } else {
+        // Modified attributes must not end up in the difficulty cache so we
+        // calculate them manually
+        let mut pp_map = map.pp_map.clone();
+
+        if let Some(ar) = overrides.ar {
+            pp_map.ar = ar;
+        }
+
+        if let Some(cs) = overrides.cs {
+            pp_map.cs = cs;
+        }
+
+        if let Some(hp) = overrides.hp {
+            pp_map.hp = hp;
+        }
+
+        if let Some(od) = overrides.od {
+            pp_map.od = od;
+        }
+
+        let mut calc = Difficulty::new().mods(mods.bits);
+
+        if let Some(clock_rate) = mods.clock_rate {
+            calc = calc.clock_rate(f64::from(clock_rate));
+        }
+
+        calc.calculate(&pp_map)
+    };
+
+    let stars = attrs.stars() as f32;
+    let mut calc = attrs.performance().mods(mods.bits).accuracy(f64::from(acc));
+
+    if let Some(clock_rate) = mods.clock_rate {
+        calc = calc.clock_rate(f64::from(clock_rate));
+    }
+
+    (stars, calc.calculate().pp() as f32)
+}
@@ -0,0 +1,322 @@
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+
+use bathbot_macros::SlashCommand;
+use bathbot_psql::model::map_request::MapRequest;
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_BASE},
+    matcher, CowUtils, EmbedBuilder, MessageBuilder,
+};
+use eyre::Result;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{
+    core::Context,
+    manager::{MapError, MapRequestAdd, MAX_MAP_REQUESTS, MAX_MAP_REQUESTS_PER_USER},
+    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "maprequest",
+    desc = "Request maps for someone to play",
+    help = "Request maps for someone to play, e.g. a streamer.\n\
+    Each user has their own queue of requests that others can add maps to \
+    once its owner opened it via `/maprequest open`. \
+    The owner of the queue can then pop the requests one by one or clear the queue.\n\
+    A queue can hold up to 25 maps, each map can only be requested once at a time, \
+    and each user can have up to 3 requests in a queue."
+)]
+pub enum MapRequestCommand {
+    #[command(name = "add")]
+    Add(MapRequestCommandAdd),
+    #[command(name = "list")]
+    List(MapRequestCommandList),
+    #[command(name = "pop")]
+    Pop(MapRequestCommandPop),
+    #[command(name = "clear")]
+    Clear(MapRequestCommandClear),
+    #[command(name = "open")]
+    Open(MapRequestCommandOpen),
+    #[command(name = "close")]
+    Close(MapRequestCommandClose),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "add", desc = "Request a map for someone to play")]
+pub struct MapRequestCommandAdd {
+    #[command(desc = "Specify the user whose queue the map should be added to")]
+    host: Id<UserMarker>,
+    #[command(desc = "Specify a map url or map id")]
+    map: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List all map requests of a queue")]
+pub struct MapRequestCommandList {
+    #[command(desc = "Specify the user whose queue should be listed, defaults to yourself")]
+    host: Option<Id<UserMarker>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "pop",
+    desc = "Remove and show the oldest request of your queue"
+)]
+pub struct MapRequestCommandPop;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "clear", desc = "Remove all requests of your queue")]
+pub struct MapRequestCommandClear;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "open", desc = "Allow others to request maps for you")]
+pub struct MapRequestCommandOpen;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "close",
+    desc = "Stop accepting map requests, already requested maps remain in the queue"
+)]
+pub struct MapRequestCommandClose;
+
+async fn slash_maprequest(mut command: InteractionCommand) -> Result<()> {
+    match MapRequestCommand::from_interaction(command.input_data())? {
+        MapRequestCommand::Add(args) => add(command, args).await,
+        MapRequestCommand::List(args) => list(command, args).await,
+        MapRequestCommand::Pop(_) => pop(command).await,
+        MapRequestCommand::Clear(_) => clear(command).await,
+        MapRequestCommand::Open(_) => open(command).await,
+        MapRequestCommand::Close(_) => close(command).await,
+    }
+}
+
+async fn add(command: InteractionCommand, args: MapRequestCommandAdd) -> Result<()> {
+    let MapRequestCommandAdd { host, map } = args;
+
+    let map_id = match matcher::get_osu_map_id(&map) {
+        Some(map_id) => map_id,
+        None if matcher::get_osu_mapset_id(&map).is_some() => {
+            let content = "Looks like you gave me a mapset url, \
+                please specify the url or id of a single map instead.";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        None => {
+            let content = "Failed to parse map url. \
+                Be sure you specify a valid map id or url to a map.";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    // Retrieving the map both validates it and stores it for listing later on
+    let map = match Context::osu_map().map(map_id, None).await {
+        Ok(map) => map,
+        Err(MapError::NotFound) => {
+            let content = format!("Could not find beatmap with id `{map_id}`");
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(MapError::Report(err)) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let requester = command.user_id()?;
+
+    let position = match Context::map_requests().add(host, requester, map_id).await {
+        Ok(MapRequestAdd::Added { position }) => position,
+        Ok(MapRequestAdd::Closed) => {
+            let content = format!("<@{host}> does not accept map requests right now");
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Ok(MapRequestAdd::Duplicate) => {
+            let content = format!("This map is already in the queue of <@{host}>");
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Ok(MapRequestAdd::Full) => {
+            let content = format!(
+                "The queue of <@{host}> is full, it can hold up to {MAX_MAP_REQUESTS} requests"
+            );
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Ok(MapRequestAdd::TooManyRequests) => {
+            let content = format!(
+                "You already have {MAX_MAP_REQUESTS_PER_USER} requests in the queue of <@{host}>"
+            );
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let content = format!(
+        "Requested [{artist} - {title} [{version}]]({OSU_BASE}b/{map_id}) for <@{host}> \
+        (position {position} in the queue)",
+        artist = map.artist().cow_escape_markdown(),
+        title = map.title().cow_escape_markdown(),
+        version = map.version().cow_escape_markdown(),
+    );
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+async fn list(command: InteractionCommand, args: MapRequestCommandList) -> Result<()> {
+    let host = match args.host {
+        Some(host) => host,
+        None => command.user_id()?,
+    };
+
+    let requests = match Context::map_requests().get(host).await {
+        Ok(requests) => requests,
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let description = if requests.is_empty() {
+        format!("The queue of <@{host}> is empty")
+    } else {
+        let mut description = format!("Requests for <@{host}>:\n");
+
+        for (i, request) in requests.iter().enumerate() {
+            let _ = writeln!(description, "`{}.` {}", i + 1, RequestFormatter(request));
+        }
+
+        description
+    };
+
+    let embed = EmbedBuilder::new().description(description).title(format!(
+        "Map requests ({}/{MAX_MAP_REQUESTS})",
+        requests.len()
+    ));
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+async fn pop(command: InteractionCommand) -> Result<()> {
+    let host = command.user_id()?;
+
+    let request = match Context::map_requests().pop(host).await {
+        Ok(Some(request)) => request,
+        Ok(None) => {
+            command.error("Your queue is empty").await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let content = format!("Next up: {}", RequestFormatter(&request));
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+async fn clear(command: InteractionCommand) -> Result<()> {
+    let host = command.user_id()?;
+
+    let content = match Context::map_requests().clear(host).await {
+        Ok(0) => "Your queue is already empty".to_owned(),
+        Ok(count) => format!("Removed {count} request(s) from your queue"),
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+async fn open(command: InteractionCommand) -> Result<()> {
+    let host = command.user_id()?;
+
+    let content = match Context::map_requests().open(host).await {
+        Ok(true) => "Your queue is now open, others can request maps for you",
+        Ok(false) => "Your queue is already open",
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+async fn close(command: InteractionCommand) -> Result<()> {
+    let host = command.user_id()?;
+
+    let content = match Context::map_requests().close(host).await {
+        Ok(true) => "Your queue is now closed, no more maps can be requested",
+        Ok(false) => "Your queue is already closed",
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+struct RequestFormatter<'a>(&'a MapRequest);
+
+impl Display for RequestFormatter<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let request = self.0;
+
+        write!(
+            f,
+            "[{artist} - {title} [{version}]]({OSU_BASE}b/{map_id}) • \
+            requested by <@{requester}> <t:{timestamp}:R>",
+            artist = request.artist.cow_escape_markdown(),
+            title = request.title.cow_escape_markdown(),
+            version = request.version.cow_escape_markdown(),
+            map_id = request.map_id,
+            requester = request.requester_id,
+            timestamp = request.insert_date.unix_timestamp(),
+        )
+    }
+}
@@ -40,6 +40,18 @@ pub struct Search {
     pub nsfw: Option<bool>,
     #[command(desc = "Specify whether the resulting list should be reversed")]
     pub reverse: Option<bool>,
+    #[command(min_value = 0.0, desc = "Specify a minimum star rating")]
+    pub min_stars: Option<f64>,
+    #[command(min_value = 0.0, desc = "Specify a maximum star rating")]
+    pub max_stars: Option<f64>,
+    #[command(min_value = 0, desc = "Specify a minimum drain length in seconds")]
+    pub min_length: Option<u32>,
+    #[command(min_value = 0, desc = "Specify a maximum drain length in seconds")]
+    pub max_length: Option<u32>,
+    #[command(min_value = 0.0, desc = "Specify a minimum BPM")]
+    pub min_bpm: Option<f64>,
+    #[command(min_value = 0.0, desc = "Specify a maximum BPM")]
+    pub max_bpm: Option<f64>,
 }
 
 #[derive(CommandOption, CreateOption, Debug)]
@@ -527,9 +539,78 @@ impl Search {
             nsfw,
             sort,
             reverse,
+            min_stars: None,
+            max_stars: None,
+            min_length: None,
+            max_length: None,
+            min_bpm: None,
+            max_bpm: None,
         })
     }
 
+    /// Validate the attribute ranges and move them into the query so that
+    /// the search itself filters by them.
+    fn apply_ranges(&mut self) -> Result<(), String> {
+        fn check<T: PartialOrd + Copy>(
+            name: &str,
+            min: Option<T>,
+            max: Option<T>,
+        ) -> Result<(), String> {
+            match (min, max) {
+                (Some(min), Some(max)) if min > max => Err(format!(
+                    "The minimum {name} must not be greater than the maximum {name}"
+                )),
+                _ => Ok(()),
+            }
+        }
+
+        check("stars", self.min_stars, self.max_stars)?;
+        check("length", self.min_length, self.max_length)?;
+        check("BPM", self.min_bpm, self.max_bpm)?;
+
+        let mut filters = Vec::new();
+
+        if let Some(min) = self.min_stars.take() {
+            filters.push(format!("stars>={min}"));
+        }
+
+        if let Some(max) = self.max_stars.take() {
+            filters.push(format!("stars<={max}"));
+        }
+
+        if let Some(min) = self.min_length.take() {
+            filters.push(format!("length>={min}"));
+        }
+
+        if let Some(max) = self.max_length.take() {
+            filters.push(format!("length<={max}"));
+        }
+
+        if let Some(min) = self.min_bpm.take() {
+            filters.push(format!("bpm>={min}"));
+        }
+
+        if let Some(max) = self.max_bpm.take() {
+            filters.push(format!("bpm<={max}"));
+        }
+
+        if filters.is_empty() {
+            return Ok(());
+        }
+
+        let query = self.query.get_or_insert_with(String::new);
+
+        for filter in filters {
+            if !query.is_empty() {
+                query.push(' ');
+            }
+
+            query.push_str(&filter);
+        }
+
+        Ok(())
+    }
+
     async fn request(&self, osu: &Osu) -> OsuResult<BeatmapsetSearchResult> {
         let sort = self
             .sort
@@ -622,7 +703,11 @@ async fn prefix_search(msg: &Message, args: Args<'_>) -> Result<()> {
     }
 }
 
-async fn search(orig: CommandOrigin<'_>, args: Search) -> Result<()> {
+async fn search(orig: CommandOrigin<'_>, mut args: Search) -> Result<()> {
+    if let Err(content) = args.apply_ranges() {
+        return orig.error(content).await;
+    }
+
     let mut search_result = match args.request(Context::osu()).await {
         Ok(response) => response,
         Err(err) => {
@@ -632,6 +717,14 @@ async fn search(orig: CommandOrigin<'_>, args: Search) -> Result<()> {
         }
     };
 
+    if search_result.mapsets.is_empty() {
+        let content = "No mapsets found for the given query and filters. \
+            Try loosening some of them, e.g. widen the star, length, or BPM range \
+            or choose a different status.";
+
+        return orig.error(content).await;
+    }
+
     let maps: BTreeMap<usize, BeatmapsetExtended> =
         search_result.mapsets.drain(..).enumerate().collect();
 
@@ -21,7 +21,9 @@ use twilight_model::{
     id::{marker::UserMarker, Id},
 };
 
-use super::{map_strain_graph, require_link, user_not_found, ScoreOrder};
+use super::{
+    map_strain_graph, require_link, resolve_mode, user_not_found, GraphPalette, ScoreOrder,
+};
 use crate::{
     active::{
         impls::{SingleScoreContent, SingleScorePagination, TopPagination},
@@ -224,11 +226,13 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
         }
     };
 
-    let mode = args
-        .mode
-        .map(GameMode::from)
-        .or(config.mode)
-        .unwrap_or(GameMode::Osu);
+    let mode = resolve_mode(
+        orig.guild_id(),
+        orig.channel_id(),
+        args.mode.map(GameMode::from),
+        config.mode,
+    )
+    .await;
 
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
@@ -251,7 +255,7 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
         None => GuildValues::default(),
     };
 
-    let score_data = config.score_data.or(guild_score_data).unwrap_or_default();
+    let score_data = ScoreData::resolve(config.score_data, guild_score_data);
     let legacy_scores = score_data.is_legacy();
 
     let mapper = args.mapper.cow_to_ascii_lowercase();
@@ -302,6 +306,7 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
     with_render &= settings.buttons.render
         && mode == GameMode::Osu
         && orig.has_permission_to(Permissions::SEND_MESSAGES)
+        && !orig.is_ephemeral()
         && Context::ordr().is_some();
 
     let entries =
@@ -353,11 +358,7 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
 
     let sort_by = args.sort.unwrap_or(ScoreOrder::Pp).into();
 
-    let list_size = args
-        .size
-        .or(config.list_size)
-        .or(guild_list_size)
-        .unwrap_or_default();
+    let list_size = ListSize::resolve(args.size, config.list_size, guild_list_size);
 
     let entries = entries.into_boxed_slice();
 
@@ -375,6 +376,7 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
                         &entry.map.pp_map,
                         entry.score.mods.clone(),
                         entry.map.cover(),
+                        GraphPalette::default(),
                     );
 
                     match fut.await {
@@ -408,6 +410,7 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
         .sort_by(sort_by)
         .condensed_list(condensed_list)
         .score_data(score_data)
+        .pp_decimals(config.pp_decimals.unwrap_or(2))
         .content(content.into_boxed_str())
         .msg_owner(msg_owner)
         .build();
@@ -0,0 +1,306 @@
+use std::{borrow::Cow, cmp::Ordering};
+
+use bathbot_macros::{command, HasName, SlashCommand};
+use bathbot_psql::model::configs::ScoreData;
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    matcher,
+    osu::MapIdType,
+};
+use eyre::{Report, Result};
+use futures::{stream::FuturesOrdered, StreamExt};
+use rosu_v2::{prelude::OsuError, request::UserId};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    channel::Message,
+    id::{marker::UserMarker, Id},
+};
+
+use super::{require_link, user_not_found};
+use crate::{
+    active::{
+        impls::{MapsetScoresEntry, MapsetScoresPagination},
+        ActiveMessages,
+    },
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::redis::osu::UserArgs,
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "mapsetscores",
+    desc = "List a user's best score on each difficulty of a mapset",
+    help = "List a user's best score on each difficulty of a mapset.\n\
+    Difficulties on which the user has no score are shown as `—`.\n\
+    If no mapset is specified, I will choose the last map I can find in the channel's \
+    history and use its mapset."
+)]
+pub struct MapsetScores<'a> {
+    #[command(
+        desc = "Specify a mapset url or mapset id",
+        help = "Specify a mapset either by mapset url or mapset id.\n\
+        Map urls work too in which case the map's mapset will be used."
+    )]
+    mapset: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord: Option<Id<UserMarker>>,
+}
+
+#[derive(HasName)]
+struct MapsetScoresArgs<'a> {
+    mapset: Option<MapIdType>,
+    name: Option<Cow<'a, str>>,
+    discord: Option<Id<UserMarker>>,
+}
+
+impl<'m> MapsetScoresArgs<'m> {
+    fn args(args: Args<'m>) -> Self {
+        let mut mapset = None;
+        let mut name = None;
+        let mut discord = None;
+
+        for arg in args.take(2) {
+            if let Some(id) = matcher::get_osu_mapset_id(arg)
+                .map(MapIdType::Set)
+                .or_else(|| matcher::get_osu_map_id(arg).map(MapIdType::Map))
+            {
+                mapset = Some(id);
+            } else if let Some(id) = matcher::get_mention_user(arg) {
+                discord = Some(id);
+            } else {
+                name = Some(arg.into());
+            }
+        }
+
+        Self {
+            mapset,
+            name,
+            discord,
+        }
+    }
+}
+
+impl<'a> TryFrom<MapsetScores<'a>> for MapsetScoresArgs<'a> {
+    type Error = &'static str;
+
+    fn try_from(args: MapsetScores<'a>) -> Result<Self, Self::Error> {
+        let mapset = match args.mapset {
+            Some(arg) => {
+                let id = matcher::get_osu_mapset_id(&arg)
+                    .map(MapIdType::Set)
+                    .or_else(|| matcher::get_osu_map_id(&arg).map(MapIdType::Map))
+                    .ok_or(
+                        "Failed to parse mapset url. \
+                        Be sure you specify a valid mapset id or url to a mapset.",
+                    )?;
+
+                Some(id)
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            mapset,
+            name: args.name,
+            discord: args.discord,
+        })
+    }
+}
+
+#[command]
+#[desc("List a user's best score on each difficulty of a mapset")]
+#[help(
+    "List a user's best score on each difficulty of a mapset.\n\
+    Difficulties on which the user has no score are shown as `—`.\n\
+    If no mapset is specified, I will choose the last map I can find in the channel's \
+    history and use its mapset."
+)]
+#[usage("[username] [mapset url / mapset id]")]
+#[examples("badewanne3 902425", "https://osu.ppy.sh/beatmapsets/902425")]
+#[alias("mss", "setscores")]
+#[group(AllModes)]
+async fn prefix_mapsetscores(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = MapsetScoresArgs::args(args);
+
+    mapset_scores(msg.into(), args).await
+}
+
+async fn slash_mapsetscores(mut command: InteractionCommand) -> Result<()> {
+    let args = MapsetScores::from_interaction(command.input_data())?;
+
+    match MapsetScoresArgs::try_from(args) {
+        Ok(args) => mapset_scores((&mut command).into(), args).await,
+        Err(content) => {
+            command.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+async fn mapset_scores(orig: CommandOrigin<'_>, args: MapsetScoresArgs<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+
+    let config = match Context::user_config().with_osu_id(owner).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match config.osu {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
+        },
+    };
+
+    let map_id = match args.mapset {
+        Some(id) => id,
+        None => {
+            let msgs = match Context::retrieve_channel_history(orig.channel_id()).await {
+                Ok(msgs) => msgs,
+                Err(_) => {
+                    let content = "No mapset specified and lacking permission to search the \
+                        channel history for maps.\nTry specifying a mapset either by url or id, \
+                        or give me the \"Read Message History\" permission.";
+
+                    return orig.error(content).await;
+                }
+            };
+
+            match Context::find_map_id_in_msgs(&msgs, 0).await {
+                Some(id) => id,
+                None => {
+                    let content = "No mapset specified and none found in recent channel history. \
+                        Try specifying a mapset either by url or id.";
+
+                    return orig.error(content).await;
+                }
+            }
+        }
+    };
+
+    let mapset_res = match map_id {
+        MapIdType::Map(id) => Context::osu().beatmapset_from_map_id(id).await,
+        MapIdType::Set(id) => Context::osu().beatmapset(id).await,
+    };
+
+    let mut mapset = match mapset_res {
+        Ok(mapset) => mapset,
+        Err(OsuError::NotFound) => {
+            let content = match map_id {
+                MapIdType::Map(id) => format!("Beatmapset of map {id} was not found"),
+                MapIdType::Set(id) => format!("Beatmapset with id {id} was not found"),
+            };
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get mapset"));
+        }
+    };
+
+    let mapset_clone = mapset.clone();
+    tokio::spawn(async move { Context::osu_map().store(&mapset_clone).await });
+
+    let Some(mut maps) = mapset.maps.take().filter(|maps| !maps.is_empty()) else {
+        return orig.error("The mapset has no maps").await;
+    };
+
+    maps.sort_unstable_by(|m1, m2| {
+        m1.mode
+            .cmp(&m2.mode)
+            .then_with(|| m1.stars.partial_cmp(&m2.stars).unwrap_or(Ordering::Equal))
+    });
+
+    let user_args = UserArgs::rosu_id(&user_id, maps[0].mode).await;
+
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(OsuError::NotFound) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let legacy_scores = match config.score_data {
+        Some(score_data) => score_data.is_legacy(),
+        None => match orig.guild_id() {
+            Some(guild_id) => Context::guild_config()
+                .peek(guild_id, |config| config.score_data)
+                .await
+                .map_or(false, ScoreData::is_legacy),
+            None => false,
+        },
+    };
+
+    let osu_id = user.user_id();
+
+    let mut score_futs: FuturesOrdered<_> = maps
+        .iter()
+        .map(|map| {
+            Context::osu_scores().user_on_map_single(
+                osu_id,
+                map.map_id,
+                map.mode,
+                None,
+                legacy_scores,
+            )
+        })
+        .collect();
+
+    let mut entries = Vec::with_capacity(maps.len());
+    let mut maps = maps.into_iter();
+
+    while let Some(score_res) = score_futs.next().await {
+        let Some(map) = maps.next() else { break };
+
+        let score = match score_res {
+            Ok(score) => Some(score.score),
+            // No score on this difficulty
+            Err(OsuError::NotFound) => None,
+            Err(err) => {
+                let _ = orig.error(OSU_API_ISSUE).await;
+                let err = Report::new(err).wrap_err("Failed to get user score on map");
+
+                return Err(err);
+            }
+        };
+
+        entries.push(MapsetScoresEntry { map, score });
+    }
+
+    let pagination = MapsetScoresPagination::builder()
+        .user(user)
+        .mapset(mapset)
+        .entries(entries.into_boxed_slice())
+        .msg_owner(owner)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
+}
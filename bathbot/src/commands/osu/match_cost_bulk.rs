@@ -0,0 +1,240 @@
+use std::{borrow::Cow, collections::HashMap, fmt::Write};
+
+use bathbot_macros::{command, SlashCommand};
+use bathbot_util::{fields, matcher, EmbedBuilder, IntHasher, MessageBuilder};
+use eyre::{Report, Result};
+use rosu_v2::prelude::OsuError;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use super::{prepare_games, process_match, retrieve_previous, MatchCostFormula};
+use crate::{
+    core::commands::{prefix::Args, CommandOrigin},
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+/// Maximum amount of matches that can be processed at once
+const MAX_MATCHES: usize = 20;
+
+/// Maximum amount of players that are listed
+const MAX_PLAYERS: usize = 50;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "bulkmatchcost",
+    desc = "Average the performance ratings of players over multiple matches",
+    help = "Calculate the performance rating of each player for multiple multiplayer matches \
+    and average them per player, e.g. to evaluate the players of a tournament stage.\n\
+    Matches that can't be retrieved or have no games are skipped and listed separately.\n\
+    Uses the same formula as the `matchcost` command."
+)]
+pub struct BulkMatchCost<'a> {
+    #[command(desc = "Specify match urls or match ids, separated by spaces")]
+    matches: Cow<'a, str>,
+    #[command(
+        min_value = 0,
+        desc = "Specify the amount of warmups to ignore per match (defaults to 0)"
+    )]
+    warmups: Option<usize>,
+    #[command(desc = "How each score's performance should be rated (defaults to score)")]
+    formula: Option<MatchCostFormula>,
+}
+
+#[command]
+#[desc("Average the performance ratings of players over multiple matches")]
+#[help(
+    "Calculate the performance rating of each player for multiple multiplayer matches \
+    and average them per player.\n\
+    Matches that can't be retrieved or have no games are skipped and listed separately.\n\
+    To ignore warmups, use the slash command `/bulkmatchcost`."
+)]
+#[usage("[match url / match id] [match url / match id] ...")]
+#[example("58320988 58320989 https://osu.ppy.sh/community/matches/58320990")]
+#[aliases("bmc", "bulkmc")]
+#[group(AllModes)]
+async fn prefix_bulkmatchcost(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = BulkMatchCost {
+        matches: args.rest().into(),
+        warmups: None,
+        formula: None,
+    };
+
+    bulk_matchcost(msg.into(), args).await
+}
+
+async fn slash_bulkmatchcost(mut command: InteractionCommand) -> Result<()> {
+    let args = BulkMatchCost::from_interaction(command.input_data())?;
+
+    bulk_matchcost((&mut command).into(), args).await
+}
+
+async fn bulk_matchcost(orig: CommandOrigin<'_>, args: BulkMatchCost<'_>) -> Result<()> {
+    let BulkMatchCost {
+        matches,
+        warmups,
+        formula,
+    } = args;
+
+    let mut match_ids = Vec::new();
+    let mut skipped = Vec::new();
+
+    for arg in matches.split(|c: char| c.is_whitespace() || c == ',') {
+        if arg.is_empty() {
+            continue;
+        }
+
+        match matcher::get_osu_match_id(arg) {
+            Some(match_id) if !match_ids.contains(&match_id) => match_ids.push(match_id),
+            Some(_) => {}
+            None => skipped.push(format!("`{arg}`: invalid match url or id")),
+        }
+    }
+
+    if match_ids.is_empty() {
+        let content = "Failed to parse any match urls.\n\
+            Be sure to specify valid mp urls or match ids, separated by spaces.";
+
+        return orig.error(content).await;
+    } else if match_ids.len() > MAX_MATCHES {
+        let content = format!("Can only process up to {MAX_MATCHES} matches at once");
+
+        return orig.error(content).await;
+    }
+
+    let warmups = warmups.unwrap_or(0);
+    let formula = formula.unwrap_or_default();
+    let osu = Context::osu();
+
+    let mut players: HashMap<u32, BulkEntry, IntHasher> = HashMap::default();
+    let mut processed = 0;
+
+    for match_id in match_ids {
+        let mut osu_match = match osu.osu_match(match_id).await {
+            Ok(osu_match) => osu_match,
+            Err(OsuError::NotFound) => {
+                skipped.push(format!("`{match_id}`: match not found"));
+
+                continue;
+            }
+            Err(OsuError::Response { status, .. }) if status == 401 => {
+                skipped.push(format!("`{match_id}`: match is private"));
+
+                continue;
+            }
+            Err(err) => {
+                let err = Report::new(err).wrap_err("Failed to get match");
+                warn!(match_id, ?err, "Failed to get match for bulk matchcost");
+                skipped.push(format!("`{match_id}`: failed to retrieve match"));
+
+                continue;
+            }
+        };
+
+        if let Err(err) = retrieve_previous(&mut osu_match, osu).await {
+            let err = Report::new(err).wrap_err("Failed to get previous match events");
+            warn!(
+                match_id,
+                ?err,
+                "Failed to get match events for bulk matchcost"
+            );
+            skipped.push(format!("`{match_id}`: failed to retrieve match"));
+
+            continue;
+        }
+
+        let games = prepare_games(&mut osu_match, warmups, 0, 1.0);
+
+        if games.is_empty() {
+            skipped.push(format!("`{match_id}`: no games beyond the warmups"));
+
+            continue;
+        }
+
+        let result = process_match(
+            &games,
+            osu_match.end_time.is_some(),
+            &osu_match.users,
+            formula,
+        );
+
+        for player in result.players() {
+            let entry = players.entry(player.user_id).or_insert_with(|| BulkEntry {
+                username: osu_match.users.get(&player.user_id).map_or_else(
+                    || player.user_id.to_string(),
+                    |user| user.username.to_string(),
+                ),
+                match_cost_sum: 0.0,
+                match_count: 0,
+            });
+
+            entry.match_cost_sum += player.match_cost;
+            entry.match_count += 1;
+        }
+
+        processed += 1;
+    }
+
+    if processed == 0 {
+        let mut content = "None of the matches could be processed:\n".to_owned();
+
+        for skipped in skipped.iter() {
+            let _ = writeln!(content, "- {skipped}");
+        }
+
+        return orig.error(content).await;
+    }
+
+    let mut players: Vec<_> = players.into_values().collect();
+    players.sort_unstable_by(|a, b| b.avg().total_cmp(&a.avg()));
+
+    let mut description = String::with_capacity(players.len().min(MAX_PLAYERS) * 50);
+
+    for (i, player) in players.iter().take(MAX_PLAYERS).enumerate() {
+        let _ = writeln!(
+            description,
+            "**{idx}** `{name}`: **{avg:.2}** ({count} match{plural})",
+            idx = i + 1,
+            name = player.username,
+            avg = player.avg(),
+            count = player.match_count,
+            plural = if player.match_count == 1 { "" } else { "es" },
+        );
+    }
+
+    if let Some(remaining) = players.len().checked_sub(MAX_PLAYERS).filter(|&n| n > 0) {
+        let _ = writeln!(description, "...and {remaining} more");
+    }
+
+    let mut embed = EmbedBuilder::new().description(description).title(format!(
+        "Average match costs over {processed} match{}",
+        if processed == 1 { "" } else { "es" }
+    ));
+
+    if !skipped.is_empty() {
+        let mut value = String::new();
+
+        for skipped in skipped.iter() {
+            let _ = writeln!(value, "- {skipped}");
+        }
+
+        let name = format!("Skipped {} match(es)", skipped.len());
+        embed = embed.fields(fields![name, value, false]);
+    }
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+struct BulkEntry {
+    username: String,
+    match_cost_sum: f32,
+    match_count: usize,
+}
+
+impl BulkEntry {
+    fn avg(&self) -> f32 {
+        self.match_cost_sum / self.match_count as f32
+    }
+}
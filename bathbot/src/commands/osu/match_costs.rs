@@ -6,14 +6,22 @@ use std::{
 };
 
 use bathbot_macros::{command, SlashCommand};
+use bathbot_model::command_fields::ShowHideOption;
 use bathbot_util::{constants::OSU_API_ISSUE, matcher, IntHasher};
-use eyre::{Report, Result};
+use eyre::{Report, Result, WrapErr};
+use plotters::prelude::{
+    ChartBuilder, Circle, Color, FontStyle, HSLColor, IntoDrawingArea, RGBColor, Rectangle,
+    SeriesLabelPosition, WHITE,
+};
+use plotters_skia::SkiaBackend;
 use rosu_v2::{
     model::mods::GameModsIntermode,
     prelude::{
-        GameModIntermode, MatchGame, Osu, OsuError, OsuMatch, OsuResult, Team, TeamType, User,
+        GameModIntermode, MatchGame, MatchScore, Osu, OsuError, OsuMatch, OsuResult, Team,
+        TeamType, User,
     },
 };
+use skia_safe::{surfaces, EncodedImageFormat};
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 
 use crate::{
@@ -63,6 +71,28 @@ pub struct MatchCost<'a> {
     skip_last: Option<usize>,
     #[command(desc = "How the data should be displayed")]
     display: Option<MatchCostDisplay>,
+    #[command(
+        desc = "Should the MVP's avatar be shown? (defaults to show)",
+        help = "Whether the avatar of the player with the highest rating should be shown \
+        as thumbnail in the compact display.\n\
+        Hiding it makes for a cleaner embed e.g. when posting tournament results."
+    )]
+    mvp_avatar: Option<ShowHideOption>,
+    #[command(
+        desc = "How each score's performance should be rated (defaults to score)",
+        help = "How each score's performance should be rated.\n\
+        `Score` compares each score to the average score of its map.\n\
+        `Accuracy weighted` additionally compares each score's accuracy to the average \
+        accuracy of its map and weighs both ratios equally. \
+        This is meant for accuracy-based tournaments."
+    )]
+    formula: Option<MatchCostFormula>,
+    #[command(
+        desc = "Attach a graph of each player's performance per game",
+        help = "Attach a bar chart that shows the performance cost of each player per game.\n\
+        Only the eight players with the highest rating are included."
+    )]
+    graph: Option<bool>,
 }
 
 #[derive(Copy, Clone, CommandOption, CreateOption, Default)]
@@ -74,6 +104,35 @@ pub enum MatchCostDisplay {
     Full,
 }
 
+#[derive(Copy, Clone, CommandOption, CreateOption, Default)]
+pub enum MatchCostFormula {
+    #[default]
+    #[option(name = "Score", value = "score")]
+    Score,
+    #[option(name = "Accuracy weighted", value = "acc")]
+    Accuracy,
+}
+
+impl MatchCostFormula {
+    /// Performance of a score relative to the averages of its game
+    fn performance_cost(self, score: &MatchScore, score_avg: f32, acc_avg: f32) -> f32 {
+        let score_ratio = score.score as f32 / score_avg;
+
+        match self {
+            Self::Score => score_ratio,
+            Self::Accuracy => {
+                let acc_ratio = if acc_avg > 0.0 {
+                    score.accuracy / acc_avg
+                } else {
+                    1.0
+                };
+
+                (1.0 - ACC_WEIGHT) * score_ratio + ACC_WEIGHT * acc_ratio
+            }
+        }
+    }
+}
+
 impl<'m> MatchCost<'m> {
     fn args(mut args: Args<'m>) -> Result<Self, &'static str> {
         let match_url = match args.next() {
@@ -97,6 +156,9 @@ impl<'m> MatchCost<'m> {
             skip_last: None,
             ez_mult: None,
             display: None,
+            mvp_avatar: None,
+            formula: None,
+            graph: None,
         })
     }
 }
@@ -139,6 +201,9 @@ async fn matchcosts(orig: CommandOrigin<'_>, args: MatchCost<'_>) -> Result<()>
         skip_last,
         ez_mult,
         display,
+        mvp_avatar,
+        formula,
+        graph,
     } = args;
 
     let Some(match_id) = matcher::get_osu_match_id(&match_url) else {
@@ -149,6 +214,7 @@ async fn matchcosts(orig: CommandOrigin<'_>, args: MatchCost<'_>) -> Result<()>
     };
 
     let warmups = warmups.unwrap_or(0);
+    let formula = formula.unwrap_or_default();
     let ez_mult = ez_mult.unwrap_or(1.0);
     let skip_last = skip_last.unwrap_or(0);
     let osu = Context::osu();
@@ -157,36 +223,7 @@ async fn matchcosts(orig: CommandOrigin<'_>, args: MatchCost<'_>) -> Result<()>
     let (osu_match, games) = match osu.osu_match(match_id).await {
         Ok(mut osu_match) => {
             retrieve_previous(&mut osu_match, osu).await?;
-
-            let games_iter = osu_match
-                .drain_games()
-                .filter(|game| game.end_time.is_some())
-                .skip(warmups)
-                .map(|mut game| {
-                    game.scores.retain(|score| score.score > 0);
-
-                    game
-                });
-
-            let mut games: Vec<_> = if ez_mult != 1.0 {
-                games_iter
-                    .map(|mut game| {
-                        game.scores.iter_mut().for_each(|score| {
-                            if score.mods.contains(GameModIntermode::Easy) {
-                                score.score = (score.score as f32 * ez_mult) as u32;
-                            }
-                        });
-
-                        game
-                    })
-                    .collect()
-            } else {
-                games_iter.collect()
-            };
-
-            if skip_last > 0 {
-                games.truncate(games.len() - skip_last);
-            }
+            let games = prepare_games(&mut osu_match, warmups, skip_last, ez_mult);
 
             (osu_match, games)
         }
@@ -217,7 +254,25 @@ async fn matchcosts(orig: CommandOrigin<'_>, args: MatchCost<'_>) -> Result<()>
 
         MatchResult::NoGames { description }
     } else {
-        process_match(&games, osu_match.end_time.is_some(), &osu_match.users)
+        process_match(
+            &games,
+            osu_match.end_time.is_some(),
+            &osu_match.users,
+            formula,
+        )
+    };
+
+    let graph = if graph == Some(true) {
+        match match_graph(&match_result, &osu_match.users) {
+            Ok(graph) => graph.map(|bytes| (MatchCostPagination::IMAGE_NAME.to_owned(), bytes)),
+            Err(err) => {
+                warn!(?err, "Failed to create matchcost graph");
+
+                None
+            }
+        }
+    } else {
+        None
     };
 
     let mut content = String::new();
@@ -248,16 +303,61 @@ async fn matchcosts(orig: CommandOrigin<'_>, args: MatchCost<'_>) -> Result<()>
         .osu_match(osu_match)
         .content(content.into_boxed_str())
         .display(display.unwrap_or_default())
+        .hide_mvp_avatar(mvp_avatar == Some(ShowHideOption::Hide))
+        .formula(formula)
+        .graph(graph.is_some())
         .msg_owner(owner)
         .result(match_result)
         .build();
 
     ActiveMessages::builder(pagination)
         .start_by_update(true)
+        .attachment(graph)
         .begin(orig)
         .await
 }
 
+/// Take the finished games of the match while ignoring warmups and the last
+/// `skip_last` games, and apply the EZ multiplier.
+pub fn prepare_games(
+    osu_match: &mut OsuMatch,
+    warmups: usize,
+    skip_last: usize,
+    ez_mult: f32,
+) -> Vec<MatchGame> {
+    let games_iter = osu_match
+        .drain_games()
+        .filter(|game| game.end_time.is_some())
+        .skip(warmups)
+        .map(|mut game| {
+            game.scores.retain(|score| score.score > 0);
+
+            game
+        });
+
+    let mut games: Vec<_> = if ez_mult != 1.0 {
+        games_iter
+            .map(|mut game| {
+                game.scores.iter_mut().for_each(|score| {
+                    if score.mods.contains(GameModIntermode::Easy) {
+                        score.score = (score.score as f32 * ez_mult) as u32;
+                    }
+                });
+
+                game
+            })
+            .collect()
+    } else {
+        games_iter.collect()
+    };
+
+    if skip_last > 0 {
+        games.truncate(games.len().saturating_sub(skip_last));
+    }
+
+    games
+}
+
 pub async fn retrieve_previous(osu_match: &mut OsuMatch, osu: &Osu) -> OsuResult<()> {
     let mut curr = &*osu_match;
     let mut prev: Option<OsuMatch> = None;
@@ -308,26 +408,34 @@ const TIEBREAKER_FACTOR: f32 = 0.25;
 // any tiebreaker performance cost >=2 gets the same bonus
 const MAX_TIEBREAKER_BONUS: f32 = 0.5;
 
+// share of the accuracy ratio in the accuracy weighted performance cost
+const ACC_WEIGHT: f32 = 0.5;
+
 pub fn process_match(
     games: &[MatchGame],
     finished: bool,
     users: &HashMap<u32, User>,
+    formula: MatchCostFormula,
 ) -> MatchResult {
     let mut users_mods = UsersMods::default();
     let mut users_performance_costs = UsersPerformanceCosts::default();
     let mut users_team = UsersTeam::default();
     let mut teams_win_count = TeamsWinCount::default();
 
-    for game in games.iter() {
+    for (game_idx, game) in games.iter().enumerate() {
         let score_sum = game.scores.iter().fold(0, |sum, score| sum + score.score);
         let score_count = game.scores.len();
         let score_avg = score_sum as f32 / score_count as f32;
 
+        let acc_sum = game.scores.iter().map(|score| score.accuracy).sum::<f32>();
+        let acc_avg = acc_sum / score_count as f32;
+
         let mut teams_score = TeamsScore::default();
 
         for score in game.scores.iter() {
             users_mods.update(score.user_id, score.mods.clone());
-            users_performance_costs.update(score.user_id, score.score, score_avg);
+            let performance_cost = formula.performance_cost(score, score_avg, acc_avg);
+            users_performance_costs.update(score.user_id, game_idx, score.score, performance_cost);
             users_team.update(score.user_id, score.team);
             teams_score.update(score.team, score.score);
         }
@@ -371,6 +479,7 @@ pub fn process_match(
                 tiebreaker_bonus: entry.tiebreaker_bonus,
                 match_cost: entry.match_cost(),
                 avg_score: entry.avg_score,
+                game_costs: entry.game_costs.clone(),
             };
 
             match team {
@@ -399,6 +508,7 @@ pub fn process_match(
                 tiebreaker_bonus: entry.tiebreaker_bonus,
                 match_cost: entry.match_cost(),
                 avg_score: entry.avg_score,
+                game_costs: entry.game_costs.clone(),
             })
             .collect();
 
@@ -437,10 +547,11 @@ struct UsersPerformanceCosts {
 }
 
 impl UsersPerformanceCosts {
-    fn update(&mut self, user_id: u32, score: u32, score_avg: f32) {
+    fn update(&mut self, user_id: u32, game_idx: usize, score: u32, performance_cost: f32) {
         let performance_cost = PerformanceCost {
+            game_idx,
             score,
-            performance_cost: score as f32 / score_avg,
+            performance_cost,
         };
 
         self.entries
@@ -500,12 +611,21 @@ impl UsersPerformanceCosts {
                 mods_bonus_factor += MOD_BONUS * (mods_used - 2) as f32;
             }
 
+            let mut game_costs = vec![None; games_count].into_boxed_slice();
+
+            for entry in entries.iter() {
+                if let Some(game_cost) = game_costs.get_mut(entry.game_idx) {
+                    *game_cost = Some(entry.performance_cost);
+                }
+            }
+
             let entry = MatchCostEntry {
                 performance_cost,
                 participation_bonus_factor,
                 mods_bonus_factor,
                 tiebreaker_bonus,
                 avg_score,
+                game_costs,
             };
 
             match_costs.insert(*user_id, entry);
@@ -516,6 +636,7 @@ impl UsersPerformanceCosts {
 }
 
 struct PerformanceCost {
+    game_idx: usize,
     score: u32,
     performance_cost: f32,
 }
@@ -589,6 +710,7 @@ struct MatchCostEntry {
     mods_bonus_factor: f32,
     tiebreaker_bonus: f32,
     avg_score: u32,
+    game_costs: Box<[Option<f32>]>,
 }
 
 impl MatchCostEntry {
@@ -606,6 +728,8 @@ pub struct UserMatchCostEntry {
     pub tiebreaker_bonus: f32,
     pub match_cost: f32,
     pub avg_score: u32,
+    /// Performance cost for each game, `None` if the user didn't play it
+    pub game_costs: Box<[Option<f32>]>,
 }
 
 impl UserMatchCostEntry {
@@ -628,6 +752,132 @@ impl TeamResult {
     }
 }
 
+/// Maximum amount of players included in the graph
+const GRAPH_MAX_PLAYERS: usize = 8;
+
+/// Draw a grouped bar chart of the performance cost of the top players for
+/// each game.
+fn match_graph(result: &MatchResult, users: &HashMap<u32, User>) -> Result<Option<Vec<u8>>> {
+    const W: u32 = 1000;
+    const H: u32 = 450;
+
+    let mut players: Vec<_> = result.players().collect();
+
+    let total_players = players.len();
+    players.sort_unstable_by(|a, b| b.match_cost.total_cmp(&a.match_cost));
+    players.truncate(GRAPH_MAX_PLAYERS);
+
+    let Some(games_count) = players
+        .first()
+        .map(|player| player.game_costs.len())
+        .filter(|&count| count > 0)
+    else {
+        return Ok(None);
+    };
+
+    let max = players
+        .iter()
+        .flat_map(|player| player.game_costs.iter().flatten())
+        .fold(1.0_f32, |max, &cost| max.max(cost));
+
+    let caption = if total_players > GRAPH_MAX_PLAYERS {
+        Cow::Owned(format!(
+            "Performance per game (top {GRAPH_MAX_PLAYERS} players)"
+        ))
+    } else {
+        Cow::Borrowed("Performance per game")
+    };
+
+    let mut surface =
+        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
+
+    {
+        let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
+
+        let background = RGBColor(19, 43, 33);
+        root.fill(&background)
+            .wrap_err("Failed to fill background")?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .x_label_area_size(40)
+            .y_label_area_size(45)
+            .margin(10)
+            .caption(caption, ("sans-serif", 25, &WHITE))
+            .build_cartesian_2d(-0.5..games_count as f64 - 0.5, 0.0..max as f64 * 1.1)
+            .map_err(|e| Report::msg(e.to_string()))
+            .wrap_err("Failed to build chart")?;
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .x_labels(games_count)
+            .x_label_formatter(&|game| {
+                if game.fract() == 0.0 {
+                    (*game as usize + 1).to_string()
+                } else {
+                    String::new()
+                }
+            })
+            .x_desc("Game")
+            .y_desc("Performance")
+            .label_style(("sans-serif", 15, &WHITE))
+            .bold_line_style(WHITE.mix(0.3))
+            .axis_style(RGBColor(7, 18, 14))
+            .axis_desc_style(("sans-serif", 20_i32, FontStyle::Bold, &WHITE))
+            .draw()
+            .map_err(|e| Report::msg(e.to_string()))
+            .wrap_err("Failed to draw mesh")?;
+
+        let bar_width = 0.8 / players.len() as f64;
+
+        for (i, player) in players.iter().enumerate() {
+            let color = HSLColor(i as f64 / players.len() as f64, 0.5, 0.5);
+
+            let bars = player
+                .game_costs
+                .iter()
+                .enumerate()
+                .filter_map(|(game, cost)| {
+                    let left = game as f64 - 0.4 + i as f64 * bar_width;
+                    let top_left = (left, (*cost)? as f64);
+                    let bot_right = (left + bar_width, 0.0);
+
+                    Some(Rectangle::new([top_left, bot_right], color.filled()))
+                });
+
+            let name = users
+                .get(&player.user_id)
+                .map_or("<unknown name>", |user| user.username.as_str());
+
+            chart
+                .draw_series(bars)
+                .map_err(|e| Report::msg(e.to_string()))
+                .wrap_err("Failed to draw bars")?
+                .label(name)
+                .legend(move |(x, y)| Circle::new((x, y), 4, color.filled()));
+        }
+
+        chart
+            .configure_series_labels()
+            .border_style(WHITE.mix(0.6).stroke_width(1))
+            .background_style(RGBColor(7, 23, 17))
+            .position(SeriesLabelPosition::UpperRight)
+            .legend_area_size(13)
+            .label_font(("sans-serif", 15, FontStyle::Bold, &WHITE))
+            .draw()
+            .map_err(|e| Report::msg(e.to_string()))
+            .wrap_err("Failed to draw legend")?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(Some(png_bytes))
+}
+
 pub enum MatchResult {
     TeamVS {
         blue: TeamResult,
@@ -642,3 +892,16 @@ pub enum MatchResult {
         description: String,
     },
 }
+
+impl MatchResult {
+    /// All players of the match regardless of their team
+    pub fn players(&self) -> impl Iterator<Item = &UserMatchCostEntry> {
+        let (first, second): (&[_], &[_]) = match self {
+            Self::TeamVS { blue, red, .. } => (&blue.players, &red.players),
+            Self::HeadToHead { players, .. } => (players, &[]),
+            Self::NoGames { .. } => (&[], &[]),
+        };
+
+        first.iter().chain(second)
+    }
+}
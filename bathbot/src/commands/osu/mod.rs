@@ -23,9 +23,9 @@ macro_rules! user_id {
 }
 
 /// Tries to extract the username and mode from args.
-/// If either fails, it checks the user config.
+/// If the username is missing, it checks the user config.
 /// If the osu user is still not found, return the linking error.
-/// If the mode is still not found, pick GameMode::Osu.
+/// If the mode is missing, it is resolved through [`resolve_mode`].
 ///
 /// Only use this when the user config is not needed otherwise,
 /// else you'll have to query multiple times from the DB.
@@ -37,10 +37,17 @@ macro_rules! user_id_mode {
             if let Some(mode) = mode {
                 (user_id, mode)
             } else {
-                let mode = crate::core::Context::user_config()
+                let config_mode = crate::core::Context::user_config()
                     .mode($orig.user_id()?)
-                    .await?
-                    .unwrap_or(rosu_v2::prelude::GameMode::Osu);
+                    .await?;
+
+                let mode = crate::commands::osu::resolve_mode(
+                    $orig.guild_id(),
+                    $orig.channel_id(),
+                    None,
+                    config_mode,
+                )
+                .await;
 
                 (user_id, mode)
             }
@@ -49,9 +56,13 @@ macro_rules! user_id_mode {
                 .with_osu_id($orig.user_id()?)
                 .await?;
 
-            let mode = mode
-                .or(config.mode)
-                .unwrap_or(rosu_v2::prelude::GameMode::Osu);
+            let mode = crate::commands::osu::resolve_mode(
+                $orig.guild_id(),
+                $orig.channel_id(),
+                mode,
+                config.mode,
+            )
+            .await;
 
             match config.osu {
                 Some(user_id) => (rosu_v2::request::UserId::Id(user_id), mode),
@@ -65,9 +76,12 @@ use std::{future::Future, pin::Pin};
 
 use bathbot_util::osu::ModSelection;
 use eyre::{Report, Result, WrapErr};
-use rosu_v2::request::UserId;
+use rosu_v2::{prelude::GameMode, request::UserId};
 use twilight_interactions::command::{CommandOption, CreateOption};
-use twilight_model::id::{marker::UserMarker, Id};
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+    Id,
+};
 
 pub use self::{
     badges::*, claim_name::*, compare::*, fix::*, graphs::*, leaderboard::*, map::*, map_search::*,
@@ -75,45 +89,65 @@ pub use self::{
     region_top::*, render::*, scores::*, simulate::*, snipe::*, top::*, whatif::*,
 };
 use crate::{
-    core::commands::{interaction::InteractionCommands, CommandOrigin},
+    core::commands::{checks::thread_parent, interaction::InteractionCommands, CommandOrigin},
     Context,
 };
 
 mod attributes;
 mod avatar;
+mod avatar_grid;
 mod badges;
 mod bookmarks;
 mod bws;
 mod cards;
 mod claim_name;
 mod compare;
+mod difficulty;
+mod farm;
 mod fix;
 mod graphs;
 mod leaderboard;
+mod link_profile;
 mod map;
+mod map_compare;
+mod map_request;
 mod map_search;
 mod mapper;
+mod mapset_scores;
 mod match_compare;
+mod match_cost_bulk;
 mod match_costs;
 mod medals;
+mod mode_stats;
 mod most_played;
 mod nochoke;
 mod osekai;
 mod osustats;
 mod pinned;
+mod play_rank;
 mod pp;
+mod pp_table;
+mod pp_weight;
 mod profile;
+mod profile_badges;
+mod random_map;
 mod rank;
 mod ranking;
 mod ratios;
 mod recent;
 mod region_top;
 mod render;
+mod rework_pp;
 mod scores;
 mod serverleaderboard;
 mod simulate;
 mod snipe;
+mod target_rank;
 mod top;
+mod top_monthly;
+mod top_stars;
+mod top_week;
+mod unlink;
 mod whatif;
 
 #[cfg(feature = "server")]
@@ -182,6 +216,68 @@ pub async fn user_not_found(user_id: UserId) -> String {
     }
 }
 
+/// Resolve the mode a command should use.
+///
+/// The specified mode takes precedence over the mode configured for the
+/// channel, followed by the user's configured mode, the guild's configured
+/// mode, and lastly osu!standard.
+pub async fn resolve_mode(
+    guild_id: Option<Id<GuildMarker>>,
+    channel_id: Id<ChannelMarker>,
+    arg_mode: Option<GameMode>,
+    config_mode: Option<GameMode>,
+) -> GameMode {
+    let (channel_mode, guild_mode) = match guild_id {
+        Some(guild_id) if arg_mode.is_none() => guild_modes(guild_id, channel_id).await,
+        _ => (None, None),
+    };
+
+    mode_precedence(arg_mode, channel_mode, config_mode, guild_mode)
+}
+
+/// The mode configured for the channel and the guild's mode.
+///
+/// Threads without their own mode use the mode of their parent channel.
+async fn guild_modes(
+    guild_id: Id<GuildMarker>,
+    channel_id: Id<ChannelMarker>,
+) -> (Option<GameMode>, Option<GameMode>) {
+    let (channel_mode, guild_mode, has_channel_modes) = Context::guild_config()
+        .peek(guild_id, |config| {
+            let channel_mode = config.channel_modes.get(channel_id);
+
+            (channel_mode, config.mode, !config.channel_modes.is_empty())
+        })
+        .await;
+
+    if channel_mode.is_some() || !has_channel_modes {
+        return (channel_mode, guild_mode);
+    }
+
+    let Some(parent_id) = thread_parent(guild_id, channel_id).await else {
+        return (None, guild_mode);
+    };
+
+    let channel_mode = Context::guild_config()
+        .peek(guild_id, |config| config.channel_modes.get(parent_id))
+        .await;
+
+    (channel_mode, guild_mode)
+}
+
+fn mode_precedence(
+    arg_mode: Option<GameMode>,
+    channel_mode: Option<GameMode>,
+    config_mode: Option<GameMode>,
+    guild_mode: Option<GameMode>,
+) -> GameMode {
+    arg_mode
+        .or(channel_mode)
+        .or(config_mode)
+        .or(guild_mode)
+        .unwrap_or(GameMode::Osu)
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, CommandOption, CreateOption)]
 pub enum ScoreOrder {
     #[option(name = "Accuracy", value = "acc")]
@@ -219,3 +315,53 @@ enum UserExtraction {
     Content(String),
     None,
 }
+
+#[cfg(test)]
+mod tests {
+    use rosu_v2::prelude::GameMode;
+
+    use super::mode_precedence;
+
+    #[test]
+    fn mode_from_arg() {
+        let mode = mode_precedence(
+            Some(GameMode::Taiko),
+            Some(GameMode::Osu),
+            Some(GameMode::Catch),
+            Some(GameMode::Mania),
+        );
+
+        assert_eq!(mode, GameMode::Taiko);
+    }
+
+    #[test]
+    fn mode_from_channel() {
+        let mode = mode_precedence(
+            None,
+            Some(GameMode::Taiko),
+            Some(GameMode::Catch),
+            Some(GameMode::Mania),
+        );
+
+        assert_eq!(mode, GameMode::Taiko);
+    }
+
+    #[test]
+    fn mode_from_user_config() {
+        let mode = mode_precedence(None, None, Some(GameMode::Catch), Some(GameMode::Mania));
+
+        assert_eq!(mode, GameMode::Catch);
+    }
+
+    #[test]
+    fn mode_from_guild_config() {
+        let mode = mode_precedence(None, None, None, Some(GameMode::Mania));
+
+        assert_eq!(mode, GameMode::Mania);
+    }
+
+    #[test]
+    fn mode_default() {
+        assert_eq!(mode_precedence(None, None, None, None), GameMode::Osu);
+    }
+}
@@ -0,0 +1,274 @@
+use std::{borrow::Cow, fmt::Write};
+
+use bathbot_macros::{command, HasName, SlashCommand};
+use bathbot_model::rosu_v2::user::User;
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    matcher,
+    numbers::WithComma,
+    EmbedBuilder, FooterBuilder, MessageBuilder,
+};
+use eyre::{Report, Result};
+use rosu_v2::{
+    prelude::{GameMode, OsuError},
+    request::UserId,
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    channel::Message,
+    id::{marker::UserMarker, Id},
+};
+
+use super::{require_link, user_not_found};
+use crate::{
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::redis::{osu::UserArgs, RedisData},
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+const MODES: [GameMode; 4] = [
+    GameMode::Osu,
+    GameMode::Taiko,
+    GameMode::Catch,
+    GameMode::Mania,
+];
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "modestats",
+    desc = "Compare a user's pp and playcount across all modes",
+    help = "Compare a user's pp and playcount across all modes.\n\
+    The pp per play of each mode show in which mode the user is the most efficient."
+)]
+pub struct ModeStats<'a> {
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord: Option<Id<UserMarker>>,
+}
+
+impl<'m> ModeStats<'m> {
+    fn args(mut args: Args<'m>) -> Self {
+        let mut name = None;
+        let mut discord = None;
+
+        if let Some(arg) = args.next() {
+            match matcher::get_mention_user(arg) {
+                Some(id) => discord = Some(id),
+                None => name = Some(arg.into()),
+            }
+        }
+
+        Self { name, discord }
+    }
+}
+
+#[command]
+#[desc("Compare a user's pp and playcount across all modes")]
+#[help(
+    "Compare a user's pp and playcount across all modes.\n\
+    The pp per play of each mode show in which mode the user is the most efficient."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[alias("ppperplay", "ppp")]
+#[group(AllModes)]
+async fn prefix_modestats(msg: &Message, args: Args<'_>) -> Result<()> {
+    mode_stats(msg.into(), ModeStats::args(args)).await
+}
+
+async fn slash_modestats(mut command: InteractionCommand) -> Result<()> {
+    let args = ModeStats::from_interaction(command.input_data())?;
+
+    mode_stats((&mut command).into(), args).await
+}
+
+async fn mode_stats(orig: CommandOrigin<'_>, args: ModeStats<'_>) -> Result<()> {
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match Context::user_config().osu_id(orig.user_id()?).await {
+            Ok(Some(user_id)) => UserId::Id(user_id),
+            Ok(None) => return require_link(&orig).await,
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+    };
+
+    // The first request resolves the user id so the others can use it
+    let user_args = UserArgs::rosu_id(&user_id, GameMode::Osu).await;
+
+    let osu = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(OsuError::NotFound) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let osu_id = osu.user_id();
+
+    let (taiko_res, catch_res, mania_res) = tokio::join!(
+        Context::redis().osu_user(UserArgs::user_id(osu_id, GameMode::Taiko)),
+        Context::redis().osu_user(UserArgs::user_id(osu_id, GameMode::Catch)),
+        Context::redis().osu_user(UserArgs::user_id(osu_id, GameMode::Mania)),
+    );
+
+    let (taiko, catch, mania) = match (taiko_res, catch_res, mania_res) {
+        (Ok(taiko), Ok(catch), Ok(mania)) => (taiko, catch, mania),
+        (Err(err), ..) | (_, Err(err), _) | (.., Err(err)) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user for all modes");
+
+            return Err(err);
+        }
+    };
+
+    let rows: Vec<_> = [&osu, &taiko, &catch, &mania]
+        .into_iter()
+        .zip(MODES)
+        .map(|(user, mode)| ModeRow::new(user, mode))
+        .collect();
+
+    let embed = mode_stats_embed(&osu, &rows);
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+struct ModeRow {
+    mode: GameMode,
+    pp: f32,
+    playcount: u32,
+    global_rank: u32,
+}
+
+impl ModeRow {
+    fn new(user: &RedisData<User>, mode: GameMode) -> Self {
+        let stats = user.stats();
+
+        Self {
+            mode,
+            pp: stats.pp(),
+            playcount: stats.playcount(),
+            global_rank: stats.global_rank(),
+        }
+    }
+
+    /// `None` if the user has never played the mode
+    fn pp_per_play(&self) -> Option<f32> {
+        (self.playcount > 0).then(|| self.pp / self.playcount as f32)
+    }
+}
+
+fn mode_str(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "osu!",
+        GameMode::Taiko => "Taiko",
+        GameMode::Catch => "Catch",
+        GameMode::Mania => "Mania",
+    }
+}
+
+fn mode_stats_embed(user: &RedisData<User>, rows: &[ModeRow]) -> EmbedBuilder {
+    let cells: Vec<_> = rows
+        .iter()
+        .map(|row| match row.pp_per_play() {
+            Some(pp_per_play) => [
+                mode_str(row.mode).to_owned(),
+                WithComma::new(row.pp).to_string(),
+                WithComma::new(row.playcount).to_string(),
+                format!("{pp_per_play:.3}"),
+                match row.global_rank {
+                    0 => "-".to_owned(),
+                    rank => format!("#{}", WithComma::new(rank)),
+                },
+            ],
+            None => [
+                mode_str(row.mode).to_owned(),
+                "-".to_owned(),
+                "-".to_owned(),
+                "-".to_owned(),
+                "-".to_owned(),
+            ],
+        })
+        .collect();
+
+    const HEADER: [&str; 5] = ["Mode", "PP", "Plays", "PP/play", "Rank"];
+
+    let mut widths = HEADER.map(str::len);
+
+    for row in cells.iter() {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let mut description = String::with_capacity(512);
+    description.push_str("```\n");
+
+    let _ = writeln!(
+        description,
+        "{:<w0$} │ {:>w1$} │ {:>w2$} │ {:>w3$} │ {:>w4$}",
+        HEADER[0],
+        HEADER[1],
+        HEADER[2],
+        HEADER[3],
+        HEADER[4],
+        w0 = widths[0],
+        w1 = widths[1],
+        w2 = widths[2],
+        w3 = widths[3],
+        w4 = widths[4],
+    );
+
+    for [mode, pp, plays, pp_per_play, rank] in cells.iter() {
+        let _ = writeln!(
+            description,
+            "{mode:<w0$} │ {pp:>w1$} │ {plays:>w2$} │ {pp_per_play:>w3$} │ {rank:>w4$}",
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+            w4 = widths[4],
+        );
+    }
+
+    description.push_str("```");
+
+    let most_efficient = rows
+        .iter()
+        .filter_map(|row| Some((row.mode, row.pp_per_play()?)))
+        .max_by(|(_, a), (_, b)| a.total_cmp(b));
+
+    let footer = match most_efficient {
+        Some((mode, pp_per_play)) => format!(
+            "Most efficient in {} with {pp_per_play:.3}pp per play",
+            mode_str(mode)
+        ),
+        None => "No mode has been played yet".to_owned(),
+    };
+
+    EmbedBuilder::new()
+        .author(user.author_builder())
+        .description(description)
+        .footer(FooterBuilder::new(footer))
+        .thumbnail(user.avatar_url())
+        .title("pp per play across modes")
+}
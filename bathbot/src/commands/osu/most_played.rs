@@ -1,9 +1,10 @@
 use std::borrow::Cow;
 
 use bathbot_macros::{command, HasName, SlashCommand};
+use bathbot_model::command_fields::GameModeOption;
 use bathbot_util::{
     constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher,
+    matcher, CowUtils,
 };
 use eyre::{Report, Result};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
@@ -20,7 +21,13 @@ use crate::{
 };
 
 #[derive(CommandModel, CreateCommand, Default, HasName, SlashCommand)]
-#[command(name = "mostplayed", desc = "Display the most played maps of a user")]
+#[command(
+    name = "mostplayed",
+    desc = "Display the most played maps of a user",
+    help = "Display the most played maps of a user.\n\
+    The maps can be filtered by mode, by mapset creator, and by a minimum playcount.\n\
+    Only the user's 100 most played maps are considered."
+)]
 pub struct MostPlayed<'a> {
     #[command(desc = "Specify a username")]
     name: Option<Cow<'a, str>>,
@@ -31,6 +38,16 @@ pub struct MostPlayed<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = "Only show maps of this mode")]
+    mode: Option<GameModeOption>,
+    #[command(
+        desc = "Only show maps of this mapper",
+        help = "Only show maps whose mapset was created by this mapper.\n\
+        Guest difficulties are not considered."
+    )]
+    mapper: Option<Cow<'a, str>>,
+    #[command(min_value = 1, desc = "Only show maps with at least this many plays")]
+    min_count: Option<u32>,
 }
 
 async fn slash_mostplayed(mut command: InteractionCommand) -> Result<()> {
@@ -49,12 +66,12 @@ async fn prefix_mostplayed(msg: &Message, mut args: Args<'_>) -> Result<()> {
     let args = match args.next() {
         Some(arg) => match matcher::get_mention_user(arg) {
             Some(id) => MostPlayed {
-                name: None,
                 discord: Some(id),
+                ..Default::default()
             },
             None => MostPlayed {
                 name: Some(Cow::Borrowed(arg)),
-                discord: None,
+                ..Default::default()
             },
         },
         None => MostPlayed::default(),
@@ -99,16 +116,72 @@ async fn mostplayed(orig: CommandOrigin<'_>, args: MostPlayed<'_>) -> Result<()>
 
     let maps_fut = Context::osu().user_most_played(user.user_id()).limit(100);
 
-    let maps = match maps_fut.await {
-        Ok(maps) => maps,
-        Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
-            let err = Report::new(err).wrap_err("Failed to get maps");
-
-            return Err(err);
+    let mut maps = match args.mapper {
+        Some(ref mapper) => {
+            let mapper = mapper.cow_to_ascii_lowercase();
+            let mapper_args = UserArgs::username(mapper.as_ref(), GameMode::Osu).await;
+            let mapper_fut = Context::redis().osu_user(mapper_args);
+
+            match tokio::join!(mapper_fut, maps_fut) {
+                (Ok(mapper), Ok(mut maps)) => {
+                    let mapper_id = mapper.user_id();
+                    maps.retain(|entry| entry.mapset.creator_id == mapper_id);
+
+                    maps
+                }
+                (Err(OsuError::NotFound), _) => {
+                    let content = format!("Mapper with username `{mapper}` was not found");
+
+                    return orig.error(content).await;
+                }
+                (Err(err), _) => {
+                    let _ = orig.error(OSU_API_ISSUE).await;
+                    let err = Report::new(err).wrap_err("Failed to get mapper");
+
+                    return Err(err);
+                }
+                (_, Err(err)) => {
+                    let _ = orig.error(OSU_API_ISSUE).await;
+                    let err = Report::new(err).wrap_err("Failed to get maps");
+
+                    return Err(err);
+                }
+            }
         }
+        None => match maps_fut.await {
+            Ok(maps) => maps,
+            Err(err) => {
+                let _ = orig.error(OSU_API_ISSUE).await;
+                let err = Report::new(err).wrap_err("Failed to get maps");
+
+                return Err(err);
+            }
+        },
     };
 
+    if let Some(mode) = args.mode.map(GameMode::from) {
+        maps.retain(|entry| entry.map.mode == mode);
+    }
+
+    if let Some(min_count) = args.min_count {
+        maps.retain(|entry| entry.count >= min_count as usize);
+    }
+
+    if maps.is_empty() {
+        let filtered = args.mode.is_some() || args.mapper.is_some() || args.min_count.is_some();
+
+        let content = if filtered {
+            format!(
+                "None of `{}`'s most played maps match the given filters",
+                user.username()
+            )
+        } else {
+            format!("`{}` has not played any maps yet", user.username())
+        };
+
+        return orig.error(content).await;
+    }
+
     let pagination = MostPlayedPagination::builder()
         .user(user)
         .maps(maps.into_boxed_slice())
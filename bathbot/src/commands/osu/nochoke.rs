@@ -18,7 +18,7 @@ use rosu_v2::{
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::id::{marker::UserMarker, Id};
 
-use super::{require_link, user_not_found};
+use super::{require_link, resolve_mode, user_not_found};
 use crate::{
     active::{impls::NoChokePagination, ActiveMessages},
     core::commands::{prefix::Args, CommandOrigin},
@@ -207,9 +207,11 @@ async fn nochoke(orig: CommandOrigin<'_>, args: Nochoke<'_>) -> Result<()> {
         },
     };
 
-    let mode = match args.mode.map(GameMode::from).or(config.mode) {
-        None | Some(GameMode::Mania) => GameMode::Osu,
-        Some(mode) => mode,
+    let arg_mode = args.mode.map(GameMode::from);
+
+    let mode = match resolve_mode(orig.guild_id(), orig.channel_id(), arg_mode, config.mode).await {
+        GameMode::Mania => return orig.error("Nochokes are not available for mania").await,
+        mode => mode,
     };
 
     let legacy_scores = match config.score_data {
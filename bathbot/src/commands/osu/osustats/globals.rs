@@ -498,6 +498,7 @@ async fn process_scores(
             classic_score: 0,
             score_id: 0,
             legacy_id: None,
+            build_id: None,
             statistics: LegacyScoreStatistics {
                 count_geki: score.count_geki,
                 count_300: score.count300,
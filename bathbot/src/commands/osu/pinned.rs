@@ -23,7 +23,10 @@ use twilight_model::{
     id::{marker::UserMarker, Id},
 };
 
-use super::{map_strain_graph, require_link, user_not_found, HasMods, ModsResult, ScoreOrder};
+use super::{
+    map_strain_graph, require_link, resolve_mode, user_not_found, GraphPalette, HasMods,
+    ModsResult, ScoreOrder,
+};
 use crate::{
     active::{
         impls::{SingleScoreContent, SingleScorePagination, TopPagination},
@@ -126,11 +129,13 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned) -> Result<()> {
         }
     };
 
-    let mode = args
-        .mode
-        .map(GameMode::from)
-        .or(config.mode)
-        .unwrap_or(GameMode::Osu);
+    let mode = resolve_mode(
+        orig.guild_id(),
+        orig.channel_id(),
+        args.mode.map(GameMode::from),
+        config.mode,
+    )
+    .await;
 
     let guild_id = orig.guild_id();
 
@@ -147,11 +152,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned) -> Result<()> {
         None => GuildValues::default(),
     };
 
-    let list_size = args
-        .size
-        .or(config.list_size)
-        .or(guild_list_size)
-        .unwrap_or_default();
+    let list_size = ListSize::resolve(args.size, config.list_size, guild_list_size);
 
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
@@ -180,7 +181,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned) -> Result<()> {
         }
     };
 
-    let score_data = config.score_data.or(guild_score_data).unwrap_or_default();
+    let score_data = ScoreData::resolve(config.score_data, guild_score_data);
     let legacy_scores = score_data.is_legacy();
     let missing_user = user_opt.is_none();
 
@@ -239,6 +240,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned) -> Result<()> {
     with_render &= settings.buttons.render
         && mode == GameMode::Osu
         && orig.has_permission_to(Permissions::SEND_MESSAGES)
+        && !orig.is_ephemeral()
         && Context::ordr().is_some();
 
     let origin = MessageOrigin::new(guild_id, orig.channel_id());
@@ -311,6 +313,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned) -> Result<()> {
                         &entry.map.pp_map,
                         entry.score.mods.clone(),
                         entry.map.cover(),
+                        GraphPalette::default(),
                     );
 
                     match fut.await {
@@ -350,6 +353,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned) -> Result<()> {
         .sort_by(sort_by)
         .condensed_list(condensed_list)
         .score_data(score_data)
+        .pp_decimals(config.pp_decimals.unwrap_or(2))
         .content(content.unwrap_or_default().into_boxed_str())
         .msg_owner(msg_owner)
         .build();
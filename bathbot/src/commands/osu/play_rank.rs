@@ -0,0 +1,221 @@
+use std::fmt::Write;
+
+use bathbot_macros::{command, SlashCommand};
+use bathbot_model::rosu_v2::user::User;
+use bathbot_util::{
+    constants::{OSU_API_ISSUE, OSU_BASE},
+    matcher,
+    numbers::WithComma,
+    CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder,
+};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{GameMode, OsuError};
+use time::OffsetDateTime;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    core::commands::CommandOrigin,
+    manager::redis::{osu::UserArgs, RedisData},
+    util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "playrank",
+    desc = "Show which global rank a player had when setting a score",
+    help = "Show which global rank the player of a score had at the time the score \
+    was set, as well as their current rank.\n\
+    The rank at the time of the score is taken from the player's rank history \
+    which only covers the last 90 days so it's not available for older scores."
+)]
+pub struct PlayRank {
+    #[command(desc = "Specify a score url")]
+    score: String,
+}
+
+async fn slash_playrank(mut command: InteractionCommand) -> Result<()> {
+    let args = PlayRank::from_interaction(command.input_data())?;
+
+    play_rank((&mut command).into(), &args.score).await
+}
+
+#[command]
+#[desc("Show which global rank a player had when setting a score")]
+#[help(
+    "Show which global rank the player of a score had at the time the score \
+    was set, as well as their current rank.\n\
+    The rank at the time of the score is taken from the player's rank history \
+    which only covers the last 90 days so it's not available for older scores."
+)]
+#[usage("[score url]")]
+#[example("https://osu.ppy.sh/scores/osu/4199510391")]
+#[group(AllModes)]
+async fn prefix_playrank(msg: &Message, mut args: Args<'_>) -> Result<()> {
+    match args.next() {
+        Some(arg) => play_rank(msg.into(), arg).await,
+        None => {
+            msg.error("You must specify a score url").await?;
+
+            Ok(())
+        }
+    }
+}
+
+async fn play_rank(orig: CommandOrigin<'_>, score: &str) -> Result<()> {
+    let Some((mode, score_id)) = matcher::get_osu_score_id(score) else {
+        let content = "Failed to parse score url. \
+            Be sure to specify a url of the form `https://osu.ppy.sh/scores/{mode}/{id}`.";
+
+        return orig.error(content).await;
+    };
+
+    let score = match Context::osu().score(score_id).mode(mode).await {
+        Ok(score) => score,
+        Err(OsuError::NotFound) => {
+            let content = format!("Score with id {score_id} was not found");
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get score");
+
+            return Err(err);
+        }
+    };
+
+    let user_args = UserArgs::user_id(score.user_id, mode);
+
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(OsuError::NotFound) => {
+            let content = format!("The player of score {score_id} was not found");
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let days_ago = (OffsetDateTime::now_utc() - score.ended_at).whole_days();
+
+    let (rank_then, history_len) = match user {
+        RedisData::Original(ref user) => (
+            rank_days_ago(&user.rank_history, days_ago),
+            user.rank_history.len(),
+        ),
+        RedisData::Archive(ref user) => (
+            rank_days_ago(&user.rank_history, days_ago),
+            user.rank_history.len(),
+        ),
+    };
+
+    let rank_now = match user.stats().global_rank() {
+        0 => None,
+        rank => Some(rank),
+    };
+
+    let mut description = String::with_capacity(256);
+
+    if let (Some(map), Some(mapset)) = (&score.map, &score.mapset) {
+        let _ = writeln!(
+            description,
+            "[{artist} - {title} [{version}]]({OSU_BASE}b/{map_id})",
+            artist = mapset.artist.cow_escape_markdown(),
+            title = mapset.title.cow_escape_markdown(),
+            version = map.version.cow_escape_markdown(),
+            map_id = map.map_id,
+        );
+    }
+
+    let username = user.username().cow_escape_markdown();
+    let timestamp = score.ended_at.unix_timestamp();
+
+    let _ = match rank_then {
+        Some(rank) => writeln!(
+            description,
+            "When the score was set <t:{timestamp}:R>, {username} was rank **#{rank}**",
+            rank = WithComma::new(rank),
+        ),
+        None if days_ago >= history_len as i64 => writeln!(
+            description,
+            "The score was set <t:{timestamp}:R> which is further back than {username}'s \
+            rank history of the last {history_len} days",
+        ),
+        None => writeln!(
+            description,
+            "When the score was set <t:{timestamp}:R>, {username} was unranked",
+        ),
+    };
+
+    let _ = match rank_now {
+        Some(rank) => write!(
+            description,
+            "Nowadays, {username} is rank **#{rank}**",
+            rank = WithComma::new(rank),
+        ),
+        None => write!(description, "Nowadays, {username} is unranked"),
+    };
+
+    if let (Some(then), Some(now)) = (rank_then, rank_now) {
+        let diff = i64::from(then) - i64::from(now);
+        let _ = write!(description, " ({diff:+} since then)");
+    }
+
+    let mode_str = match mode {
+        GameMode::Osu => "osu",
+        GameMode::Taiko => "taiko",
+        GameMode::Catch => "fruits",
+        GameMode::Mania => "mania",
+    };
+
+    let title = match score.pp {
+        Some(pp) => format!("Player rank for a {}pp score", WithComma::new(pp)),
+        None => "Player rank for a score".to_owned(),
+    };
+
+    let embed = EmbedBuilder::new()
+        .author(user.author_builder())
+        .description(description)
+        .footer(FooterBuilder::new(
+            "The rank at the time of the score is taken from the player's rank history",
+        ))
+        .title(title)
+        .url(format!("{OSU_BASE}scores/{mode_str}/{score_id}"));
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+/// The rank of the given amount of days ago.
+///
+/// The last entry of the rank history is the current day.
+fn rank_days_ago(history: &[u32], days_ago: i64) -> Option<u32> {
+    let days_ago = usize::try_from(days_ago).ok()?;
+    let idx = history.len().checked_sub(days_ago + 1)?;
+
+    history.get(idx).copied().filter(|&rank| rank > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rank_days_ago;
+
+    #[test]
+    fn rank_from_history() {
+        let history = [300, 0, 200, 100];
+
+        assert_eq!(rank_days_ago(&history, 0), Some(100));
+        assert_eq!(rank_days_ago(&history, 3), Some(300));
+        assert_eq!(rank_days_ago(&history, 2), None);
+        assert_eq!(rank_days_ago(&history, 4), None);
+        assert_eq!(rank_days_ago(&history, -1), None);
+    }
+}
@@ -2,7 +2,10 @@ use std::borrow::Cow;
 
 use bathbot_macros::{command, HasName, SlashCommand};
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_util::{constants::OSU_API_ISSUE, matcher, MessageBuilder};
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    matcher, MessageBuilder,
+};
 use eyre::{Report, Result};
 use rosu_v2::prelude::OsuError;
 use twilight_interactions::command::{CommandModel, CreateCommand};
@@ -24,10 +27,12 @@ use crate::{
 )]
 pub struct Pp<'a> {
     #[command(
-        desc = "Specify a target total pp amount",
+        desc = "Specify a target total pp amount or a target rank e.g. `#1000`",
         help = "Specify a target total pp amount.\n\
         Alternatively, prefix the value with a `+` so that it'll be interpreted as \"delta\" \
-        meaning the current total pp + the given value"
+        meaning the current total pp + the given value.\n\
+        To target a rank instead, prefix it with a `#` e.g. `#1000`. \
+        The rank will be converted into an approximate pp amount."
     )]
     pp: Cow<'a, str>,
     #[command(desc = "Specify a gamemode")]
@@ -63,7 +68,7 @@ impl<'m> Pp<'m> {
         let mut pp = None;
 
         for arg in args.take(2) {
-            if arg.parse::<f32>().is_ok() {
+            if PpValue::parse(arg).is_ok() {
                 pp = Some(Cow::Borrowed(arg));
             } else if let Some(id) = matcher::get_mention_user(arg) {
                 discord = Some(id);
@@ -73,7 +78,7 @@ impl<'m> Pp<'m> {
         }
 
         Ok(Self {
-            pp: pp.ok_or("You need to provide a decimal number")?,
+            pp: pp.ok_or("You need to provide a decimal number or a rank e.g. `#1000`")?,
             mode,
             name,
             each: None,
@@ -94,10 +99,11 @@ async fn slash_pp(mut command: InteractionCommand) -> Result<()> {
 #[help(
     "Calculate what score a user is missing to reach the given total pp amount.\n\
     Alternatively, prefix the value with a `+` so that it'll be interpreted as \"delta\" \
-    meaning the current total pp + the given value"
+    meaning the current total pp + the given value.\n\
+    To target a rank instead, prefix it with a `#` e.g. `#1000`."
 )]
-#[usage("[username] [+][number]")]
-#[example("badewanne3 8000", "+72.7")]
+#[usage("[username] [+][number] / [username] #[rank]")]
+#[example("badewanne3 8000", "+72.7", "#1000")]
 #[group(Osu)]
 pub async fn prefix_pp(msg: &Message, args: Args<'_>) -> Result<()> {
     match Pp::args(None, args) {
@@ -115,10 +121,11 @@ pub async fn prefix_pp(msg: &Message, args: Args<'_>) -> Result<()> {
 #[help(
     "Calculate what score a mania user is missing to reach the given total pp amount.\n\
     Alternatively, prefix the value with a `+` so that it'll be interpreted as \"delta\" \
-    meaning the current total pp + the given value"
+    meaning the current total pp + the given value.\n\
+    To target a rank instead, prefix it with a `#` e.g. `#1000`."
 )]
-#[usage("[username] [+][number]")]
-#[example("badewanne3 8000", "+72.7")]
+#[usage("[username] [+][number] / [username] #[rank]")]
+#[example("badewanne3 8000", "+72.7", "#1000")]
 #[alias("ppm")]
 #[group(Mania)]
 pub async fn prefix_ppmania(msg: &Message, args: Args<'_>) -> Result<()> {
@@ -137,10 +144,11 @@ pub async fn prefix_ppmania(msg: &Message, args: Args<'_>) -> Result<()> {
 #[help(
     "Calculate what score a taiko user is missing to reach the given total pp amount.\n\
     Alternatively, prefix the value with a `+` so that it'll be interpreted as \"delta\" \
-    meaning the current total pp + the given value"
+    meaning the current total pp + the given value.\n\
+    To target a rank instead, prefix it with a `#` e.g. `#1000`."
 )]
-#[usage("[username] [+][number]")]
-#[example("badewanne3 8000", "+72.7")]
+#[usage("[username] [+][number] / [username] #[rank]")]
+#[example("badewanne3 8000", "+72.7", "#1000")]
 #[alias("ppt")]
 #[group(Taiko)]
 pub async fn prefix_pptaiko(msg: &Message, args: Args<'_>) -> Result<()> {
@@ -159,10 +167,11 @@ pub async fn prefix_pptaiko(msg: &Message, args: Args<'_>) -> Result<()> {
 #[help(
     "Calculate what score a ctb user is missing to reach the given total pp amount.\n\
     Alternatively, prefix the value with a `+` so that it'll be interpreted as \"delta\" \
-    meaning the current total pp + the given value"
+    meaning the current total pp + the given value.\n\
+    To target a rank instead, prefix it with a `#` e.g. `#1000`."
 )]
-#[usage("[username] [+][number]")]
-#[example("badewanne3 8000", "+72.7")]
+#[usage("[username] [+][number] / [username] #[rank]")]
+#[example("badewanne3 8000", "+72.7", "#1000")]
 #[aliases("ppc", "ppcatch")]
 #[group(Catch)]
 pub async fn prefix_ppctb(msg: &Message, args: Args<'_>) -> Result<()> {
@@ -183,18 +192,17 @@ async fn pp(orig: CommandOrigin<'_>, args: Pp<'_>) -> Result<()> {
         pp, each, amount, ..
     } = args;
 
-    let Some(pp) = PpValue::parse(pp.as_ref()) else {
-        let content = "Failed to parse pp. Be sure to specify a decimal number.";
-
-        return orig.error(content).await;
+    let pp = match PpValue::parse(pp.as_ref()) {
+        Ok(pp) => pp,
+        Err(content) => return orig.error(content).await,
     };
 
-    let pp_value = pp.value();
-
-    if pp_value < 0.0 {
-        return orig.error("The pp number must be non-negative").await;
-    } else if pp_value > (i64::MAX / 1024) as f32 {
-        return orig.error("Number too large").await;
+    if let PpValue::Delta(pp_value) | PpValue::Raw(pp_value) = pp {
+        if pp_value < 0.0 {
+            return orig.error("The pp number must be non-negative").await;
+        } else if pp_value > (i64::MAX / 1024) as f32 {
+            return orig.error("Number too large").await;
+        }
     }
 
     // Retrieve the user and their top scores
@@ -231,14 +239,27 @@ async fn pp(orig: CommandOrigin<'_>, args: Pp<'_>) -> Result<()> {
                 .as_ref()
                 .map_or(value, |stats| stats.pp + value),
         },
+        PpValue::Rank(rank) => match Context::approx().pp(rank, mode).await {
+            Ok(pp) => pp,
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("Failed to get pp for rank"));
+            }
+        },
     };
 
-    let rank = match Context::approx().rank(target_pp, mode).await {
-        Ok(rank_pp) => Some(rank_pp),
-        Err(err) => {
-            warn!(?err, "Failed to get rank pp");
+    let rank = match pp {
+        PpValue::Rank(rank) => Some(rank),
+        PpValue::Delta(_) | PpValue::Raw(_) => {
+            match Context::approx().rank(target_pp, mode).await {
+                Ok(rank_pp) => Some(rank_pp),
+                Err(err) => {
+                    warn!(?err, "Failed to get rank pp");
 
-            None
+                    None
+                }
+            }
         }
     };
 
@@ -257,11 +278,24 @@ async fn pp(orig: CommandOrigin<'_>, args: Pp<'_>) -> Result<()> {
 enum PpValue {
     Delta(f32),
     Raw(f32),
+    /// Target rank which will be converted to pp
+    Rank(u32),
 }
 
 impl PpValue {
-    fn parse(input: &str) -> Option<Self> {
-        let pp = input.parse().ok()?;
+    fn parse(input: &str) -> Result<Self, &'static str> {
+        if let Some(rank) = input.strip_prefix('#') {
+            return match rank.parse() {
+                Ok(rank @ 1..) => Ok(Self::Rank(rank)),
+                _ => Err("Failed to parse rank. \
+                    Be sure to specify a positive integer after the `#` e.g. `#1000`."),
+            };
+        }
+
+        let Ok(pp) = input.parse() else {
+            return Err("Failed to parse pp. \
+                Be sure to specify a decimal number or a rank e.g. `#1000`.");
+        };
 
         let this = if input.starts_with('+') {
             Self::Delta(pp)
@@ -269,13 +303,6 @@ impl PpValue {
             Self::Raw(pp)
         };
 
-        Some(this)
-    }
-
-    fn value(self) -> f32 {
-        match self {
-            Self::Delta(value) => value,
-            Self::Raw(value) => value,
-        }
+        Ok(this)
     }
 }
@@ -0,0 +1,348 @@
+use std::borrow::Cow;
+
+use bathbot_macros::{command, HasMods, SlashCommand};
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_BASE},
+    matcher,
+    numbers::round,
+    osu::MapIdType,
+    EmbedBuilder, FooterBuilder, MessageBuilder,
+};
+use eyre::Result;
+use rosu_pp::Difficulty;
+use rosu_v2::prelude::GameModsIntermode;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    channel::{
+        message::{embed::EmbedField, MessageType},
+        Message,
+    },
+    guild::Permissions,
+};
+
+use super::{CustomAttrs, HasMods, ModsResult};
+use crate::{
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::MapError,
+    util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
+    Context,
+};
+
+const ACCS: [f64; 5] = [95.0, 97.0, 98.0, 99.0, 100.0];
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "pptable",
+    desc = "Display a map's pp for a range of accuracies",
+    help = "Display how much pp a map is worth at 95%, 97%, 98%, 99%, and 100% accuracy.\n\
+    The values will be adjusted to mods and custom attributes."
+)]
+pub struct PpTable<'a> {
+    #[command(
+        desc = "Specify a map url or map id",
+        help = "Specify a map either by map url or map id.\n\
+        If none is specified, it will search in the recent channel history \
+        and pick the first map it can find."
+    )]
+    map: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify mods e.g. hdhr or nm",
+        help = "Specify mods either directly or through the explicit `+mods!` / `+mods` syntax e.g. `hdhr` or `+hdhr!`"
+    )]
+    mods: Option<Cow<'a, str>>,
+    #[command(desc = "Specify an AR value to override the actual one")]
+    ar: Option<f64>,
+    #[command(desc = "Specify an OD value to override the actual one")]
+    od: Option<f64>,
+    #[command(desc = "Specify a CS value to override the actual one")]
+    cs: Option<f64>,
+    #[command(desc = "Specify an HP value to override the actual one")]
+    hp: Option<f64>,
+}
+
+#[derive(HasMods)]
+struct PpTableArgs<'a> {
+    map: Option<MapIdType>,
+    mods: Option<Cow<'a, str>>,
+    attrs: CustomAttrs,
+}
+
+impl<'m> PpTableArgs<'m> {
+    async fn args(msg: &Message, args: Args<'m>) -> Result<PpTableArgs<'m>, String> {
+        let mut map = None;
+        let mut mods = None;
+
+        for arg in args.take(2) {
+            if let Some(id) = matcher::get_osu_map_id(arg)
+                .map(MapIdType::Map)
+                .or_else(|| matcher::get_osu_mapset_id(arg).map(MapIdType::Set))
+            {
+                map = Some(id);
+            } else if matcher::get_mods(arg).is_some() {
+                mods = Some(arg.into());
+            } else {
+                let content = format!(
+                    "Failed to parse `{arg}`.\n\
+                    Be sure you specify either a valid map id, map url, or mod combination."
+                );
+
+                return Err(content);
+            }
+        }
+
+        let reply = msg
+            .referenced_message
+            .as_deref()
+            .filter(|_| msg.kind == MessageType::Reply);
+
+        if let Some(reply) = reply {
+            if let Some(id) = Context::find_map_id_in_msg(reply).await {
+                map = Some(id);
+            }
+        }
+
+        Ok(Self {
+            map,
+            mods,
+            attrs: CustomAttrs::default(),
+        })
+    }
+}
+
+impl<'a> TryFrom<PpTable<'a>> for PpTableArgs<'a> {
+    type Error = &'static str;
+
+    fn try_from(args: PpTable<'a>) -> Result<Self, Self::Error> {
+        let PpTable {
+            map,
+            mods,
+            ar,
+            od,
+            cs,
+            hp,
+        } = args;
+
+        let map = match map.map(|arg| {
+            matcher::get_osu_map_id(&arg)
+                .map(MapIdType::Map)
+                .or_else(|| matcher::get_osu_mapset_id(&arg).map(MapIdType::Set))
+        }) {
+            Some(Some(id)) => Some(id),
+            Some(None) => {
+                let content =
+                    "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+
+                return Err(content);
+            }
+            None => None,
+        };
+
+        let attrs = CustomAttrs { ar, cs, hp, od };
+
+        Ok(Self { map, mods, attrs })
+    }
+}
+
+#[command]
+#[desc("Display a map's pp for a range of accuracies")]
+#[help(
+    "Display how much pp a map is worth at 95%, 97%, 98%, 99%, and 100% accuracy.\n\
+    If no map is specified by either url or id, I will choose the last map \
+    I can find in the embeds of this channel."
+)]
+#[usage("[map url / map id] [+mods]")]
+#[examples("2240404 +hddt", "https://osu.ppy.sh/beatmapsets/902425#osu/2240404")]
+#[alias("mappp")]
+#[group(AllModes)]
+async fn prefix_pptable(
+    msg: &Message,
+    args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    match PpTableArgs::args(msg, args).await {
+        Ok(args) => pp_table(CommandOrigin::from_msg(msg, permissions), args).await,
+        Err(content) => {
+            msg.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+async fn slash_pptable(mut command: InteractionCommand) -> Result<()> {
+    let args = PpTable::from_interaction(command.input_data())?;
+
+    match PpTableArgs::try_from(args) {
+        Ok(args) => pp_table((&mut command).into(), args).await,
+        Err(content) => {
+            command.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+async fn pp_table(orig: CommandOrigin<'_>, args: PpTableArgs<'_>) -> Result<()> {
+    let mods = match args.mods() {
+        ModsResult::Mods(mods) => mods.into_mods(),
+        ModsResult::None => GameModsIntermode::new(),
+        ModsResult::Invalid => {
+            let content =
+                "Failed to parse mods. Be sure to specify a valid abbreviation e.g. `hdhr`.";
+
+            return orig.error(content).await;
+        }
+    };
+
+    let PpTableArgs { map, attrs, .. } = args;
+
+    let map_id = match map {
+        Some(MapIdType::Map(id)) => id,
+        Some(MapIdType::Set(_)) => {
+            let content = "Looks like you gave me a mapset id, I need a map id though";
+
+            return orig.error(content).await;
+        }
+        None => {
+            let msgs = match Context::retrieve_channel_history(orig.channel_id()).await {
+                Ok(msgs) => msgs,
+                Err(_) => {
+                    let content = "No beatmap specified and lacking permission to search the \
+                        channel history for maps.\nTry specifying a map either by url to the map, \
+                        or just by map id, or give me the \"Read Message History\" permission.";
+
+                    return orig.error(content).await;
+                }
+            };
+
+            match Context::find_map_id_in_msgs(&msgs, 0).await {
+                Some(MapIdType::Map(id)) => id,
+                None | Some(MapIdType::Set(_)) => {
+                    let content = "No beatmap specified and none found in recent channel history. \
+                        Try specifying a map either by url to the map, or just by map id.";
+
+                    return orig.error(content).await;
+                }
+            }
+        }
+    };
+
+    let map = match Context::osu_map().map(map_id, None).await {
+        Ok(map) => map,
+        Err(MapError::NotFound) => {
+            let content = format!(
+                "Could not find beatmap with id `{map_id}`. \
+                Did you give me a mapset id instead of a map id?",
+            );
+
+            return orig.error(content).await;
+        }
+        Err(MapError::Report(err)) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let mode = map.mode();
+
+    let mods = match mods.try_with_mode(mode) {
+        Some(mods) if mods.is_valid() => mods,
+        Some(mods) => {
+            let content =
+                format!("Looks like some mods in `{mods}` are incompatible with each other");
+
+            return orig.error(content).await;
+        }
+        None => {
+            let content = format!("The mods are incompatible with the map's mode {mode:?}");
+
+            return orig.error(content).await;
+        }
+    };
+
+    let mut pp_map = map.pp_map.clone();
+
+    if let Some(ar) = attrs.ar {
+        pp_map.ar = ar as f32;
+    }
+
+    if let Some(cs) = attrs.cs {
+        pp_map.cs = cs as f32;
+    }
+
+    if let Some(hp) = attrs.hp {
+        pp_map.hp = hp as f32;
+    }
+
+    if let Some(od) = attrs.od {
+        pp_map.od = od as f32;
+    }
+
+    let mod_bits = mods.bits();
+    let clock_rate = f64::from(mods.clock_rate().unwrap_or(1.0));
+
+    let mut diff_attrs = Difficulty::new()
+        .mods(mod_bits)
+        .clock_rate(clock_rate)
+        .calculate(&pp_map);
+
+    let stars = diff_attrs.stars();
+    let mut fields = Vec::with_capacity(ACCS.len());
+
+    for acc in ACCS {
+        let perf_attrs = diff_attrs
+            .performance()
+            .mods(mod_bits)
+            .clock_rate(clock_rate)
+            .accuracy(acc)
+            .calculate();
+
+        let pp = perf_attrs.pp();
+
+        let name = if acc == 100.0 {
+            "100% (SS)".to_owned()
+        } else {
+            format!("{acc}%")
+        };
+
+        let value = if pp > 100_000.0 {
+            format!("**{pp:.3e}pp**")
+        } else {
+            format!("**{}pp**", round(pp as f32))
+        };
+
+        fields.push(EmbedField {
+            inline: true,
+            name,
+            value,
+        });
+
+        diff_attrs = perf_attrs.into();
+    }
+
+    let mut title = format!("{} - {} [{}]", map.artist(), map.title(), map.version());
+
+    if !mods.is_empty() {
+        title.push_str(" +");
+        title.push_str(&mods.to_string());
+    }
+
+    let footer = FooterBuilder::new(format!("{stars:.2}★ • {mode:?}"));
+
+    let mut embed = EmbedBuilder::new()
+        .fields(fields)
+        .footer(footer)
+        .thumbnail(map.thumbnail())
+        .title(title)
+        .url(format!("{OSU_BASE}b/{map_id}"));
+
+    if let Some(content) = attrs.content() {
+        embed = embed.description(content);
+    }
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use std::fmt::Write;
+
+use bathbot_macros::{command, SlashCommand};
+use bathbot_util::{numbers::round, EmbedBuilder, FooterBuilder, MessageBuilder};
+use eyre::Result;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::guild::Permissions;
+
+use crate::{
+    core::commands::CommandOrigin,
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+};
+
+const MAX_POSITION: u32 = 200;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "ppweight",
+    desc = "Show how much a top play position is weighted",
+    help = "Show how much a top play position is weighted.\n\
+    The pp of the top play at position `n` are weighted by `0.95^(n-1)` \
+    before being added to the user's total pp."
+)]
+#[flags(SKIP_DEFER)]
+pub struct PpWeight {
+    #[command(min_value = 1, max_value = 200, desc = "Specify a top play position")]
+    position: u32,
+    #[command(
+        min_value = 0.0,
+        desc = "Specify a pp value to see how much it contributes at that position"
+    )]
+    pp: Option<f32>,
+}
+
+async fn slash_ppweight(mut command: InteractionCommand) -> Result<()> {
+    let args = PpWeight::from_interaction(command.input_data())?;
+
+    pp_weight((&mut command).into(), args.position, args.pp).await
+}
+
+#[command]
+#[desc("Show how much a top play position is weighted")]
+#[help(
+    "Show how much a top play position is weighted.\n\
+    The pp of the top play at position `n` are weighted by `0.95^(n-1)` \
+    before being added to the user's total pp.\n\
+    Optionally specify a pp value to see how much it contributes at that position."
+)]
+#[usage("[position] [pp]")]
+#[examples("5", "42 321.5")]
+#[alias("weight")]
+#[flags(SKIP_DEFER)]
+#[group(AllModes)]
+async fn prefix_ppweight(
+    msg: &Message,
+    mut args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    let orig = CommandOrigin::from_msg(msg, permissions);
+
+    let position = match args.next().map(str::parse) {
+        Some(Ok(position @ 1..=MAX_POSITION)) => position,
+        Some(_) => {
+            let content = format!("The position must be a number between 1 and {MAX_POSITION}");
+
+            return orig.error(content).await;
+        }
+        None => return orig.error("You must specify a top play position").await,
+    };
+
+    let pp = match args.next().map(str::parse::<f32>) {
+        Some(Ok(pp)) if pp >= 0.0 => Some(pp),
+        Some(_) => {
+            return orig
+                .error("The pp value must be a non-negative number")
+                .await
+        }
+        None => None,
+    };
+
+    pp_weight(orig, position, pp).await
+}
+
+async fn pp_weight(orig: CommandOrigin<'_>, position: u32, pp: Option<f32>) -> Result<()> {
+    let weight = weight(position);
+
+    let mut description = format!(
+        "Top play **#{position}** is weighted by **{}%** (`0.95^{}`)",
+        round(weight * 100.0),
+        position - 1,
+    );
+
+    if let Some(pp) = pp {
+        let _ = write!(
+            description,
+            "\n**{}pp** at that position contribute **{}pp** to the total pp",
+            round(pp),
+            round(pp * weight),
+        );
+    }
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .footer(FooterBuilder::new("Bonus pp are not included"))
+        .title("Top play weighting");
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.callback(builder).await?;
+
+    Ok(())
+}
+
+/// Weight of the top play at the given 1-based position
+fn weight(position: u32) -> f32 {
+    0.95_f32.powi(position as i32 - 1)
+}
@@ -15,7 +15,7 @@ use rosu_v2::{
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::id::{marker::UserMarker, Id};
 
-use super::{require_link, user_not_found};
+use super::{profile_playcount_graph, require_link, resolve_mode, user_not_found};
 use crate::{
     active::{impls::ProfileMenu, ActiveMessages},
     core::commands::{prefix::Args, CommandOrigin},
@@ -40,6 +40,12 @@ pub struct Profile<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(
+        desc = "Attach a graph of the user's playcount over time",
+        help = "Attach a graph of the user's monthly playcount over time.\n\
+        Users without enough playcount history won't get a graph."
+    )]
+    graph: Option<bool>,
 }
 
 #[derive(Copy, Clone, CommandOption, CreateOption, Debug, Eq, PartialEq)]
@@ -83,6 +89,7 @@ impl<'m> Profile<'m> {
             name,
             embed: None,
             discord,
+            graph: None,
         })
     }
 }
@@ -182,11 +189,13 @@ async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
         }
     };
 
-    let mode = args
-        .mode
-        .map(GameMode::from)
-        .or(config.mode)
-        .unwrap_or(GameMode::Osu);
+    let mode = resolve_mode(
+        orig.guild_id(),
+        orig.channel_id(),
+        args.mode.map(GameMode::from),
+        config.mode,
+    )
+    .await;
 
     let kind = args.embed.unwrap_or_default();
     let guild = orig.guild_id();
@@ -215,7 +224,7 @@ async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
     // Retrieve the user and their top scores
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
 
-    let user = match Context::redis().osu_user(user_args).await {
+    let mut user = match Context::redis().osu_user(user_args).await {
         Ok(user) => user,
         Err(OsuError::NotFound) => {
             let content = user_not_found(user_id).await;
@@ -261,6 +270,19 @@ async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
         }
     };
 
+    let graph = if args.graph.unwrap_or(false) {
+        match profile_playcount_graph(&mut user).await {
+            Ok(graph) => graph,
+            Err(err) => {
+                warn!(?err, "Failed to create profile graph");
+
+                None
+            }
+        }
+    } else {
+        None
+    };
+
     let tz = no_user_specified.then_some(config.timezone).flatten();
     let origin = MessageOrigin::new(orig.guild_id(), orig.channel_id());
 
@@ -273,10 +295,12 @@ async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
         kind,
         origin,
         owner,
+        graph.is_some(),
     );
 
     ActiveMessages::builder(pagination)
         .start_by_update(true)
+        .attachment(graph.map(|bytes| (ProfileMenu::GRAPH_NAME.to_owned(), bytes)))
         .begin(orig)
         .await
 }
@@ -0,0 +1,160 @@
+use std::{borrow::Cow, cmp::Reverse};
+
+use bathbot_macros::{command, HasName, SlashCommand};
+use bathbot_model::{rkyv_util::time::DateTimeRkyv, rosu_v2::user::Badge};
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    matcher, MessageBuilder,
+};
+use eyre::{Report, Result};
+use rkyv::{with::DeserializeWith, Infallible};
+use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use super::{require_link, user_not_found};
+use crate::{
+    active::{impls::ProfileBadgesPagination, ActiveMessages},
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::redis::{osu::UserArgs, RedisData},
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "profilebadges",
+    desc = "Display the badges on a user's osu! profile",
+    help = "Display the badges on a user's osu! profile, newest first, \
+    alongside their description and the date they were awarded.\n\
+    Unlike `/badges user`, this command uses the user's profile directly \
+    so it also includes badges that are not yet tracked by osekai."
+)]
+pub struct ProfileBadges<'a> {
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord: Option<Id<UserMarker>>,
+}
+
+impl<'m> ProfileBadges<'m> {
+    fn args(mut args: Args<'m>) -> Self {
+        let mut name = None;
+        let mut discord = None;
+
+        if let Some(arg) = args.next() {
+            match matcher::get_mention_user(arg) {
+                Some(id) => discord = Some(id),
+                None => name = Some(arg.into()),
+            }
+        }
+
+        Self { name, discord }
+    }
+}
+
+#[command]
+#[desc("Display the badges on a user's osu! profile")]
+#[help(
+    "Display the badges on a user's osu! profile, newest first, \
+    alongside their description and the date they were awarded."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[aliases("userbadges", "ubadges")]
+#[group(AllModes)]
+async fn prefix_profilebadges(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = ProfileBadges::args(args);
+
+    profile_badges(msg.into(), args).await
+}
+
+async fn slash_profilebadges(mut command: InteractionCommand) -> Result<()> {
+    let args = ProfileBadges::from_interaction(command.input_data())?;
+
+    profile_badges((&mut command).into(), args).await
+}
+
+async fn profile_badges(orig: CommandOrigin<'_>, args: ProfileBadges<'_>) -> Result<()> {
+    let msg_owner = orig.user_id()?;
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match Context::user_config().osu_id(msg_owner).await {
+            Ok(Some(user_id)) => UserId::Id(user_id),
+            Ok(None) => return require_link(&orig).await,
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+    };
+
+    let user_args = UserArgs::rosu_id(&user_id, GameMode::Osu).await;
+
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(OsuError::NotFound) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+            let err = Report::new(err).wrap_err("failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let mut badges: Vec<_> = match user {
+        RedisData::Original(ref user) => user
+            .badges
+            .iter()
+            .map(|badge| Badge {
+                awarded_at: badge.awarded_at,
+                description: badge.description.as_str().into(),
+                image_url: badge.image_url.as_str().into(),
+                url: badge.url.as_str().into(),
+            })
+            .collect(),
+        RedisData::Archive(ref user) => user
+            .badges
+            .iter()
+            .map(|badge| Badge {
+                awarded_at: DateTimeRkyv::deserialize_with(&badge.awarded_at, &mut Infallible)
+                    .unwrap(),
+                description: badge.description.as_ref().into(),
+                image_url: badge.image_url.as_ref().into(),
+                url: badge.url.as_ref().into(),
+            })
+            .collect(),
+    };
+
+    if badges.is_empty() {
+        let content = format!("User `{}` has no badges :(", user.username());
+        let builder = MessageBuilder::new().embed(content);
+        orig.create_message(builder).await?;
+
+        return Ok(());
+    }
+
+    badges.sort_unstable_by_key(|badge| Reverse(badge.awarded_at));
+
+    let pagination = ProfileBadgesPagination::builder()
+        .user(user)
+        .badges(badges.into_boxed_slice())
+        .msg_owner(msg_owner)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
+}
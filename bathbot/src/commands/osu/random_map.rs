@@ -0,0 +1,225 @@
+use std::fmt::Write;
+
+use bathbot_macros::{command, SlashCommand};
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_util::{constants::OSU_API_ISSUE, osu::MapIdType};
+use eyre::{Report, Result};
+use rand::{seq::SliceRandom, Rng};
+use rosu_v2::prelude::{BeatmapsetSearchResult, GameMode, OsuResult, RankStatus};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use super::map::{map, CustomAttrs, GraphPalette, MapArgs};
+use crate::{
+    core::commands::{prefix::Args, CommandOrigin},
+    util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
+    Context,
+};
+
+/// How many random search pages are tried before giving up
+const RANDOM_ATTEMPTS: usize = 3;
+
+/// Amount of mapsets per search page
+const SEARCH_PAGE_SIZE: u32 = 50;
+
+/// The search does not provide results beyond this page
+const MAX_SEARCH_PAGE: u32 = 200;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "randommap",
+    desc = "I'm feeling lucky: Display a random ranked map",
+    help = "I'm feeling lucky: Pick a random ranked map and display it like the `/map` command.\n\
+    The map can optionally be restricted to a gamemode and a star rating range."
+)]
+pub struct RandomMap {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(min_value = 0.0, desc = "Specify a minimum star rating")]
+    min_stars: Option<f64>,
+    #[command(min_value = 0.0, desc = "Specify a maximum star rating")]
+    max_stars: Option<f64>,
+}
+
+impl RandomMap {
+    fn args(args: Args<'_>) -> Result<Self, String> {
+        let mut mode = None;
+        let mut stars = Vec::with_capacity(2);
+
+        for arg in args.take(3) {
+            match arg.to_ascii_lowercase().as_str() {
+                "osu" | "std" | "standard" => mode = Some(GameModeOption::Osu),
+                "taiko" | "tko" => mode = Some(GameModeOption::Taiko),
+                "ctb" | "catch" => mode = Some(GameModeOption::Catch),
+                "mania" | "mna" => mode = Some(GameModeOption::Mania),
+                _ => match arg.parse::<f64>() {
+                    Ok(n) if n >= 0.0 && stars.len() < 2 => stars.push(n),
+                    _ => {
+                        let content = format!(
+                            "Failed to parse `{arg}`.\n\
+                            Be sure to only specify a gamemode and up to two star ratings."
+                        );
+
+                        return Err(content);
+                    }
+                },
+            }
+        }
+
+        Ok(Self {
+            mode,
+            min_stars: stars.first().copied(),
+            max_stars: stars.get(1).copied(),
+        })
+    }
+
+    fn query(&self) -> Result<String, &'static str> {
+        let mut query = String::new();
+
+        if let (Some(min), Some(max)) = (self.min_stars, self.max_stars) {
+            if min > max {
+                return Err("The minimum stars must not be greater than the maximum stars");
+            }
+        }
+
+        if let Some(min) = self.min_stars {
+            let _ = write!(query, "stars>={min}");
+        }
+
+        if let Some(max) = self.max_stars {
+            if !query.is_empty() {
+                query.push(' ');
+            }
+
+            let _ = write!(query, "stars<={max}");
+        }
+
+        Ok(query)
+    }
+
+    /// Pick a random map of the search result that matches the arguments.
+    fn pick(&self, search_result: &BeatmapsetSearchResult) -> Option<u32> {
+        let mode = self.mode.map(GameMode::from);
+        let min_stars = self.min_stars.unwrap_or(0.0) as f32;
+        let max_stars = self.max_stars.map_or(f32::MAX, |max| max as f32);
+
+        let map_ids: Vec<_> = search_result
+            .mapsets
+            .iter()
+            .filter_map(|mapset| mapset.maps.as_deref())
+            .flatten()
+            .filter(|map| mode.map_or(true, |mode| map.mode == mode))
+            .filter(|map| (min_stars..=max_stars).contains(&map.stars))
+            .map(|map| map.map_id)
+            .collect();
+
+        map_ids.choose(&mut rand::thread_rng()).copied()
+    }
+
+    async fn search(&self, query: &str, page: u32) -> OsuResult<BeatmapsetSearchResult> {
+        let mut search_fut = Context::osu()
+            .beatmapset_search()
+            .status(Some(RankStatus::Ranked))
+            .page(page as usize);
+
+        if !query.is_empty() {
+            search_fut = search_fut.query(query);
+        }
+
+        if let Some(mode) = self.mode {
+            search_fut = search_fut.mode(mode.into());
+        }
+
+        search_fut.await
+    }
+}
+
+#[command]
+#[desc("I'm feeling lucky: Display a random ranked map")]
+#[help(
+    "I'm feeling lucky: Pick a random ranked map and display it like the `map` command.\n\
+    Optionally, specify a gamemode and a minimum and maximum star rating."
+)]
+#[usage("[osu / taiko / ctb / mania] [min stars] [max stars]")]
+#[examples("", "mania", "osu 5 6.5")]
+#[aliases("lucky", "feelinglucky", "randmap")]
+#[group(AllModes)]
+async fn prefix_randommap(msg: &Message, args: Args<'_>) -> Result<()> {
+    match RandomMap::args(args) {
+        Ok(args) => random_map(msg.into(), args).await,
+        Err(content) => {
+            msg.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+async fn slash_randommap(mut command: InteractionCommand) -> Result<()> {
+    let args = RandomMap::from_interaction(command.input_data())?;
+
+    random_map((&mut command).into(), args).await
+}
+
+async fn random_map(orig: CommandOrigin<'_>, args: RandomMap) -> Result<()> {
+    let query = match args.query() {
+        Ok(query) => query,
+        Err(content) => return orig.error(content).await,
+    };
+
+    // The first page tells us how many results there are in total
+    let first_page = match args.search(&query, 1).await {
+        Ok(search_result) => search_result,
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get search results"));
+        }
+    };
+
+    let pages = first_page
+        .total
+        .div_ceil(SEARCH_PAGE_SIZE)
+        .min(MAX_SEARCH_PAGE);
+
+    if pages == 0 {
+        return orig.error("Found no ranked maps for these filters").await;
+    }
+
+    let mut map_id = None;
+
+    for _ in 0..RANDOM_ATTEMPTS {
+        let page = rand::thread_rng().gen_range(1..=pages);
+
+        map_id = if page == 1 {
+            args.pick(&first_page)
+        } else {
+            match args.search(&query, page).await {
+                Ok(search_result) => args.pick(&search_result),
+                Err(err) => {
+                    warn!(?err, page, "Failed to get random search page");
+
+                    None
+                }
+            }
+        };
+
+        if map_id.is_some() {
+            break;
+        }
+    }
+
+    // Fall back to the first page before giving up
+    let Some(map_id) = map_id.or_else(|| args.pick(&first_page)) else {
+        return orig.error("Failed to pick a random map, try again").await;
+    };
+
+    let args = MapArgs {
+        map: Some(MapIdType::Map(map_id)),
+        mods: None,
+        attrs: CustomAttrs::default(),
+        svg: false,
+        palette: GraphPalette::default(),
+    };
+
+    map(orig, args).await
+}
@@ -12,6 +12,7 @@ use rosu_v2::prelude::{CountryCode, GameMode, OsuResult, Rankings as RosuRanking
 use super::{RankingPp, RankingScore};
 use crate::{
     active::{impls::RankingPagination, ActiveMessages},
+    commands::osu::resolve_mode,
     core::commands::CommandOrigin,
     manager::redis::{osu::UserArgs, RedisData},
     util::ChannelExt,
@@ -43,7 +44,10 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankingPp<'_>) -> Result<(
             }
         },
         None => match Context::user_config().with_osu_id(owner).await {
-            Ok(config) => (config.mode.unwrap_or(GameMode::Osu), config.osu),
+            Ok(config) => (
+                resolve_mode(orig.guild_id(), orig.channel_id(), None, config.mode).await,
+                config.osu,
+            ),
             Err(err) => {
                 let _ = orig.error(GENERAL_ISSUE).await;
 
@@ -130,7 +134,10 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankingScore) -> Result
             }
         },
         None => match Context::user_config().with_osu_id(owner).await {
-            Ok(config) => (config.mode.unwrap_or(GameMode::Osu), config.osu),
+            Ok(config) => (
+                resolve_mode(orig.guild_id(), orig.channel_id(), None, config.mode).await,
+                config.osu,
+            ),
             Err(err) => {
                 let _ = orig.error(GENERAL_ISSUE).await;
 
@@ -13,7 +13,7 @@ use rosu_v2::{
 
 use super::RecentFix;
 use crate::{
-    commands::osu::{require_link, user_not_found, FixEntry, FixScore},
+    commands::osu::{require_link, resolve_mode, user_not_found, FixEntry, FixScore},
     core::{commands::CommandOrigin, Context},
     embeds::{EmbedData, FixScoreEmbed},
     manager::redis::osu::{UserArgs, UserArgsSlim},
@@ -32,10 +32,11 @@ pub(super) async fn fix(orig: CommandOrigin<'_>, args: RecentFix) -> Result<()>
         },
     };
 
-    let mode = match args.mode.map(GameMode::from).or(config.mode) {
-        None => GameMode::Osu,
-        Some(GameMode::Mania) => return orig.error("Can't fix mania scores \\:(").await,
-        Some(mode) => mode,
+    let arg_mode = args.mode.map(GameMode::from);
+
+    let mode = match resolve_mode(orig.guild_id(), orig.channel_id(), arg_mode, config.mode).await {
+        GameMode::Mania => return orig.error("Can't fix mania scores \\:(").await,
+        mode => mode,
     };
 
     let legacy_scores = match config.score_data {
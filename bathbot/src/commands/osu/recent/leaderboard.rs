@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use bathbot_macros::command;
 use bathbot_model::command_fields::GameModeOption;
+use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
     constants::{GENERAL_ISSUE, OSU_API_ISSUE},
     matcher,
@@ -18,7 +19,8 @@ use super::RecentLeaderboard;
 use crate::{
     active::{impls::LeaderboardPagination, ActiveMessages},
     commands::osu::{
-        require_link, user_not_found, HasMods, LeaderboardScore, LeaderboardUserScore, ModsResult,
+        require_link, resolve_mode, user_not_found, HasMods, LeaderboardScore,
+        LeaderboardUserScore, ModsResult,
     },
     core::commands::{prefix::Args, CommandOrigin},
     manager::{redis::osu::UserArgs, Mods},
@@ -177,11 +179,13 @@ pub(super) async fn leaderboard(
         }
     };
 
-    let mode = args
-        .mode
-        .map(GameMode::from)
-        .or(config.mode)
-        .unwrap_or(GameMode::Osu);
+    let mode = resolve_mode(
+        orig.guild_id(),
+        orig.channel_id(),
+        args.mode.map(GameMode::from),
+        config.mode,
+    )
+    .await;
 
     let user_id = if let Some(user_id) = user_id!(orig, args) {
         user_id
@@ -191,17 +195,17 @@ pub(super) async fn leaderboard(
         return require_link(&orig).await;
     };
 
-    let score_data = match config.score_data {
-        Some(score_data) => score_data,
-        None => match orig.guild_id() {
-            Some(guild_id) => Context::guild_config()
+    let guild_score_data = match orig.guild_id() {
+        Some(guild_id) => {
+            Context::guild_config()
                 .peek(guild_id, |config| config.score_data)
                 .await
-                .unwrap_or_default(),
-            None => Default::default(),
-        },
+        }
+        None => None,
     };
 
+    let score_data = ScoreData::resolve(config.score_data, guild_score_data);
+
     let legacy_scores = score_data.is_legacy();
 
     // Retrieve the recent scores
@@ -336,6 +340,7 @@ pub(super) async fn leaderboard(
         Some(mods) => Mods {
             bits: mods.bits(),
             clock_rate: Some(mods.legacy_clock_rate()),
+            ..Mods::default()
         },
         None => Mods::default(),
     };
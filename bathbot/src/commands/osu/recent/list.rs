@@ -27,7 +27,7 @@ use rosu_v2::{
 use super::{RecentList, RecentListUnique};
 use crate::{
     active::{impls::RecentListPagination, ActiveMessages},
-    commands::osu::{require_link, user_not_found, HasMods, ModsResult, ScoreOrder},
+    commands::osu::{require_link, resolve_mode, user_not_found, HasMods, ModsResult, ScoreOrder},
     core::commands::{prefix::Args, CommandOrigin},
     manager::{redis::osu::UserArgs, Mods, OsuMap},
     util::{
@@ -337,10 +337,13 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: RecentList<'_>) -> Resul
         },
     };
 
-    let mode = match args.mode.map(GameMode::from).or(config.mode) {
-        None => GameMode::Osu,
-        Some(mode) => mode,
-    };
+    let mode = resolve_mode(
+        orig.guild_id(),
+        orig.channel_id(),
+        args.mode.map(GameMode::from),
+        config.mode,
+    )
+    .await;
 
     let legacy_scores = match config.score_data {
         Some(score_data) => score_data.is_legacy(),
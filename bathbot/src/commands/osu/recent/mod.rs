@@ -68,7 +68,12 @@ pub struct RecentScore<'a> {
     index: Option<Cow<'a, str>>,
     #[command(desc = "Consider only scores with this grade")]
     grade: Option<GradeOption>,
-    #[command(desc = "Specify whether only passes should be considered")]
+    #[command(
+        desc = "Specify whether only passes should be considered",
+        help = "Specify whether only passes should be considered.\n\
+        If set to `true`, failed plays are skipped so the `index` only counts passes, \
+        e.g. `index:2` shows the second most recent pass."
+    )]
     passes: Option<bool>,
     #[command(
         desc = "Specify a linked discord user",
@@ -166,6 +171,10 @@ impl<'a> TryFrom<RecentBest> for TopArgs<'a> {
             index: args.index,
             query: args.query,
             size: args.size,
+            weighted: false,
+            unchoked: false,
+            fc_total: false,
+            export: None,
             has_dash_r: false,
             has_dash_p_or_i: false,
         })
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, mem, sync::Arc};
+use std::{borrow::Cow, fmt::Write, mem, sync::Arc};
 
 use bathbot_macros::{command, HasName, SlashCommand};
 use bathbot_model::{
@@ -29,7 +29,7 @@ use crate::{
         ActiveMessages,
     },
     commands::{
-        osu::{map_strain_graph, require_link, user_not_found},
+        osu::{map_strain_graph, require_link, resolve_mode, user_not_found, GraphPalette},
         utility::{MissAnalyzerCheck, ScoreEmbedDataWrap},
     },
     core::commands::{interaction::InteractionCommands, prefix::Args, CommandOrigin},
@@ -358,11 +358,13 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         score_data: guild_score_data,
     } = guild_values;
 
-    let mode = args
-        .mode
-        .map(GameMode::from)
-        .or(config.mode)
-        .unwrap_or(GameMode::Osu);
+    let mode = resolve_mode(
+        orig.guild_id(),
+        orig.channel_id(),
+        args.mode.map(GameMode::from),
+        config.mode,
+    )
+    .await;
 
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
@@ -391,7 +393,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         _ => false,
     };
 
-    let score_data = config.score_data.or(guild_score_data).unwrap_or_default();
+    let score_data = ScoreData::resolve(config.score_data, guild_score_data);
     let legacy_scores = score_data.is_legacy();
 
     let scores_fut = Context::osu_scores()
@@ -411,13 +413,18 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         Ok((user, scores)) if scores.is_empty() => {
             let username = user.username();
             let content = format!(
-                "No recent {}plays found for user `{username}`",
+                "No recent {}{} found for user `{username}`",
                 match mode {
                     GameMode::Osu => "",
                     GameMode::Taiko => "taiko ",
                     GameMode::Catch => "ctb ",
                     GameMode::Mania => "mania ",
                 },
+                if passes == Some(true) {
+                    "passes"
+                } else {
+                    "plays"
+                },
             );
 
             return orig.error(content).await;
@@ -481,13 +488,23 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
     let Some([score, prev_scores @ ..]) = scores.get(num..) else {
         let len = scores.len();
         let username = user.username();
+        let genitive = if username.ends_with('s') { "" } else { "s" };
 
-        let content = format!(
-            "There {verb} only {len} score{plural} in `{username}`'{genitive} recent history.",
-            verb = if len != 1 { "are" } else { "is" },
-            plural = if len != 1 { "s" } else { "" },
-            genitive = if username.ends_with('s') { "" } else { "s" }
-        );
+        let content = match (grade, passes) {
+            (None, Some(true)) if len == 0 => {
+                format!("There are no passes in `{username}`'{genitive} recent history.")
+            }
+            (None, Some(true)) => format!(
+                "There {verb} only {len} pass{plural} in `{username}`'{genitive} recent history.",
+                verb = if len != 1 { "are" } else { "is" },
+                plural = if len != 1 { "es" } else { "" },
+            ),
+            _ => format!(
+                "There {verb} only {len} score{plural} in `{username}`'{genitive} recent history.",
+                verb = if len != 1 { "are" } else { "is" },
+                plural = if len != 1 { "s" } else { "" },
+            ),
+        };
 
         return orig.error(content).await;
     };
@@ -495,61 +512,54 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
     let map_id = score.map_id;
     let mods = &score.mods;
 
-    let tries = match retries {
-        Retries::Hide => None,
-        Retries::ConsiderMods => {
-            // Treats DT & NC as well as SD & PF as the same.
-            fn same_mods(a: &GameMods, b: &GameMods) -> bool {
-                a.iter().zip(b.iter()).all(|(a, b)| match (a, b) {
-                    (GameMod::DoubleTimeOsu(a), GameMod::NightcoreOsu(b))
-                    | (GameMod::NightcoreOsu(b), GameMod::DoubleTimeOsu(a)) => {
-                        a.speed_change.eq(&b.speed_change)
-                    }
-                    (GameMod::SuddenDeathOsu(a), GameMod::PerfectOsu(b))
-                    | (GameMod::PerfectOsu(b), GameMod::SuddenDeathOsu(a)) => {
-                        a.restart.eq(&b.restart)
-                    }
-                    (GameMod::DoubleTimeTaiko(a), GameMod::NightcoreTaiko(b))
-                    | (GameMod::NightcoreTaiko(b), GameMod::DoubleTimeTaiko(a)) => {
-                        a.speed_change.eq(&b.speed_change)
-                    }
-                    (GameMod::SuddenDeathTaiko(a), GameMod::PerfectTaiko(b))
-                    | (GameMod::PerfectTaiko(b), GameMod::SuddenDeathTaiko(a)) => {
-                        a.restart.eq(&b.restart)
-                    }
-                    (GameMod::DoubleTimeCatch(a), GameMod::NightcoreCatch(b))
-                    | (GameMod::NightcoreCatch(b), GameMod::DoubleTimeCatch(a)) => {
-                        a.speed_change.eq(&b.speed_change)
-                    }
-                    (GameMod::SuddenDeathCatch(a), GameMod::PerfectCatch(b))
-                    | (GameMod::PerfectCatch(b), GameMod::SuddenDeathCatch(a)) => {
-                        a.restart.eq(&b.restart)
-                    }
-                    (GameMod::DoubleTimeMania(a), GameMod::NightcoreMania(b))
-                    | (GameMod::NightcoreMania(b), GameMod::DoubleTimeMania(a)) => {
-                        a.speed_change.eq(&b.speed_change)
-                    }
-                    (GameMod::SuddenDeathMania(a), GameMod::PerfectMania(b))
-                    | (GameMod::PerfectMania(b), GameMod::SuddenDeathMania(a)) => {
-                        a.restart.eq(&b.restart)
-                    }
-                    (a, b) => a.eq(b),
-                })
+    // Treats DT & NC as well as SD & PF as the same.
+    fn same_mods(a: &GameMods, b: &GameMods) -> bool {
+        a.iter().zip(b.iter()).all(|(a, b)| match (a, b) {
+            (GameMod::DoubleTimeOsu(a), GameMod::NightcoreOsu(b))
+            | (GameMod::NightcoreOsu(b), GameMod::DoubleTimeOsu(a)) => {
+                a.speed_change.eq(&b.speed_change)
+            }
+            (GameMod::SuddenDeathOsu(a), GameMod::PerfectOsu(b))
+            | (GameMod::PerfectOsu(b), GameMod::SuddenDeathOsu(a)) => a.restart.eq(&b.restart),
+            (GameMod::DoubleTimeTaiko(a), GameMod::NightcoreTaiko(b))
+            | (GameMod::NightcoreTaiko(b), GameMod::DoubleTimeTaiko(a)) => {
+                a.speed_change.eq(&b.speed_change)
+            }
+            (GameMod::SuddenDeathTaiko(a), GameMod::PerfectTaiko(b))
+            | (GameMod::PerfectTaiko(b), GameMod::SuddenDeathTaiko(a)) => a.restart.eq(&b.restart),
+            (GameMod::DoubleTimeCatch(a), GameMod::NightcoreCatch(b))
+            | (GameMod::NightcoreCatch(b), GameMod::DoubleTimeCatch(a)) => {
+                a.speed_change.eq(&b.speed_change)
             }
+            (GameMod::SuddenDeathCatch(a), GameMod::PerfectCatch(b))
+            | (GameMod::PerfectCatch(b), GameMod::SuddenDeathCatch(a)) => a.restart.eq(&b.restart),
+            (GameMod::DoubleTimeMania(a), GameMod::NightcoreMania(b))
+            | (GameMod::NightcoreMania(b), GameMod::DoubleTimeMania(a)) => {
+                a.speed_change.eq(&b.speed_change)
+            }
+            (GameMod::SuddenDeathMania(a), GameMod::PerfectMania(b))
+            | (GameMod::PerfectMania(b), GameMod::SuddenDeathMania(a)) => a.restart.eq(&b.restart),
+            (a, b) => a.eq(b),
+        })
+    }
 
-            Some(
-                1 + prev_scores
-                    .iter()
-                    .take_while(|s| same_mods(&s.mods, mods) && s.map_id == map_id)
-                    .count(),
-            )
-        }
-        Retries::IgnoreMods => Some(
-            1 + prev_scores
-                .iter()
-                .take_while(|s| s.map_id == map_id)
-                .count(),
-        ),
+    let is_retry = |prev: &Score| match retries {
+        Retries::Hide => false,
+        Retries::ConsiderMods => same_mods(&prev.mods, mods) && prev.map_id == map_id,
+        Retries::IgnoreMods => prev.map_id == map_id,
+    };
+
+    let tries = (retries != Retries::Hide)
+        .then(|| 1 + prev_scores.iter().take_while(|prev| is_retry(prev)).count());
+
+    // Fails right before a pass are the attempts it took to pass
+    let fails_until_pass = if score.passed {
+        prev_scores
+            .iter()
+            .take_while(|prev| is_retry(prev) && !prev.passed)
+            .count()
+    } else {
+        0
     };
 
     let user_id = user.user_id();
@@ -587,10 +597,13 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         }
     };
 
-    with_miss_analyzer &= mode == GameMode::Osu;
+    // The miss analyzer responds publicly so it's unavailable for ephemeral
+    // responses
+    with_miss_analyzer &= mode == GameMode::Osu && !orig.is_ephemeral();
     with_render &= settings.buttons.render
         && mode == GameMode::Osu
         && orig.has_permission_to(Permissions::SEND_MESSAGES)
+        && !orig.is_ephemeral()
         && Context::ordr().is_some();
 
     #[cfg(feature = "twitch")]
@@ -634,10 +647,13 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
     );
 
     let mut content = tries.map_or(SingleScoreContent::None, |tries| {
-        SingleScoreContent::OnlyForIndex {
-            idx: num,
-            content: format!("Try #{tries}"),
+        let mut content = format!("Try #{tries}");
+
+        if fails_until_pass > 0 {
+            let _ = write!(content, " • Passed after {} attempts", fails_until_pass + 1);
         }
+
+        SingleScoreContent::OnlyForIndex { idx: num, content }
     });
 
     if missing_settings {
@@ -690,6 +706,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
                         &entry.map.pp_map,
                         entry.score.mods.clone(),
                         entry.map.cover(),
+                        GraphPalette::default(),
                     );
 
                     match fut.await {
@@ -841,7 +858,12 @@ pub struct Rs<'a> {
     index: Option<Cow<'a, str>>,
     #[command(desc = "Consider only scores with this grade")]
     grade: Option<GradeOption>,
-    #[command(desc = "Specify whether only passes should be considered")]
+    #[command(
+        desc = "Specify whether only passes should be considered",
+        help = "Specify whether only passes should be considered.\n\
+        If set to `true`, failed plays are skipped so the `index` only counts passes, \
+        e.g. `index:2` shows the second most recent pass."
+    )]
     passes: Option<bool>,
     #[command(
         desc = "Specify a linked discord user",
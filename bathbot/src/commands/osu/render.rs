@@ -124,7 +124,9 @@ pub async fn slash_render(mut command: InteractionCommand) -> Result<()> {
 async fn render_replay(command: InteractionCommand, replay: RenderReplay) -> Result<()> {
     let owner = command.user_id()?;
 
-    if let Some(cooldown) = Context::check_ratelimit(owner, BucketName::Render) {
+    if let Some(cooldown) =
+        Context::check_guild_ratelimit(owner, command.guild_id, BucketName::Render).await
+    {
         trace!("Ratelimiting user {owner} on bucket `Render` for {cooldown} seconds");
 
         let content = format!("Command on cooldown, try again in {cooldown} seconds");
@@ -238,7 +240,9 @@ async fn render_score(mut command: InteractionCommand, score: RenderScore) -> Re
         Err(err) => warn!(?err),
     }
 
-    if let Some(cooldown) = Context::check_ratelimit(owner, BucketName::Render) {
+    if let Some(cooldown) =
+        Context::check_guild_ratelimit(owner, command.guild_id, BucketName::Render).await
+    {
         trace!("Ratelimiting user {owner} on bucket `Render` for {cooldown} seconds");
 
         let content = format!("Command on cooldown, try again in {cooldown} seconds");
@@ -0,0 +1,183 @@
+use std::fmt::Write;
+
+use bathbot_macros::{command, SlashCommand};
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE, OSU_BASE},
+    matcher,
+    numbers::WithComma,
+    CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder, ModsFormatter,
+};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{GameMode, OsuError};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    core::commands::CommandOrigin,
+    manager::MapError,
+    util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "reworkpp",
+    desc = "Compare a score's pp on osu! with Bathbot's own calculation",
+    help = "Compare the pp value osu! shows for a score with the pp that \
+    Bathbot calculates for it through rosu-pp.\n\
+    osu! keeps recalculating scores so both values are usually based on the same algorithm \
+    and differences mostly indicate pending recalculations or deviations of rosu-pp."
+)]
+pub struct ReworkPp {
+    #[command(desc = "Specify a score url")]
+    score: String,
+}
+
+async fn slash_reworkpp(mut command: InteractionCommand) -> Result<()> {
+    let args = ReworkPp::from_interaction(command.input_data())?;
+
+    rework_pp((&mut command).into(), &args.score).await
+}
+
+#[command]
+#[desc("Compare a score's pp on osu! with Bathbot's own calculation")]
+#[help(
+    "Compare the pp value osu! shows for a score with the pp that \
+    Bathbot calculates for it through rosu-pp.\n\
+    osu! keeps recalculating scores so both values are usually based on the same algorithm \
+    and differences mostly indicate pending recalculations or deviations of rosu-pp."
+)]
+#[usage("[score url]")]
+#[example("https://osu.ppy.sh/scores/osu/4199510391")]
+#[aliases("recalcpp", "recalc")]
+#[group(AllModes)]
+async fn prefix_reworkpp(msg: &Message, mut args: Args<'_>) -> Result<()> {
+    match args.next() {
+        Some(arg) => rework_pp(msg.into(), arg).await,
+        None => {
+            msg.error("You must specify a score url").await?;
+
+            Ok(())
+        }
+    }
+}
+
+async fn rework_pp(orig: CommandOrigin<'_>, score: &str) -> Result<()> {
+    let Some((mode, score_id)) = matcher::get_osu_score_id(score) else {
+        let content = "Failed to parse score url. \
+            Be sure to specify a url of the form `https://osu.ppy.sh/scores/{mode}/{id}`.";
+
+        return orig.error(content).await;
+    };
+
+    let score = match Context::osu().score(score_id).mode(mode).await {
+        Ok(score) => score,
+        Err(OsuError::NotFound) => {
+            let content = format!("Score with id {score_id} was not found");
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get score");
+
+            return Err(err);
+        }
+    };
+
+    let checksum = score.map.as_ref().and_then(|map| map.checksum.as_deref());
+
+    let map = match Context::osu_map().map(score.map_id, checksum).await {
+        Ok(map) => map,
+        Err(MapError::NotFound) => {
+            let content = format!(
+                "Failed to load the map with id {} so the score can't be recalculated",
+                score.map_id
+            );
+
+            return orig.error(content).await;
+        }
+        Err(MapError::Report(err)) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let attrs = Context::pp(&map).score(&score).performance().await;
+    let bathbot_pp = attrs.pp() as f32;
+
+    let mut description = String::with_capacity(256);
+
+    let _ = writeln!(
+        description,
+        "[{artist} - {title} [{version}]]({OSU_BASE}b/{map_id}) **+{mods}** [{stars:.2}★]",
+        artist = map.artist().cow_escape_markdown(),
+        title = map.title().cow_escape_markdown(),
+        version = map.version().cow_escape_markdown(),
+        map_id = map.map_id(),
+        mods = ModsFormatter::new(&score.mods),
+        stars = attrs.stars(),
+    );
+
+    let _ = writeln!(
+        description,
+        "Set <t:{}:R> with {:.2}% accuracy\n",
+        score.ended_at.unix_timestamp(),
+        score.accuracy,
+    );
+
+    match score.pp {
+        Some(osu_pp) => {
+            let delta = bathbot_pp - osu_pp;
+
+            let _ = writeln!(description, "osu! pp: **{}pp**", WithComma::new(osu_pp));
+
+            let _ = writeln!(
+                description,
+                "Bathbot (rosu-pp) pp: **{}pp**",
+                WithComma::new(bathbot_pp)
+            );
+
+            let _ = write!(
+                description,
+                "Difference: **{sign}{delta}pp**",
+                sign = if delta < 0.0 { "-" } else { "+" },
+                delta = WithComma::new(delta.abs()),
+            );
+
+            if osu_pp > 0.0 {
+                let _ = write!(description, " ({:+.2}%)", delta / osu_pp * 100.0);
+            }
+        }
+        None => {
+            description.push_str("osu! pp: -\n");
+
+            let _ = write!(
+                description,
+                "Bathbot (rosu-pp) pp: **{}pp**\n\
+                osu! shows no pp for the score so there is nothing to compare to",
+                WithComma::new(bathbot_pp)
+            );
+        }
+    }
+
+    let mode_str = match mode {
+        GameMode::Osu => "osu",
+        GameMode::Taiko => "taiko",
+        GameMode::Catch => "fruits",
+        GameMode::Mania => "mania",
+    };
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .footer(FooterBuilder::new(
+            "Differences may stem from pending recalculations on osu! or from rosu-pp",
+        ))
+        .title("osu! pp vs. Bathbot pp")
+        .url(format!("{OSU_BASE}scores/{mode_str}/{score_id}"));
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
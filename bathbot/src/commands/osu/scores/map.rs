@@ -1,6 +1,6 @@
 use std::{borrow::Cow, fmt::Write};
 
-use bathbot_model::Countries;
+use bathbot_model::{command_fields::GameModeOption, Countries};
 use bathbot_util::{
     constants::GENERAL_ISSUE,
     matcher,
@@ -28,10 +28,9 @@ use crate::{
 
 pub async fn map_scores(mut command: InteractionCommand, args: MapScores) -> Result<()> {
     let Some(guild_id) = command.guild_id else {
-        // TODO: use mode when /cs uses it
         let MapScores {
             map,
-            mode: _,
+            mode,
             sort,
             mods,
             country: _,
@@ -73,6 +72,9 @@ pub async fn map_scores(mut command: InteractionCommand, args: MapScores) -> Res
             name: None,
             map: map.map(Cow::Owned),
             difficulty: AutocompleteValue::None,
+            mode: mode
+                .and_then(Option::<GameMode>::from)
+                .map(GameModeOption::from),
             sort,
             mods: mods.map(Cow::Owned),
             index,
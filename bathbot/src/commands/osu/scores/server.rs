@@ -87,6 +87,18 @@ pub async fn server_scores(mut command: InteractionCommand, args: ServerScores)
         }
     };
 
+    let members = match Context::user_config()
+        .guild_members(guild_id, members)
+        .await
+    {
+        Ok(members) => members,
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
     let mode = mode_res.unwrap_or_else(|err| {
         warn!(?err);
 
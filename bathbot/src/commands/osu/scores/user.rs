@@ -14,7 +14,7 @@ use rosu_v2::{
 use super::{process_scores, separate_content, MapStatus, ScoresOrder, UserScores};
 use crate::{
     active::{impls::ScoresUserPagination, ActiveMessages},
-    commands::osu::{require_link, user_not_found, HasMods, ModsResult},
+    commands::osu::{require_link, resolve_mode, user_not_found, HasMods, ModsResult},
     core::{commands::CommandOrigin, Context},
     manager::redis::{osu::UserArgs, RedisData},
     util::{
@@ -43,7 +43,15 @@ pub async fn user_scores(mut command: InteractionCommand, args: UserScores) -> R
     let author_id = command.user_id()?;
     let config = Context::user_config().with_osu_id(author_id).await?;
 
-    let mode = args.mode.map_or(config.mode, Option::from);
+    // Explicitly specifying all modes is the only way to not use a single mode
+    let mode = match args.mode.map(Option::<GameMode>::from) {
+        Some(None) => None,
+        arg_mode => {
+            let arg_mode = arg_mode.flatten();
+
+            Some(resolve_mode(command.guild_id, command.channel_id, arg_mode, config.mode).await)
+        }
+    };
 
     let user_id = {
         let orig = CommandOrigin::from(&mut command);
@@ -156,6 +156,18 @@ async fn slash_serverleaderboard(mut command: InteractionCommand) -> Result<()>
         }
     };
 
+    let members = match Context::user_config()
+        .guild_members(guild_id, members)
+        .await
+    {
+        Ok(members) => members,
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
     let guild_icon = cache
         .guild(guild_id)
         .await
@@ -77,6 +77,21 @@ pub struct Simulate<'m> {
     od: Option<f32>,
     #[command(desc = "Specify a .osu file")]
     file: Option<Attachment>,
+    #[command(
+        desc = "Remember your mods for future simulations of the same mode",
+        help = "If enabled, the mods you specify will be remembered for the map's mode \
+        and are used whenever you simulate on that mode without specifying mods.\n\
+        Specifying mods always overrides the remembered ones.\n\
+        Disabling it forgets all remembered mods."
+    )]
+    remember_mods: Option<bool>,
+    #[command(
+        desc = "Show the individual pp components",
+        help = "Show how the total pp are made up of the pp for each skill \
+        e.g. aim, speed, accuracy, and flashlight for osu!standard.\n\
+        Only available for the current pp versions; catch has no components."
+    )]
+    verbose: Option<bool>,
 }
 
 pub async fn slash_simulate(mut command: InteractionCommand) -> Result<()> {
@@ -111,7 +126,31 @@ async fn simulate(orig: CommandOrigin<'_>, mut args: SimulateArgs) -> Result<()>
         SimulateMap::Attached(ref map) => map.max_combo,
     };
 
-    let mods = match args.mods.map(|mods| mods.try_with_mode(mode)) {
+    let owner = orig.user_id()?;
+    let user_config = Context::user_config();
+
+    if let Some(remember) = args.remember_mods {
+        if let Err(err) = user_config.remember_simulate_mods(owner, remember).await {
+            warn!(?err, "Failed to update simulate mods toggle");
+        }
+    }
+
+    // Explicit mods take precedence over remembered ones
+    let explicit_mods = args.mods.is_some();
+
+    let mods_intermode = match args.mods {
+        Some(mods) => Some(mods),
+        None => match user_config.simulate_mods(owner, mode).await {
+            Ok(mods) => mods,
+            Err(err) => {
+                warn!(?err, "Failed to get remembered simulate mods");
+
+                None
+            }
+        },
+    };
+
+    let mods = match mods_intermode.as_ref().map(|mods| mods.try_with_mode(mode)) {
         Some(mods @ Some(_)) => mods,
         None => None,
         Some(None) => {
@@ -121,6 +160,12 @@ async fn simulate(orig: CommandOrigin<'_>, mut args: SimulateArgs) -> Result<()>
         }
     };
 
+    if let Some(mods) = mods_intermode.as_ref().filter(|_| explicit_mods) {
+        if let Err(err) = user_config.store_simulate_mods(owner, mode, mods).await {
+            warn!(?err, "Failed to store simulate mods");
+        }
+    }
+
     let simulate_data = SimulateData {
         mods,
         acc: args.acc,
@@ -142,9 +187,10 @@ async fn simulate(orig: CommandOrigin<'_>, mut args: SimulateArgs) -> Result<()>
         score: None,
         version,
         max_combo,
+        verbose: args.verbose,
     };
 
-    let active = SimulateComponents::new(map, simulate_data, orig.user_id()?);
+    let active = SimulateComponents::new(map, simulate_data, owner);
 
     ActiveMessages::builder(active)
         .start_by_update(true)
@@ -173,11 +219,12 @@ async fn simulate(orig: CommandOrigin<'_>, mut args: SimulateArgs) -> Result<()>
     - ar: `ar=[number]` or `ar[number]`\n\
     - cs: `cs=[number]` or `cs[number]`\n\
     - hp: `hp=[number]` or `hp[number]`\n\
-    - od: `od=[number]` or `od[number]`"
+    - od: `od=[number]` or `od[number]`\n\
+    Add `verbose` to show the individual pp components."
 )]
 #[usage(
     "[map url / map id] [+mods] [acc%] [combox] [clockrate*] \
-    [n300x300] [n100x100] [n50x50] [missesm] [gekisxgeki] [katusxkatus]"
+    [n300x300] [n100x100] [n50x50] [missesm] [gekisxgeki] [katusxkatus] [verbose]"
 )]
 #[example("1980365 +hdhr 4000x 1m 2499x300 99.1% 1.05*")]
 #[alias("s", "sim")]
@@ -213,11 +260,12 @@ async fn prefix_simulate(
     - ar: `ar=[number]` or `ar[number]`\n\
     - cs: `cs=[number]` or `cs[number]`\n\
     - hp: `hp=[number]` or `hp[number]`\n\
-    - od: `od=[number]` or `od[number]`"
+    - od: `od=[number]` or `od[number]`\n\
+    Add `verbose` to show the individual pp components."
 )]
 #[usage(
     "[map url / map id] [+mods] [acc%] [combox] [clockrate*] \
-    [n300x300] [n100x100] [missesm]"
+    [n300x300] [n100x100] [missesm] [verbose]"
 )]
 #[example("1980365 +hdhr 4000x 1m 2499x300 99.1% 1.05*")]
 #[alias("st", "simt", "simtaiko")]
@@ -298,11 +346,12 @@ async fn prefix_simulatectb(
     - ar: `ar=[number]` or `ar[number]`\n\
     - cs: `cs=[number]` or `cs[number]`\n\
     - hp: `hp=[number]` or `hp[number]`\n\
-    - od: `od=[number]` or `od[number]`"
+    - od: `od=[number]` or `od[number]`\n\
+    Add `verbose` to show the individual pp components."
 )]
 #[usage(
     "[map url / map id] [+mods] [acc%] [combox] [clockrate*] \
-    [n300x300] [n100x100] [n50x50] [missesm] [n320x320] [n200x200]"
+    [n300x300] [n100x100] [n50x50] [missesm] [n320x320] [n200x200] [verbose]"
 )]
 #[example("1980365 +hdhr 1m 4000x 2499x300 99.1% 1.05* 42x200")]
 #[alias("sm", "simm", "simmania")]
@@ -409,6 +458,8 @@ struct SimulateArgs {
     cs: Option<f32>,
     hp: Option<f32>,
     od: Option<f32>,
+    remember_mods: Option<bool>,
+    verbose: bool,
 }
 
 impl SimulateArgs {
@@ -447,6 +498,12 @@ impl SimulateArgs {
                 continue;
             }
 
+            if arg.eq_ignore_ascii_case("verbose") || arg == "-v" {
+                simulate.verbose = true;
+
+                continue;
+            }
+
             match SimulateArg::parse(arg).map_err(ParseError::into_str)? {
                 SimulateArg::Acc(val) => simulate.acc = Some(val.clamp(0.0, 100.0)),
                 SimulateArg::Bpm(val) => simulate.bpm = Some(val),
@@ -517,6 +574,8 @@ impl SimulateArgs {
             cs: simulate.cs,
             hp: simulate.hp,
             od: simulate.od,
+            remember_mods: simulate.remember_mods,
+            verbose: simulate.verbose.unwrap_or(false),
         })
     }
 }
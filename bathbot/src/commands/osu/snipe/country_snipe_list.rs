@@ -16,7 +16,7 @@ use rosu_v2::{
 use super::{SnipeCountryList, SnipeGameMode};
 use crate::{
     active::{impls::SnipeCountryListPagination, ActiveMessages},
-    commands::osu::user_not_found,
+    commands::osu::{resolve_mode, user_not_found},
     core::commands::{prefix::Args, CommandOrigin},
     manager::redis::{osu::UserArgs, RedisData},
     util::ChannelExt,
@@ -135,7 +135,7 @@ pub(super) async fn country_list(
         Ok(config) => {
             let mode = match mode {
                 Some(mode) => mode.into(),
-                None => config.mode.unwrap_or(GameMode::Osu),
+                None => resolve_mode(orig.guild_id(), orig.channel_id(), None, config.mode).await,
             };
 
             match config.osu {
@@ -0,0 +1,258 @@
+use std::{borrow::Cow, fmt::Write};
+
+use bathbot_macros::command;
+use bathbot_model::{Countries, SnipeMapScore};
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE, OSU_BASE},
+    matcher,
+    numbers::{round, WithComma},
+    osu::MapIdType,
+    EmbedBuilder, FooterBuilder, MessageBuilder,
+};
+use eyre::{Report, Result};
+use rosu_v2::{
+    model::GameMode,
+    prelude::{CountryCode, OsuError},
+    request::UserId,
+};
+use twilight_model::channel::{message::MessageType, Message};
+
+use super::SnipeCountryMap;
+use crate::{
+    commands::osu::{leaderboard::get_map_id, user_not_found},
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::{
+        redis::{osu::UserArgs, RedisData},
+        MapError,
+    },
+    Context,
+};
+
+#[command]
+#[desc("Who holds the country's #1 on a map")]
+#[help(
+    "Display who currently holds the country's #1 on a map and by how much they lead #2.\n\
+    As arguments, provide an optional map url or map id and an optional country acronym, \
+    e.g. `be`.\n\
+    If no map is given, I will choose the last map I can find in the embeds of this channel.\n\
+    If no country is specified, I will take the country of the linked user.\n\
+    Data for osu!standard originates from [Mr Helix](https://osu.ppy.sh/users/2330619)'s \
+    [huismetbenen](https://snipe.huismetbenen.nl/)."
+)]
+#[usage("[map url / map id] [country acronym]")]
+#[examples("2240404 fr", "https://osu.ppy.sh/beatmapsets/902425#osu/2240404")]
+#[aliases("csmap", "snipemap")]
+#[group(Osu)]
+async fn prefix_countrysnipemap(msg: &Message, args: Args<'_>) -> Result<()> {
+    let mut map = None;
+    let mut country = None;
+
+    for arg in args.take(2) {
+        if matcher::get_osu_map_id(arg).is_some() || matcher::get_osu_mapset_id(arg).is_some() {
+            map = Some(Cow::Borrowed(arg));
+        } else {
+            country = Some(Cow::Borrowed(arg));
+        }
+    }
+
+    let reply = msg
+        .referenced_message
+        .as_deref()
+        .filter(|_| msg.kind == MessageType::Reply);
+
+    if let Some(reply) = reply {
+        if let Some(MapIdType::Map(id)) = Context::find_map_id_in_msg(reply).await {
+            map = Some(Cow::Owned(id.to_string()));
+        }
+    }
+
+    let args = SnipeCountryMap { map, country };
+
+    country_map(msg.into(), args).await
+}
+
+pub(super) async fn country_map(orig: CommandOrigin<'_>, args: SnipeCountryMap<'_>) -> Result<()> {
+    let map = match args.map {
+        Some(ref map) => match matcher::get_osu_map_id(map)
+            .map(MapIdType::Map)
+            .or_else(|| matcher::get_osu_mapset_id(map).map(MapIdType::Set))
+        {
+            Some(id) => Some(id),
+            None => {
+                let content =
+                    "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+
+                return orig.error(content).await;
+            }
+        },
+        None => None,
+    };
+
+    let map_id = match get_map_id(&orig, map).await {
+        Ok(map_id) => map_id,
+        Err(content) => return orig.error(content).await,
+    };
+
+    let config = match Context::user_config().with_osu_id(orig.user_id()?).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get user config"));
+        }
+    };
+
+    let mode = GameMode::Osu;
+
+    let country_code = match args.country {
+        Some(ref country) => match Countries::name(country).to_code() {
+            Some(code) => CountryCode::from(code),
+            None if country.len() == 2 => CountryCode::from(country.as_ref()),
+            None => {
+                let content =
+                    format!("Looks like `{country}` is neither a country name nor a country code");
+
+                return orig.error(content).await;
+            }
+        },
+        None => match config.osu {
+            Some(user_id) => {
+                let user_args = UserArgs::user_id(user_id, mode);
+
+                let user = match Context::redis().osu_user(user_args).await {
+                    Ok(user) => user,
+                    Err(OsuError::NotFound) => {
+                        let content = user_not_found(UserId::Id(user_id)).await;
+
+                        return orig.error(content).await;
+                    }
+                    Err(err) => {
+                        let _ = orig.error(OSU_API_ISSUE).await;
+                        let err = Report::new(err).wrap_err("Failed to get user");
+
+                        return Err(err);
+                    }
+                };
+
+                match &user {
+                    RedisData::Original(user) => user.country_code.as_str().into(),
+                    RedisData::Archive(user) => user.country_code.as_str().into(),
+                }
+            }
+            None => {
+                let content = "Since you're not linked, you must specify a country (code)";
+
+                return orig.error(content).await;
+            }
+        },
+    };
+
+    // Check if huisemetbenen supports the country
+    if !Context::huismetbenen()
+        .is_supported(country_code.as_str(), mode)
+        .await
+    {
+        let content = format!("The country code `{country_code}` is not supported :(",);
+
+        return orig.error(content).await;
+    }
+
+    let map = match Context::osu_map().map(map_id, None).await {
+        Ok(map) => map,
+        Err(MapError::NotFound) => {
+            let content = format!(
+                "Could not find beatmap with id `{map_id}`. \
+                Did you give me a mapset id instead of a map id?",
+            );
+
+            return orig.error(content).await;
+        }
+        Err(MapError::Report(err)) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    if map.mode() != GameMode::Osu {
+        let content = "National #1s on maps are only available for osu!standard";
+
+        return orig.error(content).await;
+    }
+
+    let scores = match Context::redis()
+        .snipe_map_leaderboard(&country_code, map_id)
+        .await
+    {
+        Ok(scores) => scores.into_original(),
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get snipe map leaderboard"));
+        }
+    };
+
+    let description = match scores.as_slice() {
+        [] => format!("No national #1 is recorded for `{country_code}` on this map"),
+        [first] => {
+            let mut description = holder_line(first);
+            description.push_str("\nNobody else from the country has a score on this map");
+
+            description
+        }
+        [first, second, ..] => {
+            let mut description = holder_line(first);
+
+            let _ = write!(
+                description,
+                "\nLeads [{name}]({OSU_BASE}u/{user_id}) (#2) by **{margin}** score",
+                name = second.username,
+                user_id = second.user_id,
+                margin = WithComma::new(first.score.saturating_sub(second.score)),
+            );
+
+            if let (Some(first_pp), Some(second_pp)) = (first.pp, second.pp) {
+                let _ = write!(description, " and **{}pp**", round(first_pp - second_pp));
+            }
+
+            description
+        }
+    };
+
+    let title = format!(
+        "{artist} - {title} [{version}]",
+        artist = map.artist(),
+        title = map.title(),
+        version = map.version(),
+    );
+
+    let footer = FooterBuilder::new(format!("National leaderboard of {country_code}"));
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .footer(footer)
+        .thumbnail(map.thumbnail())
+        .title(title)
+        .url(format!("{OSU_BASE}b/{map_id}"));
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+fn holder_line(score: &SnipeMapScore) -> String {
+    let mut line = format!(
+        "#1: [{name}]({OSU_BASE}u/{user_id}) with **{score}** score • {acc}%",
+        name = score.username,
+        user_id = score.user_id,
+        score = WithComma::new(score.score),
+        acc = round(score.accuracy),
+    );
+
+    if let Some(pp) = score.pp {
+        let _ = write!(line, " • {}pp", round(pp));
+    }
+
+    line
+}
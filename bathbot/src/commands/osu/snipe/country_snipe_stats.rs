@@ -19,7 +19,7 @@ use twilight_model::guild::Permissions;
 
 use super::{SnipeCountryStats, SnipeGameMode};
 use crate::{
-    commands::osu::user_not_found,
+    commands::osu::{resolve_mode, user_not_found},
     core::commands::CommandOrigin,
     embeds::{CountrySnipeStatsEmbed, EmbedData},
     manager::redis::{osu::UserArgs, RedisData},
@@ -117,11 +117,13 @@ pub(super) async fn country_stats(
         }
     };
 
-    let mode = args
-        .mode
-        .map(GameMode::from)
-        .or(config.mode)
-        .unwrap_or(GameMode::Osu);
+    let mode = resolve_mode(
+        orig.guild_id(),
+        orig.channel_id(),
+        args.mode.map(GameMode::from),
+        config.mode,
+    )
+    .await;
 
     let country_code = match args.country {
         Some(ref country) => match Countries::name(country).to_code() {
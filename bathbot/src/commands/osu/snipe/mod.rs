@@ -8,12 +8,13 @@ use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand,
 use twilight_model::id::{marker::UserMarker, Id};
 
 pub use self::{
-    country_snipe_list::*, country_snipe_stats::*, player_snipe_list::*, player_snipe_stats::*,
-    sniped::*, sniped_difference::*,
+    country_snipe_list::*, country_snipe_map::*, country_snipe_stats::*, player_snipe_list::*,
+    player_snipe_stats::*, sniped::*, sniped_difference::*,
 };
 use crate::util::{interaction::InteractionCommand, InteractionCommandExt};
 
 mod country_snipe_list;
+mod country_snipe_map;
 mod country_snipe_stats;
 mod player_snipe_list;
 mod sniped_difference;
@@ -42,6 +43,8 @@ pub enum Snipe<'a> {
 pub enum SnipeCountry<'a> {
     #[command(name = "list")]
     List(SnipeCountryList<'a>),
+    #[command(name = "map")]
+    Map(SnipeCountryMap<'a>),
     #[command(name = "stats")]
     Stats(SnipeCountryStats<'a>),
 }
@@ -99,6 +102,25 @@ pub struct SnipeCountryList<'a> {
     sort: Option<SnipeCountryListOrder>,
 }
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "map",
+    desc = "Who holds the country's #1 on a map",
+    help = "Display who currently holds the country's #1 on a map and by how much they lead #2.\n\
+    Only available for osu!standard."
+)]
+pub struct SnipeCountryMap<'a> {
+    #[command(
+        desc = "Specify a map url or map id",
+        help = "Specify a map either by map url or map id.\n\
+        If none is specified, it will search in the recent channel history \
+        and pick the first map it can find."
+    )]
+    map: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a country (code)")]
+    country: Option<Cow<'a, str>>,
+}
+
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "stats", desc = "#1-count related stats for a country")]
 pub struct SnipeCountryStats<'a> {
@@ -228,6 +250,7 @@ pub struct SnipePlayerSniped<'a> {
 async fn slash_snipe(mut command: InteractionCommand) -> Result<()> {
     match Snipe::from_interaction(command.input_data())? {
         Snipe::Country(SnipeCountry::List(args)) => country_list((&mut command).into(), args).await,
+        Snipe::Country(SnipeCountry::Map(args)) => country_map((&mut command).into(), args).await,
         Snipe::Country(SnipeCountry::Stats(args)) => {
             country_stats((&mut command).into(), args).await
         }
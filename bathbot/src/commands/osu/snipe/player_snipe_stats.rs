@@ -16,7 +16,7 @@ use twilight_model::guild::Permissions;
 
 use super::{SnipeGameMode, SnipePlayerStats};
 use crate::{
-    commands::osu::require_link,
+    commands::osu::{require_link, resolve_mode},
     core::commands::{prefix::Args, CommandOrigin},
     embeds::{EmbedData, PlayerSnipeStatsEmbed},
     manager::redis::{osu::UserArgs, RedisData},
@@ -102,11 +102,13 @@ pub(super) async fn player_stats(
         },
     };
 
-    let mode = args
-        .mode
-        .map(GameMode::from)
-        .or(config.mode)
-        .unwrap_or(GameMode::Osu);
+    let mode = resolve_mode(
+        orig.guild_id(),
+        orig.channel_id(),
+        args.mode.map(GameMode::from),
+        config.mode,
+    )
+    .await;
 
     let legacy_scores = match config.score_data {
         Some(score_data) => score_data.is_legacy(),
@@ -0,0 +1,411 @@
+use std::{borrow::Cow, fmt::Write};
+
+use bathbot_macros::{command, HasMods, SlashCommand};
+use bathbot_psql::model::configs::ScoreData;
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE, OSU_BASE},
+    matcher,
+    numbers::{round, WithComma},
+    osu::{MapIdType, ModSelection},
+    EmbedBuilder, FooterBuilder, MessageBuilder,
+};
+use eyre::Result;
+use rosu_pp::any::DifficultyAttributes;
+use rosu_v2::prelude::{GameMods, Score};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    channel::{message::MessageType, Message},
+    guild::Permissions,
+};
+
+use super::{leaderboard::get_map_id, HasMods, ModsResult};
+use crate::{
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::{MapError, Mods, OsuMap},
+    util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
+    Context,
+};
+
+/// The amount of scores the osu!api provides for a map leaderboard.
+const LEADERBOARD_LEN: usize = 100;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "targetrank",
+    desc = "What it takes to reach a position on a map's leaderboard",
+    help = "Check what it takes to reach a position on a map's global leaderboard.\n\
+    The requirement is based on the score that currently occupies the position. \
+    Additionally, the pp of that score and an accuracy that would give as much pp \
+    with a full combo are shown."
+)]
+pub struct TargetRank<'a> {
+    #[command(
+        min_value = 1,
+        max_value = 100,
+        desc = "Specify the target leaderboard position"
+    )]
+    position: u8,
+    #[command(
+        desc = "Specify a map url or map id",
+        help = "Specify a map either by map url or map id.\n\
+        If none is specified, it will search in the recent channel history \
+        and pick the first map it can find."
+    )]
+    map: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify mods e.g. hdhr or nm",
+        help = "Specify mods either directly or through the explicit `+mods!` / `+mods` syntax, \
+        e.g. `hdhr` or `+hdhr!`, to use the leaderboard of those mods."
+    )]
+    mods: Option<Cow<'a, str>>,
+}
+
+#[derive(HasMods)]
+struct TargetRankArgs<'a> {
+    position: usize,
+    map: Option<MapIdType>,
+    mods: Option<Cow<'a, str>>,
+}
+
+impl<'m> TargetRankArgs<'m> {
+    async fn args(msg: &Message, args: Args<'m>) -> Result<TargetRankArgs<'m>, String> {
+        let mut position = None;
+        let mut map = None;
+        let mut mods = None;
+
+        for arg in args.take(3) {
+            if let Some(id) = matcher::get_osu_map_id(arg)
+                .map(MapIdType::Map)
+                .or_else(|| matcher::get_osu_mapset_id(arg).map(MapIdType::Set))
+            {
+                map = Some(id);
+            } else if let Ok(n) = arg.trim_start_matches('#').parse::<usize>() {
+                position = Some(n);
+            } else if matcher::get_mods(arg).is_some() {
+                mods = Some(arg.into());
+            } else {
+                let content = format!(
+                    "Failed to parse `{arg}`.\n\
+                    Must be either a position, a map id, map url, or mods.",
+                );
+
+                return Err(content);
+            }
+        }
+
+        let position = match position {
+            Some(position @ 1..=LEADERBOARD_LEN) => position,
+            Some(_) => return Err("The position must be between 1 and 100".to_owned()),
+            None => return Err("You must specify a target position e.g. `#5`".to_owned()),
+        };
+
+        let reply = msg
+            .referenced_message
+            .as_deref()
+            .filter(|_| msg.kind == MessageType::Reply);
+
+        if let Some(reply) = reply {
+            if let Some(id) = Context::find_map_id_in_msg(reply).await {
+                map = Some(id);
+            }
+        }
+
+        Ok(Self {
+            position,
+            map,
+            mods,
+        })
+    }
+}
+
+impl<'a> TryFrom<TargetRank<'a>> for TargetRankArgs<'a> {
+    type Error = &'static str;
+
+    fn try_from(args: TargetRank<'a>) -> Result<Self, Self::Error> {
+        let TargetRank {
+            position,
+            map,
+            mods,
+        } = args;
+
+        let map = match map.map(|arg| {
+            matcher::get_osu_map_id(&arg)
+                .map(MapIdType::Map)
+                .or_else(|| matcher::get_osu_mapset_id(&arg).map(MapIdType::Set))
+        }) {
+            Some(Some(id)) => Some(id),
+            Some(None) => {
+                let content =
+                    "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+
+                return Err(content);
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            position: position as usize,
+            map,
+            mods,
+        })
+    }
+}
+
+#[command]
+#[desc("What it takes to reach a position on a map's leaderboard")]
+#[help(
+    "Check what it takes to reach a position on a map's global leaderboard.\n\
+    The requirement is based on the score that currently occupies the position.\n\
+    If no map is specified by either url or id, I will choose the last map \
+    I can find in the embeds of this channel."
+)]
+#[usage("[#position] [map url / map id] [+mods]")]
+#[examples(
+    "#5 2240404",
+    "50 https://osu.ppy.sh/beatmapsets/902425#osu/2240404 +hd"
+)]
+#[aliases("tr", "maprank")]
+#[group(AllModes)]
+async fn prefix_targetrank(
+    msg: &Message,
+    args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    match TargetRankArgs::args(msg, args).await {
+        Ok(args) => target_rank(CommandOrigin::from_msg(msg, permissions), args).await,
+        Err(content) => {
+            msg.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+async fn slash_targetrank(mut command: InteractionCommand) -> Result<()> {
+    let args = TargetRank::from_interaction(command.input_data())?;
+
+    match TargetRankArgs::try_from(args) {
+        Ok(args) => target_rank((&mut command).into(), args).await,
+        Err(content) => {
+            command.error(content).await?;
+
+            Ok(())
+        }
+    }
+}
+
+async fn target_rank(orig: CommandOrigin<'_>, args: TargetRankArgs<'_>) -> Result<()> {
+    let mods = match args.mods() {
+        ModsResult::Mods(ModSelection::Include(mods) | ModSelection::Exact(mods)) => Some(mods),
+        ModsResult::None => None,
+        ModsResult::Mods(ModSelection::Exclude(_)) | ModsResult::Invalid => {
+            let content = "Failed to parse mods.\n\
+            If you want a mod-specific leaderboard, specify it e.g. as `+hdhr`.";
+
+            return orig.error(content).await;
+        }
+    };
+
+    let map_id = match get_map_id(&orig, args.map).await {
+        Ok(map_id) => map_id,
+        Err(content) => return orig.error(content).await,
+    };
+
+    let config = Context::user_config().with_osu_id(orig.user_id()?).await?;
+
+    let map = match Context::osu_map().map(map_id, None).await {
+        Ok(map) => map,
+        Err(MapError::NotFound) => {
+            let content = format!(
+                "Could not find beatmap with id `{map_id}`. \
+                Did you give me a mapset id instead of a map id?",
+            );
+
+            return orig.error(content).await;
+        }
+        Err(MapError::Report(err)) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let guild_score_data = match orig.guild_id() {
+        Some(guild_id) => {
+            Context::guild_config()
+                .peek(guild_id, |config| config.score_data)
+                .await
+        }
+        None => None,
+    };
+
+    let score_data = ScoreData::resolve(config.score_data, guild_score_data);
+
+    let mode = map.mode();
+
+    let scores_fut = Context::osu_scores().map_leaderboard(
+        map_id,
+        mode,
+        mods.clone(),
+        LEADERBOARD_LEN as u32,
+        score_data.is_legacy(),
+    );
+
+    let mut scores = match scores_fut.await {
+        Ok(scores) => scores,
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get leaderboard"));
+        }
+    };
+
+    let position = args.position;
+    let leaderboard_name = match mods {
+        Some(ref mods) if mods.is_empty() => "NM leaderboard".to_owned(),
+        Some(ref mods) => format!("+{mods} leaderboard"),
+        None => "global leaderboard".to_owned(),
+    };
+
+    let description = if position > scores.len() {
+        if scores.len() < LEADERBOARD_LEN {
+            format!(
+                "The {leaderboard_name} only has {len} score{plural} so **any pass** \
+                would get you to #{next}",
+                len = scores.len(),
+                plural = if scores.len() == 1 { "" } else { "s" },
+                next = scores.len() + 1,
+            )
+        } else {
+            format!("The {leaderboard_name} only shows the top {LEADERBOARD_LEN} scores")
+        }
+    } else {
+        let score = scores.swap_remove(position - 1);
+
+        // Use the specified mods or otherwise those of the occupant
+        let target_mods = match mods.and_then(|mods| mods.try_with_mode(mode)) {
+            Some(mods) => mods,
+            None => score.mods.clone(),
+        };
+
+        score_description(&map, score, &target_mods, score_data, position).await
+    };
+
+    let title = format!(
+        "{artist} - {title} [{version}]",
+        artist = map.artist(),
+        title = map.title(),
+        version = map.version(),
+    );
+
+    let footer = FooterBuilder::new(format!("Target: #{position} on the {leaderboard_name}"));
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .footer(footer)
+        .thumbnail(map.thumbnail())
+        .title(title)
+        .url(format!("{OSU_BASE}b/{map_id}"));
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+async fn score_description(
+    map: &OsuMap,
+    mut score: Score,
+    target_mods: &GameMods,
+    score_data: ScoreData,
+    position: usize,
+) -> String {
+    let username = score
+        .user
+        .take()
+        .map_or_else(|| "<unknown user>".into(), |user| user.username);
+
+    let pp = match score.pp {
+        Some(pp) => pp,
+        None => Context::pp(map).score(&score).performance().await.pp() as f32,
+    };
+
+    let score_value = match score_data {
+        ScoreData::LazerWithClassicScoring => score.classic_score,
+        ScoreData::Stable | ScoreData::Lazer => score.score,
+    };
+
+    let mut description = format!(
+        "#{position} is held by [{username}]({OSU_BASE}u/{user_id}) with \
+        {score} score • {acc}% • {combo}x • {pp}pp • +{mods}\n\n\
+        To take their spot you need **more than {score}** score.",
+        user_id = score.user_id,
+        score = WithComma::new(score_value),
+        acc = round(score.accuracy),
+        combo = score.max_combo,
+        pp = round(pp),
+        mods = score.mods,
+    );
+
+    let target = Mods::from(target_mods);
+
+    let attrs = Context::pp(map)
+        .mode(score.mode)
+        .mods(target)
+        .difficulty()
+        .await
+        .to_owned();
+
+    match fc_acc_for_pp(&attrs, target, f64::from(pp)) {
+        Some(acc) => {
+            let _ = write!(
+                description,
+                "\nFor **{pp}pp** with +{target_mods}, a full combo with about **{acc}%** \
+                accuracy would do.",
+                pp = round(pp),
+                acc = round(acc as f32),
+            );
+        }
+        None => {
+            let _ = write!(
+                description,
+                "\nEven an SS with +{target_mods} would not be worth their {pp}pp.",
+                pp = round(pp),
+            );
+        }
+    }
+
+    description
+}
+
+/// Find the lowest accuracy for which a full combo is worth at least the given
+/// pp. Returns `None` if not even an SS is worth as much.
+fn fc_acc_for_pp(attrs: &DifficultyAttributes, mods: Mods, target_pp: f64) -> Option<f64> {
+    let pp_at = |acc: f64| {
+        let mut calc = attrs.to_owned().performance().mods(mods.bits).accuracy(acc);
+
+        if let Some(clock_rate) = mods.clock_rate {
+            calc = calc.clock_rate(f64::from(clock_rate));
+        }
+
+        calc.calculate().pp()
+    };
+
+    if pp_at(100.0) < target_pp {
+        return None;
+    }
+
+    let (mut low, mut high) = (0.0, 100.0);
+
+    for _ in 0..20 {
+        let mid = (low + high) / 2.0;
+
+        if pp_at(mid) >= target_pp {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+
+    Some(high)
+}
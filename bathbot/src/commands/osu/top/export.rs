@@ -0,0 +1,133 @@
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+
+use bathbot_util::datetime::DATETIME_Z_FORMAT;
+use eyre::{Result, WrapErr};
+use serde::Serialize;
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+use crate::commands::utility::ScoreEmbedDataWrap;
+
+#[derive(Copy, Clone, CommandOption, CreateOption, Eq, PartialEq)]
+pub enum TopExportFormat {
+    #[option(name = "CSV", value = "csv")]
+    Csv,
+    #[option(name = "JSON", value = "json")]
+    Json,
+}
+
+impl TopExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ExportEntry<'a> {
+    position: usize,
+    map_id: u32,
+    artist: &'a str,
+    title: &'a str,
+    version: &'a str,
+    mods: String,
+    stars: f32,
+    pp: f32,
+    accuracy: f32,
+    combo: u32,
+    max_combo: u32,
+    misses: u32,
+    grade: String,
+    date: String,
+}
+
+/// Serialize the given top scores in the specified format.
+pub fn export_scores(entries: &[ScoreEmbedDataWrap], format: TopExportFormat) -> Result<Vec<u8>> {
+    let entries = entries
+        .iter()
+        .map(|entry| {
+            let entry = entry.get_half();
+            let score = &entry.score;
+
+            let position = entry
+                .original_idx
+                .or(entry.pb_idx.as_ref().and_then(|idx| idx.idx))
+                .map_or(0, |idx| idx + 1);
+
+            let date = score
+                .ended_at
+                .format(DATETIME_Z_FORMAT)
+                .wrap_err("Failed to format score date")?;
+
+            Ok(ExportEntry {
+                position,
+                map_id: entry.map.map_id(),
+                artist: entry.map.artist(),
+                title: entry.map.title(),
+                version: entry.map.version(),
+                mods: score.mods.to_string(),
+                stars: entry.stars,
+                pp: score.pp,
+                accuracy: score.accuracy,
+                combo: score.max_combo,
+                max_combo: entry.max_combo,
+                misses: score.statistics.count_miss,
+                grade: score.grade.to_string(),
+                date,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    match format {
+        TopExportFormat::Csv => Ok(to_csv(&entries).into_bytes()),
+        TopExportFormat::Json => {
+            serde_json::to_vec_pretty(&entries).wrap_err("Failed to serialize scores")
+        }
+    }
+}
+
+fn to_csv(entries: &[ExportEntry<'_>]) -> String {
+    let mut csv = String::with_capacity(64 + entries.len() * 128);
+
+    csv.push_str(
+        "position,map_id,artist,title,version,mods,stars,pp,accuracy,\
+        combo,max_combo,misses,grade,date\n",
+    );
+
+    for entry in entries {
+        let _ = writeln!(
+            csv,
+            "{},{},{},{},{},{},{:.2},{:.2},{:.2},{},{},{},{},{}",
+            entry.position,
+            entry.map_id,
+            CsvField(entry.artist),
+            CsvField(entry.title),
+            CsvField(entry.version),
+            entry.mods,
+            entry.stars,
+            entry.pp,
+            entry.accuracy,
+            entry.combo,
+            entry.max_combo,
+            entry.misses,
+            entry.grade,
+            entry.date,
+        );
+    }
+
+    csv
+}
+
+/// Quotes a field if it contains characters with special meaning in CSV.
+struct CsvField<'a>(&'a str);
+
+impl Display for CsvField<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.0.contains([',', '"', '\n', '\r']) {
+            write!(f, "\"{}\"", self.0.replace('"', "\"\""))
+        } else {
+            f.write_str(self.0)
+        }
+    }
+}
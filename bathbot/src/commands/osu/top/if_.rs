@@ -20,7 +20,7 @@ use twilight_model::id::{marker::UserMarker, Id};
 
 use crate::{
     active::{impls::TopIfPagination, ActiveMessages},
-    commands::osu::{require_link, user_not_found},
+    commands::osu::{require_link, resolve_mode, user_not_found},
     core::commands::{prefix::Args, CommandOrigin},
     manager::{redis::osu::UserArgs, OsuMap},
     util::{
@@ -213,9 +213,15 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
         },
     };
 
-    let mode = match args.mode.map(GameMode::from).or(config.mode) {
-        None | Some(GameMode::Mania) => GameMode::Osu,
-        Some(mode) => mode,
+    let arg_mode = args.mode.map(GameMode::from);
+
+    let mode = match resolve_mode(orig.guild_id(), orig.channel_id(), arg_mode, config.mode).await {
+        GameMode::Mania => {
+            return orig
+                .error("Adjusting mods is not available for mania")
+                .await
+        }
+        mode => mode,
     };
 
     if let Err(content) = mods.clone().validate(mode) {
@@ -11,7 +11,7 @@ use bathbot_util::{
     matcher,
     numbers::round,
     osu::ModSelection,
-    CowUtils,
+    CowUtils, MessageBuilder,
 };
 use eyre::{Report, Result};
 use rand::{thread_rng, Rng};
@@ -25,8 +25,12 @@ use twilight_model::{
     id::{marker::UserMarker, Id},
 };
 
+use self::export::{export_scores, TopExportFormat};
 pub use self::{if_::*, old::*};
-use super::{map_strain_graph, require_link, user_not_found, HasMods, ModsResult, ScoreOrder};
+use super::{
+    map_strain_graph, require_link, resolve_mode, user_not_found, GraphPalette, HasMods,
+    ModsResult, ScoreOrder,
+};
 use crate::{
     active::{
         impls::{SingleScoreContent, SingleScorePagination, TopPagination},
@@ -39,12 +43,14 @@ use crate::{
     manager::redis::osu::UserArgs,
     util::{
         interaction::InteractionCommand,
+        osu::IfFc,
         query::{IFilterCriteria, Searchable, TopCriteria},
         ChannelExt, CheckPermissions, InteractionCommandExt,
     },
     Context,
 };
 
+mod export;
 mod if_;
 mod old;
 
@@ -102,6 +108,33 @@ pub struct Top {
         The default can be set with the `/config` command."
     )]
     size: Option<ListSize>,
+    #[command(
+        desc = "Show how much pp each score contributes to the total",
+        help = "Show how much pp each score contributes to the user's total pp \
+        i.e. its pp weighted by `0.95^(index - 1)`."
+    )]
+    weighted: Option<bool>,
+    #[command(
+        desc = "Show how much pp each score would be worth as FC",
+        help = "For each score with misses or a broken combo, \
+        show how much pp it would be worth as a full combo and the potential gain."
+    )]
+    unchoked: Option<bool>,
+    #[command(
+        desc = "Show the user's total pp if every top score were an FC",
+        help = "Show the user's total pp if every top score were a full combo.\n\
+        Each score's FC pp is recalculated and the scores are reweighted by `0.95^(index - 1)`.\n\
+        Note that this requires additional pp calculations and thus takes a little longer.\n\
+        Since combo does not matter in mania, this does nothing for mania scores."
+    )]
+    fc_total: Option<bool>,
+    #[command(
+        desc = "Attach the scores as a file instead of listing them",
+        help = "Instead of showing the scores in a paginated list, attach them as a CSV or JSON file.\n\
+        The file contains map, mods, stars, pp, accuracy, combo, misses, grade, and date \
+        of every score that matches the other options."
+    )]
+    export: Option<TopExportFormat>,
 }
 
 #[derive(Copy, Clone, Default, CommandOption, CreateOption, Eq, PartialEq)]
@@ -167,13 +200,18 @@ impl From<ScoreOrder> for TopScoreOrder {
      - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
      - `sort`: `acc`, `combo`, `date` (= `rb` command), `length`, or `position` (default)\n\
      - `reverse`: `true` or `false` (default)\n\
+     - `weighted`: `true` or `false` (default), shows each score's weighted pp\n\
+     - `unchoked`: `true` or `false` (default), shows each score's pp if it were an FC\n\
+     - `fctotal`: `true` or `false` (default), shows the total pp if every score were an FC\n\
+     - `export`: `csv` or `json`, attaches the scores as file instead of listing them\n\
      \n\
      Instead of showing the scores in a list, you can also __show a single score__ by \
      specifying a number right after the command, e.g. `<top2 badewanne3`."
 )]
 #[usage(
     "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] \
-    [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false]"
+    [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false] \
+    [weighted=true/false] [unchoked=true/false] [fctotal=true/false] [export=csv/json]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr sort=combo",
@@ -205,13 +243,18 @@ async fn prefix_top(msg: &Message, args: Args<'_>) -> Result<()> {
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `sort`: `acc`, `combo`, `date` (= `rbm` command), `length`, or `position` (default)\n\
     - `reverse`: `true` or `false` (default)\n\
+    - `weighted`: `true` or `false` (default), shows each score's weighted pp\n\
+    - `unchoked`: `true` or `false` (default), shows each score's pp if it were an FC\n\
+    - `fctotal`: `true` or `false` (default), shows the total pp if every score were an FC\n\
+    - `export`: `csv` or `json`, attaches the scores as file instead of listing them\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<topm2 badewanne3`."
 )]
 #[usage(
     "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] \
-    [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false]"
+    [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false] \
+    [weighted=true/false] [unchoked=true/false] [fctotal=true/false] [export=csv/json]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr sort=combo",
@@ -243,13 +286,18 @@ async fn prefix_topmania(msg: &Message, args: Args<'_>) -> Result<()> {
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `sort`: `acc`, `combo`, `date` (= `rbt` command), `length`, or `position` (default)\n\
     - `reverse`: `true` or `false` (default)\n\
+    - `weighted`: `true` or `false` (default), shows each score's weighted pp\n\
+    - `unchoked`: `true` or `false` (default), shows each score's pp if it were an FC\n\
+    - `fctotal`: `true` or `false` (default), shows the total pp if every score were an FC\n\
+    - `export`: `csv` or `json`, attaches the scores as file instead of listing them\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<topt2 badewanne3`."
 )]
 #[usage(
     "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] \
-    [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false]"
+    [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false] \
+    [weighted=true/false] [unchoked=true/false] [fctotal=true/false] [export=csv/json]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr sort=combo",
@@ -281,13 +329,18 @@ async fn prefix_toptaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `sort`: `acc`, `combo`, `date` (= `rbc` command), `length`, or `position` (default)\n\
     - `reverse`: `true` or `false` (default)\n\
+    - `weighted`: `true` or `false` (default), shows each score's weighted pp\n\
+    - `unchoked`: `true` or `false` (default), shows each score's pp if it were an FC\n\
+    - `fctotal`: `true` or `false` (default), shows the total pp if every score were an FC\n\
+    - `export`: `csv` or `json`, attaches the scores as file instead of listing them\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<topc2 badewanne3`."
 )]
 #[usage(
     "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] \
-   [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false]"
+   [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false] \
+    [weighted=true/false] [unchoked=true/false] [fctotal=true/false] [export=csv/json]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr sort=combo",
@@ -318,12 +371,17 @@ async fn prefix_topctb(msg: &Message, args: Args<'_>) -> Result<()> {
     - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `reverse`: `true` or `false` (default)\n\
+    - `weighted`: `true` or `false` (default), shows each score's weighted pp\n\
+    - `unchoked`: `true` or `false` (default), shows each score's pp if it were an FC\n\
+    - `fctotal`: `true` or `false` (default), shows the total pp if every score were an FC\n\
+    - `export`: `csv` or `json`, attaches the scores as file instead of listing them\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<rb2 badewanne3`."
 )]
 #[usage(
-    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false]"
+    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false] \
+    [weighted=true/false] [unchoked=true/false] [fctotal=true/false] [export=csv/json]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr",
@@ -365,12 +423,17 @@ async fn prefix_recentbest(msg: &Message, args: Args<'_>) -> Result<()> {
     - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `reverse`: `true` or `false` (default)\n\
+    - `weighted`: `true` or `false` (default), shows each score's weighted pp\n\
+    - `unchoked`: `true` or `false` (default), shows each score's pp if it were an FC\n\
+    - `fctotal`: `true` or `false` (default), shows the total pp if every score were an FC\n\
+    - `export`: `csv` or `json`, attaches the scores as file instead of listing them\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<rbm2 badewanne3`."
 )]
 #[usage(
-    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false]"
+    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false] \
+    [weighted=true/false] [unchoked=true/false] [fctotal=true/false] [export=csv/json]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr",
@@ -405,12 +468,17 @@ async fn prefix_recentbestmania(msg: &Message, args: Args<'_>) -> Result<()> {
     - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `reverse`: `true` or `false` (default)\n\
+    - `weighted`: `true` or `false` (default), shows each score's weighted pp\n\
+    - `unchoked`: `true` or `false` (default), shows each score's pp if it were an FC\n\
+    - `fctotal`: `true` or `false` (default), shows the total pp if every score were an FC\n\
+    - `export`: `csv` or `json`, attaches the scores as file instead of listing them\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<rbt2 badewanne3`."
 )]
 #[usage(
-    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false]"
+    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false] \
+    [weighted=true/false] [unchoked=true/false] [fctotal=true/false] [export=csv/json]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr",
@@ -445,12 +513,17 @@ async fn prefix_recentbesttaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `reverse`: `true` or `false` (default)\n\
+    - `weighted`: `true` or `false` (default), shows each score's weighted pp\n\
+    - `unchoked`: `true` or `false` (default), shows each score's pp if it were an FC\n\
+    - `fctotal`: `true` or `false` (default), shows the total pp if every score were an FC\n\
+    - `export`: `csv` or `json`, attaches the scores as file instead of listing them\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<rbc2 badewanne3`."
 )]
 #[usage(
-    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false]"
+    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false] \
+    [weighted=true/false] [unchoked=true/false] [fctotal=true/false] [export=csv/json]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr",
@@ -504,6 +577,10 @@ pub struct TopArgs<'a> {
     pub index: Option<String>,
     pub query: Option<String>,
     pub size: Option<ListSize>,
+    pub weighted: bool,
+    pub unchoked: bool,
+    pub fc_total: bool,
+    pub export: Option<TopExportFormat>,
     pub has_dash_r: bool,
     pub has_dash_p_or_i: bool,
 }
@@ -531,6 +608,10 @@ impl<'m> TopArgs<'m> {
         let mut grade = None;
         let mut sort_by = None;
         let mut reverse = None;
+        let mut weighted = None;
+        let mut unchoked = None;
+        let mut fc_total = None;
+        let mut export = None;
         let mut has_dash_r = None;
         let mut has_dash_p_or_i = None;
         let num = args.num;
@@ -638,10 +719,51 @@ impl<'m> TopArgs<'m> {
                             return Err(content.into());
                         }
                     },
+                    "weighted" | "w" => match value {
+                        "true" | "t" | "1" => weighted = Some(true),
+                        "false" | "f" | "0" => weighted = Some(false),
+                        _ => {
+                            let content =
+                                "Failed to parse `weighted`. Must be either `true` or `false`.";
+
+                            return Err(content.into());
+                        }
+                    },
+                    "unchoked" | "fc" => match value {
+                        "true" | "t" | "1" => unchoked = Some(true),
+                        "false" | "f" | "0" => unchoked = Some(false),
+                        _ => {
+                            let content =
+                                "Failed to parse `unchoked`. Must be either `true` or `false`.";
+
+                            return Err(content.into());
+                        }
+                    },
+                    "fctotal" => match value {
+                        "true" | "t" | "1" => fc_total = Some(true),
+                        "false" | "f" | "0" => fc_total = Some(false),
+                        _ => {
+                            let content =
+                                "Failed to parse `fctotal`. Must be either `true` or `false`.";
+
+                            return Err(content.into());
+                        }
+                    },
+                    "export" => match value {
+                        "csv" => export = Some(TopExportFormat::Csv),
+                        "json" => export = Some(TopExportFormat::Json),
+                        _ => {
+                            let content =
+                                "Failed to parse `export`. Must be either `csv` or `json`.";
+
+                            return Err(content.into());
+                        }
+                    },
                     _ => {
                         let content = format!(
                             "Unrecognized option `{key}`.\n\
-                            Available options are: `acc`, `combo`, `sort`, `grade`, or `reverse`."
+                            Available options are: `acc`, `combo`, `sort`, `grade`, `reverse`, \
+                            `weighted`, `unchoked`, `fctotal`, or `export`."
                         );
 
                         return Err(content.into());
@@ -673,6 +795,10 @@ impl<'m> TopArgs<'m> {
             index: num.to_string_opt(),
             query: None,
             size: None,
+            weighted: weighted.unwrap_or(false),
+            unchoked: unchoked.unwrap_or(false),
+            fc_total: fc_total.unwrap_or(false),
+            export,
             has_dash_r: has_dash_r.unwrap_or(false),
             has_dash_p_or_i: has_dash_p_or_i.unwrap_or(false),
         };
@@ -707,6 +833,10 @@ impl TryFrom<Top> for TopArgs<'static> {
             index: args.index,
             query: args.query,
             size: args.size,
+            weighted: args.weighted.unwrap_or(false),
+            unchoked: args.unchoked.unwrap_or(false),
+            fc_total: args.fc_total.unwrap_or(false),
+            export: args.export,
             has_dash_r: false,
             has_dash_p_or_i: false,
         })
@@ -725,7 +855,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
         }
     };
 
-    let mode = args.mode.or(config.mode).unwrap_or(GameMode::Osu);
+    let mode = resolve_mode(orig.guild_id(), orig.channel_id(), args.mode, config.mode).await;
 
     if args.sort_by == TopScoreOrder::Pp && args.has_dash_r {
         let mode_long = mode_long(mode);
@@ -784,7 +914,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
         None => GuildValues::default(),
     };
 
-    let score_data = config.score_data.or(guild_score_data).unwrap_or_default();
+    let score_data = ScoreData::resolve(config.score_data, guild_score_data);
     let legacy_scores = score_data.is_legacy();
 
     // Retrieve the user and their top scores
@@ -820,8 +950,18 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
     with_render &= settings.buttons.render
         && mode == GameMode::Osu
         && orig.has_permission_to(Permissions::SEND_MESSAGES)
+        && !orig.is_ephemeral()
         && Context::ordr().is_some();
 
+    // The pp of all top scores, needed to reweight them for the total FC pp
+    let top_pps: Option<Vec<(u64, f32)>> =
+        (args.fc_total && args.export.is_none() && mode != GameMode::Mania).then(|| {
+            scores
+                .iter()
+                .map(|score| (score.id, score.pp.unwrap_or(0.0)))
+                .collect()
+        });
+
     let pre_len = scores.len();
 
     let entries = match process_scores(scores, &args, with_render, score_data).await {
@@ -836,6 +976,34 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
     let post_len = entries.len();
     let username = user.username();
 
+    if let Some(format) = args.export {
+        if entries.is_empty() {
+            let content = format!("`{username}` has no top scores with the specified properties");
+
+            return orig.error(content).await;
+        }
+
+        let bytes = match export_scores(&entries, format) {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        };
+
+        let content = format!("Exported {post_len} top scores of `{username}`");
+        let filename = format!("top_{}.{}", username.replace(' ', "_"), format.extension());
+
+        let builder = MessageBuilder::new()
+            .content(content)
+            .attachment(filename, bytes);
+
+        orig.create_message(builder).await?;
+
+        return Ok(());
+    }
+
     let index = match args.index.as_deref() {
         Some("random" | "?") => (post_len > 0).then(|| thread_rng().gen_range(1..=post_len)),
         Some(n) => match n.parse::<usize>() {
@@ -864,13 +1032,52 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
         .or_else(|| (post_len == 1).then_some(0));
 
     let entries = entries.into_boxed_slice();
-    let content = write_content(username, &args, entries.len(), index);
+    let mut content = write_content(username, &args, entries.len(), index);
+
+    // The pp of each entry if it were an FC, shared by the unchoked pp of each
+    // entry and the total FC pp
+    let if_fc_pps = if args.unchoked || top_pps.is_some() {
+        let mut if_fc_pps = Vec::with_capacity(entries.len());
+
+        for entry in entries.iter() {
+            let entry = entry.get_half();
+            let if_fc = IfFc::new(&entry.score, &entry.map).await;
+            if_fc_pps.push(if_fc.map(|if_fc| if_fc.pp));
+        }
+
+        Some(if_fc_pps.into_boxed_slice())
+    } else {
+        None
+    };
+
+    let fc_content = if args.fc_total && args.export.is_none() && mode == GameMode::Mania {
+        Some("The total pp if every top score were an FC is not available for mania".to_owned())
+    } else if let Some((top_pps, if_fc_pps)) = top_pps.zip(if_fc_pps.as_deref()) {
+        let fc_pp = fc_total_pp(&top_pps, &entries, if_fc_pps, user.stats().pp());
 
-    let list_size = args
-        .size
-        .or(config.list_size)
-        .or(guild_list_size)
-        .unwrap_or_default();
+        let scores = if pre_len == post_len {
+            "every top score"
+        } else {
+            "every listed top score"
+        };
+
+        Some(format!(
+            "Total pp if {scores} were an FC: **{}pp** ({:+}pp)",
+            round(fc_pp),
+            round(fc_pp - user.stats().pp()),
+        ))
+    } else {
+        None
+    };
+
+    if let Some(fc_content) = fc_content {
+        content = Some(match content {
+            Some(content) => format!("{fc_content}\n{content}"),
+            None => fc_content,
+        });
+    }
+
+    let list_size = ListSize::resolve(args.size, config.list_size, guild_list_size);
 
     let condensed_list = match (single_idx, list_size) {
         (Some(_), _) | (None, ListSize::Single) => {
@@ -888,6 +1095,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
                         &entry.map.pp_map,
                         entry.score.mods.clone(),
                         entry.map.cover(),
+                        GraphPalette::default(),
                     );
 
                     match fut.await {
@@ -920,6 +1128,8 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
         (None, ListSize::Detailed) => false,
     };
 
+    let unchoked = if_fc_pps.filter(|_| args.unchoked);
+
     let pagination = TopPagination::builder()
         .user(user)
         .mode(mode)
@@ -927,6 +1137,9 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
         .sort_by(args.sort_by)
         .condensed_list(condensed_list)
         .score_data(score_data)
+        .weighted(args.weighted)
+        .unchoked(unchoked)
+        .pp_decimals(config.pp_decimals.unwrap_or(2))
         .content(content.unwrap_or_default().into_boxed_str())
         .msg_owner(msg_owner)
         .build();
@@ -937,6 +1150,42 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
         .await
 }
 
+/// The user's total pp if every listed top score were an FC i.e. the FC pp of
+/// the entries and the pp of all other top scores, reweighted by `0.95^idx`,
+/// plus the user's bonus pp.
+fn fc_total_pp(
+    top_pps: &[(u64, f32)],
+    entries: &[ScoreEmbedDataWrap],
+    if_fc_pps: &[Option<f32>],
+    user_pp: f32,
+) -> f32 {
+    let mut actual_pp = 0.0;
+    let mut fc_pps = Vec::with_capacity(top_pps.len());
+
+    for (&(score_id, pp), i) in top_pps.iter().zip(0..) {
+        actual_pp += pp * 0.95_f32.powi(i);
+
+        let fc_pp = entries
+            .iter()
+            .position(|entry| entry.get_half().score.score_id == score_id)
+            .and_then(|idx| if_fc_pps[idx])
+            .unwrap_or(pp);
+
+        fc_pps.push(fc_pp);
+    }
+
+    fc_pps.sort_unstable_by(|a, b| b.total_cmp(a));
+
+    let fc_pp = fc_pps
+        .iter()
+        .zip(0..)
+        .fold(0.0, |sum, (pp, i)| sum + pp * 0.95_f32.powi(i));
+
+    let bonus_pp = user_pp - actual_pp;
+
+    fc_pp + bonus_pp
+}
+
 async fn process_scores(
     scores: Vec<Score>,
     args: &TopArgs<'_>,
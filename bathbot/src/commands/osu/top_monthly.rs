@@ -0,0 +1,280 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use bathbot_macros::{command, HasName, SlashCommand};
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_psql::model::configs::ScoreData;
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    matcher,
+};
+use eyre::{Report, Result};
+use rosu_v2::{
+    prelude::{GameMode, OsuError, Score},
+    request::UserId,
+};
+use time::{Month, OffsetDateTime};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use super::{require_link, resolve_mode, user_not_found};
+use crate::{
+    active::{
+        impls::{TopMonthlyEntry, TopMonthlyPagination},
+        ActiveMessages,
+    },
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::redis::osu::UserArgs,
+    util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "topmonthly",
+    desc = "Display a user's best top play of each month",
+    help = "Display the highest-pp play of each month among a user's top100.\n\
+    The months are listed chronologically, starting with the month of the user's \
+    oldest top play, so it's easy to see how the user progressed over time."
+)]
+pub struct TopMonthly<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord: Option<Id<UserMarker>>,
+}
+
+impl<'m> TopMonthly<'m> {
+    fn args(mode: Option<GameModeOption>, mut args: Args<'m>) -> Self {
+        let mut name = None;
+        let mut discord = None;
+
+        if let Some(arg) = args.next() {
+            match matcher::get_mention_user(arg) {
+                Some(id) => discord = Some(id),
+                None => name = Some(arg.into()),
+            }
+        }
+
+        Self {
+            mode,
+            name,
+            discord,
+        }
+    }
+}
+
+#[command]
+#[desc("Display a user's best top play of each month")]
+#[help(
+    "Display the highest-pp play of each month among a user's top100, \
+    listed chronologically."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[aliases("monthlybest", "bestmonthly")]
+#[group(Osu)]
+async fn prefix_topmonthly(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = TopMonthly::args(None, args);
+
+    top_monthly(msg.into(), args).await
+}
+
+#[command]
+#[desc("Display a mania user's best top play of each month")]
+#[help(
+    "Display the highest-pp play of each month among a mania user's top100, \
+    listed chronologically."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[aliases("monthlybestmania", "bestmonthlymania")]
+#[group(Mania)]
+async fn prefix_topmonthlymania(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = TopMonthly::args(Some(GameModeOption::Mania), args);
+
+    top_monthly(msg.into(), args).await
+}
+
+#[command]
+#[desc("Display a taiko user's best top play of each month")]
+#[help(
+    "Display the highest-pp play of each month among a taiko user's top100, \
+    listed chronologically."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[aliases("monthlybesttaiko", "bestmonthlytaiko")]
+#[group(Taiko)]
+async fn prefix_topmonthlytaiko(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = TopMonthly::args(Some(GameModeOption::Taiko), args);
+
+    top_monthly(msg.into(), args).await
+}
+
+#[command]
+#[desc("Display a ctb user's best top play of each month")]
+#[help(
+    "Display the highest-pp play of each month among a ctb user's top100, \
+    listed chronologically."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[aliases("topmonthlycatch", "monthlybestctb", "bestmonthlyctb")]
+#[group(Catch)]
+async fn prefix_topmonthlyctb(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = TopMonthly::args(Some(GameModeOption::Catch), args);
+
+    top_monthly(msg.into(), args).await
+}
+
+async fn slash_topmonthly(mut command: InteractionCommand) -> Result<()> {
+    let args = TopMonthly::from_interaction(command.input_data())?;
+
+    top_monthly((&mut command).into(), args).await
+}
+
+async fn top_monthly(orig: CommandOrigin<'_>, args: TopMonthly<'_>) -> Result<()> {
+    let msg_owner = orig.user_id()?;
+
+    let mut config = match Context::user_config().with_osu_id(msg_owner).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let mode = resolve_mode(
+        orig.guild_id(),
+        orig.channel_id(),
+        args.mode.map(GameMode::from),
+        config.mode,
+    )
+    .await;
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match config.osu.take() {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
+        },
+    };
+
+    let guild_score_data = match orig.guild_id() {
+        Some(guild_id) => {
+            Context::guild_config()
+                .peek(guild_id, |config| config.score_data)
+                .await
+        }
+        None => None,
+    };
+
+    let legacy_scores = ScoreData::resolve(config.score_data, guild_score_data).is_legacy();
+
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
+    let scores_fut = Context::osu_scores()
+        .top(legacy_scores)
+        .limit(100)
+        .exec_with_user(user_args);
+
+    let (user, scores) = match scores_fut.await {
+        Ok(tuple) => tuple,
+        Err(OsuError::NotFound) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+            let err = Report::new(err).wrap_err("failed to get user or scores");
+
+            return Err(err);
+        }
+    };
+
+    let entries = best_per_month(scores, OffsetDateTime::now_utc());
+    let username = user.username();
+
+    let months_with_play = entries.iter().filter(|entry| entry.best.is_some()).count();
+
+    if months_with_play == 0 {
+        let content = format!("`{username}` has no top plays");
+
+        return orig.error(content).await;
+    }
+
+    let content = format!(
+        "`{username}` set top plays in {months_with_play} of the last {total} month{plural}",
+        total = entries.len(),
+        plural = if entries.len() == 1 { "" } else { "s" },
+    );
+
+    let pagination = TopMonthlyPagination::builder()
+        .user(user)
+        .entries(entries.into_boxed_slice())
+        .content(content.into_boxed_str())
+        .msg_owner(msg_owner)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
+}
+
+/// Group the scores by the month they were set in and keep the highest-pp
+/// score of each month.
+///
+/// Every month from the oldest score up to `now` gets an entry, even if no
+/// score was set in it.
+fn best_per_month(scores: Vec<Score>, now: OffsetDateTime) -> Vec<TopMonthlyEntry> {
+    let mut best: BTreeMap<(i32, u8), (usize, Score)> = BTreeMap::new();
+
+    for (i, score) in scores.into_iter().enumerate() {
+        let key = (score.ended_at.year(), u8::from(score.ended_at.month()));
+
+        let is_better = match best.get(&key) {
+            Some((_, curr)) => score.pp > curr.pp,
+            None => true,
+        };
+
+        if is_better {
+            best.insert(key, (i, score));
+        }
+    }
+
+    let (Some(&(mut year, first_month)), Some(&last)) =
+        (best.keys().next(), best.keys().next_back())
+    else {
+        return Vec::new();
+    };
+
+    let mut month = Month::try_from(first_month).unwrap_or(Month::January);
+    let last = last.max((now.year(), u8::from(now.month())));
+
+    let mut entries = Vec::new();
+
+    while (year, u8::from(month)) <= last {
+        entries.push(TopMonthlyEntry {
+            year,
+            month,
+            best: best.remove(&(year, u8::from(month))),
+        });
+
+        if month == Month::December {
+            year += 1;
+        }
+
+        month = month.next();
+    }
+
+    entries
+}
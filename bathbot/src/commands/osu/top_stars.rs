@@ -0,0 +1,468 @@
+use std::{borrow::Cow, collections::BTreeMap, fmt::Write};
+
+use bathbot_macros::{command, SlashCommand};
+use bathbot_model::{command_fields::GameModeOption, rosu_v2::user::User};
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    matcher, EmbedBuilder, FooterBuilder, MessageBuilder,
+};
+use eyre::{Report, Result};
+use rosu_v2::{
+    prelude::{GameMode, OsuError, Score},
+    request::UserId,
+    OsuResult,
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    channel::{message::embed::EmbedField, Message},
+    id::{marker::UserMarker, Id},
+};
+
+use super::{require_link, resolve_mode, user_not_found, UserExtraction};
+use crate::{
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::redis::{osu::UserArgs, RedisData},
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, Default, SlashCommand)]
+#[command(
+    name = "topstars",
+    desc = "Count top plays per star rating",
+    help = "Bucket a user's top100 into star rating ranges and count the plays of each range.\n\
+    The star rating of each play takes its mods into account.\n\
+    If a second user is specified, both users' counts are compared."
+)]
+pub struct TopStars<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name1: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a username to compare with")]
+    name2: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name1` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord1: Option<Id<UserMarker>>,
+    #[command(desc = "Specify a linked discord user to compare with")]
+    discord2: Option<Id<UserMarker>>,
+}
+
+impl<'m> TopStars<'m> {
+    fn args(mode: Option<GameModeOption>, args: Args<'m>) -> Self {
+        let mut args_ = TopStars {
+            mode,
+            ..Default::default()
+        };
+
+        for arg in args.take(2) {
+            if let Some(id) = matcher::get_mention_user(arg) {
+                if args_.discord1.is_none() {
+                    args_.discord1 = Some(id);
+                } else {
+                    args_.discord2 = Some(id);
+                }
+            } else if args_.name1.is_none() {
+                args_.name1 = Some(arg.into());
+            } else {
+                args_.name2 = Some(arg.into());
+            }
+        }
+
+        args_
+    }
+}
+
+#[command]
+#[desc("Count top plays per star rating")]
+#[help(
+    "Bucket a user's top100 into star rating ranges and count the plays of each range.\n\
+    The star rating of each play takes its mods into account.\n\
+    If a second user is specified, both users' counts are compared."
+)]
+#[usage("[username] [username]")]
+#[examples("badewanne3", "badewanne3 \"nathan on osu\"")]
+#[alias("ts", "starbuckets")]
+#[group(Osu)]
+async fn prefix_topstars(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = TopStars::args(None, args);
+
+    topstars(msg.into(), args).await
+}
+
+#[command]
+#[desc("Count mania top plays per star rating")]
+#[help(
+    "Bucket a user's mania top100 into star rating ranges and count the plays of each range.\n\
+    The star rating of each play takes its mods into account.\n\
+    If a second user is specified, both users' counts are compared."
+)]
+#[usage("[username] [username]")]
+#[examples("badewanne3", "badewanne3 \"nathan on osu\"")]
+#[alias("tsm")]
+#[group(Mania)]
+async fn prefix_topstarsmania(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = TopStars::args(Some(GameModeOption::Mania), args);
+
+    topstars(msg.into(), args).await
+}
+
+#[command]
+#[desc("Count taiko top plays per star rating")]
+#[help(
+    "Bucket a user's taiko top100 into star rating ranges and count the plays of each range.\n\
+    The star rating of each play takes its mods into account.\n\
+    If a second user is specified, both users' counts are compared."
+)]
+#[usage("[username] [username]")]
+#[examples("badewanne3", "badewanne3 \"nathan on osu\"")]
+#[alias("tst")]
+#[group(Taiko)]
+async fn prefix_topstarstaiko(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = TopStars::args(Some(GameModeOption::Taiko), args);
+
+    topstars(msg.into(), args).await
+}
+
+#[command]
+#[desc("Count ctb top plays per star rating")]
+#[help(
+    "Bucket a user's ctb top100 into star rating ranges and count the plays of each range.\n\
+    The star rating of each play takes its mods into account.\n\
+    If a second user is specified, both users' counts are compared."
+)]
+#[usage("[username] [username]")]
+#[examples("badewanne3", "badewanne3 \"nathan on osu\"")]
+#[alias("tsc", "topstarscatch")]
+#[group(Catch)]
+async fn prefix_topstarsctb(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = TopStars::args(Some(GameModeOption::Catch), args);
+
+    topstars(msg.into(), args).await
+}
+
+async fn slash_topstars(mut command: InteractionCommand) -> Result<()> {
+    let args = TopStars::from_interaction(command.input_data())?;
+
+    topstars((&mut command).into(), args).await
+}
+
+async fn topstars(orig: CommandOrigin<'_>, mut args: TopStars<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+
+    let config = match Context::user_config().with_osu_id(owner).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let user_id1 = match extract_user_id(&mut args).await {
+        UserExtraction::Id(user_id) => user_id,
+        UserExtraction::Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+        UserExtraction::Content(content) => return orig.error(content).await,
+        UserExtraction::None => match config.osu {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
+        },
+    };
+
+    let user_id2 = match extract_user_id(&mut args).await {
+        UserExtraction::Id(user_id) => Some(user_id),
+        UserExtraction::Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+        UserExtraction::Content(content) => return orig.error(content).await,
+        UserExtraction::None => None,
+    };
+
+    if user_id2.as_ref() == Some(&user_id1) {
+        return orig.error("Give two different names").await;
+    }
+
+    let mode = resolve_mode(
+        orig.guild_id(),
+        orig.channel_id(),
+        args.mode.map(GameMode::from),
+        config.mode,
+    )
+    .await;
+
+    let (user1, scores1) = match get_user_and_scores(&user_id1, mode).await {
+        Ok(tuple) => tuple,
+        Err(OsuError::NotFound) => {
+            let content = user_not_found(user_id1).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user or scores");
+
+            return Err(err);
+        }
+    };
+
+    let second = match user_id2 {
+        Some(user_id2) => match get_user_and_scores(&user_id2, mode).await {
+            Ok(tuple) => Some(tuple),
+            Err(OsuError::NotFound) => {
+                let content = user_not_found(user_id2).await;
+
+                return orig.error(content).await;
+            }
+            Err(err) => {
+                let _ = orig.error(OSU_API_ISSUE).await;
+                let err = Report::new(err).wrap_err("Failed to get user or scores");
+
+                return Err(err);
+            }
+        },
+        None => None,
+    };
+
+    // Check if different names that both belong to the same user were given
+    if let Some((ref user2, _)) = second {
+        if user1.user_id() == user2.user_id() {
+            let content = "You must specify two different users";
+
+            return orig.error(content).await;
+        }
+    }
+
+    let buckets1 = match star_buckets(&scores1).await {
+        Ok(buckets) => buckets,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to bucket scores"));
+        }
+    };
+
+    let second = match second {
+        Some((user2, scores2)) => match star_buckets(&scores2).await {
+            Ok(buckets) => Some((user2, buckets)),
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("Failed to bucket scores"));
+            }
+        },
+        None => None,
+    };
+
+    if buckets1.is_empty() && second.iter().all(|(_, buckets)| buckets.is_empty()) {
+        let content = match second {
+            Some((ref user2, _)) => format!(
+                "Neither `{}` nor `{}` have any top scores",
+                user1.username(),
+                user2.username()
+            ),
+            None => format!("`{}` has no top scores", user1.username()),
+        };
+
+        return orig.error(content).await;
+    }
+
+    let embed = match second {
+        Some((user2, buckets2)) => compare_embed(&user1, &buckets1, &user2, &buckets2),
+        None => single_embed(&user1, &buckets1),
+    };
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+async fn extract_user_id(args: &mut TopStars<'_>) -> UserExtraction {
+    if let Some(name) = args.name1.take().or_else(|| args.name2.take()) {
+        UserExtraction::Id(UserId::Name(name.as_ref().into()))
+    } else if let Some(discord) = args.discord1.take().or_else(|| args.discord2.take()) {
+        match Context::user_config().osu_id(discord).await {
+            Ok(Some(user_id)) => UserExtraction::Id(UserId::Id(user_id)),
+            Ok(None) => {
+                UserExtraction::Content(format!("<@{discord}> is not linked to an osu!profile"))
+            }
+            Err(err) => UserExtraction::Err(err),
+        }
+    } else {
+        UserExtraction::None
+    }
+}
+
+async fn get_user_and_scores(
+    user_id: &UserId,
+    mode: GameMode,
+) -> OsuResult<(RedisData<User>, Vec<Score>)> {
+    let args = UserArgs::rosu_id(user_id, mode).await;
+
+    Context::osu_scores()
+        .top(false)
+        .limit(100)
+        .exec_with_user(args)
+        .await
+}
+
+/// Amount of top scores for each full star, e.g. the key `5` counts the
+/// scores between 5★ and 6★.
+type StarBuckets = BTreeMap<u32, usize>;
+
+async fn star_buckets(scores: &[Score]) -> Result<StarBuckets> {
+    let maps_id_checksum = scores
+        .iter()
+        .filter_map(|score| score.map.as_ref())
+        .map(|map| (map.map_id as i32, map.checksum.as_deref()))
+        .collect();
+
+    let mut maps = Context::osu_map().maps(&maps_id_checksum).await?;
+    let mut buckets = StarBuckets::new();
+
+    for score in scores {
+        let Some(map) = maps.remove(&score.map_id) else {
+            continue;
+        };
+
+        let map = map.convert(score.mode);
+
+        let stars = Context::pp(&map)
+            .mode(score.mode)
+            .mods(&score.mods)
+            .difficulty()
+            .await
+            .stars();
+
+        *buckets.entry(stars as u32).or_default() += 1;
+    }
+
+    Ok(buckets)
+}
+
+/// Bucket keys from the lowest to the highest bucket of all given buckets,
+/// including empty buckets in between.
+fn bucket_range<'b>(buckets: impl IntoIterator<Item = &'b StarBuckets>) -> Vec<u32> {
+    let (min, max) = buckets
+        .into_iter()
+        .filter_map(|buckets| buckets.keys().next().zip(buckets.keys().next_back()))
+        .fold((u32::MAX, 0), |(min, max), (first, last)| {
+            (min.min(*first), max.max(*last))
+        });
+
+    (min..=max).collect()
+}
+
+fn bucket_label(stars: u32) -> String {
+    format!("{stars}-{}★", stars + 1)
+}
+
+fn counts_field(name: String, keys: &[u32], buckets: &StarBuckets) -> EmbedField {
+    let label_len = keys.iter().map(|&stars| bucket_label(stars).len()).max();
+    let count_len = keys
+        .iter()
+        .map(|stars| buckets.get(stars).copied().unwrap_or(0).to_string().len())
+        .max();
+
+    let mut value = String::from("```\n");
+
+    for &stars in keys {
+        let _ = writeln!(
+            value,
+            "{label:<label_len$} │ {count:>count_len$}",
+            label = bucket_label(stars),
+            count = buckets.get(&stars).copied().unwrap_or(0),
+            label_len = label_len.unwrap_or(0),
+            count_len = count_len.unwrap_or(0),
+        );
+    }
+
+    value.push_str("```");
+
+    EmbedField {
+        inline: true,
+        name,
+        value,
+    }
+}
+
+fn single_embed(user: &RedisData<User>, buckets: &StarBuckets) -> EmbedBuilder {
+    let keys = bucket_range([buckets]);
+    let total: usize = buckets.values().sum();
+    let field = counts_field("Top plays".to_owned(), &keys, buckets);
+
+    EmbedBuilder::new()
+        .author(user.author_builder())
+        .fields(vec![field])
+        .footer(FooterBuilder::new(format!(
+            "{total} top plays • Star ratings include mods"
+        )))
+        .thumbnail(user.avatar_url())
+        .title("Top plays per star rating")
+}
+
+fn compare_embed(
+    user1: &RedisData<User>,
+    buckets1: &StarBuckets,
+    user2: &RedisData<User>,
+    buckets2: &StarBuckets,
+) -> EmbedBuilder {
+    let keys = bucket_range([buckets1, buckets2]);
+
+    let diffs: Vec<_> = keys
+        .iter()
+        .map(|stars| {
+            let count1 = buckets1.get(stars).copied().unwrap_or(0) as isize;
+            let count2 = buckets2.get(stars).copied().unwrap_or(0) as isize;
+
+            format!("{:+}", count1 - count2)
+        })
+        .collect();
+
+    let diff_len = diffs.iter().map(String::len).max().unwrap_or(0);
+    let mut diff_value = String::from("```\n");
+
+    for diff in diffs {
+        let _ = writeln!(diff_value, "{diff:>diff_len$}");
+    }
+
+    diff_value.push_str("```");
+
+    let fields = vec![
+        counts_field(user1.username().to_owned(), &keys, buckets1),
+        counts_field(user2.username().to_owned(), &keys, buckets2),
+        EmbedField {
+            inline: true,
+            name: "Difference".to_owned(),
+            value: diff_value,
+        },
+    ];
+
+    let total1: usize = buckets1.values().sum();
+    let total2: usize = buckets2.values().sum();
+
+    let footer = format!(
+        "{total1} and {total2} top plays • Difference from {}'s perspective • \
+        Star ratings include mods",
+        user1.username()
+    );
+
+    EmbedBuilder::new()
+        .fields(fields)
+        .footer(FooterBuilder::new(footer))
+        .title(format!(
+            "Top plays per star rating: {} vs {}",
+            user1.username(),
+            user2.username()
+        ))
+}
@@ -0,0 +1,375 @@
+use std::borrow::Cow;
+
+use bathbot_macros::{command, HasName, SlashCommand};
+use bathbot_model::{command_fields::GameModeOption, embed_builder::SettingsImage};
+use bathbot_psql::model::configs::{GuildConfig, ListSize, ScoreData};
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    matcher,
+};
+use eyre::{Report, Result};
+use rosu_v2::{
+    prelude::{GameMode, OsuError, Score},
+    request::UserId,
+};
+use time::{Duration, OffsetDateTime};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use super::{
+    map_strain_graph, require_link, resolve_mode, user_not_found, GraphPalette, ScoreOrder,
+};
+use crate::{
+    active::{
+        impls::{SingleScoreContent, SingleScorePagination, TopPagination},
+        ActiveMessages,
+    },
+    commands::utility::{MissAnalyzerCheck, ScoreEmbedDataPersonalBest, ScoreEmbedDataWrap},
+    core::commands::{prefix::Args, CommandOrigin},
+    manager::redis::osu::UserArgs,
+    util::{interaction::InteractionCommand, ChannelExt, InteractionCommandExt},
+    Context,
+};
+
+/// Scores that were set within this duration count as recent
+const RECENT_DAYS: i64 = 7;
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "topweek",
+    desc = "Display a user's top plays that were set in the last 7 days",
+    help = "Display all scores of a user's top100 that were set within the last 7 days.\n\
+    The scores keep their position in the top100 and are sorted by pp."
+)]
+pub struct TopWeek<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord: Option<Id<UserMarker>>,
+    #[command(
+        desc = "Size of the embed",
+        help = "Size of the embed.\n\
+        `Condensed` shows 10 scores, `Detailed` shows 5, and `Single` shows 1.\n\
+        The default can be set with the `/config` command."
+    )]
+    size: Option<ListSize>,
+}
+
+impl<'m> TopWeek<'m> {
+    fn args(mode: Option<GameModeOption>, mut args: Args<'m>) -> Self {
+        let mut name = None;
+        let mut discord = None;
+
+        if let Some(arg) = args.next() {
+            match matcher::get_mention_user(arg) {
+                Some(id) => discord = Some(id),
+                None => name = Some(arg.into()),
+            }
+        }
+
+        Self {
+            mode,
+            name,
+            discord,
+            size: None,
+        }
+    }
+}
+
+#[command]
+#[desc("Display a user's top plays from the last 7 days")]
+#[help(
+    "Display all scores of a user's top100 that were set within the last 7 days.\n\
+    The scores keep their position in the top100 and are sorted by pp."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[aliases("tw", "weektop")]
+#[group(Osu)]
+async fn prefix_topweek(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = TopWeek::args(None, args);
+
+    top_week(msg.into(), args).await
+}
+
+#[command]
+#[desc("Display a mania user's top plays from the last 7 days")]
+#[help(
+    "Display all scores of a mania user's top100 that were set within the last 7 days.\n\
+    The scores keep their position in the top100 and are sorted by pp."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[aliases("twm", "weektopmania")]
+#[group(Mania)]
+async fn prefix_topweekmania(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = TopWeek::args(Some(GameModeOption::Mania), args);
+
+    top_week(msg.into(), args).await
+}
+
+#[command]
+#[desc("Display a taiko user's top plays from the last 7 days")]
+#[help(
+    "Display all scores of a taiko user's top100 that were set within the last 7 days.\n\
+    The scores keep their position in the top100 and are sorted by pp."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[aliases("twt", "weektoptaiko")]
+#[group(Taiko)]
+async fn prefix_topweektaiko(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = TopWeek::args(Some(GameModeOption::Taiko), args);
+
+    top_week(msg.into(), args).await
+}
+
+#[command]
+#[desc("Display a ctb user's top plays from the last 7 days")]
+#[help(
+    "Display all scores of a ctb user's top100 that were set within the last 7 days.\n\
+    The scores keep their position in the top100 and are sorted by pp."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[aliases("twc", "topweekcatch", "weektopctb")]
+#[group(Catch)]
+async fn prefix_topweekctb(msg: &Message, args: Args<'_>) -> Result<()> {
+    let args = TopWeek::args(Some(GameModeOption::Catch), args);
+
+    top_week(msg.into(), args).await
+}
+
+async fn slash_topweek(mut command: InteractionCommand) -> Result<()> {
+    let args = TopWeek::from_interaction(command.input_data())?;
+
+    top_week((&mut command).into(), args).await
+}
+
+async fn top_week(orig: CommandOrigin<'_>, args: TopWeek<'_>) -> Result<()> {
+    let msg_owner = orig.user_id()?;
+
+    let mut config = match Context::user_config().with_osu_id(msg_owner).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let mode = resolve_mode(
+        orig.guild_id(),
+        orig.channel_id(),
+        args.mode.map(GameMode::from),
+        config.mode,
+    )
+    .await;
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match config.osu.take() {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
+        },
+    };
+
+    let GuildValues {
+        list_size: guild_list_size,
+        score_data: guild_score_data,
+    } = match orig.guild_id() {
+        Some(guild_id) => {
+            Context::guild_config()
+                .peek(guild_id, |config| GuildValues::from(config))
+                .await
+        }
+        None => GuildValues::default(),
+    };
+
+    let score_data = ScoreData::resolve(config.score_data, guild_score_data);
+    let legacy_scores = score_data.is_legacy();
+
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
+    let scores_fut = Context::osu_scores()
+        .top(legacy_scores)
+        .limit(100)
+        .exec_with_user(user_args);
+
+    let (user, scores) = match scores_fut.await {
+        Ok(tuple) => tuple,
+        Err(OsuError::NotFound) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+            let err = Report::new(err).wrap_err("failed to get user or scores");
+
+            return Err(err);
+        }
+    };
+
+    let username = user.username();
+    let since = OffsetDateTime::now_utc() - Duration::days(RECENT_DAYS);
+
+    let entries = match process_scores(scores, since, legacy_scores).await {
+        Ok(entries) => entries,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to process scores"));
+        }
+    };
+
+    if entries.is_empty() {
+        let content = format!("`{username}` has no top plays from the last {RECENT_DAYS} days");
+
+        return orig.error(content).await;
+    }
+
+    let content = format!(
+        "`{username}` set {count} of their top plays in the last {RECENT_DAYS} days",
+        count = entries.len(),
+    );
+
+    let settings = config.score_embed.unwrap_or_default();
+
+    let list_size = ListSize::resolve(args.size, config.list_size, guild_list_size);
+
+    let entries = entries.into_boxed_slice();
+
+    let condensed_list = match list_size {
+        ListSize::Condensed => true,
+        ListSize::Detailed => false,
+        ListSize::Single => {
+            let content = SingleScoreContent::SameForAll(content);
+
+            let graph = match entries.first() {
+                Some(entry) if matches!(settings.image, SettingsImage::ImageWithStrains) => {
+                    let entry = entry.get_half();
+
+                    let fut = map_strain_graph(
+                        &entry.map.pp_map,
+                        entry.score.mods.clone(),
+                        entry.map.cover(),
+                        GraphPalette::default(),
+                    );
+
+                    match fut.await {
+                        Ok(graph) => Some((SingleScorePagination::IMAGE_NAME.to_owned(), graph)),
+                        Err(err) => {
+                            warn!(?err, "Failed to create strain graph");
+
+                            None
+                        }
+                    }
+                }
+                Some(_) | None => None,
+            };
+
+            let pagination = SingleScorePagination::new(
+                &user, entries, settings, score_data, msg_owner, content,
+            );
+
+            return ActiveMessages::builder(pagination)
+                .start_by_update(true)
+                .attachment(graph)
+                .begin(orig)
+                .await;
+        }
+    };
+
+    let pagination = TopPagination::builder()
+        .user(user)
+        .mode(mode)
+        .entries(entries)
+        .sort_by(ScoreOrder::Pp.into())
+        .condensed_list(condensed_list)
+        .score_data(score_data)
+        .pp_decimals(config.pp_decimals.unwrap_or(2))
+        .content(content.into_boxed_str())
+        .msg_owner(msg_owner)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
+}
+
+/// Keep only the scores that were set after `since` while preserving their
+/// position in the top100.
+async fn process_scores(
+    scores: Vec<Score>,
+    since: OffsetDateTime,
+    legacy_scores: bool,
+) -> Result<Vec<ScoreEmbedDataWrap>> {
+    let recent: Vec<_> = scores
+        .into_iter()
+        .enumerate()
+        .filter(|(_, score)| score.ended_at >= since)
+        .collect();
+
+    let maps_id_checksum = recent
+        .iter()
+        .filter_map(|(_, score)| score.map.as_ref())
+        .map(|map| (map.map_id as i32, map.checksum.as_deref()))
+        .collect();
+
+    let mut maps = Context::osu_map().maps(&maps_id_checksum).await?;
+    let mut entries = Vec::with_capacity(recent.len());
+
+    for (i, score) in recent {
+        let Some(mut map) = maps.remove(&score.map_id) else {
+            continue;
+        };
+
+        map.convert_mut(score.mode);
+
+        let map_checksum = score
+            .map
+            .as_ref()
+            .filter(|_| score.replay)
+            .and_then(|map| map.checksum.clone());
+
+        let pb_idx = Some(ScoreEmbedDataPersonalBest::from_index(i));
+
+        let entry = ScoreEmbedDataWrap::new_half(
+            score,
+            map,
+            map_checksum,
+            pb_idx,
+            legacy_scores,
+            false,
+            MissAnalyzerCheck::without(),
+        )
+        .await;
+
+        entries.push(entry);
+    }
+
+    Ok(entries)
+}
+
+#[derive(Default)]
+struct GuildValues {
+    list_size: Option<ListSize>,
+    score_data: Option<ScoreData>,
+}
+
+impl From<&GuildConfig> for GuildValues {
+    fn from(config: &GuildConfig) -> Self {
+        Self {
+            list_size: config.list_size,
+            score_data: config.score_data,
+        }
+    }
+}
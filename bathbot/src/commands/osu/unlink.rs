@@ -0,0 +1,64 @@
+use bathbot_macros::SlashCommand;
+use bathbot_util::constants::GENERAL_ISSUE;
+use eyre::Result;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    active::{impls::UnlinkConfirm, ActiveMessages},
+    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "unlink",
+    desc = "Unlink your discord from its osu! profile",
+    help = "Unlink your discord from its osu! profile.\n\
+    You will be asked to confirm before anything is removed.\n\
+    By default your stored preferences such as mode, list size, or score embed settings \
+    are kept so that they still apply when you link again. \
+    Your twitch link, bookmarks, and the osu! trackings of channels are never affected."
+)]
+#[flags(EPHEMERAL)]
+pub struct Unlink {
+    #[command(desc = "Also reset your stored preferences, defaults to false")]
+    reset_preferences: Option<bool>,
+}
+
+async fn slash_unlink(mut command: InteractionCommand) -> Result<()> {
+    let args = Unlink::from_interaction(command.input_data())?;
+    let owner = command.user_id()?;
+
+    let config = match Context::user_config().with_osu_id(owner).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let Some(osu_id) = config.osu else {
+        command
+            .error("You are not linked to an osu! profile")
+            .await?;
+
+        return Ok(());
+    };
+
+    let username = match Context::osu_user().name(osu_id).await {
+        Ok(name) => name,
+        Err(err) => {
+            warn!(?err, "Failed to get username for unlink");
+
+            None
+        }
+    };
+
+    let reset_preferences = args.reset_preferences.unwrap_or(false);
+    let active_msg = UnlinkConfirm::new(osu_id, username, reset_preferences, owner);
+
+    ActiveMessages::builder(active_msg)
+        .begin(&mut command)
+        .await
+}
@@ -5,11 +5,20 @@ use bathbot_model::command_fields::GameModeOption;
 use bathbot_util::{
     constants::OSU_API_ISSUE,
     matcher,
+    numbers::WithComma,
     osu::{approx_more_pp, ExtractablePp, PpListUtil},
     MessageBuilder,
 };
-use eyre::{Report, Result};
-use rosu_v2::prelude::OsuError;
+use eyre::{Report, Result, WrapErr};
+use plotters::{
+    prelude::{ChartBuilder, Circle, EmptyElement, IntoDrawingArea, SeriesLabelPosition},
+    series::LineSeries,
+    style::{Color, RGBColor, WHITE},
+};
+use plotters_backend::FontStyle;
+use plotters_skia::SkiaBackend;
+use rosu_v2::prelude::{GameMode, OsuError};
+use skia_safe::{surfaces, EncodedImageFormat};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{marker::UserMarker, Id};
 
@@ -38,6 +47,29 @@ pub enum WhatIfData {
     },
 }
 
+/// Whether the embed shows a projection of the rank for multiple new scores.
+pub enum WhatIfProjection {
+    None,
+    Graph,
+    /// The pp are outside of the rank approximation's range
+    OutOfRange,
+}
+
+/// Total pp and approximated rank after adding some amount of scores.
+struct ProjectionPoint {
+    count: usize,
+    pp: f32,
+    rank: Option<u32>,
+}
+
+/// Amount of steps between no additional scores and all additional scores.
+const PROJECTION_STEPS: usize = 10;
+
+const PROJECTION_W: u32 = 900;
+const PROJECTION_H: u32 = 350;
+
+pub const PROJECTION_GRAPH_NAME: &str = "whatif_projection.png";
+
 impl WhatIfData {
     pub fn count(&self) -> usize {
         match self {
@@ -63,7 +95,10 @@ pub struct WhatIf<'a> {
     #[command(
         min_value = 1,
         max_value = 1000,
-        desc = "Specify how many times a score should be added, defaults to 1"
+        desc = "Specify how many times a score should be added, defaults to 1",
+        help = "Specify how many times a score should be added, defaults to 1.\n\
+        When adding multiple scores, a graph projects how the rank would change \
+        with each additional score."
     )]
     count: Option<usize>,
     #[command(
@@ -224,6 +259,9 @@ async fn whatif(orig: CommandOrigin<'_>, args: WhatIf<'_>) -> Result<()> {
         }
     };
 
+    // Top pp and bonus pp before adding new scores
+    let mut projection_base = None;
+
     let whatif_data = if scores.is_empty() {
         let pp = iter::repeat(pp)
             .zip(0..)
@@ -239,6 +277,8 @@ async fn whatif(orig: CommandOrigin<'_>, args: WhatIf<'_>) -> Result<()> {
             }
         };
 
+        projection_base = Some((Vec::new(), 0.0));
+
         WhatIfData::NoScores { count, rank }
     } else if pp < scores.last().and_then(|s| s.pp).unwrap_or(0.0) {
         WhatIfData::NonTop100
@@ -255,6 +295,8 @@ async fn whatif(orig: CommandOrigin<'_>, args: WhatIf<'_>) -> Result<()> {
             .position(|&pp_| pp_ < pp)
             .unwrap_or(scores.len() - 1);
 
+        projection_base = Some((pps.clone(), bonus_pp));
+
         pps.extend(iter::repeat(pp).take(count));
         pps.sort_unstable_by(|a, b| b.total_cmp(a));
 
@@ -279,10 +321,191 @@ async fn whatif(orig: CommandOrigin<'_>, args: WhatIf<'_>) -> Result<()> {
         }
     };
 
+    let mut graph = None;
+
+    let projection = match projection_base {
+        Some((base, bonus_pp)) if count > 1 => {
+            let curr_rank = Some(user.stats().global_rank()).filter(|&rank| rank > 0);
+            let points = rank_projection(&base, bonus_pp, pp, count, mode, curr_rank).await;
+
+            match projection_graph(&points) {
+                Ok(Some(bytes)) => {
+                    graph = Some(bytes);
+
+                    WhatIfProjection::Graph
+                }
+                Ok(None) => WhatIfProjection::OutOfRange,
+                Err(err) => {
+                    warn!(?err, "Failed to create rank projection graph");
+
+                    WhatIfProjection::None
+                }
+            }
+        }
+        _ => WhatIfProjection::None,
+    };
+
     // Sending the embed
-    let embed = WhatIfEmbed::new(&user, pp, whatif_data);
-    let builder = MessageBuilder::new().embed(embed.build());
+    let embed = WhatIfEmbed::new(&user, pp, whatif_data, projection);
+    let mut builder = MessageBuilder::new().embed(embed.build());
+
+    if let Some(bytes) = graph {
+        builder = builder.attachment(PROJECTION_GRAPH_NAME, bytes);
+    }
+
     orig.create_message(builder).await?;
 
     Ok(())
 }
+
+/// Total pp and approximated rank for increasing amounts of additional scores.
+async fn rank_projection(
+    base: &[f32],
+    bonus_pp: f32,
+    pp: f32,
+    count: usize,
+    mode: GameMode,
+    curr_rank: Option<u32>,
+) -> Vec<ProjectionPoint> {
+    let mut points: Vec<ProjectionPoint> = Vec::with_capacity(PROJECTION_STEPS + 1);
+    let mut pps = Vec::with_capacity(base.len() + count);
+
+    for step in 0..=PROJECTION_STEPS {
+        let added = step * count / PROJECTION_STEPS;
+
+        if points.last().is_some_and(|point| point.count == added) {
+            continue;
+        }
+
+        pps.clear();
+        pps.extend_from_slice(base);
+        pps.extend(iter::repeat(pp).take(added));
+        pps.sort_unstable_by(|a, b| b.total_cmp(a));
+
+        let total_pp = pps.accum_weighted() + bonus_pp;
+
+        let rank = if added == 0 {
+            curr_rank
+        } else {
+            match Context::approx().rank(total_pp, mode).await {
+                // No stored ranks to approximate with
+                Ok(0) => None,
+                // Approximations may be slightly off so the rank is capped
+                Ok(rank) => Some(curr_rank.map_or(rank, |curr| rank.min(curr))),
+                Err(err) => {
+                    warn!(?err, "Failed to approximate rank");
+
+                    None
+                }
+            }
+        };
+
+        points.push(ProjectionPoint {
+            count: added,
+            pp: total_pp,
+            rank,
+        });
+    }
+
+    points
+}
+
+/// Returns `None` if less than two ranks could be approximated.
+fn projection_graph(points: &[ProjectionPoint]) -> Result<Option<Vec<u8>>> {
+    let ranked: Vec<_> = points
+        .iter()
+        .filter_map(|point| Some((point.count, -(point.rank? as i64), point.pp)))
+        .collect();
+
+    let [(first_count, first_rank, first_pp), .., (last_count, last_rank, last_pp)] = ranked[..]
+    else {
+        return Ok(None);
+    };
+
+    let (min, max) = ranked
+        .iter()
+        .fold((i64::MAX, i64::MIN), |(min, max), (_, rank, _)| {
+            (min.min(*rank), max.max(*rank))
+        });
+
+    // Padding so that equal ranks don't result in an empty range
+    let padding = ((max - min) / 10).max(1);
+    let (min, max) = (min - padding, (max + padding).min(-1));
+
+    let mut surface = surfaces::raster_n32_premul((PROJECTION_W as i32, PROJECTION_H as i32))
+        .wrap_err("Failed to create surface")?;
+
+    {
+        let root =
+            SkiaBackend::new(surface.canvas(), PROJECTION_W, PROJECTION_H).into_drawing_area();
+
+        let background = RGBColor(19, 43, 33);
+        root.fill(&background)
+            .wrap_err("Failed to fill background")?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .x_label_area_size(40_i32)
+            .y_label_area_size(75_i32)
+            .margin(10_i32)
+            .build_cartesian_2d(first_count..last_count, min..max)
+            .wrap_err("Failed to build chart")?;
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .x_desc("Additional scores")
+            .y_label_formatter(&|rank| format!("#{}", WithComma::new(-*rank)))
+            .y_desc("Approx. rank")
+            .label_style(("sans-serif", 15_i32, &WHITE))
+            .bold_line_style(WHITE.mix(0.3))
+            .axis_style(RGBColor(7, 18, 14))
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("Failed to draw mesh")?;
+
+        let line_color = RGBColor(0, 208, 138);
+        let data = ranked.iter().map(|(count, rank, _)| (*count, *rank));
+
+        chart
+            .draw_series(LineSeries::new(data, line_color.stroke_width(3)))
+            .wrap_err("Failed to draw line")?
+            .label(format!(
+                "Before: {}pp • #{}",
+                WithComma::new(first_pp),
+                WithComma::new(-first_rank)
+            ))
+            .legend(EmptyElement::at);
+
+        let circles = ranked
+            .iter()
+            .map(|(count, rank, _)| Circle::new((*count, *rank), 4_i32, line_color.filled()));
+
+        chart
+            .draw_series(circles)
+            .wrap_err("Failed to draw points")?
+            .label(format!(
+                "After: {}pp • #{}",
+                WithComma::new(last_pp),
+                WithComma::new(-last_rank)
+            ))
+            .legend(EmptyElement::at);
+
+        chart
+            .configure_series_labels()
+            .border_style(WHITE.mix(0.6).stroke_width(1))
+            .background_style(RGBColor(7, 23, 17))
+            .position(SeriesLabelPosition::LowerRight)
+            .legend_area_size(0_i32)
+            .label_font(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("Failed to draw legend")?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(Some(png_bytes))
+}
@@ -1,9 +1,9 @@
-use std::str::FromStr;
+use std::{fmt::Write, str::FromStr};
 
 use bathbot_psql::model::osu::ArtistTitle;
 use bathbot_util::{
     constants::{GENERAL_ISSUE, OSU_BASE},
-    MessageBuilder,
+    EmbedBuilder, MessageBuilder,
 };
 use eyre::{Report, Result};
 use rosu_v2::prelude::GameMode;
@@ -11,6 +11,7 @@ use tokio::{
     fs::{remove_file, File},
     io::AsyncWriteExt,
 };
+use twilight_model::channel::Attachment;
 
 use super::OwnerAddBg;
 use crate::{
@@ -20,92 +21,202 @@ use crate::{
 };
 
 pub async fn addbg(command: InteractionCommand, bg: OwnerAddBg) -> Result<()> {
-    let OwnerAddBg { image, mode } = bg;
+    let OwnerAddBg {
+        image,
+        image2,
+        image3,
+        image4,
+        image5,
+        mode,
+        check,
+    } = bg;
 
-    let mode = mode.map_or(GameMode::Osu, GameMode::from);
+    let mode = mode.map(GameMode::from);
+    let images = [Some(image), image2, image3, image4, image5];
+    let check = check == Some(true);
+    let mut description = String::new();
 
-    // Check if attachement as proper name
-    let mut filename_split = image.filename.split('.');
+    // Process each image on its own so that one failure does not prevent the
+    // others from being added
+    for image in images.into_iter().flatten() {
+        let res = if check {
+            check_bg(&image, mode).await
+        } else {
+            add_bg(&image, mode).await
+        };
 
-    let mapset_id = match filename_split.next().map(u32::from_str) {
-        Some(Ok(id)) => id,
-        None | Some(Err(_)) => {
-            let content = "Provided image has no appropriate name. \
-                Be sure to let the name be the mapset id, e.g. 948199.png";
-            command.error(content).await?;
-
-            return Ok(());
+        match res {
+            Ok(content) => {
+                let _ = writeln!(description, "✅ `{}`: {content}", image.filename);
+            }
+            Err(content) => {
+                let _ = writeln!(description, "❌ `{}`: {content}", image.filename);
+            }
         }
+    }
+
+    let title = if check {
+        "Background check"
+    } else {
+        "Added backgrounds"
+    };
+
+    let embed = EmbedBuilder::new().title(title).description(description);
+    let builder = MessageBuilder::new().embed(embed);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
+
+/// Check if the attachement has a proper name and file type and return the
+/// mapset id as well as the mode if the name specifies one.
+fn parse_filename(filename: &str) -> Result<(u32, Option<GameMode>), &'static str> {
+    const INVALID_NAME: &str = "Image has no appropriate name. \
+        Be sure to let the name be the mapset id and optionally the mode, \
+        e.g. 948199.png or 948199_mania.png";
+
+    let mut filename_split = filename.split('.');
+
+    let name = filename_split.next().ok_or(INVALID_NAME)?;
+
+    let (mapset_id, mode) = match name.split_once('_') {
+        Some((mapset_id, mode)) => (mapset_id, Some(mode)),
+        None => (name, None),
+    };
+
+    let Ok(mapset_id) = u32::from_str(mapset_id) else {
+        return Err(INVALID_NAME);
+    };
+
+    let mode = match mode {
+        None => None,
+        Some("osu" | "std") => Some(GameMode::Osu),
+        Some("taiko") => Some(GameMode::Taiko),
+        Some("fruits" | "catch" | "ctb") => Some(GameMode::Catch),
+        Some("mania") => Some(GameMode::Mania),
+        Some(_) => return Err(INVALID_NAME),
     };
 
-    // Check if attachement has proper file type
     let valid_filetype_opt = filename_split
         .next()
         .filter(|&filetype| filetype == "jpg" || filetype == "png");
 
     if valid_filetype_opt.is_none() {
-        let content = "Provided image has inappropriate type. Must be either `.jpg` or `.png`";
-        command.error(content).await?;
+        return Err("Image has inappropriate type. Must be either `.jpg` or `.png`");
+    }
+
+    Ok((mapset_id, mode))
+}
+
+/// Parse the filename and pick the mode of the filename, falling back to the
+/// specified mode or osu!standard.
+fn parse_image(
+    filename: &str,
+    fallback: Option<GameMode>,
+) -> Result<(u32, GameMode), &'static str> {
+    let (mapset_id, mode) = parse_filename(filename)?;
 
-        return Ok(());
+    match mode.or(fallback).unwrap_or(GameMode::Osu) {
+        GameMode::Taiko | GameMode::Catch => {
+            Err("Backgrounds can only be added for osu!standard or osu!mania")
+        }
+        mode => Ok((mapset_id, mode)),
     }
+}
+
+async fn add_bg(image: &Attachment, mode: Option<GameMode>) -> Result<String, String> {
+    let (mapset_id, mode) = parse_image(&image.filename, mode)?;
 
     // Download attachement
-    let path = match Context::client().get_discord_attachment(&image).await {
-        Ok(content) => {
-            let mut path = BotConfig::get().paths.backgrounds.clone();
-
-            match mode {
-                GameMode::Osu => path.push("osu"),
-                GameMode::Mania => path.push("mania"),
-                GameMode::Taiko | GameMode::Catch => unreachable!(),
-            }
+    let content = match Context::client().get_discord_attachment(image).await {
+        Ok(content) => content,
+        Err(err) => {
+            warn!(?err, "Failed to get discord attachment");
 
-            path.push(&image.filename);
+            return Err(GENERAL_ISSUE.to_owned());
+        }
+    };
 
-            // Create file
-            let mut file = match File::create(&path).await {
-                Ok(file) => file,
-                Err(err) => {
-                    let _ = command.error(GENERAL_ISSUE).await;
-                    let err = Report::new(err).wrap_err("failed to create file for new bg");
+    let mut path = BotConfig::get().paths.backgrounds.clone();
 
-                    return Err(err);
-                }
-            };
+    match mode {
+        GameMode::Osu => path.push("osu"),
+        GameMode::Mania => path.push("mania"),
+        GameMode::Taiko | GameMode::Catch => unreachable!(),
+    }
 
-            // Store in file
-            if let Err(err) = file.write_all(&content).await {
-                let _ = command.error(GENERAL_ISSUE).await;
-                let err = Report::new(err).wrap_err("failed writing to bg file");
+    path.push(&image.filename);
 
-                return Err(err);
-            }
-            path
-        }
+    // Create file
+    let mut file = match File::create(&path).await {
+        Ok(file) => file,
         Err(err) => {
-            let _ = command.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("failed to create file for new bg");
+            warn!("{err:?}");
 
-            return Err(err.wrap_err("failed to get discord attachment"));
+            return Err(GENERAL_ISSUE.to_owned());
         }
     };
 
+    // Store in file
+    if let Err(err) = file.write_all(&content).await {
+        let err = Report::new(err).wrap_err("failed writing to bg file");
+        warn!("{err:?}");
+
+        return Err(GENERAL_ISSUE.to_owned());
+    }
+
     // Check if valid mapset id
-    let content = match prepare_mapset(mapset_id, &image.filename, mode).await {
-        Ok(ArtistTitle { artist, title }) => format!(
+    match prepare_mapset(mapset_id, &image.filename, mode).await {
+        Ok(ArtistTitle { artist, title }) => Ok(format!(
             "Background for [{artist} - {title}]({OSU_BASE}s/{mapset_id}) successfully added ({mode})",
-        ),
+        )),
         Err(err_msg) => {
             let _ = remove_file(path).await;
 
-            err_msg.to_owned()
+            Err(err_msg.to_owned())
+        }
+    }
+}
+
+/// Report what adding the background would do without downloading or storing
+/// anything.
+async fn check_bg(image: &Attachment, mode: Option<GameMode>) -> Result<String, String> {
+    let (mapset_id, mode) = parse_image(&image.filename, mode)?;
+    let ArtistTitle { artist, title } = validate_mapset(mapset_id).await?;
+
+    let existing = match Context::games().bggame_mapset_filename(mapset_id).await {
+        Ok(existing) => existing,
+        Err(err) => {
+            warn!("{err:?}");
+
+            return Err(GENERAL_ISSUE.to_owned());
         }
     };
 
-    let builder = MessageBuilder::new().embed(content);
-    command.callback(builder, false).await?;
+    let mut content = format!(
+        "Background for [{artist} - {title}]({OSU_BASE}s/{mapset_id}) would be added ({mode})",
+    );
 
-    Ok(())
+    if let Some(existing) = existing {
+        let _ = write!(
+            content,
+            ". There already is an entry for this mapset with file `{existing}` which would be updated"
+        );
+    }
+
+    Ok(content)
+}
+
+async fn validate_mapset(mapset_id: u32) -> Result<ArtistTitle, &'static str> {
+    Context::osu_map()
+        .artist_title(mapset_id)
+        .await
+        .map_err(|err| {
+            warn!("{:?}", Report::new(err));
+
+            GENERAL_ISSUE
+        })
 }
 
 async fn prepare_mapset(
@@ -113,22 +224,45 @@ async fn prepare_mapset(
     filename: &str,
     mode: GameMode,
 ) -> Result<ArtistTitle, &'static str> {
-    let artist_title = match Context::osu_map().artist_title(mapset_id).await {
-        Ok(artist_title) => artist_title,
-        Err(err) => {
-            warn!("{:?}", Report::new(err));
-
-            return Err(GENERAL_ISSUE);
-        }
-    };
+    let artist_title = validate_mapset(mapset_id).await?;
 
     let upsert_fut = Context::games().bggame_upsert_mapset(mapset_id, filename, mode);
 
     if let Err(err) = upsert_fut.await {
         warn!("{err:?}");
 
-        return Err("There is already an entry with this mapset id");
+        return Err(GENERAL_ISSUE);
     }
 
     Ok(artist_title)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filename_with_mode() {
+        assert_eq!(parse_filename("948199.png"), Ok((948199, None)));
+        assert_eq!(
+            parse_filename("948199_mania.jpg"),
+            Ok((948199, Some(GameMode::Mania)))
+        );
+        assert!(parse_filename("948199_unknown.png").is_err());
+        assert!(parse_filename("948199.gif").is_err());
+    }
+
+    #[test]
+    fn rejects_taiko_and_catch_per_file() {
+        assert_eq!(
+            parse_image("948199.png", Some(GameMode::Mania)),
+            Ok((948199, GameMode::Mania))
+        );
+        assert_eq!(
+            parse_image("948199_osu.png", Some(GameMode::Mania)),
+            Ok((948199, GameMode::Osu))
+        );
+        assert!(parse_image("948199_taiko.png", None).is_err());
+        assert!(parse_image("948199.png", Some(GameMode::Catch)).is_err());
+    }
+}
@@ -0,0 +1,134 @@
+use std::str::FromStr;
+
+use bathbot_model::MapsetTags;
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_BASE},
+    EmbedBuilder, MessageBuilder,
+};
+use eyre::Result;
+
+use super::OwnerBgTags;
+use crate::{
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+pub async fn bgtags(command: InteractionCommand, args: OwnerBgTags) -> Result<()> {
+    let OwnerBgTags {
+        mapset_id,
+        add,
+        remove,
+    } = args;
+
+    let Ok(mapset_id) = u32::try_from(mapset_id) else {
+        let content = format!("Invalid mapset id {mapset_id}");
+        command.error_callback(content).await?;
+
+        return Ok(());
+    };
+
+    let add = match add.as_deref().map(parse_tags).transpose() {
+        Ok(add) => add.unwrap_or_else(MapsetTags::empty),
+        Err(content) => {
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    let remove = match remove.as_deref().map(parse_tags).transpose() {
+        Ok(remove) => remove.unwrap_or_else(MapsetTags::empty),
+        Err(content) => {
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    if add.intersects(remove) {
+        let content = format!(
+            "Cannot add and remove the same tags: {}",
+            add.intersection(remove).join(", ")
+        );
+        command.error_callback(content).await?;
+
+        return Ok(());
+    }
+
+    let games = Context::games();
+
+    let before = match games.bggame_mapset_tags(mapset_id).await {
+        Ok(Some(tags)) => tags,
+        Ok(None) => {
+            let content = format!("There is no background for mapset id {mapset_id}");
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error_callback(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let after = (before | add) - remove;
+
+    let description = if after == before {
+        format!("Tags: {}", before.join(", "))
+    } else {
+        match games.bggame_update_mapset_tags(mapset_id, after).await {
+            Ok(true) => {}
+            Ok(false) => {
+                let content = format!("There is no background for mapset id {mapset_id}");
+                command.error_callback(content).await?;
+
+                return Ok(());
+            }
+            Err(err) => {
+                let _ = command.error_callback(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        }
+
+        format!("Before: {}\nAfter: {}", before.join(", "), after.join(", "))
+    };
+
+    let embed = EmbedBuilder::new()
+        .title(format!("Tags of mapset {mapset_id}"))
+        .url(format!("{OSU_BASE}s/{mapset_id}"))
+        .description(description);
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
+
+/// Parse comma or whitespace separated tag names.
+fn parse_tags(input: &str) -> Result<MapsetTags, String> {
+    let mut tags = MapsetTags::empty();
+    let mut unknown = Vec::new();
+
+    let names = input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|name| !name.is_empty());
+
+    for name in names {
+        match MapsetTags::from_str(name) {
+            Ok(tag) => tags |= tag,
+            Err(name) => unknown.push(name),
+        }
+    }
+
+    if unknown.is_empty() {
+        Ok(tags)
+    } else {
+        Err(format!(
+            "Unknown tags: `{}`. Available tags: {}",
+            unknown.join("`, `"),
+            MapsetTags::all().join(", ")
+        ))
+    }
+}
@@ -13,11 +13,13 @@ pub async fn cache(command: InteractionCommand) -> Result<()> {
         "Guilds: {guilds}\n\
         Unavailable guilds: {unavailable_guilds}\n\
         Users: {users}\n\
+        Members: {members}\n\
         Roles: {roles}\n\
         Channels: {channels}",
         guilds = WithComma::new(stats.guilds),
         unavailable_guilds = WithComma::new(stats.unavailable_guilds),
         users = WithComma::new(stats.users),
+        members = WithComma::new(stats.members),
         roles = WithComma::new(stats.roles),
         channels = WithComma::new(stats.channels),
     );
@@ -0,0 +1,170 @@
+use std::fmt::Write;
+
+use bathbot_util::{constants::GENERAL_ISSUE, datetime::HowLongAgoDynamic, MessageBuilder};
+use eyre::Result;
+use rosu_v2::prelude::GameMode;
+use time::OffsetDateTime;
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use super::{CacheEntryKind, OwnerCacheEntry};
+use crate::{
+    core::Context,
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+};
+
+pub async fn cache_entry(command: InteractionCommand, args: OwnerCacheEntry) -> Result<()> {
+    let OwnerCacheEntry { kind, id, evict } = args;
+    let evict = evict.unwrap_or(false);
+
+    let res = match kind {
+        CacheEntryKind::User => user_entry(&id, evict).await,
+        CacheEntryKind::Map => map_entry(&id, evict).await,
+        CacheEntryKind::Guild => guild_entry(&id, evict).await,
+    };
+
+    match res {
+        Ok(Ok(description)) => {
+            let builder = MessageBuilder::new().embed(description);
+            command.callback(builder, false).await?;
+
+            Ok(())
+        }
+        Ok(Err(content)) => {
+            command.error_callback(content).await?;
+
+            Ok(())
+        }
+        Err(err) => {
+            let _ = command.error_callback(GENERAL_ISSUE).await;
+
+            Err(err.wrap_err("Failed to inspect cache entry"))
+        }
+    }
+}
+
+async fn user_entry(id: &str, evict: bool) -> Result<Result<String, &'static str>> {
+    let Ok(user_id) = id.parse::<u32>() else {
+        return Ok(Err("Must provide a valid osu! user id"));
+    };
+
+    let mut description = format!("**osu! user {user_id}**\n");
+
+    for mode in [
+        GameMode::Osu,
+        GameMode::Taiko,
+        GameMode::Catch,
+        GameMode::Mania,
+    ] {
+        let _ = match Context::redis().osu_user_age(user_id, mode).await? {
+            Some(age) => writeln!(description, "`{mode:?}`: Cached in redis {age}s ago"),
+            None => writeln!(description, "`{mode:?}`: Not cached in redis"),
+        };
+    }
+
+    let _ = match Context::osu_user().last_update(user_id).await? {
+        Some(last_update) => writeln!(
+            description,
+            "Stats stored in the database {}",
+            HowLongAgoDynamic::new(&last_update)
+        ),
+        None => writeln!(description, "No stats stored in the database"),
+    };
+
+    if evict {
+        let removed = Context::redis().evict_osu_user(user_id).await?;
+        let _ = write!(description, "\nEvicted {removed} redis entries");
+    }
+
+    Ok(Ok(description))
+}
+
+async fn map_entry(id: &str, evict: bool) -> Result<Result<String, &'static str>> {
+    let Ok(map_id) = id.parse::<u32>() else {
+        return Ok(Err("Must provide a valid map id"));
+    };
+
+    let mut description = format!("**Map {map_id}**\n");
+
+    let _ = match Context::osu_map().last_update(map_id).await? {
+        Some(last_update) => writeln!(
+            description,
+            "Stored in the database {}",
+            HowLongAgoDynamic::new(&last_update)
+        ),
+        None => writeln!(description, "Not stored in the database"),
+    };
+
+    if evict {
+        Context::osu_map().evict(map_id).await?;
+        description.push_str("\nEvicted map, it will be retrieved anew on the next request");
+    }
+
+    Ok(Ok(description))
+}
+
+async fn guild_entry(id: &str, evict: bool) -> Result<Result<String, &'static str>> {
+    let Ok(Some(guild_id)) = id.parse().map(Id::new_checked) else {
+        return Ok(Err("Must provide a valid guild id"));
+    };
+
+    let summary = Context::guild_config().peek_cached(guild_id, |config| {
+        format!(
+            "Prefixes: {:?}\n\
+            Authorities: {:?}\n\
+            List size: {:?}\n\
+            Track limit: {:?}\n\
+            Allow songs: {:?}",
+            config.prefixes,
+            config.authorities,
+            config.list_size,
+            config.track_limit,
+            config.allow_songs,
+        )
+    });
+
+    let mut description = format!("**Guild config {guild_id}**\n");
+
+    match summary {
+        Some(summary) => {
+            let _ = writeln!(description, "Held in memory\n{summary}");
+        }
+        None => description.push_str("Not held in memory\n"),
+    }
+
+    match bot_joined_at(guild_id).await {
+        Some(joined_at) => {
+            let _ = writeln!(
+                description,
+                "Joined the guild {}",
+                HowLongAgoDynamic::new(&joined_at)
+            );
+        }
+        None => description.push_str("Unknown when the guild was joined\n"),
+    }
+
+    if evict {
+        let content = if Context::guild_config().reload(guild_id).await? {
+            "\nEvicted config and reloaded it from the database"
+        } else {
+            "\nEvicted config, the database has no entry for it"
+        };
+
+        description.push_str(content);
+    }
+
+    Ok(Ok(description))
+}
+
+async fn bot_joined_at(guild_id: Id<GuildMarker>) -> Option<OffsetDateTime> {
+    let current_user = Context::cache().current_user().await.ok().flatten()?;
+
+    let member = Context::http()
+        .guild_member(guild_id, current_user.id)
+        .await
+        .ok()?
+        .model()
+        .await
+        .ok()?;
+
+    OffsetDateTime::from_unix_timestamp(member.joined_at.as_secs()).ok()
+}
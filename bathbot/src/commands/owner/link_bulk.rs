@@ -0,0 +1,101 @@
+use std::fmt::Write;
+
+use bathbot_util::{matcher, EmbedBuilder, MessageBuilder};
+use eyre::Result;
+use rosu_v2::prelude::{GameMode, OsuError};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{
+    core::Context,
+    manager::redis::osu::UserArgs,
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+};
+
+const MAX_PAIRS: usize = 50;
+
+pub async fn linkbulk(command: InteractionCommand, pairs: &str) -> Result<()> {
+    let pairs: Vec<_> = pairs
+        .split([',', '\n'])
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .collect();
+
+    if pairs.is_empty() {
+        let content = "Must provide pairs of the form `discord_id:osu_id`";
+        command.error_callback(content).await?;
+
+        return Ok(());
+    } else if pairs.len() > MAX_PAIRS {
+        let content = format!("Can link at most {MAX_PAIRS} pairs at once");
+        command.error_callback(content).await?;
+
+        return Ok(());
+    }
+
+    command.defer(false).await?;
+
+    let mut description = String::with_capacity(pairs.len() * 48);
+    let mut linked = 0;
+
+    for pair in pairs.iter() {
+        let _ = match link_pair(pair).await {
+            Ok(username) => {
+                linked += 1;
+
+                writeln!(description, "✅ `{pair}` → {username}")
+            }
+            Err(reason) => writeln!(description, "❌ `{pair}`: {reason}"),
+        };
+    }
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .title(format!("Linked {linked}/{} pair(s)", pairs.len()));
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+/// Link a single pair and return the osu! username on success or the reason
+/// for failure otherwise.
+async fn link_pair(pair: &str) -> Result<String, String> {
+    let (discord_id, osu_id) = parse_pair(pair).ok_or("Failed to parse pair")?;
+
+    let user_args = UserArgs::user_id(osu_id, GameMode::Osu);
+
+    let username = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user.username().to_owned(),
+        Err(OsuError::NotFound) => return Err(format!("osu! user {osu_id} was not found")),
+        Err(err) => {
+            warn!(?err, osu_id, "Failed to get user for bulk link");
+
+            return Err("Failed to request osu! user".to_owned());
+        }
+    };
+
+    if let Err(err) = Context::user_config().link_osu(discord_id, osu_id).await {
+        warn!(?err, %discord_id, osu_id, "Failed to link user for bulk link");
+
+        return Err("Failed to store link".to_owned());
+    }
+
+    Ok(username)
+}
+
+/// Parse `discord_id:osu_id` or `<@mention> osu_id`
+fn parse_pair(pair: &str) -> Option<(Id<UserMarker>, u32)> {
+    let (discord, osu) = pair
+        .split_once(':')
+        .or_else(|| pair.split_once(char::is_whitespace))?;
+
+    let discord = discord.trim();
+
+    let discord_id = matcher::get_mention_user(discord)
+        .or_else(|| discord.parse().ok().and_then(Id::new_checked))?;
+
+    let osu_id = osu.trim().parse().ok()?;
+
+    Some((discord_id, osu_id))
+}
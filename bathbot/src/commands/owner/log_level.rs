@@ -0,0 +1,25 @@
+use bathbot_util::MessageBuilder;
+use eyre::Result;
+
+use crate::{
+    core::logging,
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+};
+
+pub async fn loglevel(command: InteractionCommand, filter: Option<&str>) -> Result<()> {
+    match logging::set_stdout_filter(filter) {
+        Ok((previous, new)) => {
+            let content = format!("Log filter: `{previous}` -> `{new}`");
+            let builder = MessageBuilder::new().embed(content);
+            command.callback(builder, false).await?;
+
+            info!(%previous, %new, "Changed log filter");
+        }
+        Err(err) => {
+            let content = format!("Failed to change log filter: {err:#}");
+            command.error_callback(content).await?;
+        }
+    }
+
+    Ok(())
+}
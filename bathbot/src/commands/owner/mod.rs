@@ -1,13 +1,16 @@
 use bathbot_macros::SlashCommand;
 use bathbot_model::command_fields::GameModeOption;
 use eyre::Result;
-use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::channel::Attachment;
 
 pub use self::reshard::RESHARD_TX;
-use self::{add_bg::*, cache::*, request_members::*};
+use self::{
+    add_bg::*, bg_tags::*, cache::*, cache_entry::*, link_bulk::*, log_level::*, pp_bench::*,
+    register_commands::*, remove_bg::*, request_members::*, role_assigns::*, shards::*,
+};
 #[cfg(feature = "osutracking")]
-use self::{tracking_interval::*, tracking_stats::*};
+use self::{tracking_interval::*, tracking_stats::*, tracking_user::*};
 #[cfg(feature = "osutracking")]
 use crate::tracking::default_tracking_interval;
 use crate::{
@@ -16,9 +19,18 @@ use crate::{
 };
 
 mod add_bg;
+mod bg_tags;
 mod cache;
+mod cache_entry;
+mod link_bulk;
+mod log_level;
+mod pp_bench;
+mod register_commands;
+mod remove_bg;
 mod request_members;
 mod reshard;
+mod role_assigns;
+mod shards;
 
 #[cfg(feature = "osutracking")]
 mod tracking_interval;
@@ -26,6 +38,9 @@ mod tracking_interval;
 #[cfg(feature = "osutracking")]
 mod tracking_stats;
 
+#[cfg(feature = "osutracking")]
+mod tracking_user;
+
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "owner", desc = "You won't be able to use this :^)")]
 #[flags(ONLY_OWNER, SKIP_DEFER)]
@@ -33,30 +48,150 @@ mod tracking_stats;
 pub enum Owner {
     #[command(name = "add_bg")]
     AddBg(OwnerAddBg),
+    #[command(name = "bg_tags")]
+    BgTags(OwnerBgTags),
     #[command(name = "cache")]
     Cache(OwnerCache),
+    #[command(name = "cache_entry")]
+    CacheEntry(OwnerCacheEntry),
+    #[command(name = "link_bulk")]
+    LinkBulk(OwnerLinkBulk),
+    #[command(name = "loglevel")]
+    LogLevel(OwnerLogLevel),
+    #[command(name = "pp_bench")]
+    PpBench(OwnerPpBench),
+    #[command(name = "register_commands")]
+    RegisterCommands(OwnerRegisterCommands),
+    #[command(name = "remove_bg")]
+    RemoveBg(OwnerRemoveBg),
     #[command(name = "requestmembers")]
     RequestMembers(OwnerRequestMembers),
     #[command(name = "reshard")]
     Reshard(OwnerReshard),
+    #[command(name = "role_assigns")]
+    RoleAssigns(OwnerRoleAssigns),
+    #[command(name = "shards")]
+    Shards(OwnerShards),
     #[cfg(feature = "osutracking")]
     #[command(name = "tracking")]
     Tracking(OwnerTracking),
 }
 
 #[derive(CommandModel, CreateCommand)]
-#[command(name = "add_bg", desc = "Add a background to the bg game")]
+#[command(name = "add_bg", desc = "Add backgrounds to the bg game")]
 pub struct OwnerAddBg {
-    #[command(desc = "Add a png or jpg image with the mapset id as name")]
+    #[command(
+        desc = "Add a png or jpg image named after the mapset id and optionally mode e.g. 948199_mania.png"
+    )]
     image: Attachment,
-    #[command(desc = "Specify the mode of the background's map")]
+    #[command(desc = "Add another image")]
+    image2: Option<Attachment>,
+    #[command(desc = "Add another image")]
+    image3: Option<Attachment>,
+    #[command(desc = "Add another image")]
+    image4: Option<Attachment>,
+    #[command(desc = "Add another image")]
+    image5: Option<Attachment>,
+    #[command(desc = "Specify the mode for images whose name does not contain one")]
     mode: Option<GameModeOption>,
+    #[command(desc = "Only check what would happen without storing anything")]
+    check: Option<bool>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "bg_tags",
+    desc = "View or edit the tags of a bg game background"
+)]
+pub struct OwnerBgTags {
+    #[command(min_value = 1, desc = "Specify the mapset id of the background")]
+    mapset_id: i64,
+    #[command(desc = "Comma-separated tags to add, e.g. `farm, old`")]
+    add: Option<String>,
+    #[command(desc = "Comma-separated tags to remove, e.g. `meme`")]
+    remove: Option<String>,
 }
 
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "cache", desc = "Display stats about the internal cache")]
 pub struct OwnerCache;
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "cache_entry",
+    desc = "Inspect a single cache entry and optionally evict it"
+)]
+pub struct OwnerCacheEntry {
+    #[command(desc = "Specify the kind of entry")]
+    kind: CacheEntryKind,
+    #[command(desc = "Specify the osu! user id, map id, or guild id")]
+    id: String, // u64 might be larger than what discord accepts as valid integer
+    #[command(desc = "Evict the entry so that it will be retrieved anew")]
+    evict: Option<bool>,
+}
+
+#[derive(CommandOption, CreateOption)]
+pub enum CacheEntryKind {
+    #[option(name = "osu! user", value = "user")]
+    User,
+    #[option(name = "Map", value = "map")]
+    Map,
+    #[option(name = "Guild config", value = "guild")]
+    Guild,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "link_bulk",
+    desc = "Link multiple discord users to osu! user ids at once"
+)]
+pub struct OwnerLinkBulk {
+    #[command(desc = "Comma-separated pairs of the form `discord_id:osu_id`, e.g. `123:2, 456:3`")]
+    pairs: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "loglevel",
+    desc = "Change the stdout log filter until the next restart"
+)]
+pub struct OwnerLogLevel {
+    #[command(
+        desc = "Filter directives e.g. `bathbot=trace,info`, resets to the default if omitted"
+    )]
+    filter: Option<String>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "pp_bench",
+    desc = "Time parsing and pp calculation of a map for each mode"
+)]
+pub struct OwnerPpBench {
+    #[command(desc = "Specify a map url or map id")]
+    map: String,
+    #[command(
+        min_value = 1,
+        max_value = 20,
+        desc = "Specify the amount of repetitions, defaults to 5"
+    )]
+    reps: Option<i64>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "register_commands",
+    desc = "Re-register all slash commands with their current definitions"
+)]
+pub struct OwnerRegisterCommands;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "remove_bg", desc = "Remove a background from the bg game")]
+pub struct OwnerRemoveBg {
+    #[command(min_value = 1, desc = "Specify the mapset id of the background")]
+    mapset_id: i64,
+}
+
 #[derive(CommandModel, CreateCommand)]
 #[command(
     name = "requestmembers",
@@ -71,6 +206,20 @@ pub struct OwnerRequestMembers {
 #[command(name = "reshard", desc = "Reshard the gateway")]
 pub struct OwnerReshard;
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "role_assigns",
+    desc = "Assign roles for reactions that were added while offline"
+)]
+pub struct OwnerRoleAssigns;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "shards",
+    desc = "Display connection state, latency, and guild count of each shard"
+)]
+pub struct OwnerShards;
+
 #[cfg(feature = "osutracking")]
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "tracking", desc = "Stuff about osu!tracking")]
@@ -81,6 +230,8 @@ pub enum OwnerTracking {
     Stats(OwnerTrackingStats),
     #[command(name = "toggle")]
     Toggle(OwnerTrackingToggle),
+    #[command(name = "user")]
+    User(OwnerTrackingUser),
 }
 
 #[cfg(feature = "osutracking")]
@@ -101,12 +252,34 @@ pub struct OwnerTrackingStats;
 #[command(name = "toggle", desc = "Enable or disable tracking")]
 pub struct OwnerTrackingToggle;
 
+#[cfg(feature = "osutracking")]
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "user",
+    desc = "Display when a tracked user was last checked and will be checked next"
+)]
+pub struct OwnerTrackingUser {
+    #[command(desc = "Specify a username")]
+    name: String,
+    #[command(desc = "Specify the tracked mode, defaults to osu!standard")]
+    mode: Option<GameModeOption>,
+}
+
 async fn slash_owner(mut command: InteractionCommand) -> Result<()> {
     match Owner::from_interaction(command.input_data())? {
         Owner::AddBg(bg) => addbg(command, bg).await,
+        Owner::BgTags(args) => bgtags(command, args).await,
         Owner::Cache(_) => cache(command).await,
+        Owner::CacheEntry(args) => cache_entry(command, args).await,
+        Owner::LinkBulk(args) => linkbulk(command, &args.pairs).await,
+        Owner::LogLevel(args) => loglevel(command, args.filter.as_deref()).await,
+        Owner::PpBench(args) => ppbench(command, &args.map, args.reps).await,
+        Owner::RegisterCommands(_) => register_commands(command).await,
+        Owner::RemoveBg(args) => removebg(command, args.mapset_id).await,
         Owner::RequestMembers(args) => request_members(command, &args.guild_id).await,
         Owner::Reshard(_) => reshard(command).await,
+        Owner::RoleAssigns(_) => role_assigns(command).await,
+        Owner::Shards(_) => shards(command).await,
         #[cfg(feature = "osutracking")]
         Owner::Tracking(OwnerTracking::Interval(interval)) => {
             let secs = interval
@@ -128,5 +301,7 @@ async fn slash_owner(mut command: InteractionCommand) -> Result<()> {
 
             Ok(())
         }
+        #[cfg(feature = "osutracking")]
+        Owner::Tracking(OwnerTracking::User(args)) => trackinguser(command, args).await,
     }
 }
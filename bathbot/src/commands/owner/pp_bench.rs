@@ -0,0 +1,170 @@
+use std::{
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+use bathbot_util::{
+    constants::GENERAL_ISSUE, matcher, EmbedBuilder, FooterBuilder, MessageBuilder,
+};
+use eyre::Result;
+use rosu_pp::{model::mode::GameMode as Mode, Beatmap, Difficulty};
+
+use crate::{
+    manager::MapError,
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+const DEFAULT_REPS: usize = 5;
+const MAX_REPS: usize = 20;
+
+pub async fn ppbench(command: InteractionCommand, map: &str, reps: Option<i64>) -> Result<()> {
+    let Some(map_id) = matcher::get_osu_map_id(map) else {
+        let content =
+            "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+        command.error_callback(content).await?;
+
+        return Ok(());
+    };
+
+    let reps = reps.map_or(DEFAULT_REPS, |reps| reps.clamp(1, MAX_REPS as i64) as usize);
+
+    command.defer(false).await?;
+
+    let (pp_map, mut parse_times) = match load_map(map_id, reps).await {
+        Ok(tuple) => tuple,
+        Err(MapError::NotFound) => {
+            let content = format!("Could not find map {map_id}");
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(MapError::Report(err)) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to load map for pp bench"));
+        }
+    };
+
+    let mut description = String::with_capacity(512);
+    description.push_str("```\n");
+
+    let _ = writeln!(description, "{:<10} │ {:>10} │ {:>10}", "", "min", "median");
+
+    write_row(&mut description, "Parse", &mut parse_times);
+
+    for mode in [Mode::Osu, Mode::Taiko, Mode::Catch, Mode::Mania] {
+        let mut map = pp_map.clone();
+
+        if map.mode != mode {
+            map.convert_in_place(mode);
+        }
+
+        // Only osu!standard maps can be converted to other modes
+        if map.mode != mode {
+            let _ = writeln!(
+                description,
+                "{:<10} │ {:>10} │ {:>10}",
+                mode_str(mode),
+                "-",
+                "-"
+            );
+
+            continue;
+        }
+
+        let (mut diff_times, mut perf_times) = bench_mode(&map, reps);
+
+        write_row(
+            &mut description,
+            &format!("{} diff", mode_str(mode)),
+            &mut diff_times,
+        );
+        write_row(
+            &mut description,
+            &format!("{} pp", mode_str(mode)),
+            &mut perf_times,
+        );
+    }
+
+    description.push_str("```");
+
+    let footer = format!(
+        "{reps} repetition{} • Difficulty is calculated without cache",
+        if reps == 1 { "" } else { "s" }
+    );
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .footer(FooterBuilder::new(footer))
+        .title(format!("pp calculation benchmark for map {map_id}"));
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+/// Load the map repeatedly through the [`MapManager`] which includes the
+/// filename lookup and reading the file on top of parsing it.
+///
+/// [`MapManager`]: crate::manager::MapManager
+async fn load_map(map_id: u32, reps: usize) -> Result<(Beatmap, Vec<Duration>), MapError> {
+    let mut times = Vec::with_capacity(reps);
+
+    let start = Instant::now();
+    let map = Context::osu_map().pp_map(map_id).await?;
+    times.push(start.elapsed());
+
+    for _ in 1..reps {
+        let start = Instant::now();
+        Context::osu_map().pp_map(map_id).await?;
+        times.push(start.elapsed());
+    }
+
+    Ok((map, times))
+}
+
+/// Time the difficulty and performance calculation on the given map.
+///
+/// The difficulty cache of [`PpManager`](crate::manager::PpManager) is
+/// bypassed so that each repetition actually calculates.
+fn bench_mode(map: &Beatmap, reps: usize) -> (Vec<Duration>, Vec<Duration>) {
+    let mut diff_times = Vec::with_capacity(reps);
+    let mut perf_times = Vec::with_capacity(reps);
+
+    for _ in 0..reps {
+        let start = Instant::now();
+        let attrs = Difficulty::new().calculate(map);
+        diff_times.push(start.elapsed());
+
+        let start = Instant::now();
+        let _ = attrs.performance().calculate();
+        perf_times.push(start.elapsed());
+    }
+
+    (diff_times, perf_times)
+}
+
+fn write_row(description: &mut String, label: &str, times: &mut [Duration]) {
+    times.sort_unstable();
+
+    let min = times.first().copied().unwrap_or_default();
+    let median = times.get(times.len() / 2).copied().unwrap_or_default();
+
+    let _ = writeln!(
+        description,
+        "{label:<10} │ {:>10} │ {:>10}",
+        format!("{min:.2?}"),
+        format!("{median:.2?}"),
+    );
+}
+
+fn mode_str(mode: Mode) -> &'static str {
+    match mode {
+        Mode::Osu => "osu",
+        Mode::Taiko => "taiko",
+        Mode::Catch => "catch",
+        Mode::Mania => "mania",
+    }
+}
@@ -0,0 +1,43 @@
+use bathbot_util::MessageBuilder;
+use eyre::Result;
+
+use crate::{
+    core::{commands::interaction::InteractionCommands, Context},
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+};
+
+pub async fn register_commands(command: InteractionCommand) -> Result<()> {
+    command.defer(false).await?;
+
+    let slash_commands = InteractionCommands::get().collect();
+
+    #[cfg(feature = "global_slash")]
+    let (res, scope) = (Context::set_global_commands(slash_commands).await, "global");
+
+    #[cfg(not(feature = "global_slash"))]
+    let (res, scope) = (Context::set_guild_commands(slash_commands).await, "guild");
+
+    match res {
+        Ok(cmds) => {
+            InteractionCommands::set_ids(&cmds);
+
+            let content = format!("Registered {} {scope} slash commands", cmds.len());
+            let builder = MessageBuilder::new().embed(content);
+            command.update(builder).await?;
+
+            Ok(())
+        }
+        Err(err) => {
+            // Discord's response explains which definition it rejected
+            let mut content = format!("Failed to register slash commands:\n{err:#}");
+
+            if let Some((idx, _)) = content.char_indices().nth(2000) {
+                content.truncate(idx);
+            }
+
+            let _ = command.error(content).await;
+
+            Err(err.wrap_err("Failed to re-register slash commands"))
+        }
+    }
+}
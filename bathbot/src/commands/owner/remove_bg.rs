@@ -0,0 +1,62 @@
+use bathbot_util::{constants::GENERAL_ISSUE, MessageBuilder};
+use eyre::{Report, Result};
+use rosu_v2::prelude::GameMode;
+use tokio::fs::remove_file;
+
+use crate::{
+    core::BotConfig,
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+pub async fn removebg(command: InteractionCommand, mapset_id: i64) -> Result<()> {
+    let Ok(mapset_id) = u32::try_from(mapset_id) else {
+        let content = format!("Invalid mapset id {mapset_id}");
+        command.error_callback(content).await?;
+
+        return Ok(());
+    };
+
+    let (filename, mode) = match Context::games().bggame_remove_mapset(mapset_id).await {
+        Ok(Some(entry)) => entry,
+        Ok(None) => {
+            let content = format!("There is no background for mapset id {mapset_id}");
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error_callback(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let mut path = BotConfig::get().paths.backgrounds.clone();
+
+    match mode {
+        GameMode::Osu => path.push("osu"),
+        GameMode::Mania => path.push("mania"),
+        GameMode::Taiko | GameMode::Catch => {}
+    }
+
+    path.push(&filename);
+
+    let content = match remove_file(&path).await {
+        Ok(_) => format!("Removed background `{filename}` of mapset {mapset_id} ({mode})"),
+        Err(err) => {
+            let err = Report::new(err).wrap_err("failed to remove bg file");
+            warn!(path = %path.display(), "{err:?}");
+
+            format!(
+                "Removed mapset {mapset_id} from the database but failed to delete \
+                its file `{filename}` ({mode})"
+            )
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
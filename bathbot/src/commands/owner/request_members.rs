@@ -25,13 +25,18 @@ pub async fn request_members(command: InteractionCommand, guild_id: &str) -> Res
         return Ok(());
     };
 
-    ctx.member_requests
-        .pending_guilds
-        .lock()
-        .unwrap()
-        .insert(guild);
+    if let Err(remaining) = ctx.member_requests.debounce_forced(guild) {
+        let content = format!(
+            "Members of guild {guild} were requested recently, try again in {}s",
+            remaining.as_secs() + 1
+        );
 
-    match ctx.member_requests.tx.send((guild, shard_id)) {
+        command.error_callback(content).await?;
+
+        return Ok(());
+    }
+
+    match ctx.member_requests.enqueue(guild, shard_id) {
         Ok(_) => {
             let content = "Successfully enqueued member request";
             let builder = MessageBuilder::new().embed(content);
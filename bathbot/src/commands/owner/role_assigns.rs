@@ -0,0 +1,26 @@
+use bathbot_util::{constants::GENERAL_ISSUE, MessageBuilder};
+use eyre::Result;
+
+use crate::{
+    core::Context,
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+};
+
+pub async fn role_assigns(command: InteractionCommand) -> Result<()> {
+    command.defer(false).await?;
+
+    match Context::role_assigns().reconcile().await {
+        Ok(reconciled) => {
+            let content = format!("Reconciled role assigns by assigning {reconciled} missing role(s)");
+            let builder = MessageBuilder::new().embed(content);
+            command.update(builder).await?;
+
+            Ok(())
+        }
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            Err(err.wrap_err("Failed to reconcile role assigns"))
+        }
+    }
+}
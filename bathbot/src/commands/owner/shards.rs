@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use bathbot_util::IntHasher;
+use eyre::Result;
+
+use crate::{
+    active::{
+        impls::{ShardEntry, ShardsPagination},
+        ActiveMessages,
+    },
+    util::{interaction::InteractionCommand, Authored},
+    Context,
+};
+
+pub async fn shards(mut command: InteractionCommand) -> Result<()> {
+    let owner = command.user_id()?;
+    let ctx = Context::get();
+
+    let mut guild_counts: HashMap<u64, usize, IntHasher> = HashMap::default();
+
+    for shard_id in ctx.guild_shards().pin().values() {
+        *guild_counts.entry(*shard_id).or_default() += 1;
+    }
+
+    let mut shard_ids: Vec<_> = ctx.shard_senders.read().unwrap().keys().copied().collect();
+    shard_ids.sort_unstable();
+
+    let entries: Box<[_]> = {
+        let statuses = ctx.shard_statuses.pin();
+
+        shard_ids
+            .into_iter()
+            .map(|shard_id| ShardEntry {
+                shard_id,
+                status: statuses.get(&shard_id).copied(),
+                guilds: guild_counts.get(&shard_id).copied().unwrap_or(0),
+            })
+            .collect()
+    };
+
+    let pagination = ShardsPagination::builder()
+        .entries(entries)
+        .total_guilds(Context::cache().stats().guilds)
+        .msg_owner(owner)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(false)
+        .begin(&mut command)
+        .await
+}
@@ -31,6 +31,14 @@ pub async fn trackingstats(command: InteractionCommand) -> Result<()> {
             value: format!("{}ms", stats.ms_per_track),
             inline: true,
         },
+        EmbedField {
+            name: "Full cycle".to_owned(),
+            value: match stats.target_cycle {
+                Some(target) => format!("~{}s (target {target}s)", stats.cycle),
+                None => format!("~{}s", stats.cycle),
+            },
+            inline: true,
+        },
     ];
 
     if let Some(entry) = stats.next_pop {
@@ -0,0 +1,86 @@
+use bathbot_psql::model::osu::TrackedOsuUserKey;
+use bathbot_util::{constants::OSU_API_ISSUE, EmbedBuilder, MessageBuilder};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{GameMode, OsuError};
+use time::OffsetDateTime;
+use twilight_model::channel::message::embed::EmbedField;
+
+use super::OwnerTrackingUser;
+use crate::{
+    manager::redis::osu::UserArgs,
+    tracking::TrackedUserStatus,
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+pub async fn trackinguser(command: InteractionCommand, args: OwnerTrackingUser) -> Result<()> {
+    let mode = args.mode.map_or(GameMode::Osu, GameMode::from);
+
+    command.defer(false).await?;
+
+    let user_args = UserArgs::username(&args.name, mode).await;
+
+    let user_id = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user.user_id(),
+        Err(OsuError::NotFound) => {
+            let content = format!("User `{}` was not found", args.name);
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error(OSU_API_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get user"));
+        }
+    };
+
+    let key = TrackedOsuUserKey { user_id, mode };
+    let tracking = Context::tracking();
+
+    let fields = match tracking.user_status(key).await {
+        TrackedUserStatus::NotTracked => {
+            let content = format!("User `{}` is not tracked in {mode}", args.name);
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        TrackedUserStatus::Processing { last_top_score } => vec![
+            field("Last top score", timestamp(last_top_score)),
+            field("Next check", "Currently being checked".to_owned()),
+        ],
+        TrackedUserStatus::Queued(stats) => vec![
+            field("Last top score", timestamp(stats.last_top_score)),
+            field("Last check", timestamp(stats.last_check)),
+            field("Next check", format!("~{}", timestamp(stats.next_check))),
+            field(
+                "Queue position",
+                format!("{}/{}", stats.position + 1, stats.queue),
+            ),
+        ],
+    };
+
+    let mut title = format!("Tracking of {} ({user_id}) | mode={mode}", args.name);
+
+    if tracking.stop_tracking() {
+        title.push_str(" | tracking disabled");
+    }
+
+    let embed = EmbedBuilder::new().fields(fields).title(title);
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+fn field(name: &str, value: String) -> EmbedField {
+    EmbedField {
+        name: name.to_owned(),
+        value,
+        inline: true,
+    }
+}
+
+fn timestamp(datetime: OffsetDateTime) -> String {
+    format!("<t:{}:R>", datetime.unix_timestamp())
+}
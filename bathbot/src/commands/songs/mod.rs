@@ -20,13 +20,15 @@ mod time_traveler;
 mod wordsneversaid;
 mod zenzenzense;
 
-use std::fmt::Write;
+use std::{collections::VecDeque, fmt::Write};
 
 use bathbot_macros::SlashCommand;
 use bathbot_util::MessageBuilder;
 use eyre::{ContextCompat, Result};
-use tokio::time::{interval, Duration};
+use hashbrown::hash_map::Entry;
+use tokio::time::{interval, sleep, Duration, Instant};
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::id::{marker::ChannelMarker, Id};
 
 pub use self::{
     bombsaway::*, catchit::*, chicago::*, ding::*, fireandflames::*, fireflies::*, flamingo::*,
@@ -40,7 +42,7 @@ use crate::{
     Context,
 };
 
-async fn song(lyrics: &[&str], delay: u64, orig: CommandOrigin<'_>) -> Result<()> {
+async fn song(lyrics: &[&'static str], delay: u64, orig: CommandOrigin<'_>) -> Result<()> {
     debug_assert!(lyrics.len() > 1);
 
     if !orig.can_view_channel() {
@@ -50,7 +52,9 @@ async fn song(lyrics: &[&str], delay: u64, orig: CommandOrigin<'_>) -> Result<()
         return orig.error_callback(content).await;
     }
 
-    let (id, allow) = match orig.guild_id() {
+    let guild_id = orig.guild_id();
+
+    let (id, allow) = match guild_id {
         Some(guild) => {
             let allow = Context::guild_config()
                 .peek(guild, |config| config.allow_songs.unwrap_or(true))
@@ -61,8 +65,22 @@ async fn song(lyrics: &[&str], delay: u64, orig: CommandOrigin<'_>) -> Result<()
         None => (orig.user_id()?, true),
     };
 
+    let channel_id = orig.channel_id();
+
+    // Check before the ratelimit so that rejected songs don't use up the cooldown
+    if allow && !RecentSongs::try_insert(channel_id, lyrics[0]) {
+        let content = "I just sang that song in this channel, pick another one or try again later";
+
+        return orig.error_callback(content).await;
+    }
+
     // same bucket for guilds
-    if let Some(cooldown) = Context::check_ratelimit(id, BucketName::Songs) {
+    if let Some(cooldown) = Context::check_guild_ratelimit(id, guild_id, BucketName::Songs).await {
+        // The song won't be sung after all
+        if allow {
+            RecentSongs::forget(channel_id, lyrics[0]);
+        }
+
         let content = format!("Command on cooldown, try again in {cooldown} seconds");
 
         return orig.error_callback(content).await;
@@ -103,6 +121,85 @@ async fn song(lyrics: &[&str], delay: u64, orig: CommandOrigin<'_>) -> Result<()
     Ok(())
 }
 
+/// The last few songs that were sung in a channel.
+///
+/// Songs are identified by their first line of lyrics.
+#[derive(Default)]
+pub struct RecentSongs {
+    songs: VecDeque<(&'static str, Instant)>,
+}
+
+impl RecentSongs {
+    /// How many songs are remembered per channel.
+    const LEN: usize = 3;
+    /// How long a song is remembered.
+    const WINDOW: Duration = Duration::from_secs(300);
+
+    /// Remember the song for the channel unless it was sung recently.
+    ///
+    /// Returns `false` if the song was recently sung in the channel.
+    fn try_insert(channel_id: Id<ChannelMarker>, first_line: &'static str) -> bool {
+        {
+            let mut guard = Context::get().recent_songs.own(channel_id);
+            let recent = guard.entry().or_default();
+            recent.evict_expired();
+
+            if recent.songs.iter().any(|(song, _)| *song == first_line) {
+                return false;
+            }
+
+            if recent.songs.len() == Self::LEN {
+                recent.songs.pop_front();
+            }
+
+            recent.songs.push_back((first_line, Instant::now()));
+        }
+
+        // Forget the channel once its songs expired
+        tokio::spawn(async move {
+            sleep(Self::WINDOW).await;
+            Self::evict(channel_id);
+        });
+
+        true
+    }
+
+    /// Remove a remembered song of the channel.
+    fn forget(channel_id: Id<ChannelMarker>, first_line: &'static str) {
+        let mut guard = Context::get().recent_songs.own(channel_id);
+
+        if let Entry::Occupied(mut entry) = guard.entry() {
+            entry
+                .get_mut()
+                .songs
+                .retain(|(song, _)| *song != first_line);
+
+            if entry.get().songs.is_empty() {
+                entry.remove();
+            }
+        }
+    }
+
+    /// Remove expired songs of the channel and the channel itself if no songs
+    /// remain.
+    fn evict(channel_id: Id<ChannelMarker>) {
+        let mut guard = Context::get().recent_songs.own(channel_id);
+
+        if let Entry::Occupied(mut entry) = guard.entry() {
+            entry.get_mut().evict_expired();
+
+            if entry.get().songs.is_empty() {
+                entry.remove();
+            }
+        }
+    }
+
+    fn evict_expired(&mut self) {
+        self.songs
+            .retain(|(_, sung_at)| sung_at.elapsed() < Self::WINDOW);
+    }
+}
+
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(
     name = "song",
@@ -0,0 +1,422 @@
+use std::{collections::HashMap, fmt::Write};
+
+use bathbot_psql::model::osu::TrackedOsuUserKey;
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    EmbedBuilder, IntHasher, MessageBuilder,
+};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{GameMode, OsuError};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use twilight_model::{
+    channel::Attachment,
+    id::{
+        marker::{ChannelMarker, GuildMarker},
+        Id,
+    },
+};
+
+use crate::{core::commands::CommandOrigin, manager::redis::osu::UserArgs, Context};
+
+/// How many conflicts are listed at most in the import summary
+const MAX_CONFLICTS_SHOWN: usize = 20;
+
+#[derive(Default, Deserialize, Serialize)]
+struct TrackingBackup {
+    guild: u64,
+    osu: Vec<OsuTrackEntry>,
+    #[serde(default)]
+    osu_snipes: Vec<OsuSnipeEntry>,
+    #[serde(default)]
+    twitch: Vec<TwitchTrackEntry>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OsuTrackEntry {
+    channel: u64,
+    user_id: u32,
+    mode: u8,
+    limit: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_rank_gain: Option<u32>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OsuSnipeEntry {
+    channel: u64,
+    user_id: u32,
+}
+
+#[derive(Deserialize, Serialize)]
+struct TwitchTrackEntry {
+    channel: u64,
+    twitch_id: u64,
+}
+
+pub(super) async fn track_export(orig: CommandOrigin<'_>) -> Result<()> {
+    let guild = orig.guild_id().unwrap();
+
+    let backup = match collect_backup(guild).await {
+        Ok(backup) => backup,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to collect tracking entries"));
+        }
+    };
+
+    let bytes = match serde_json::to_vec_pretty(&backup) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to serialize tracking backup"));
+        }
+    };
+
+    let content = format!(
+        "Exported {osu} osu! tracking(s), {snipes} snipe tracking(s), \
+        and {twitch} twitch tracking(s).\n\
+        Use `/track import` with this file to restore them.",
+        osu = backup.osu.len(),
+        snipes = backup.osu_snipes.len(),
+        twitch = backup.twitch.len(),
+    );
+
+    let builder = MessageBuilder::new()
+        .embed(content)
+        .attachment(format!("tracking_{guild}.json"), bytes);
+
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+pub(super) async fn track_import(
+    orig: CommandOrigin<'_>,
+    file: Attachment,
+    target: Option<Id<ChannelMarker>>,
+) -> Result<()> {
+    let guild = orig.guild_id().unwrap();
+
+    let bytes = match Context::client().get_discord_attachment(&file).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to download tracking backup"));
+        }
+    };
+
+    let backup: TrackingBackup = match serde_json::from_slice(&bytes) {
+        Ok(backup) => backup,
+        Err(err) => {
+            let content = format!("Failed to parse the file as tracking backup: {err}");
+
+            return orig.error(content).await;
+        }
+    };
+
+    if let Some(channel) = target {
+        if !is_guild_channel(guild, channel).await {
+            return orig
+                .error("The given channel must be part of this server")
+                .await;
+        }
+    }
+
+    let mut import = Import::new(guild, target);
+
+    if let Err(err) = import.osu(backup.osu).await {
+        let _ = orig.error(OSU_API_ISSUE).await;
+
+        return Err(err.wrap_err("Failed to import osu! trackings"));
+    }
+
+    if let Err(err) = import.osu_snipes(backup.osu_snipes).await {
+        let _ = orig.error(GENERAL_ISSUE).await;
+
+        return Err(err.wrap_err("Failed to import osu! snipe trackings"));
+    }
+
+    #[cfg(feature = "twitchtracking")]
+    if let Err(err) = import.twitch(backup.twitch).await {
+        let _ = orig.error(GENERAL_ISSUE).await;
+
+        return Err(err.wrap_err("Failed to import twitch trackings"));
+    }
+
+    #[cfg(not(feature = "twitchtracking"))]
+    if !backup.twitch.is_empty() {
+        let conflict = format!(
+            "Skipped {} twitch tracking(s) because twitch tracking is disabled",
+            backup.twitch.len()
+        );
+
+        import.conflicts.push(conflict);
+    }
+
+    let builder = MessageBuilder::new().embed(import.embed());
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+async fn collect_backup(guild: Id<GuildMarker>) -> Result<TrackingBackup> {
+    let mut channels = GuildChannels::new(guild);
+    let mut backup = TrackingBackup {
+        guild: guild.get(),
+        ..Default::default()
+    };
+
+    for (key, channel, limit, min_rank_gain) in Context::tracking().list_all().await {
+        if channels.contains(channel).await {
+            backup.osu.push(OsuTrackEntry {
+                channel: channel.get(),
+                user_id: key.user_id,
+                mode: key.mode as u8,
+                limit,
+                min_rank_gain,
+            });
+        }
+    }
+
+    let snipes = Context::osu_tracking().get_snipe_tracks().await?;
+
+    for (user_id, snipe_channels) in snipes {
+        for channel in snipe_channels {
+            if channels.contains(channel).await {
+                backup.osu_snipes.push(OsuSnipeEntry {
+                    channel: channel.get(),
+                    user_id,
+                });
+            }
+        }
+    }
+
+    #[cfg(feature = "twitchtracking")]
+    for twitch_id in Context::tracked_users() {
+        for channel in Context::tracked_channels_for(twitch_id).unwrap_or_default() {
+            if channels.contains(channel).await {
+                backup.twitch.push(TwitchTrackEntry {
+                    channel: channel.get(),
+                    twitch_id,
+                });
+            }
+        }
+    }
+
+    Ok(backup)
+}
+
+/// Whether the channel exists and belongs to the guild
+async fn is_guild_channel(guild: Id<GuildMarker>, channel: Id<ChannelMarker>) -> bool {
+    matches!(
+        Context::cache().channel(Some(guild), channel).await,
+        Ok(Some(_))
+    )
+}
+
+/// Caches whether channels belong to the guild so that each channel is only
+/// looked up once.
+struct GuildChannels {
+    guild: Id<GuildMarker>,
+    checked: HashMap<Id<ChannelMarker>, bool, IntHasher>,
+}
+
+impl GuildChannels {
+    fn new(guild: Id<GuildMarker>) -> Self {
+        Self {
+            guild,
+            checked: HashMap::default(),
+        }
+    }
+
+    async fn contains(&mut self, channel: Id<ChannelMarker>) -> bool {
+        if let Some(contains) = self.checked.get(&channel) {
+            return *contains;
+        }
+
+        let contains = is_guild_channel(self.guild, channel).await;
+        self.checked.insert(channel, contains);
+
+        contains
+    }
+}
+
+struct Import {
+    channels: GuildChannels,
+    target: Option<Id<ChannelMarker>>,
+    added: usize,
+    conflicts: Vec<String>,
+}
+
+impl Import {
+    fn new(guild: Id<GuildMarker>, target: Option<Id<ChannelMarker>>) -> Self {
+        Self {
+            channels: GuildChannels::new(guild),
+            target,
+            added: 0,
+            conflicts: Vec::new(),
+        }
+    }
+
+    /// Returns the channel the entry should be imported into or `None` if the
+    /// channel does not belong to the guild.
+    async fn channel(&mut self, channel: u64) -> Option<Id<ChannelMarker>> {
+        if let Some(target) = self.target {
+            return Some(target);
+        }
+
+        let channel = Id::new_checked(channel)?;
+
+        self.channels.contains(channel).await.then_some(channel)
+    }
+
+    async fn osu(&mut self, entries: Vec<OsuTrackEntry>) -> Result<()> {
+        let tracking = Context::tracking();
+
+        for entry in entries {
+            let OsuTrackEntry {
+                channel,
+                user_id,
+                mode,
+                limit,
+                min_rank_gain,
+            } = entry;
+
+            let Some(channel_id) = self.channel(channel).await else {
+                let conflict = format!("osu! user {user_id}: channel {channel} not found");
+                self.conflicts.push(conflict);
+
+                continue;
+            };
+
+            if !(1..=100).contains(&limit) {
+                let conflict = format!("osu! user {user_id}: invalid limit {limit}");
+                self.conflicts.push(conflict);
+
+                continue;
+            }
+
+            let mode = GameMode::from(mode);
+            let user_args = UserArgs::user_id(user_id, mode);
+
+            match Context::redis().osu_user(user_args).await {
+                Ok(_) => {}
+                Err(OsuError::NotFound) => {
+                    let conflict = format!("osu! user {user_id}: user not found");
+                    self.conflicts.push(conflict);
+
+                    continue;
+                }
+                Err(err) => return Err(Report::new(err).wrap_err("Failed to get user")),
+            }
+
+            let now = OffsetDateTime::now_utc();
+
+            if tracking.add(user_id, mode, now, channel_id, limit).await? {
+                if min_rank_gain.is_some() {
+                    let key = TrackedOsuUserKey { user_id, mode };
+                    tracking
+                        .set_min_rank_gain(key, channel_id, min_rank_gain)
+                        .await?;
+                }
+
+                self.added += 1;
+            } else {
+                let conflict = format!("osu! user {user_id}: already tracked in <#{channel_id}>");
+                self.conflicts.push(conflict);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn osu_snipes(&mut self, entries: Vec<OsuSnipeEntry>) -> Result<()> {
+        for OsuSnipeEntry { channel, user_id } in entries {
+            let Some(channel_id) = self.channel(channel).await else {
+                let conflict = format!("osu! snipes of {user_id}: channel {channel} not found");
+                self.conflicts.push(conflict);
+
+                continue;
+            };
+
+            if Context::osu_tracking()
+                .add_snipe_track(channel_id, user_id)
+                .await?
+            {
+                self.added += 1;
+            } else {
+                let conflict =
+                    format!("osu! snipes of {user_id}: already tracked in <#{channel_id}>");
+                self.conflicts.push(conflict);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "twitchtracking")]
+    async fn twitch(&mut self, entries: Vec<TwitchTrackEntry>) -> Result<()> {
+        let mut twitch_ids: Vec<_> = entries.iter().map(|entry| entry.twitch_id).collect();
+        twitch_ids.sort_unstable();
+        twitch_ids.dedup();
+
+        let users = Context::client()
+            .get_twitch_users(&twitch_ids)
+            .await
+            .map_err(|err| err.wrap_err("Failed to get twitch users"))?;
+
+        for TwitchTrackEntry { channel, twitch_id } in entries {
+            if users.iter().all(|user| user.user_id != twitch_id) {
+                let conflict = format!("twitch user {twitch_id}: user not found");
+                self.conflicts.push(conflict);
+
+                continue;
+            }
+
+            let Some(channel_id) = self.channel(channel).await else {
+                let conflict = format!("twitch user {twitch_id}: channel {channel} not found");
+                self.conflicts.push(conflict);
+
+                continue;
+            };
+
+            Context::add_tracking(twitch_id, channel_id);
+
+            if Context::twitch().track(channel_id, twitch_id).await? {
+                self.added += 1;
+            } else {
+                let conflict =
+                    format!("twitch user {twitch_id}: already tracked in <#{channel_id}>");
+                self.conflicts.push(conflict);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn embed(&self) -> EmbedBuilder {
+        let mut description = format!("Imported {} tracking(s)", self.added);
+
+        if !self.conflicts.is_empty() {
+            let _ = writeln!(description, "\n\n**{} conflict(s):**", self.conflicts.len());
+
+            for conflict in self.conflicts.iter().take(MAX_CONFLICTS_SHOWN) {
+                let _ = writeln!(description, "- {conflict}");
+            }
+
+            if let Some(remaining) = self.conflicts.len().checked_sub(MAX_CONFLICTS_SHOWN) {
+                if remaining > 0 {
+                    let _ = writeln!(description, "- ...and {remaining} more");
+                }
+            }
+        }
+
+        EmbedBuilder::new()
+            .description(description)
+            .title("Tracking import")
+    }
+}
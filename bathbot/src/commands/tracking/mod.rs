@@ -5,8 +5,14 @@ use bathbot_model::command_fields::GameModeOption;
 use bathbot_util::CowUtils;
 use eyre::Result;
 use rosu_v2::prelude::{GameMode, OsuError, Username};
-use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_interactions::command::{AutocompleteValue, CommandModel, CreateCommand};
+use twilight_model::{
+    application::command::{CommandOptionChoice, CommandOptionChoiceValue},
+    channel::Attachment,
+    id::{marker::ChannelMarker, Id},
+};
 
+use self::backup::{track_export, track_import};
 pub use self::{track::*, track_list::*, untrack::*, untrack_all::*};
 use crate::{
     core::commands::prefix::{Args, ArgsNum},
@@ -15,18 +21,20 @@ use crate::{
     Context,
 };
 
+mod backup;
 mod track;
 mod track_list;
 mod untrack;
 mod untrack_all;
 
-#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[derive(CreateCommand, SlashCommand)]
 #[command(
     name = "track",
     dm_permission = false,
     desc = "Track top score updates for players"
 )]
 #[flags(AUTHORITY)]
+#[allow(dead_code)]
 pub enum Track {
     #[command(name = "add")]
     Add(TrackAdd),
@@ -34,6 +42,24 @@ pub enum Track {
     Remove(TrackRemove),
     #[command(name = "list")]
     List(TrackList),
+    #[command(name = "export")]
+    Export(TrackExport),
+    #[command(name = "import")]
+    Import(TrackImport),
+}
+
+#[derive(CommandModel)]
+enum Track_ {
+    #[command(name = "add")]
+    Add(TrackAdd),
+    #[command(name = "remove")]
+    Remove(TrackRemove_),
+    #[command(name = "list")]
+    List(TrackList),
+    #[command(name = "export")]
+    Export(TrackExport),
+    #[command(name = "import")]
+    Import(TrackImport),
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -57,6 +83,26 @@ pub struct TrackAdd {
         the top scores.\nThe value must be between 1 and 100."
     )]
     limit: Option<u8>,
+    #[command(
+        desc = "Also notify when the players gain or lose a national #1 (osu!standard only)",
+        help = "Also notify when the players gain or lose a national #1 i.e. when they snipe \
+        someone or get sniped.\n\
+        Only available for osu!standard players of countries that have snipe data."
+    )]
+    snipes: Option<bool>,
+    #[command(
+        desc = "Show the users' current top plays and confirm before tracking",
+        help = "Show the users' current top plays so you know what the channel will be \
+        notified about.\nThe users will only be tracked after pressing the confirm button."
+    )]
+    preview: Option<bool>,
+    #[command(
+        max_value = 1_000_000,
+        desc = "Only notify if a play improves the user's global rank by at least this much",
+        help = "Only notify if a play improves the user's global rank by at least this much.\n\
+        The value only applies to this channel. Specify `0` to disable it."
+    )]
+    min_rank_gain: Option<u32>,
     #[command(desc = "Specify a second username")]
     name2: Option<String>,
     #[command(desc = "Specify a third username")]
@@ -67,12 +113,13 @@ pub struct TrackAdd {
     name5: Option<String>,
 }
 
-#[derive(CommandModel, CreateCommand)]
+#[derive(CreateCommand)]
 #[command(
     name = "remove",
     desc = "Untrack players in a channel",
     help = "Untrack players in a channel i.e. stop sending notifications when they get new top scores"
 )]
+#[allow(dead_code)]
 pub enum TrackRemove {
     #[command(name = "user")]
     User(TrackRemoveUser),
@@ -80,15 +127,36 @@ pub enum TrackRemove {
     All(TrackRemoveAll),
 }
 
-#[derive(CommandModel, CreateCommand)]
+#[derive(CommandModel)]
+enum TrackRemove_ {
+    #[command(name = "user")]
+    User(TrackRemoveUser_),
+    #[command(name = "all")]
+    All(TrackRemoveAll),
+}
+
+#[derive(CreateCommand)]
 #[command(name = "user", desc = "Untrack specific users in a channel")]
+#[allow(dead_code)]
 pub struct TrackRemoveUser {
-    #[command(desc = "Choose a username to be untracked")]
+    #[command(
+        autocomplete = true,
+        desc = "Choose a username to be untracked",
+        help = "Choose a username to be untracked.\n\
+        Suggestions are based on the users that are currently tracked in this channel."
+    )]
     name: String,
     #[command(desc = "Specify a mode for the tracked users")]
     mode: Option<GameModeOption>,
 }
 
+#[derive(CommandModel)]
+#[command(autocomplete = true)]
+struct TrackRemoveUser_ {
+    name: AutocompleteValue<String>,
+    mode: Option<GameModeOption>,
+}
+
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "all", desc = "Untrack all users in a channel")]
 pub struct TrackRemoveAll {
@@ -103,17 +171,111 @@ pub struct TrackRemoveAll {
 )]
 pub struct TrackList;
 
-async fn slash_track(mut command: InteractionCommand) -> Result<()> {
-    match Track::from_interaction(command.input_data())? {
-        Track::Add(add) => track((&mut command).into(), add.into()).await,
-        Track::Remove(TrackRemove::User(user)) => untrack((&mut command).into(), user.into()).await,
-        Track::Remove(TrackRemove::All(all)) => {
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "export",
+    desc = "Export all trackings of this server as JSON",
+    help = "Export all osu! and twitch trackings of this server's channels as JSON file.\n\
+    The file can be used as backup or to move the trackings to another server via `/track import`."
+)]
+pub struct TrackExport;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "import",
+    desc = "Restore trackings from an exported JSON file",
+    help = "Restore trackings from a JSON file that was created via `/track export`.\n\
+    Entries whose channel is not part of this server, whose user does not exist, \
+    or which are already tracked are skipped and listed as conflicts.\n\
+    When moving trackings to a different server, specify the `channel` option \
+    so that all entries are imported into that channel."
+)]
+pub struct TrackImport {
+    #[command(desc = "Specify the exported JSON file")]
+    file: Attachment,
+    #[command(desc = "Import all entries into this channel instead of their original ones")]
+    channel: Option<Id<ChannelMarker>>,
+}
+
+pub async fn slash_track(mut command: InteractionCommand) -> Result<()> {
+    match Track_::from_interaction(command.input_data())? {
+        Track_::Add(add) => track((&mut command).into(), add.into()).await,
+        Track_::Remove(TrackRemove_::User(user)) => {
+            let TrackRemoveUser_ { name, mode } = user;
+
+            let name = match name {
+                AutocompleteValue::None => String::new(),
+                AutocompleteValue::Focused(name) => {
+                    return handle_untrack_autocomplete(&command, name).await
+                }
+                AutocompleteValue::Completed(name) => name,
+            };
+
+            let args = TrackArgs {
+                mode: mode.map(GameMode::from),
+                name,
+                limit: None,
+                snipes: false,
+                preview: false,
+                min_rank_gain: None,
+                more_names: Vec::new(),
+            };
+
+            untrack((&mut command).into(), args).await
+        }
+        Track_::Remove(TrackRemove_::All(all)) => {
             untrackall((&mut command).into(), all.mode.map(GameMode::from)).await
         }
-        Track::List(_) => tracklist((&mut command).into()).await,
+        Track_::List(_) => tracklist((&mut command).into()).await,
+        Track_::Export(_) => track_export((&mut command).into()).await,
+        Track_::Import(import) => {
+            track_import((&mut command).into(), import.file, import.channel).await
+        }
     }
 }
 
+/// Suggest the usernames that are tracked in the channel and contain the
+/// given input.
+async fn handle_untrack_autocomplete(command: &InteractionCommand, name: String) -> Result<()> {
+    let name = name.cow_to_ascii_lowercase();
+
+    let tracked = Context::tracking().list(command.channel_id).await;
+
+    let mut user_ids: Vec<_> = tracked.iter().map(|(key, _)| key.user_id as i32).collect();
+    user_ids.sort_unstable();
+    user_ids.dedup();
+
+    let names = match Context::osu_user().names(&user_ids).await {
+        Ok(names) => names,
+        Err(err) => {
+            command.autocomplete(Vec::new()).await?;
+
+            return Err(err.wrap_err("failed to get usernames"));
+        }
+    };
+
+    let mut names: Vec<_> = names
+        .into_values()
+        .filter(|username| username.cow_to_ascii_lowercase().contains(name.as_ref()))
+        .collect();
+
+    names.sort_unstable();
+
+    let choices = names
+        .into_iter()
+        .take(25)
+        .map(|username| CommandOptionChoice {
+            name: username.to_string(),
+            name_localizations: None,
+            value: CommandOptionChoiceValue::String(username.to_string()),
+        })
+        .collect();
+
+    command.autocomplete(choices).await?;
+
+    Ok(())
+}
+
 async fn get_names(
     names: &[String],
     mode: GameMode,
@@ -149,6 +311,9 @@ struct TrackArgs {
     mode: Option<GameMode>,
     name: String,
     limit: Option<u8>,
+    snipes: bool,
+    preview: bool,
+    min_rank_gain: Option<u32>,
     more_names: Vec<String>,
 }
 
@@ -156,6 +321,9 @@ impl TrackArgs {
     async fn args(mode: Option<GameMode>, args: Args<'_>) -> Result<Self, Cow<'static, str>> {
         let mut name = None;
         let mut more_names = Vec::new();
+        let mut snipes = false;
+        let mut preview = false;
+        let mut min_rank_gain = None;
 
         let mut limit = match args.num {
             ArgsNum::Value(n) => Some(n.min(100) as u8),
@@ -176,9 +344,39 @@ impl TrackArgs {
                             return Err(content.into());
                         }
                     },
+                    "snipes" => match value {
+                        "true" | "t" | "yes" | "y" => snipes = true,
+                        "false" | "f" | "no" | "n" => snipes = false,
+                        _ => {
+                            let content =
+                                "Failed to parse `snipes`. Must be either `true` or `false`.";
+
+                            return Err(content.into());
+                        }
+                    },
+                    "preview" => match value {
+                        "true" | "t" | "yes" | "y" => preview = true,
+                        "false" | "f" | "no" | "n" => preview = false,
+                        _ => {
+                            let content =
+                                "Failed to parse `preview`. Must be either `true` or `false`.";
+
+                            return Err(content.into());
+                        }
+                    },
+                    "rankgain" | "mingain" => match value.parse() {
+                        Ok(num) => min_rank_gain = Some(num),
+                        Err(_) => {
+                            let content =
+                                "Failed to parse `rankgain`. Must be a non-negative integer.";
+
+                            return Err(content.into());
+                        }
+                    },
                     _ => {
                         let content = format!(
-                            "Unrecognized option `{key}`.\nAvailable options are: `limit`."
+                            "Unrecognized option `{key}`.\n\
+                            Available options are: `limit`, `snipes`, `preview`, `rankgain`."
                         );
 
                         return Err(content.into());
@@ -199,6 +397,9 @@ impl TrackArgs {
         let args = Self {
             name,
             limit,
+            snipes,
+            preview,
+            min_rank_gain,
             more_names,
             mode,
         };
@@ -213,6 +414,9 @@ impl From<TrackAdd> for TrackArgs {
             name,
             mode,
             limit,
+            snipes,
+            preview,
+            min_rank_gain,
             name2,
             name3,
             name4,
@@ -241,20 +445,10 @@ impl From<TrackAdd> for TrackArgs {
             mode: Some(mode.into()),
             name,
             limit,
+            snipes: snipes.unwrap_or(false),
+            preview: preview.unwrap_or(false),
+            min_rank_gain,
             more_names,
         }
     }
 }
-
-impl From<TrackRemoveUser> for TrackArgs {
-    fn from(remove: TrackRemoveUser) -> Self {
-        let TrackRemoveUser { name, mode } = remove;
-
-        Self {
-            mode: mode.map(GameMode::from),
-            name,
-            limit: None,
-            more_names: Vec::new(),
-        }
-    }
-}
@@ -1,11 +1,17 @@
 use bathbot_macros::command;
+use bathbot_psql::model::osu::TrackedOsuUserKey;
 use bathbot_util::{constants::OSU_API_ISSUE, MessageBuilder};
 use eyre::{Report, Result};
-use rosu_v2::prelude::{GameMode, OsuError};
+use rosu_v2::prelude::{GameMode, OsuError, Username};
 use time::OffsetDateTime;
+use twilight_model::id::{marker::ChannelMarker, Id};
 
 use super::TrackArgs;
 use crate::{
+    active::{
+        impls::{TrackPreview, TrackPreviewEntry},
+        ActiveMessages,
+    },
     core::commands::CommandOrigin,
     embeds::{EmbedData, TrackEmbed},
     util::ChannelExt,
@@ -17,6 +23,9 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
         name,
         mode,
         limit,
+        snipes,
+        preview,
+        min_rank_gain,
         mut more_names,
     } = args;
 
@@ -47,6 +56,12 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
 
     let mode = mode.unwrap_or(GameMode::Osu);
 
+    if snipes && mode != GameMode::Osu {
+        let content = "Snipe notifications are only available for osu!standard";
+
+        return orig.error(content).await;
+    }
+
     let users = match super::get_names(&more_names, mode).await {
         Ok(map) => map,
         Err((OsuError::NotFound, name)) => {
@@ -62,7 +77,95 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
         }
     };
 
+    let options = TrackOptions {
+        mode,
+        limit,
+        snipes,
+        min_rank_gain,
+    };
+
+    let mut users: Vec<_> = users.into_iter().collect();
+
+    if preview {
+        users.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+        return track_preview(orig, users, options).await;
+    }
+
+    let embed = store_tracks(orig.channel_id(), users, options).await;
+    let builder = MessageBuilder::new().embed(embed.build());
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+/// Fetch the current top scores of each user and let the author confirm
+/// before anything is stored.
+async fn track_preview(
+    orig: CommandOrigin<'_>,
+    users: Vec<(Username, u32)>,
+    options: TrackOptions,
+) -> Result<()> {
+    let mut entries = Vec::with_capacity(users.len());
+
+    for (username, user_id) in users {
+        let scores_fut = Context::osu()
+            .user_scores(user_id)
+            .best()
+            .mode(options.mode)
+            .limit(TrackPreview::SCORES_PER_USER);
+
+        match scores_fut.await {
+            Ok(scores) => entries.push(TrackPreviewEntry {
+                username,
+                user_id,
+                scores,
+            }),
+            Err(OsuError::NotFound) => {
+                let content = format!("User `{username}` was not found");
+
+                return orig.error(content).await;
+            }
+            Err(err) => {
+                let _ = orig.error(OSU_API_ISSUE).await;
+                let err = Report::new(err).wrap_err("Failed to get top scores for track preview");
+
+                return Err(err);
+            }
+        }
+    }
+
     let channel = orig.channel_id();
+    let owner = orig.user_id()?;
+    let active_msg = TrackPreview::new(entries, options, channel, owner);
+
+    ActiveMessages::builder(active_msg).begin(orig).await
+}
+
+/// Settings that apply to all users of a track command
+#[derive(Copy, Clone)]
+pub struct TrackOptions {
+    pub mode: GameMode,
+    pub limit: u8,
+    pub snipes: bool,
+    /// `Some(0)` disables the minimum rank gain, `None` keeps it as is
+    pub min_rank_gain: Option<u32>,
+}
+
+/// Store the tracked users for the channel and return an embed summarizing
+/// which users were added.
+pub async fn store_tracks(
+    channel: Id<ChannelMarker>,
+    users: Vec<(Username, u32)>,
+    options: TrackOptions,
+) -> TrackEmbed {
+    let TrackOptions {
+        mode,
+        limit,
+        snipes,
+        min_rank_gain,
+    } = options;
+
     let mut success = Vec::with_capacity(users.len());
     let mut failure = Vec::new();
     let tracking = Context::tracking();
@@ -70,27 +173,38 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
     for (username, user_id) in users {
         let add_fut = tracking.add(user_id, mode, OffsetDateTime::now_utc(), channel, limit);
 
-        match add_fut.await {
+        let add_res = match add_fut.await {
+            Ok(added) if snipes => Context::osu_tracking()
+                .add_snipe_track(channel, user_id)
+                .await
+                .map(|snipe_added| added || snipe_added),
+            res => res,
+        };
+
+        let add_res = match (add_res, min_rank_gain) {
+            (Ok(added), Some(gain)) => {
+                let key = TrackedOsuUserKey { user_id, mode };
+
+                tracking
+                    .set_min_rank_gain(key, channel, (gain > 0).then_some(gain))
+                    .await
+                    .map(|gain_updated| added || gain_updated)
+            }
+            (res, _) => res,
+        };
+
+        match add_res {
             Ok(true) => success.push(username),
             Ok(false) => failure.push(username),
             Err(err) => {
                 warn!(?err, "Failed to add tracked entry");
 
-                let embed = TrackEmbed::new(mode, success, failure, Some(username), limit).build();
-
-                let builder = MessageBuilder::new().embed(embed);
-                orig.create_message(builder).await?;
-
-                return Ok(());
+                return TrackEmbed::new(options, success, failure, Some(username));
             }
         }
     }
 
-    let embed = TrackEmbed::new(mode, success, failure, None, limit);
-    let builder = MessageBuilder::new().embed(embed.build());
-    orig.create_message(builder).await?;
-
-    Ok(())
+    TrackEmbed::new(options, success, failure, None)
 }
 
 #[command]
@@ -104,13 +218,20 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
     a new score in his top 42.\n\
     Alternatively, you can provide a limit by specifying `limit=number`, \
     e.g. `track limit=42 badewanne3`.\n\
-    The limit must be between 1 and 100, **defaults to 50** if none is given."
+    The limit must be between 1 and 100, **defaults to 50** if none is given.\n\
+    To also be notified when the users gain or lose a national #1, specify `snipes=true`.\n\
+    To see the users' current top plays and confirm before tracking, specify `preview=true`.\n\
+    To only be notified if a play improves the user's global rank by at least `n`, \
+    specify `rankgain=n`; `rankgain=0` disables it again."
+)]
+#[usage(
+    "[limit=number] [snipes=true/false] [preview=true/false] [rankgain=number] \
+    [username1] [username2] ..."
 )]
-#[usage("[limit=number] [username1] [username2] ...")]
 #[examples(
     "badewanne3 \"freddie benson\" peppy limit=23",
     "limit=45 cookiezi whitecat",
-    "\"freddie benson\""
+    "\"freddie benson\" snipes=true"
 )]
 #[flags(AUTHORITY, ONLY_GUILDS)]
 #[group(Tracking)]
@@ -136,9 +257,12 @@ async fn prefix_track(msg: &Message, args: Args<'_>) -> Result<()> {
     a new score in his top 42.\n\
     Alternatively, you can provide a limit by specifying `limit=number`, \
     e.g. `trackmania limit=42 badewanne3`.\n\
-    The limit must be between 1 and 100, **defaults to 50** if none is given."
+    The limit must be between 1 and 100, **defaults to 50** if none is given.\n\
+    To see the users' current top plays and confirm before tracking, specify `preview=true`.\n\
+    To only be notified if a play improves the user's global rank by at least `n`, \
+    specify `rankgain=n`; `rankgain=0` disables it again."
 )]
-#[usage("[limit=number] [username1] [username2] ...")]
+#[usage("[limit=number] [preview=true/false] [rankgain=number] [username1] [username2] ...")]
 #[examples(
     "badewanne3 \"freddie benson\" peppy limit=23",
     "limit=45 cookiezi whitecat",
@@ -168,9 +292,12 @@ pub async fn prefix_trackmania(msg: &Message, args: Args<'_>) -> Result<()> {
     a new score in his top 42.\n\
     Alternatively, you can provide a limit by specifying `limit=number`, \
     e.g. `tracktaiko limit=42 badewanne3`.\n\
-    The limit must be between 1 and 100, **defaults to 50** if none is given."
+    The limit must be between 1 and 100, **defaults to 50** if none is given.\n\
+    To see the users' current top plays and confirm before tracking, specify `preview=true`.\n\
+    To only be notified if a play improves the user's global rank by at least `n`, \
+    specify `rankgain=n`; `rankgain=0` disables it again."
 )]
-#[usage("[limit=number] [username1] [username2] ...")]
+#[usage("[limit=number] [preview=true/false] [rankgain=number] [username1] [username2] ...")]
 #[examples(
     "badewanne3 \"freddie benson\" peppy limit=23",
     "limit=45 cookiezi whitecat",
@@ -200,9 +327,12 @@ pub async fn prefix_tracktaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     a new score in his top 42.\n\
     Alternatively, you can provide a limit by specifying `limit=number`, \
     e.g. `trackctb limit=42 badewanne3`.\n\
-    The limit must be between 1 and 100, **defaults to 50** if none is given."
+    The limit must be between 1 and 100, **defaults to 50** if none is given.\n\
+    To see the users' current top plays and confirm before tracking, specify `preview=true`.\n\
+    To only be notified if a play improves the user's global rank by at least `n`, \
+    specify `rankgain=n`; `rankgain=0` disables it again."
 )]
-#[usage("[limit=number] [username1] [username2] ...")]
+#[usage("[limit=number] [preview=true/false] [rankgain=number] [username1] [username2] ...")]
 #[examples(
     "badewanne3 \"freddie benson\" peppy limit=23",
     "limit=45 cookiezi whitecat",
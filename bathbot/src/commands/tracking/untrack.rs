@@ -70,7 +70,17 @@ pub(super) async fn untrack(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<
     let tracking = Context::tracking();
 
     for (username, user_id) in users {
-        let remove_fut = tracking.remove_user(user_id, mode, channel);
+        let remove_fut = async {
+            tracking.remove_user(user_id, mode, channel).await?;
+
+            if mode.map_or(true, |mode| mode == GameMode::Osu) {
+                Context::osu_tracking()
+                    .remove_snipe_track(channel, user_id)
+                    .await?;
+            }
+
+            Ok::<_, Report>(())
+        };
 
         match remove_fut.await {
             Ok(_) => success.insert(username),
@@ -1,6 +1,6 @@
 use bathbot_macros::command;
 use bathbot_util::{constants::GENERAL_ISSUE, MessageBuilder};
-use eyre::Result;
+use eyre::{Report, Result};
 use rosu_v2::model::GameMode;
 
 use crate::{core::commands::CommandOrigin, util::ChannelExt, Context};
@@ -39,7 +39,17 @@ async fn prefix_untrackall(msg: &Message, mut args: Args<'_>) -> Result<()> {
 pub async fn untrackall(orig: CommandOrigin<'_>, mode: Option<GameMode>) -> Result<()> {
     let channel_id = orig.channel_id();
 
-    let remove_fut = Context::tracking().remove_channel(channel_id, mode);
+    let remove_fut = async {
+        let amount = Context::tracking().remove_channel(channel_id, mode).await?;
+
+        if mode.map_or(true, |mode| mode == GameMode::Osu) {
+            Context::osu_tracking()
+                .remove_snipe_tracks(channel_id)
+                .await?;
+        }
+
+        Ok::<_, Report>(amount)
+    };
 
     match remove_fut.await {
         Ok(amount) => {
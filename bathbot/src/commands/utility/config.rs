@@ -60,8 +60,9 @@ pub struct Config {
     mode: Option<ConfigGameMode>,
     #[command(
         desc = "Adjust the amount of scores shown per page in top, rb, pinned, ...",
-        help = "Adjust the amount of scores shown per page in `/top`, `/rb`, `/pinned`, and `/mapper`.\n\
-        `Condensed` shows 10 scores, `Detailed` shows 5, and `Single` shows 1."
+        help = "Adjust the amount of scores shown per page in `/top`, `/rb`, `/pinned`, `/mapper`, and `/topweek`.\n\
+        `Condensed` shows 10 scores, `Detailed` shows 5, and `Single` shows 1.\n\
+        Takes precedence over the server's default but not over the `size` option of a command."
     )]
     list_embeds: Option<ListSize>,
     #[command(desc = "Should the amount of retries be shown for the recent command?")]
@@ -93,6 +94,24 @@ pub struct Config {
         They have a different score and grade calculation and only lazer adds the new mods."
     )]
     score_data: Option<ScoreData>,
+    #[command(
+        min_value = 15,
+        max_value = 300,
+        desc = "Specify the amount of seconds until paginations time out",
+        help = "Specify the amount of seconds until buttons of paginations stop working.\n\
+        Every interaction with the pagination resets the timer.\n\
+        The value must be between 15 and 300, defaults to 60."
+    )]
+    pagination_timeout: Option<i64>,
+    #[command(
+        min_value = 0,
+        max_value = 2,
+        desc = "Specify the amount of decimal places for pp values",
+        help = "Specify the amount of decimal places for pp values in score lists \
+        like `/top`, `/pinned`, `/mapper`, and `/topweek`.\n\
+        The value must be between 0 and 2, defaults to 2."
+    )]
+    pp_decimals: Option<i64>,
 }
 
 // FIXME: Some attribute command does not register the #[cfg(feature = "")]
@@ -114,8 +133,9 @@ pub struct Config {
     mode: Option<ConfigGameMode>,
     #[command(
         desc = "Adjust the amount of scores shown per page in top, rb, pinned, ...",
-        help = "Adjust the amount of scores shown per page in top, rb, pinned, and mapper.\n\
-        `Condensed` shows 10 scores, `Detailed` shows 5, and `Single` shows 1."
+        help = "Adjust the amount of scores shown per page in top, rb, pinned, mapper, and topweek.\n\
+        `Condensed` shows 10 scores, `Detailed` shows 5, and `Single` shows 1.\n\
+        Takes precedence over the server's default but not over the `size` option of a command."
     )]
     list_embeds: Option<ListSize>,
     #[command(desc = "Specify if and how retries should be shown for the recent command")]
@@ -147,6 +167,24 @@ pub struct Config {
         They have a different score and grade calculation and only lazer adds the new mods."
     )]
     score_data: Option<ScoreData>,
+    #[command(
+        min_value = 15,
+        max_value = 300,
+        desc = "Specify the amount of seconds until paginations time out",
+        help = "Specify the amount of seconds until buttons of paginations stop working.\n\
+        Every interaction with the pagination resets the timer.\n\
+        The value must be between 15 and 300, defaults to 60."
+    )]
+    pagination_timeout: Option<i64>,
+    #[command(
+        min_value = 0,
+        max_value = 2,
+        desc = "Specify the amount of decimal places for pp values",
+        help = "Specify the amount of decimal places for pp values in score lists \
+        like `/top`, `/pinned`, `/mapper`, and `/topweek`.\n\
+        The value must be between 0 and 2, defaults to 2."
+    )]
+    pp_decimals: Option<i64>,
 }
 
 #[derive(CommandOption, CreateOption)]
@@ -202,6 +240,8 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
         mut skin_url,
         render_button,
         score_data,
+        pagination_timeout,
+        pp_decimals,
     } = config;
 
     if let Some(ref skin_url) = skin_url {
@@ -251,6 +291,14 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
         config.score_data = Some(score_data);
     }
 
+    if let Some(secs) = pagination_timeout {
+        config.pagination_timeout = Some(secs as u16);
+    }
+
+    if let Some(decimals) = pp_decimals {
+        config.pp_decimals = Some(decimals as u8);
+    }
+
     #[cfg(feature = "server")]
     if let Some(ConfigLink::Unlink) = osu {
         config.osu.take();
@@ -388,6 +436,13 @@ async fn handle_both_links(
         return HandleResult::Err(err);
     }
 
+    // Logging in through osu! proves ownership of the account
+    if let Some(osu_id) = config.osu {
+        if let Err(err) = Context::user_config().verify_osu(author.id, osu_id).await {
+            warn!(?err, "Failed to verify osu! link");
+        }
+    }
+
     HandleResult::TwitchName(twitch_name)
 }
 
@@ -485,6 +540,13 @@ async fn handle_osu_link(
         return HandleResult::Err(err);
     }
 
+    // Logging in through osu! proves ownership of the account
+    if let Some(osu_id) = config.osu {
+        if let Err(err) = Context::user_config().verify_osu(author.id, osu_id).await {
+            warn!(?err, "Failed to verify osu! link");
+        }
+    }
+
     HandleResult::TwitchName(twitch_name)
 }
 
@@ -582,6 +644,8 @@ async fn convert_config(
         timezone,
         render_button,
         score_data,
+        pagination_timeout,
+        pp_decimals,
     } = config;
 
     UserConfig {
@@ -594,6 +658,8 @@ async fn convert_config(
         timezone,
         render_button,
         score_data,
+        pagination_timeout,
+        pp_decimals,
     }
 }
 
@@ -0,0 +1,50 @@
+use std::fmt::Write;
+
+use bathbot_macros::SlashCommand;
+use bathbot_util::{EmbedBuilder, FooterBuilder, MessageBuilder};
+use eyre::Result;
+use twilight_interactions::command::CreateCommand;
+
+use crate::{
+    core::{buckets::BucketName, Context},
+    util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
+};
+
+#[derive(CreateCommand, SlashCommand)]
+#[command(
+    name = "cooldowns",
+    desc = "Check whether you are currently on cooldown for any command",
+    help = "Some commands have a cooldown to prevent spam.\n\
+    This command shows for each of those cooldowns how long it will take until you can use \
+    the corresponding commands again.\n\
+    Checking your cooldowns does not count towards any of them."
+)]
+pub struct Cooldowns;
+
+async fn slash_cooldowns(command: InteractionCommand) -> Result<()> {
+    let user_id = command.user_id()?;
+    let mut description = String::new();
+
+    for bucket in BucketName::iter() {
+        let _ = write!(description, "**{}**: ", bucket.name());
+
+        match Context::peek_ratelimit(user_id, command.guild_id, bucket).await {
+            Some(cooldown) => {
+                let _ = writeln!(description, "{cooldown} second(s) remaining");
+            }
+            None => description.push_str("Ready\n"),
+        }
+    }
+
+    let embed = EmbedBuilder::new()
+        .title("Your cooldowns")
+        .description(description)
+        .footer(FooterBuilder::new(
+            "Commands not listed here have no cooldown",
+        ));
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
@@ -711,6 +711,7 @@ pub struct ScoreEmbedDataRaw {
     pub classic_score: u32,
     pub score_id: u64,
     pub legacy_id: Option<u64>,
+    pub build_id: Option<u32>,
     pub statistics: LegacyScoreStatistics,
     pub has_replay: bool,
 }
@@ -747,6 +748,7 @@ impl ScoreEmbedDataRaw {
             classic_score: score.classic_score,
             score_id: score.id,
             legacy_id: score.legacy_score_id,
+            build_id: score.build_id,
             statistics: score.statistics.as_legacy(score.mode),
             has_replay: score.replay,
         }
@@ -788,6 +790,7 @@ impl ScoreEmbedDataRaw {
             classic_score: self.classic_score,
             score_id: self.score_id,
             legacy_id: self.legacy_id,
+            build_id: self.build_id,
             statistics: self.statistics,
         };
 
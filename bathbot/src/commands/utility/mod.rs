@@ -2,10 +2,12 @@ mod authorities;
 mod changelog;
 mod commands;
 mod config;
+mod cooldowns;
 mod embed_builder;
 mod invite;
 mod ping;
 mod prefix;
+mod role_assign;
 mod roll;
 mod server_config;
 mod skin;
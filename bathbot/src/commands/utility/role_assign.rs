@@ -0,0 +1,346 @@
+use std::fmt::Write;
+
+use bathbot_macros::SlashCommand;
+use bathbot_psql::model::role_assign::{RoleAssign, RoleAssignEmoji};
+use bathbot_util::{constants::GENERAL_ISSUE, matcher, EmbedBuilder, MessageBuilder};
+use eyre::Result;
+use twilight_http::request::channel::reaction::RequestReactionType;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    guild::Permissions,
+    id::{
+        marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+use crate::{
+    core::commands::checks::{check_guild_permissions, RolesLookup},
+    util::{interaction::InteractionCommand, InteractionCommandExt},
+    Context,
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "roleassign",
+    dm_permission = false,
+    desc = "Manage roles that are assigned by reacting to a message",
+    help = "Manage roles that are assigned by reacting to a message.\n\
+    Whenever a member reacts with the emoji on the message, they gain the role \
+    and when they remove their reaction, they lose the role again."
+)]
+#[flags(AUTHORITY, ONLY_GUILDS)]
+pub enum RoleAssignCommand {
+    #[command(name = "add")]
+    Add(RoleAssignAdd),
+    #[command(name = "remove")]
+    Remove(RoleAssignRemove),
+    #[command(name = "list")]
+    List(RoleAssignList),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "add",
+    desc = "Assign a role when reacting with an emoji on a message",
+    help = "Assign a role when reacting with an emoji on a message.\n\
+    The emoji must be either a default emoji or a custom emoji that I can use \
+    and the role must be below my highest role.\n\
+    Unless you have the `Manage Roles` permission, the role must also be below \
+    your own highest role."
+)]
+pub struct RoleAssignAdd {
+    #[command(desc = "Specify the channel of the message")]
+    channel: Id<ChannelMarker>,
+    #[command(desc = "Specify the message id or message link")]
+    message: String,
+    #[command(desc = "Specify the emoji to react with")]
+    emoji: String,
+    #[command(desc = "Specify the role that should be assigned")]
+    role: Id<RoleMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "remove", desc = "Stop assigning a role for a reaction")]
+pub struct RoleAssignRemove {
+    #[command(desc = "Specify the message id or message link")]
+    message: String,
+    #[command(desc = "Specify the emoji of the reaction")]
+    emoji: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List all role assigns of this server")]
+pub struct RoleAssignList;
+
+async fn slash_roleassign(mut command: InteractionCommand) -> Result<()> {
+    let guild_id = command.guild_id.unwrap();
+
+    match RoleAssignCommand::from_interaction(command.input_data())? {
+        RoleAssignCommand::Add(args) => add(command, guild_id, args).await,
+        RoleAssignCommand::Remove(args) => remove(command, guild_id, args).await,
+        RoleAssignCommand::List(_) => list(command, guild_id).await,
+    }
+}
+
+async fn add(
+    command: InteractionCommand,
+    guild_id: Id<GuildMarker>,
+    args: RoleAssignAdd,
+) -> Result<()> {
+    let RoleAssignAdd {
+        channel,
+        message,
+        emoji,
+        role,
+    } = args;
+
+    let Some(message_id) = matcher::get_message_id(&message) else {
+        let content = "Failed to parse message. Be sure to specify a message id or message link.";
+
+        command.error(content).await?;
+
+        return Ok(());
+    };
+
+    let Some((role_assign_emoji, reaction)) = parse_emoji(&emoji) else {
+        command.error(EMOJI_PARSE_ERROR).await?;
+
+        return Ok(());
+    };
+
+    if let Err(content) = check_role(guild_id, command.user_id()?, role).await? {
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    // Reacting ourselves both checks that the emoji is usable and provides
+    // the reaction for members to click on
+    if let Err(err) = Context::http()
+        .create_reaction(channel, message_id, &reaction)
+        .await
+    {
+        debug!(?err, "Failed to react for role assign");
+
+        let content = "Failed to react with the emoji on the message.\n\
+            Be sure the message exists in the channel and that I can use the emoji.";
+
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    let role_assign = RoleAssign {
+        guild_id,
+        channel_id: channel,
+        message_id,
+        emoji: role_assign_emoji,
+        role_id: role,
+    };
+
+    if let Err(err) = Context::role_assigns().add(role_assign).await {
+        let _ = command.error(GENERAL_ISSUE).await;
+
+        return Err(err);
+    }
+
+    let content = format!(
+        "Reacting with {emoji} on https://discord.com/channels/{guild_id}/{channel}/{message_id} \
+        now assigns <@&{role}>"
+    );
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+async fn remove(
+    command: InteractionCommand,
+    guild_id: Id<GuildMarker>,
+    args: RoleAssignRemove,
+) -> Result<()> {
+    let RoleAssignRemove { message, emoji } = args;
+
+    let Some(message_id) = matcher::get_message_id(&message) else {
+        let content = "Failed to parse message. Be sure to specify a message id or message link.";
+
+        command.error(content).await?;
+
+        return Ok(());
+    };
+
+    let Some((role_assign_emoji, _)) = parse_emoji(&emoji) else {
+        command.error(EMOJI_PARSE_ERROR).await?;
+
+        return Ok(());
+    };
+
+    match Context::role_assigns()
+        .remove(guild_id, message_id, &role_assign_emoji)
+        .await
+    {
+        Ok(true) => {
+            let content = format!("Reacting with {emoji} on the message no longer assigns a role");
+            let builder = MessageBuilder::new().embed(content);
+            command.update(builder).await?;
+
+            Ok(())
+        }
+        Ok(false) => {
+            let content = "There was no role assign for that emoji on the message";
+
+            command.error(content).await?;
+
+            Ok(())
+        }
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            Err(err)
+        }
+    }
+}
+
+async fn list(command: InteractionCommand, guild_id: Id<GuildMarker>) -> Result<()> {
+    let mut role_assigns = Context::role_assigns().list(guild_id);
+    role_assigns.sort_unstable_by_key(|role_assign| (role_assign.message_id, role_assign.role_id));
+
+    let description = if role_assigns.is_empty() {
+        "This server has no role assigns".to_owned()
+    } else {
+        let mut description = String::with_capacity(role_assigns.len() * 128);
+
+        for role_assign in role_assigns {
+            let RoleAssign {
+                guild_id,
+                channel_id,
+                message_id,
+                emoji,
+                role_id,
+            } = role_assign;
+
+            let _ = writeln!(
+                description,
+                "{emoji} on https://discord.com/channels/{guild_id}/{channel_id}/{message_id} \
+                → <@&{role_id}>"
+            );
+        }
+
+        description
+    };
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .title("Role assigns");
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+const EMOJI_PARSE_ERROR: &str =
+    "Failed to parse emoji. Be sure to specify a default emoji or a custom emoji.";
+
+/// Parse either a custom emoji or a unicode emoji.
+fn parse_emoji(emoji: &str) -> Option<(RoleAssignEmoji, RequestReactionType<'_>)> {
+    if let Some((id, name)) = matcher::get_custom_emote(emoji) {
+        let reaction = RequestReactionType::Custom {
+            id,
+            name: Some(name),
+        };
+
+        return Some((RoleAssignEmoji::Custom(id), reaction));
+    }
+
+    let emoji = emoji.trim();
+
+    let is_unicode = !emoji.is_empty()
+        && emoji.chars().count() <= 16
+        && !emoji
+            .chars()
+            .any(|c| c.is_ascii_alphabetic() || c.is_whitespace());
+
+    is_unicode.then(|| {
+        let reaction = RequestReactionType::Unicode { name: emoji };
+
+        (RoleAssignEmoji::Unicode(emoji.into()), reaction)
+    })
+}
+
+/// Check whether both the bot and the invoking member can manage the role.
+async fn check_role(
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    role_id: Id<RoleMarker>,
+) -> Result<Result<(), &'static str>> {
+    let cache = Context::cache();
+
+    let Some(current_user) = cache.current_user().await? else {
+        return Ok(Err(GENERAL_ISSUE));
+    };
+
+    if role_id.cast::<GuildMarker>() == guild_id {
+        return Ok(Err("The @everyone role cannot be assigned"));
+    }
+
+    let Some(role) = cache.role(guild_id, role_id).await? else {
+        return Ok(Err("The role does not exist in this server"));
+    };
+
+    let (permissions, lookup) = check_guild_permissions(current_user.id, guild_id).await;
+
+    if !permissions.contains(Permissions::MANAGE_ROLES) {
+        return Ok(Err("I need the `Manage Roles` permission to assign roles"));
+    }
+
+    let RolesLookup::Found(member) = lookup else {
+        return Ok(Err(GENERAL_ISSUE));
+    };
+
+    if role.position >= highest_role_position(guild_id, member.roles()).await? {
+        return Ok(Err(
+            "The role must be below my highest role so I can assign it",
+        ));
+    }
+
+    // Authorities without role permissions must not hand out roles above their own
+    let (permissions, lookup) = check_guild_permissions(user_id, guild_id).await;
+
+    if permissions.intersects(Permissions::ADMINISTRATOR | Permissions::MANAGE_ROLES) {
+        return Ok(Ok(()));
+    }
+
+    let member = match lookup {
+        RolesLookup::Found(member) => member,
+        RolesLookup::NotChecked | RolesLookup::NotFound => {
+            match cache.member(guild_id, user_id).await? {
+                Some(member) => member,
+                None => return Ok(Err(GENERAL_ISSUE)),
+            }
+        }
+    };
+
+    if role.position >= highest_role_position(guild_id, member.roles()).await? {
+        return Ok(Err(
+            "The role must be below your highest role unless you have the \
+            `Manage Roles` permission",
+        ));
+    }
+
+    Ok(Ok(()))
+}
+
+async fn highest_role_position(guild_id: Id<GuildMarker>, roles: &[Id<RoleMarker>]) -> Result<i64> {
+    let position = Context::cache()
+        .roles(guild_id, roles.to_vec())
+        .await?
+        .iter()
+        .map(|role| role.position)
+        .max()
+        .unwrap_or(0);
+
+    Ok(position)
+}
@@ -1,13 +1,29 @@
+use std::fmt::Write;
+
 use bathbot_macros::{command, SlashCommand};
-use bathbot_model::command_fields::{EnableDisable, ShowHideOption};
-use bathbot_psql::model::configs::{GuildConfig, HideSolutions, ListSize, Retries, ScoreData};
-use bathbot_util::constants::GENERAL_ISSUE;
+use bathbot_model::command_fields::{EnableDisable, GameModeOption, ShowHideOption};
+use bathbot_psql::model::configs::{
+    ChannelModes, ChannelRestrictions, Cooldown, Cooldowns, DisabledCommands, GuildConfig,
+    HideSolutions, ListSize, Retries, ScoreData,
+};
+use bathbot_util::{constants::GENERAL_ISSUE, EmbedBuilder, MessageBuilder};
 use eyre::Result;
-use twilight_interactions::command::{CommandModel, CreateCommand};
-use twilight_model::id::{marker::RoleMarker, Id};
+use rosu_v2::prelude::GameMode;
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::id::{
+    marker::{ChannelMarker, RoleMarker},
+    Id,
+};
 
-use super::AuthorityCommandKind;
+use super::{AuthorityCommandKind, ConfigGameMode};
 use crate::{
+    core::{
+        buckets::BucketName,
+        commands::{
+            interaction::{InteractionCommandKind, InteractionCommands},
+            prefix::{PrefixCommand, PrefixCommands},
+        },
+    },
     embeds::{EmbedData, ServerConfigEmbed},
     util::{interaction::InteractionCommand, InteractionCommandExt},
     Context,
@@ -23,6 +39,14 @@ use crate::{
 pub enum ServerConfig {
     #[command(name = "authorities")]
     Authorities(ServerConfigAuthorities),
+    #[command(name = "channelmodes")]
+    ChannelModes(ServerConfigChannelModes),
+    #[command(name = "channels")]
+    Channels(ServerConfigChannels),
+    #[command(name = "commands")]
+    Commands(ServerConfigCommands),
+    #[command(name = "cooldown")]
+    Cooldown(ServerConfigCooldown),
     #[command(name = "edit")]
     Edit(ServerConfigEdit),
 }
@@ -86,15 +110,254 @@ pub struct ServerConfigAuthoritiesRemove {
 pub struct ServerConfigAuthoritiesList;
 
 #[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "channelmodes",
+    desc = "Set default gamemodes for specific channels",
+    help = "Set default gamemodes for specific channels.\n\
+    Commands used in such a channel without a specified mode will use the channel's mode, \
+    even if the member configured a different mode for themselves.\n\
+    Threads use the mode of their parent channel unless they have their own mode.\n\
+    Up to 50 channels can have a mode."
+)]
+pub enum ServerConfigChannelModes {
+    #[command(name = "set")]
+    Set(ServerConfigChannelModesSet),
+    #[command(name = "remove")]
+    Remove(ServerConfigChannelModesRemove),
+    #[command(name = "list")]
+    List(ServerConfigChannelModesList),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "set", desc = "Set the default gamemode of a channel")]
+pub struct ServerConfigChannelModesSet {
+    #[command(desc = "Specify the gamemode")]
+    mode: GameModeOption,
+    #[command(desc = "Specify the channel, defaults to the current one")]
+    channel: Option<Id<ChannelMarker>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "remove", desc = "Remove the default gamemode of a channel")]
+pub struct ServerConfigChannelModesRemove {
+    #[command(desc = "Specify the channel, defaults to the current one")]
+    channel: Option<Id<ChannelMarker>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "Display all channel gamemodes of this server")]
+pub struct ServerConfigChannelModesList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "channels",
+    desc = "Restrict commands to specific channels",
+    help = "Restrict commands to specific channels.\n\
+    Once a channel is allowed for a command, the command can only be used in allowed channels.\n\
+    Commands can be allowed in up to 10 channels. \
+    The `serverconfig` command itself cannot be restricted."
+)]
+pub enum ServerConfigChannels {
+    #[command(name = "allow")]
+    Allow(ServerConfigChannelsAllow),
+    #[command(name = "disallow")]
+    Disallow(ServerConfigChannelsDisallow),
+    #[command(name = "clear")]
+    Clear(ServerConfigChannelsClear),
+    #[command(name = "list")]
+    List(ServerConfigChannelsList),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "allow", desc = "Allow a command in a channel")]
+pub struct ServerConfigChannelsAllow {
+    #[command(desc = "Specify the name of the command e.g. `bg` or `song`")]
+    command: String,
+    #[command(desc = "Specify the channel, defaults to the current one")]
+    channel: Option<Id<ChannelMarker>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "disallow",
+    desc = "Remove a channel from a command's allowed channels"
+)]
+pub struct ServerConfigChannelsDisallow {
+    #[command(desc = "Specify the name of the command e.g. `bg` or `song`")]
+    command: String,
+    #[command(desc = "Specify the channel, defaults to the current one")]
+    channel: Option<Id<ChannelMarker>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "clear", desc = "Remove all channel restrictions of a command")]
+pub struct ServerConfigChannelsClear {
+    #[command(desc = "Specify the name of the command e.g. `bg` or `song`")]
+    command: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "list",
+    desc = "Display all channel restrictions of this server"
+)]
+pub struct ServerConfigChannelsList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "commands",
+    desc = "Disable or re-enable commands for this server",
+    help = "Disable or re-enable commands for this server.\n\
+    Disabled commands can neither be used as slash command nor as prefix command.\n\
+    The `serverconfig` command itself cannot be disabled."
+)]
+pub enum ServerConfigCommands {
+    #[command(name = "disable")]
+    Disable(ServerConfigCommandsDisable),
+    #[command(name = "enable")]
+    Enable(ServerConfigCommandsEnable),
+    #[command(name = "list")]
+    List(ServerConfigCommandsList),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "disable", desc = "Disable a command for this server")]
+pub struct ServerConfigCommandsDisable {
+    #[command(desc = "Specify the name of the command e.g. `bg` or `song`")]
+    command: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "enable", desc = "Re-enable a disabled command for this server")]
+pub struct ServerConfigCommandsEnable {
+    #[command(desc = "Specify the name of the command e.g. `bg` or `song`")]
+    command: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "Display all disabled commands of this server")]
+pub struct ServerConfigCommandsList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "cooldown",
+    desc = "Adjust the cooldown of commands for this server",
+    help = "Adjust the cooldown of commands for this server.\n\
+    The specified values replace the command's default ratelimit entirely:\n\
+    - `seconds`: The minimum amount of seconds between two uses of a command by the same member\n\
+    - `uses` & `timespan`: How often a member may use the command within the timespan in seconds. \
+    Both must be specified together; if neither is specified, there is no such limit.\n\
+    If no option besides `commands` is specified, the ratelimit will be reset to its default.\n\
+    Seconds and timespan must be between 0 and 600, uses between 1 and 20."
+)]
+pub struct ServerConfigCooldown {
+    #[command(desc = "Specify the commands whose cooldown should be adjusted")]
+    commands: CooldownBucket,
+    #[command(
+        min_value = 0,
+        max_value = 600,
+        desc = "Specify the minimum seconds between two uses, defaults to 0"
+    )]
+    seconds: Option<i64>,
+    #[command(
+        min_value = 1,
+        max_value = 20,
+        desc = "Specify how often the commands can be used within the timespan"
+    )]
+    uses: Option<i64>,
+    #[command(
+        min_value = 0,
+        max_value = 600,
+        desc = "Specify the timespan in seconds for the amount of uses"
+    )]
+    timespan: Option<i64>,
+}
+
+impl ServerConfigCooldown {
+    /// Returns `Ok(None)` if the cooldown should be reset.
+    fn cooldown(&self) -> Result<Option<Cooldown>, String> {
+        let bucket = BucketName::from(self.commands) as u8;
+
+        let limit = match (self.timespan, self.uses) {
+            (None, None) if self.seconds.is_none() => return Ok(None),
+            (None, None) => None,
+            (Some(timespan), Some(uses)) => match (u16::try_from(timespan), u8::try_from(uses)) {
+                (Ok(timespan), Ok(uses)) => Some((timespan, uses)),
+                _ => return Err(Self::invalid_content()),
+            },
+            _ => return Err("`uses` and `timespan` must be specified together".to_owned()),
+        };
+
+        let seconds = match self.seconds.map(u16::try_from).transpose() {
+            Ok(seconds) => seconds.unwrap_or(0),
+            Err(_) => return Err(Self::invalid_content()),
+        };
+
+        let cooldown = Cooldown {
+            bucket,
+            seconds,
+            limit,
+        };
+
+        if cooldown.is_valid() {
+            Ok(Some(cooldown))
+        } else {
+            Err(Self::invalid_content())
+        }
+    }
+
+    fn invalid_content() -> String {
+        format!(
+            "Seconds and timespan must be between 0 and {}, uses between 1 and {}",
+            Cooldowns::MAX_SECONDS,
+            Cooldowns::MAX_USES,
+        )
+    }
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption)]
+pub enum CooldownBucket {
+    #[option(name = "Background game: bigger", value = "bg_bigger")]
+    BgBigger,
+    #[option(name = "Background game: hint", value = "bg_hint")]
+    BgHint,
+    #[option(name = "Background game: skip", value = "bg_skip")]
+    BgSkip,
+    #[option(name = "Match compare", value = "match_compare")]
+    MatchCompare,
+    #[option(name = "Match live", value = "match_live")]
+    MatchLive,
+    #[option(name = "Render", value = "render")]
+    Render,
+    #[option(name = "Songs", value = "songs")]
+    Songs,
+}
+
+impl From<CooldownBucket> for BucketName {
+    #[inline]
+    fn from(bucket: CooldownBucket) -> Self {
+        match bucket {
+            CooldownBucket::BgBigger => Self::BgBigger,
+            CooldownBucket::BgHint => Self::BgHint,
+            CooldownBucket::BgSkip => Self::BgSkip,
+            CooldownBucket::MatchCompare => Self::MatchCompare,
+            CooldownBucket::MatchLive => Self::MatchLive,
+            CooldownBucket::Render => Self::Render,
+            CooldownBucket::Songs => Self::Songs,
+        }
+    }
+}
+
+#[derive(CommandModel, CreateCommand, Default)]
 #[command(name = "edit", desc = "Adjust configurations for a server")]
 pub struct ServerConfigEdit {
     #[command(desc = "Choose whether song commands can be used or not")]
     song_commands: Option<EnableDisable>,
     #[command(
         desc = "Adjust the amount of scores shown per page in top, rb, pinned, ...",
-        help = "Adjust the amount of scores shown per page in top, rb, pinned, and mapper.\n\
+        help = "Adjust the amount of scores shown per page in top, rb, pinned, mapper, and topweek.\n\
         `Condensed` shows 10 scores, `Detailed` shows 5, and `Single` shows 1.\n\
-        Applies only if the member has not specified a config for themselves."
+        Applies only if neither the command's `size` option nor the member's own config is set."
     )]
     list_embeds: Option<ListSize>,
     #[command(
@@ -134,6 +397,36 @@ pub struct ServerConfigEdit {
         Applies only if the member has not specified a config for themselves."
     )]
     score_data: Option<ScoreData>,
+    #[command(
+        desc = "Can anyone use the buttons of paginations and games?",
+        help = "Can anyone use the buttons of paginations and games?\n\
+        By default, only the member who used the command and authorities can use them."
+    )]
+    anyone_can_interact: Option<bool>,
+    #[command(
+        min_value = 15,
+        max_value = 300,
+        desc = "Specify the amount of seconds until paginations time out",
+        help = "Specify the amount of seconds until buttons of paginations stop working.\n\
+        The value must be between 15 and 300, defaults to 60.\n\
+        Applies only if the member has not specified a config for themselves."
+    )]
+    pagination_timeout: Option<i64>,
+    #[command(
+        desc = "Specify a gamemode for members without a configured mode",
+        help = "Specify the gamemode that commands use when no mode is specified.\n\
+        Applies only if the member has not specified a config for themselves."
+    )]
+    mode: Option<ConfigGameMode>,
+    #[command(
+        desc = "Should only verified links count for server leaderboards?",
+        help = "Should only verified links count for server leaderboards?\n\
+        A link is verified if the member linked by logging in through osu! via `/link` \
+        or by placing a code on their osu! profile via `/linkprofile`.\n\
+        If enabled, members without a verified link won't show up in \
+        `/serverleaderboard` and `/scores server`."
+    )]
+    require_verification: Option<bool>,
 }
 
 impl ServerConfigEdit {
@@ -147,6 +440,10 @@ impl ServerConfigEdit {
             allow_custom_skins,
             hide_medal_solutions,
             score_data,
+            anyone_can_interact,
+            pagination_timeout,
+            mode,
+            require_verification,
         } = self;
 
         song_commands.is_some()
@@ -157,6 +454,10 @@ impl ServerConfigEdit {
             || allow_custom_skins.is_some()
             || hide_medal_solutions.is_some()
             || score_data.is_some()
+            || anyone_can_interact.is_some()
+            || pagination_timeout.is_some()
+            || mode.is_some()
+            || require_verification.is_some()
     }
 }
 
@@ -184,6 +485,36 @@ async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
         ServerConfig::Authorities(args) => {
             return super::authorities((&mut command).into(), args.into()).await
         }
+        ServerConfig::ChannelModes(args) => return channel_modes(command, args).await,
+        ServerConfig::Channels(args) => return channel_restrictions(command, args).await,
+        ServerConfig::Commands(args) => return disabled_commands(command, args).await,
+        ServerConfig::Cooldown(args) => {
+            let bucket = BucketName::from(args.commands) as u8;
+
+            let cooldown = match args.cooldown() {
+                Ok(cooldown) => cooldown,
+                Err(content) => {
+                    command.error_callback(content).await?;
+
+                    return Ok(());
+                }
+            };
+
+            let f = |config: &mut GuildConfig| match cooldown {
+                Some(cooldown) => {
+                    config.cooldowns.set(cooldown);
+                }
+                None => config.cooldowns.reset(bucket),
+            };
+
+            if let Err(err) = Context::guild_config().update(guild_id, f).await {
+                let _ = command.error_callback(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("failed to update guild config"));
+            }
+
+            ServerConfigEdit::default()
+        }
         ServerConfig::Edit(edit) => edit,
     };
 
@@ -198,6 +529,10 @@ async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
                 allow_custom_skins,
                 hide_medal_solutions,
                 score_data,
+                anyone_can_interact,
+                pagination_timeout,
+                mode,
+                require_verification,
             } = args;
 
             if let Some(list_embeds) = list_embeds {
@@ -231,6 +566,22 @@ async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
             if let Some(score_data) = score_data {
                 config.score_data = Some(score_data);
             }
+
+            if let Some(anyone_can_interact) = anyone_can_interact {
+                config.allow_foreign_interactions = Some(anyone_can_interact);
+            }
+
+            if let Some(secs) = pagination_timeout {
+                config.pagination_timeout = Some(secs as u16);
+            }
+
+            if let Some(mode) = mode {
+                config.mode = mode.into();
+            }
+
+            if let Some(require_verification) = require_verification {
+                config.require_verification = Some(require_verification);
+            }
         };
 
         if let Err(err) = Context::guild_config().update(guild_id, f).await {
@@ -258,3 +609,353 @@ async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
 
     Ok(())
 }
+
+async fn disabled_commands(command: InteractionCommand, args: ServerConfigCommands) -> Result<()> {
+    let guild_id = command.guild_id.unwrap();
+
+    let (name, disable) = match args {
+        ServerConfigCommands::Disable(args) => (args.command, true),
+        ServerConfigCommands::Enable(args) => (args.command, false),
+        ServerConfigCommands::List(_) => {
+            let disabled = Context::guild_config()
+                .peek(guild_id, |config| {
+                    let mut names: Vec<_> = config.disabled_commands.iter().collect();
+                    names.sort_unstable();
+
+                    names
+                        .into_iter()
+                        .fold(String::new(), |mut description, name| {
+                            if !description.is_empty() {
+                                description.push_str(", ");
+                            }
+
+                            description.push('`');
+                            description.push_str(name);
+                            description.push('`');
+
+                            description
+                        })
+                })
+                .await;
+
+            let description = if disabled.is_empty() {
+                "No commands are disabled in this server".to_owned()
+            } else {
+                disabled
+            };
+
+            let embed = EmbedBuilder::new()
+                .title("Disabled commands")
+                .description(description);
+
+            let builder = MessageBuilder::new().embed(embed);
+            command.callback(builder, false).await?;
+
+            return Ok(());
+        }
+    };
+
+    let Some(name) = resolve_command_name(&name) else {
+        let content = format!("There is no command with the name `{name}`");
+        command.error_callback(content).await?;
+
+        return Ok(());
+    };
+
+    if name == "serverconfig" {
+        let content = "The `serverconfig` command cannot be disabled";
+        command.error_callback(content).await?;
+
+        return Ok(());
+    }
+
+    let f = |config: &mut GuildConfig| {
+        if disable {
+            config.disabled_commands.insert(name.to_owned())
+        } else {
+            config.disabled_commands.remove(name)
+        }
+    };
+
+    let updated = match Context::guild_config().update(guild_id, f).await {
+        Ok(updated) => updated,
+        Err(err) => {
+            let _ = command.error_callback(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to update guild config"));
+        }
+    };
+
+    let content = match (disable, updated) {
+        (true, true) => format!("The `{name}` command is now disabled in this server"),
+        (true, false) => format!(
+            "The `{name}` command is already disabled or the maximum of {} \
+            disabled commands has been reached",
+            DisabledCommands::LEN
+        ),
+        (false, true) => format!("The `{name}` command is now enabled again in this server"),
+        (false, false) => format!("The `{name}` command was not disabled in this server"),
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
+
+async fn channel_modes(command: InteractionCommand, args: ServerConfigChannelModes) -> Result<()> {
+    let guild_id = command.guild_id.unwrap();
+
+    let (channel, mode) = match args {
+        ServerConfigChannelModes::Set(args) => (
+            args.channel.unwrap_or(command.channel_id),
+            Some(GameMode::from(args.mode)),
+        ),
+        ServerConfigChannelModes::Remove(args) => {
+            (args.channel.unwrap_or(command.channel_id), None)
+        }
+        ServerConfigChannelModes::List(_) => {
+            let f = |config: &GuildConfig| {
+                let mut description = String::new();
+
+                for entry in config.channel_modes.iter() {
+                    let _ = writeln!(
+                        description,
+                        "<#{}>: `{}`",
+                        entry.channel(),
+                        mode_name(entry.mode())
+                    );
+                }
+
+                description
+            };
+
+            let mut description = Context::guild_config().peek(guild_id, f).await;
+
+            if description.is_empty() {
+                description.push_str("No channels have a gamemode");
+            }
+
+            let embed = EmbedBuilder::new()
+                .title("Channel gamemodes")
+                .description(description);
+
+            let builder = MessageBuilder::new().embed(embed);
+            command.callback(builder, false).await?;
+
+            return Ok(());
+        }
+    };
+
+    let f = |config: &mut GuildConfig| match mode {
+        Some(mode) => config.channel_modes.set(channel, mode),
+        None => config.channel_modes.remove(channel),
+    };
+
+    let updated = match Context::guild_config().update(guild_id, f).await {
+        Ok(updated) => updated,
+        Err(err) => {
+            let _ = command.error_callback(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to update guild config"));
+        }
+    };
+
+    let content = match (mode, updated) {
+        (Some(mode), true) => format!(
+            "Commands in <#{channel}> now use `{}` if no mode is specified",
+            mode_name(mode)
+        ),
+        (Some(_), false) => format!(
+            "A limit has been reached, at most {} channels can have a gamemode",
+            ChannelModes::CHANNELS_LEN
+        ),
+        (None, true) => format!("<#{channel}> no longer has a gamemode"),
+        (None, false) => format!("<#{channel}> did not have a gamemode"),
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
+
+fn mode_name(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "osu",
+        GameMode::Taiko => "taiko",
+        GameMode::Catch => "catch",
+        GameMode::Mania => "mania",
+    }
+}
+
+async fn channel_restrictions(
+    command: InteractionCommand,
+    args: ServerConfigChannels,
+) -> Result<()> {
+    #[derive(Copy, Clone)]
+    enum Action {
+        Allow(Id<ChannelMarker>),
+        Disallow(Id<ChannelMarker>),
+        Clear,
+    }
+
+    let guild_id = command.guild_id.unwrap();
+
+    let (name, action) = match args {
+        ServerConfigChannels::Allow(args) => (
+            args.command,
+            Action::Allow(args.channel.unwrap_or(command.channel_id)),
+        ),
+        ServerConfigChannels::Disallow(args) => (
+            args.command,
+            Action::Disallow(args.channel.unwrap_or(command.channel_id)),
+        ),
+        ServerConfigChannels::Clear(args) => (args.command, Action::Clear),
+        ServerConfigChannels::List(_) => {
+            let f = |config: &GuildConfig| {
+                let mut description = String::new();
+
+                for restriction in config.channel_restrictions.iter() {
+                    let _ = write!(description, "`{}`: ", restriction.command);
+                    let mut channels = restriction.channels();
+
+                    if let Some(first) = channels.next() {
+                        let _ = write!(description, "<#{first}>");
+
+                        for channel in channels {
+                            let _ = write!(description, ", <#{channel}>");
+                        }
+                    }
+
+                    description.push('\n');
+                }
+
+                description
+            };
+
+            let mut description = Context::guild_config().peek(guild_id, f).await;
+
+            if description.is_empty() {
+                description.push_str("No commands are restricted to specific channels");
+            }
+
+            let embed = EmbedBuilder::new()
+                .title("Channel restrictions")
+                .description(description);
+
+            let builder = MessageBuilder::new().embed(embed);
+            command.callback(builder, false).await?;
+
+            return Ok(());
+        }
+    };
+
+    let Some(name) = resolve_command_name(&name) else {
+        let content = format!("There is no command with the name `{name}`");
+        command.error_callback(content).await?;
+
+        return Ok(());
+    };
+
+    if name == "serverconfig" {
+        let content = "The `serverconfig` command cannot be restricted";
+        command.error_callback(content).await?;
+
+        return Ok(());
+    }
+
+    let f = |config: &mut GuildConfig| {
+        let restrictions = &mut config.channel_restrictions;
+
+        match action {
+            Action::Allow(channel) => restrictions.allow(name, channel),
+            Action::Disallow(channel) => restrictions.disallow(name, channel),
+            Action::Clear => restrictions.clear(name),
+        }
+    };
+
+    let updated = match Context::guild_config().update(guild_id, f).await {
+        Ok(updated) => updated,
+        Err(err) => {
+            let _ = command.error_callback(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("failed to update guild config"));
+        }
+    };
+
+    let content = match (action, updated) {
+        (Action::Allow(channel), true) => {
+            format!("The `{name}` command is now allowed in <#{channel}>")
+        }
+        (Action::Allow(channel), false) => format!(
+            "The `{name}` command is already allowed in <#{channel}> or a limit has been \
+            reached (at most {} channels per command and {} restricted commands)",
+            ChannelRestrictions::CHANNELS_LEN,
+            ChannelRestrictions::COMMANDS_LEN,
+        ),
+        (Action::Disallow(channel), true) => {
+            format!("The `{name}` command is no longer allowed in <#{channel}>")
+        }
+        (Action::Disallow(channel), false) => {
+            format!("The `{name}` command was not specifically allowed in <#{channel}>")
+        }
+        (Action::Clear, true) => {
+            format!("The `{name}` command can now be used in all channels again")
+        }
+        (Action::Clear, false) => {
+            format!("The `{name}` command was not restricted to specific channels")
+        }
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
+
+/// Find the command with the given name and return the name under which it
+/// is stored in the guild config.
+///
+/// Owner-only commands are not considered.
+fn resolve_command_name(name: &str) -> Option<&'static str> {
+    let name = name.trim().trim_start_matches('/').to_ascii_lowercase();
+
+    if let Some(InteractionCommandKind::Chat(cmd)) = InteractionCommands::get().command(&name) {
+        return (!cmd.flags.only_owner()).then_some(cmd.name);
+    }
+
+    PrefixCommands::get()
+        .command(&name)
+        .filter(|cmd| !cmd.flags.only_owner())
+        .map(PrefixCommand::config_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use bathbot_psql::model::configs::DisabledCommands;
+
+    use super::resolve_command_name;
+    use crate::core::commands::prefix::PrefixCommands;
+
+    #[test]
+    fn disabled_slash_name_applies_to_prefix_alias() {
+        let mut disabled = DisabledCommands::default();
+        let name = resolve_command_name("bg").expect("missing bg command");
+        assert!(disabled.insert(name.to_owned()));
+
+        for alias in ["bg", "backgroundgame"] {
+            let cmd = PrefixCommands::get()
+                .command(alias)
+                .expect("missing prefix command");
+
+            assert!(disabled.contains(cmd.config_name()), "alias `{alias}`");
+        }
+    }
+
+    #[test]
+    fn prefix_name_resolves_to_slash_name() {
+        assert_eq!(resolve_command_name("backgroundgame"), Some("bg"));
+        assert_eq!(resolve_command_name("nochokes"), Some("nochoke"));
+    }
+}
@@ -43,6 +43,7 @@ impl Buckets {
     }
 }
 
+#[derive(Copy, Clone)]
 pub struct Ratelimit {
     pub delay: i64,
     pub limit: Option<(i64, i32)>,
@@ -77,11 +78,14 @@ impl Bucket {
         }
     }
 
-    pub fn take(&mut self, user_id: u64) -> i64 {
+    /// Acquire an entry for the user. If `ratelimit` is specified, it will be
+    /// used instead of the bucket's default ratelimit.
+    pub fn take(&mut self, user_id: u64, ratelimit: Option<Ratelimit>) -> i64 {
+        let Ratelimit { delay, limit } = ratelimit.unwrap_or(self.ratelimit);
         let time = OffsetDateTime::now_utc().unix_timestamp();
         let user = self.users.entry(user_id).or_default();
 
-        if let Some((timespan, limit)) = self.ratelimit.limit {
+        if let Some((timespan, limit)) = limit {
             if user.tickets + 1 > limit {
                 if time < (user.set_time + timespan) {
                     return (user.set_time + timespan) - time;
@@ -92,8 +96,8 @@ impl Bucket {
             }
         }
 
-        if time < user.last_time + self.ratelimit.delay {
-            (user.last_time + self.ratelimit.delay) - time
+        if time < user.last_time + delay {
+            (user.last_time + delay) - time
         } else {
             user.tickets += 1;
             user.last_time = time;
@@ -101,18 +105,80 @@ impl Bucket {
             0
         }
     }
+
+    /// Same as [`Bucket::take`] but without acquiring an entry.
+    pub fn peek(&self, user_id: u64, ratelimit: Option<Ratelimit>) -> i64 {
+        let Some(user) = self.users.get(&user_id) else {
+            return 0;
+        };
+
+        let Ratelimit { delay, limit } = ratelimit.unwrap_or(self.ratelimit);
+        let time = OffsetDateTime::now_utc().unix_timestamp();
+
+        if let Some((timespan, limit)) = limit {
+            if user.tickets + 1 > limit && time < (user.set_time + timespan) {
+                return (user.set_time + timespan) - time;
+            }
+        }
+
+        ((user.last_time + delay) - time).max(0)
+    }
 }
 
 // Some buckets require certain features to be enabled
 #[allow(unused)]
 #[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
+#[repr(u8)]
 pub enum BucketName {
-    All,
-    BgBigger,
-    BgHint,
-    BgSkip,
-    MatchCompare,
-    MatchLive,
-    Render,
-    Songs,
+    All = 0,
+    BgBigger = 1,
+    BgHint = 2,
+    BgSkip = 3,
+    MatchCompare = 4,
+    MatchLive = 5,
+    Render = 6,
+    Songs = 7,
+}
+
+impl BucketName {
+    pub fn iter() -> impl Iterator<Item = Self> {
+        [
+            Self::All,
+            Self::BgBigger,
+            Self::BgHint,
+            Self::BgSkip,
+            Self::MatchCompare,
+            Self::MatchLive,
+            Self::Render,
+            Self::Songs,
+        ]
+        .into_iter()
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::All => "Prefix commands",
+            Self::BgBigger => "Background game: bigger",
+            Self::BgHint => "Background game: hint",
+            Self::BgSkip => "Background game: skip",
+            Self::MatchCompare => "Match compare",
+            Self::MatchLive => "Match live",
+            Self::Render => "Render",
+            Self::Songs => "Songs",
+        }
+    }
+
+    /// Whether guild authorities may adjust the ratelimit of this bucket.
+    pub fn guild_configurable(self) -> bool {
+        self != Self::All
+    }
+}
+
+impl TryFrom<u8> for BucketName {
+    type Error = ();
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::iter().find(|bucket| *bucket as u8 == value).ok_or(())
+    }
 }
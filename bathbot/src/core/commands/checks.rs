@@ -2,9 +2,10 @@ use std::fmt::Write;
 
 use bathbot_cache::model::CachedArchive;
 use bathbot_model::twilight_model::{
-    channel::{PermissionOverwrite, PermissionOverwriteTypeRkyv},
+    channel::{ChannelTypeRkyv, PermissionOverwrite, PermissionOverwriteTypeRkyv},
     guild::Member,
 };
+use bathbot_psql::model::configs::GuildConfig;
 use eyre::{ContextCompat, Result};
 use rkyv::{with::DeserializeWith, Archived, Infallible};
 use twilight_model::{
@@ -81,6 +82,88 @@ pub async fn check_authority(
     Ok(None)
 }
 
+/// Whether the command was disabled by the authorities of the guild
+pub async fn check_disabled(guild: Option<Id<GuildMarker>>, name: &str) -> bool {
+    let Some(guild) = guild else { return false };
+
+    Context::guild_config()
+        .peek(guild, |config| config.disabled_commands.contains(name))
+        .await
+}
+
+/// Not restricted -> None
+/// Restricted to other channels -> Some(message to user)
+///
+/// Threads are allowed if their parent channel is allowed.
+pub async fn check_channel_restriction(
+    guild: Option<Id<GuildMarker>>,
+    channel: Id<ChannelMarker>,
+    name: &str,
+) -> Option<String> {
+    let guild = guild?;
+
+    let content = Context::guild_config()
+        .peek(guild, |config| restriction_content(config, channel, name))
+        .await?;
+
+    match thread_parent(guild, channel).await {
+        Some(parent) => {
+            Context::guild_config()
+                .peek(guild, |config| restriction_content(config, parent, name))
+                .await
+        }
+        None => Some(content),
+    }
+}
+
+fn restriction_content(
+    config: &GuildConfig,
+    channel: Id<ChannelMarker>,
+    name: &str,
+) -> Option<String> {
+    let restriction = config.channel_restrictions.get(name)?;
+
+    if restriction.channels().any(|allowed| allowed == channel) {
+        return None;
+    }
+
+    let mut content = String::from("That command can only be used in ");
+    let mut channels = restriction.channels();
+
+    if let Some(first) = channels.next() {
+        let _ = write!(content, "<#{first}>");
+
+        for channel in channels {
+            let _ = write!(content, ", <#{channel}>");
+        }
+    }
+
+    Some(content)
+}
+
+/// The parent channel if the channel is a thread.
+pub async fn thread_parent(
+    guild_id: Id<GuildMarker>,
+    channel_id: Id<ChannelMarker>,
+) -> Option<Id<ChannelMarker>> {
+    let channel = match Context::cache().channel(Some(guild_id), channel_id).await {
+        Ok(channel) => channel?,
+        Err(err) => {
+            warn!(?err, "Failed to get channel from cache");
+
+            return None;
+        }
+    };
+
+    let kind = ChannelTypeRkyv::deserialize_with(&channel.kind, &mut Infallible).unwrap();
+
+    if !kind.is_thread() {
+        return None;
+    }
+
+    channel.parent_id.as_ref().map(|id| Id::new(id.get()))
+}
+
 pub async fn check_guild_permissions(
     user: Id<UserMarker>,
     guild: Id<GuildMarker>,
@@ -28,4 +28,12 @@ impl CommandFlags {
     pub fn only_owner(self) -> bool {
         self.contains(CommandFlags::ONLY_OWNER)
     }
+
+    /// Whether the command's response can be made ephemeral through the
+    /// `private` option.
+    ///
+    /// Only applies to deferred commands that are not ephemeral anyway.
+    pub fn privatable(self) -> bool {
+        self.defer() && !self.ephemeral() && !self.only_owner()
+    }
 }
@@ -4,7 +4,10 @@ use std::{
 };
 
 use twilight_interactions::command::ApplicationCommandData;
-use twilight_model::id::{marker::CommandMarker, Id};
+use twilight_model::{
+    application::command::{CommandOption, CommandOptionType},
+    id::{marker::CommandMarker, Id},
+};
 
 use super::{twilight_command::Command, CommandResult};
 use crate::{
@@ -21,7 +24,15 @@ pub enum InteractionCommandKind {
 impl InteractionCommandKind {
     pub fn create(&self) -> Command {
         match self {
-            InteractionCommandKind::Chat(cmd) => (cmd.create)().into(),
+            InteractionCommandKind::Chat(cmd) => {
+                let mut command: Command = (cmd.create)().into();
+
+                if cmd.flags.privatable() {
+                    add_private_option(&mut command.options);
+                }
+
+                command
+            }
             InteractionCommandKind::Message(cmd) => (cmd.create)(),
         }
     }
@@ -48,6 +59,45 @@ impl InteractionCommandKind {
     }
 }
 
+/// Name of the boolean option that is added to all deferred slash commands
+/// which lets users opt into an ephemeral response.
+pub const PRIVATE_OPTION: &str = "private";
+
+/// Add the [`PRIVATE_OPTION`] to all leaf options i.e. to the command itself
+/// or to each of its subcommands.
+fn add_private_option(options: &mut Vec<CommandOption>) {
+    let mut has_subcommands = false;
+
+    for option in options.iter_mut() {
+        if let CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup = option.kind {
+            has_subcommands = true;
+            add_private_option(option.options.get_or_insert_with(Vec::new));
+        }
+    }
+
+    // Discord allows at most 25 options per command
+    if has_subcommands || options.len() >= 25 {
+        return;
+    }
+
+    options.push(CommandOption {
+        autocomplete: None,
+        channel_types: None,
+        choices: None,
+        description: "Only show the response to you".to_owned(),
+        description_localizations: None,
+        kind: CommandOptionType::Boolean,
+        max_length: None,
+        max_value: None,
+        min_length: None,
+        min_value: None,
+        name: PRIVATE_OPTION.to_owned(),
+        name_localizations: None,
+        options: None,
+        required: Some(false),
+    });
+}
+
 pub struct SlashCommand {
     pub bucket: Option<BucketName>,
     pub create: fn() -> ApplicationCommandData,
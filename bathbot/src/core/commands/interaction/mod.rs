@@ -6,7 +6,7 @@ use linkme::distributed_slice;
 use once_cell::sync::OnceCell;
 use radix_trie::{iter::Keys, Trie, TrieCommon};
 
-pub use self::command::{InteractionCommandKind, MessageCommand, SlashCommand};
+pub use self::command::{InteractionCommandKind, MessageCommand, SlashCommand, PRIVATE_OPTION};
 use self::twilight_command::Command;
 
 mod command;
@@ -79,14 +79,12 @@ impl InteractionCommands {
                 .command(name)
                 .unwrap_or_else(|| panic!("unknown command `{name}`"));
 
-            match cmd {
-                InteractionCommandKind::Chat(cmd) => {
-                    cmd.id.set(id).expect("command id has already been set");
-                }
-                InteractionCommandKind::Message(cmd) => {
-                    cmd.id.set(id).expect("command id has already been set");
-                }
-            }
+            // Commands keep their id when being registered again so if the
+            // id has already been set, there is nothing to update
+            let _ = match cmd {
+                InteractionCommandKind::Chat(cmd) => cmd.id.set(id),
+                InteractionCommandKind::Message(cmd) => cmd.id.set(id),
+            };
         }
     }
 }
@@ -50,11 +50,20 @@ impl CommandOrigin<'_> {
         }
     }
 
+    /// Whether responses are only visible to the author.
+    pub fn is_ephemeral(&self) -> bool {
+        match self {
+            CommandOrigin::Message { .. } => false,
+            CommandOrigin::Interaction { command } => command.ephemeral,
+        }
+    }
+
     /// Respond to something.
     ///
     /// In case of a message, discard the response message created.
     ///
-    /// In case of an interaction, the response will **not** be ephemeral.
+    /// In case of an interaction, the response will only be ephemeral if the
+    /// command is.
     pub async fn callback(&self, builder: MessageBuilder<'_>) -> Result<()> {
         match self {
             Self::Message { msg, permissions } => msg
@@ -63,7 +72,7 @@ impl CommandOrigin<'_> {
                 .map(|_| ())
                 .wrap_err("failed to create message to callback"),
             Self::Interaction { command } => command
-                .callback(builder, false)
+                .callback(builder, command.ephemeral)
                 .await
                 .map(|_| ())
                 .wrap_err("failed to callback"),
@@ -72,7 +81,8 @@ impl CommandOrigin<'_> {
 
     /// Respond to something and return the resulting response message.
     ///
-    /// In case of an interaction, the response will **not** be ephemeral.
+    /// In case of an interaction, the response will only be ephemeral if the
+    /// command is.
     pub async fn callback_with_response(
         &self,
         builder: MessageBuilder<'_>,
@@ -84,7 +94,7 @@ impl CommandOrigin<'_> {
                 .wrap_err("failed to create message for response callback"),
             Self::Interaction { command } => {
                 command
-                    .callback(builder, false)
+                    .callback(builder, command.ephemeral)
                     .await
                     .wrap_err("failed to callback for response")?;
 
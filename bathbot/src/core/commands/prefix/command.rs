@@ -1,7 +1,13 @@
 use twilight_model::{channel::Message, guild::Permissions};
 
 use super::{Args, CommandResult, PrefixCommandGroup};
-use crate::core::{buckets::BucketName, commands::flags::CommandFlags};
+use crate::core::{
+    buckets::BucketName,
+    commands::{
+        flags::CommandFlags,
+        interaction::{InteractionCommandKind, InteractionCommands},
+    },
+};
 
 pub struct PrefixCommand {
     pub names: &'static [&'static str],
@@ -19,4 +25,24 @@ impl PrefixCommand {
     pub fn name(&self) -> &str {
         self.names[0]
     }
+
+    /// The name under which the command is stored in guild configs.
+    ///
+    /// If the command has a slash counterpart, i.e. its name or one of its
+    /// aliases is the name of a slash command, the slash command's name is
+    /// used so that disabling or restricting one also applies to the other.
+    pub fn config_name(&self) -> &'static str {
+        let is_slash = |name: &&'static str| {
+            matches!(
+                InteractionCommands::get_command(name),
+                Some(InteractionCommandKind::Chat(_))
+            )
+        };
+
+        self.names
+            .iter()
+            .find(is_slash)
+            .copied()
+            .unwrap_or(self.names[0])
+    }
 }
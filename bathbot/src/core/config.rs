@@ -1,4 +1,4 @@
-use std::{env, fmt::Debug, mem::MaybeUninit, path::PathBuf, str::FromStr};
+use std::{env, fmt::Debug, mem::MaybeUninit, path::PathBuf, str::FromStr, time::Duration};
 
 use eyre::Result;
 use once_cell::sync::OnceCell;
@@ -27,6 +27,13 @@ pub struct BotConfig {
     pub owner: Id<UserMarker>,
     pub dev_guild: Id<GuildMarker>,
     pub hl_channel: Id<ChannelMarker>,
+    /// Channel to which command errors are reported, if any
+    pub error_report_channel: Option<Id<ChannelMarker>>,
+    pub member_requests: MemberRequestConfig,
+    /// Upper bound for how long it takes to check all tracked osu! users once.
+    /// If not set, the delay between checks is not tightened.
+    #[cfg(feature = "osutracking")]
+    pub tracking_target_cycle: Option<Duration>,
 }
 
 #[derive(Debug)]
@@ -45,6 +52,17 @@ pub struct Server {
     pub public_url: Box<str>,
 }
 
+#[derive(Debug)]
+pub struct MemberRequestConfig {
+    /// How many member requests are sent before waiting for the interval
+    pub batch_size: usize,
+    /// Delay between batches
+    pub interval: Duration,
+    /// How many guilds can be queued before further guilds are held back until
+    /// the queue drains
+    pub queue_capacity: usize,
+}
+
 #[derive(Debug)]
 pub struct Tokens {
     pub discord: Box<str>,
@@ -127,6 +145,16 @@ impl BotConfig {
             owner: env_var("OWNER_USER_ID")?,
             dev_guild: env_var("DEV_GUILD_ID")?,
             hl_channel: env_var("HL_IMAGE_CHANNEL")?,
+            error_report_channel: env_var_opt("ERROR_REPORT_CHANNEL")?,
+            member_requests: MemberRequestConfig {
+                batch_size: env_var_or("MEMBER_REQUEST_BATCH_SIZE", 1_u16)?.max(1) as usize,
+                interval: Duration::from_millis(env_var_or("MEMBER_REQUEST_INTERVAL_MS", 600)?),
+                queue_capacity: env_var_or("MEMBER_REQUEST_QUEUE_SIZE", 10_000_u64)?.max(1)
+                    as usize,
+            },
+            #[cfg(feature = "osutracking")]
+            tracking_target_cycle: env_var_opt::<u64>("TRACKING_TARGET_CYCLE_SECS")?
+                .map(|secs| Duration::from_secs(secs.max(1))),
         };
 
         if CONFIG.set(config).is_err() {
@@ -222,6 +250,30 @@ fn env_var<T: EnvKind>(name: &str) -> Result<T> {
     })
 }
 
+fn env_var_or<T: EnvKind>(name: &str, default: T) -> Result<T> {
+    match env::var(name) {
+        Ok(value) => T::from_str(value).map_err(|value| {
+            eyre!(
+                "failed to parse env variable `{name}={value}`; expected {expected}",
+                expected = T::EXPECTED
+            )
+        }),
+        Err(_) => Ok(default),
+    }
+}
+
+fn env_var_opt<T: EnvKind>(name: &str) -> Result<Option<T>> {
+    match env::var(name) {
+        Ok(value) => T::from_str(value).map(Some).map_err(|value| {
+            eyre!(
+                "failed to parse env variable `{name}={value}`; expected {expected}",
+                expected = T::EXPECTED
+            )
+        }),
+        Err(_) => Ok(None),
+    }
+}
+
 trait AsUsize {
     fn to_usize(self) -> usize;
 }
@@ -4,8 +4,8 @@ use rosu_v2::prelude::GameMode;
 use super::Context;
 use crate::manager::{
     redis::RedisManager, ApproxManager, BookmarkManager, GameManager, GithubManager,
-    GuildConfigManager, HuismetbenenCountryManager, MapManager, OsuMap, OsuUserManager, PpManager,
-    ReplayManager, ScoresManager, UserConfigManager,
+    GuildConfigManager, HuismetbenenCountryManager, MapManager, MapRequestManager, OsuMap,
+    OsuUserManager, PpManager, ReplayManager, RoleAssignManager, ScoresManager, UserConfigManager,
 };
 
 impl Context {
@@ -53,6 +53,10 @@ impl Context {
         BookmarkManager::new()
     }
 
+    pub fn map_requests() -> MapRequestManager {
+        MapRequestManager::new()
+    }
+
     pub fn replay() -> ReplayManager {
         let ctx = Self::get();
 
@@ -78,4 +82,10 @@ impl Context {
     pub fn huismetbenen() -> HuismetbenenCountryManager {
         HuismetbenenCountryManager::new()
     }
+
+    pub fn role_assigns() -> RoleAssignManager {
+        let ctx = Self::get();
+
+        RoleAssignManager::new(&ctx.clients.psql, &ctx.data.role_assigns)
+    }
 }
@@ -1,7 +1,7 @@
 use std::{
     collections::HashMap,
     sync::{Arc, Mutex, OnceLock, RwLock},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use bathbot_cache::Cache;
@@ -18,7 +18,10 @@ use papaya::HashMap as PapayaMap;
 use rkyv::with::With;
 use rosu_v2::Osu;
 use time::OffsetDateTime;
-use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::mpsc::{
+    error::{SendError, TrySendError},
+    Sender,
+};
 use twilight_gateway::{
     stream, CloseFrame, Config, ConfigBuilder, EventTypeFlags, Intents, MessageSender, Session,
     Shard, ShardId,
@@ -39,12 +42,15 @@ use twilight_standby::Standby;
 
 use self::osutrack::OsuTrackUserNotifTimestamps;
 use super::{
-    buckets::{BucketName, Buckets},
-    BotConfig, BotMetrics,
+    buckets::{BucketName, Buckets, Ratelimit},
+    BotConfig, BotMetrics, ShardStatus,
 };
 use crate::{
     active::{impls::BackgroundGame, ActiveMessages},
+    commands::songs::RecentSongs,
+    manager::{DifficultyCache, RoleAssigns},
     tracking::Ordr,
+    util::retry_with_backoff,
 };
 
 mod games;
@@ -73,6 +79,7 @@ static CONTEXT: OnceLock<Box<Context>> = OnceLock::new();
 pub struct Context {
     pub buckets: Buckets,
     pub shard_senders: RwLock<HashMap<u64, MessageSender>>,
+    pub shard_statuses: PapayaMap<u64, ShardStatus, IntHasher>,
     pub member_requests: MemberRequests,
     pub active_msgs: ActiveMessages,
     pub start_time: OffsetDateTime,
@@ -83,6 +90,9 @@ pub struct Context {
     /// Keeps track of the amount of times content was added to a usual bot
     /// response to remind users about the new /builder command.
     pub builder_notices: StdMutexMap<Id<UserMarker>, usize, IntHasher>,
+
+    /// Songs that were recently sung in a channel.
+    pub recent_songs: StdMutexMap<Id<ChannelMarker>, RecentSongs, IntHasher>,
 }
 
 impl Context {
@@ -143,6 +153,10 @@ impl Context {
         &Self::get().data.miss_analyzer_guilds
     }
 
+    pub fn difficulty_cache() -> &'static DifficultyCache {
+        &Self::get().data.difficulty_cache
+    }
+
     pub fn has_miss_analyzer(guild: &Id<GuildMarker>) -> bool {
         Self::miss_analyzer_guilds().pin().contains_key(guild)
     }
@@ -152,7 +166,7 @@ impl Context {
         &Self::get().data.online_twitch_streams
     }
 
-    pub async fn init(tx: UnboundedSender<(Id<GuildMarker>, u64)>) -> Result<ContextResult> {
+    pub async fn init(tx: Sender<(Id<GuildMarker>, u64)>) -> Result<ContextResult> {
         let (_prometheus, reader) = {
             const DEFAULT_BUCKETS: [f64; 10] =
                 [0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
@@ -270,6 +284,7 @@ impl Context {
         let ctx = Self {
             clients,
             shard_senders,
+            shard_statuses: PapayaMap::default(),
             data,
             buckets: Buckets::new(),
             member_requests: MemberRequests::new(tx),
@@ -277,6 +292,7 @@ impl Context {
             start_time,
             metrics: reader,
             builder_notices: StdMutexMap::default(),
+            recent_songs: StdMutexMap::default(),
         };
 
         if CONTEXT.set(Box::new(ctx)).is_err() {
@@ -298,11 +314,68 @@ impl Context {
             .get(bucket)
             .lock()
             .unwrap()
-            .take(user_id.get());
+            .take(user_id.get(), None);
+
+        (ratelimit > 0).then_some(ratelimit)
+    }
+
+    /// Same as [`Context::check_ratelimit`] but considers the guild's
+    /// configured ratelimit for the bucket.
+    pub async fn check_guild_ratelimit(
+        user_id: Id<UserMarker>,
+        guild_id: Option<Id<GuildMarker>>,
+        bucket: BucketName,
+    ) -> Option<i64> {
+        let guild_ratelimit = Self::guild_bucket_ratelimit(guild_id, bucket).await;
+
+        let ratelimit = Self::get()
+            .buckets
+            .get(bucket)
+            .lock()
+            .unwrap()
+            .take(user_id.get(), guild_ratelimit);
+
+        (ratelimit > 0).then_some(ratelimit)
+    }
+
+    /// Optionally return the cooldown in amount of seconds for the user in the
+    /// bucket without acquiring an entry.
+    pub async fn peek_ratelimit(
+        user_id: Id<UserMarker>,
+        guild_id: Option<Id<GuildMarker>>,
+        bucket: BucketName,
+    ) -> Option<i64> {
+        let guild_ratelimit = Self::guild_bucket_ratelimit(guild_id, bucket).await;
+
+        let ratelimit = Self::get()
+            .buckets
+            .get(bucket)
+            .lock()
+            .unwrap()
+            .peek(user_id.get(), guild_ratelimit);
 
         (ratelimit > 0).then_some(ratelimit)
     }
 
+    /// The guild's override of the bucket's default ratelimit, if any.
+    async fn guild_bucket_ratelimit(
+        guild_id: Option<Id<GuildMarker>>,
+        bucket: BucketName,
+    ) -> Option<Ratelimit> {
+        let guild_id = guild_id.filter(|_| bucket.guild_configurable())?;
+
+        let cooldown = Self::guild_config()
+            .peek(guild_id, |config| config.cooldowns.get(bucket as u8))
+            .await?;
+
+        Some(Ratelimit {
+            delay: i64::from(cooldown.seconds),
+            limit: cooldown
+                .limit
+                .map(|(timespan, uses)| (i64::from(timespan), i32::from(uses))),
+        })
+    }
+
     pub async fn down_resumable(shards: &mut [Shard]) -> HashMap<u64, Session, IntHasher> {
         shards
             .iter_mut()
@@ -353,17 +426,113 @@ pub type ContextResult = (Vec<Shard>,);
 pub type ContextResult = (Vec<Shard>, tokio::sync::oneshot::Sender<()>);
 
 pub struct MemberRequests {
-    pub tx: UnboundedSender<(Id<GuildMarker>, u64)>,
+    pub tx: Sender<(Id<GuildMarker>, u64)>,
     pub pending_guilds: Mutex<HashSet<Id<GuildMarker>, IntHasher>>,
+    forced: Mutex<HashMap<Id<GuildMarker>, Instant, IntHasher>>,
 }
 
 impl MemberRequests {
-    fn new(tx: UnboundedSender<(Id<GuildMarker>, u64)>) -> Self {
+    /// Minimum delay between forced member requests for the same guild
+    const FORCE_DEBOUNCE: Duration = Duration::from_secs(60);
+
+    fn new(tx: Sender<(Id<GuildMarker>, u64)>) -> Self {
         Self {
             tx,
             pending_guilds: Mutex::new(HashSet::default()),
+            forced: Mutex::new(HashMap::default()),
+        }
+    }
+
+    /// Checks whether a member request for the guild may be forced.
+    ///
+    /// Returns the remaining time to wait if the guild was forced too
+    /// recently.
+    pub fn debounce_forced(&self, guild_id: Id<GuildMarker>) -> Result<(), Duration> {
+        let now = Instant::now();
+        let mut forced = self.forced.lock().unwrap();
+        forced.retain(|_, last| now.duration_since(*last) < Self::FORCE_DEBOUNCE);
+
+        match forced.get(&guild_id) {
+            Some(last) => Err(Self::FORCE_DEBOUNCE - now.duration_since(*last)),
+            None => {
+                forced.insert(guild_id, now);
+
+                Ok(())
+            }
         }
     }
+
+    /// Queue a member request for the guild.
+    ///
+    /// Never waits on the queue. If it is full, the guild remains in the
+    /// pending guilds and is picked up through [`MemberRequests::overflowed`]
+    /// once the queue has been worked off.
+    pub fn enqueue(
+        &self,
+        guild_id: Id<GuildMarker>,
+        shard_id: u64,
+    ) -> Result<(), SendError<(Id<GuildMarker>, u64)>> {
+        let (inserted, len) = {
+            let mut pending = self.pending_guilds.lock().unwrap();
+            let inserted = pending.insert(guild_id);
+
+            (inserted, pending.len())
+        };
+
+        BotMetrics::set_member_request_queue(len);
+
+        // Already queued or waiting for the queue to drain
+        if !inserted {
+            return Ok(());
+        }
+
+        match self.tx.try_send((guild_id, shard_id)) {
+            Ok(_) | Err(TrySendError::Full(_)) => Ok(()),
+            Err(TrySendError::Closed(value)) => {
+                self.take_pending(guild_id);
+
+                Err(SendError(value))
+            }
+        }
+    }
+
+    /// Pick a pending guild that did not fit into the queue.
+    ///
+    /// Only meaningful while the queue is empty because all other pending
+    /// guilds are still in the queue otherwise.
+    pub fn overflowed(&self) -> Option<(Id<GuildMarker>, u64)> {
+        let guild_shards = Context::get().guild_shards().pin();
+        let mut pending = self.pending_guilds.lock().unwrap();
+
+        loop {
+            let guild_id = pending.iter().next().copied()?;
+
+            match guild_shards.get(&guild_id) {
+                Some(shard_id) => return Some((guild_id, *shard_id)),
+                // Bot is no longer in the guild
+                None => {
+                    pending.remove(&guild_id);
+                }
+            }
+        }
+    }
+
+    /// Remove the guild from the pending guilds.
+    ///
+    /// Returns `false` if the guild was not pending e.g. because it was queued
+    /// multiple times and has already been processed.
+    pub fn take_pending(&self, guild_id: Id<GuildMarker>) -> bool {
+        let (removed, len) = {
+            let mut pending = self.pending_guilds.lock().unwrap();
+            let removed = pending.remove(&guild_id);
+
+            (removed, pending.len())
+        };
+
+        BotMetrics::set_member_request_queue(len);
+
+        removed
+    }
 }
 
 struct Clients {
@@ -390,6 +559,8 @@ struct ContextData {
     guild_configs: GuildConfigs,              // read-heavy
     guild_shards: GuildShards,                // necessary to request members for a guild
     miss_analyzer_guilds: MissAnalyzerGuilds, // read-heavy
+    role_assigns: RoleAssigns,                // read-heavy
+    difficulty_cache: DifficultyCache,
     osutrack_user_notif_timestamps: OsuTrackUserNotifTimestamps,
     #[cfg(feature = "twitch")]
     online_twitch_streams: crate::tracking::OnlineTwitchStreams,
@@ -402,20 +573,37 @@ impl ContextData {
         application_id: Id<ApplicationMarker>,
     ) -> Result<Self> {
         #[cfg(feature = "twitchtracking")]
-        let (guild_configs_res, tracked_streams_res, guild_shards, miss_analyzer_guilds) = tokio::join!(
+        let (
+            guild_configs_res,
+            tracked_streams_res,
+            role_assigns_res,
+            guild_shards,
+            miss_analyzer_guilds,
+        ) = tokio::join!(
             psql.select_guild_configs::<IntHasher>(),
             psql.select_tracked_twitch_streams::<IntHasher>(),
+            psql.select_role_assigns(),
             Self::fetch_guild_shards(&cache),
             Self::fetch_miss_analyzer_guilds(&cache),
         );
 
         #[cfg(not(feature = "twitchtracking"))]
-        let (guild_configs_res, guild_shards, miss_analyzer_guilds) = tokio::join!(
+        let (guild_configs_res, role_assigns_res, guild_shards, miss_analyzer_guilds) = tokio::join!(
             psql.select_guild_configs::<IntHasher>(),
+            psql.select_role_assigns(),
             Self::fetch_guild_shards(&cache),
             Self::fetch_miss_analyzer_guilds(&cache),
         );
 
+        let mut role_assigns = HashMap::<_, Vec<_>, IntHasher>::default();
+
+        for role_assign in role_assigns_res.wrap_err("Failed to get role assigns")? {
+            role_assigns
+                .entry(role_assign.message_id)
+                .or_default()
+                .push(role_assign);
+        }
+
         Ok(Self {
             cache,
             guild_configs: guild_configs_res
@@ -437,6 +625,8 @@ impl ContextData {
                 .await
                 .wrap_err("Failed to create osu tracking")?,
             miss_analyzer_guilds,
+            role_assigns: role_assigns.into_iter().collect(),
+            difficulty_cache: DifficultyCache::default(),
             osutrack_user_notif_timestamps: OsuTrackUserNotifTimestamps::default(),
             #[cfg(feature = "twitch")]
             online_twitch_streams: crate::tracking::OnlineTwitchStreams::default(),
@@ -507,13 +697,18 @@ async fn discord_http(config: &BotConfig) -> Result<(Arc<Client>, Id<Application
 
     let http = Arc::new(http);
 
-    let current_user = http
-        .current_user()
-        .await
-        .wrap_err("Failed to get current user")?
-        .model()
-        .await
-        .wrap_err("Failed to deserialize current user")?;
+    let http_ref = &http;
+
+    let current_user = retry_with_backoff("Fetching current user", || async move {
+        http_ref
+            .current_user()
+            .await
+            .wrap_err("Failed to get current user")?
+            .model()
+            .await
+            .wrap_err("Failed to deserialize current user")
+    })
+    .await?;
 
     let application_id = current_user.id.cast();
 
@@ -533,6 +728,7 @@ async fn discord_gateway(
     let intents = Intents::GUILDS
         | Intents::GUILD_MEMBERS
         | Intents::GUILD_MESSAGES
+        | Intents::GUILD_MESSAGE_REACTIONS
         | Intents::DIRECT_MESSAGES
         | Intents::MESSAGE_CONTENT;
 
@@ -550,6 +746,8 @@ async fn discord_gateway(
         | EventTypeFlags::MESSAGE_CREATE
         | EventTypeFlags::MESSAGE_DELETE
         | EventTypeFlags::MESSAGE_DELETE_BULK
+        | EventTypeFlags::REACTION_ADD
+        | EventTypeFlags::REACTION_REMOVE
         | EventTypeFlags::READY
         | EventTypeFlags::ROLE_CREATE
         | EventTypeFlags::ROLE_DELETE
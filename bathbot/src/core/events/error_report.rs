@@ -0,0 +1,106 @@
+use std::{
+    fmt::Write,
+    mem,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use eyre::Report;
+
+use crate::{core::BotConfig, util::ChannelExt};
+
+/// Maximum amount of reports within [`REPORT_WINDOW`]
+const REPORT_LIMIT: usize = 5;
+
+const REPORT_WINDOW: Duration = Duration::from_secs(60);
+
+/// Maximum amount of causes that are shown for an error
+const CHAIN_LIMIT: usize = 5;
+
+/// Maximum length of a single cause
+const CAUSE_LEN_LIMIT: usize = 200;
+
+static WINDOW: Mutex<ReportWindow> = Mutex::new(ReportWindow {
+    start: None,
+    sent: 0,
+    suppressed: 0,
+});
+
+struct ReportWindow {
+    start: Option<Instant>,
+    sent: usize,
+    suppressed: usize,
+}
+
+impl ReportWindow {
+    /// Returns `None` if the report should be suppressed, otherwise the
+    /// amount of reports that were suppressed since the previous report.
+    fn try_report(&mut self, now: Instant) -> Option<usize> {
+        match self.start {
+            Some(start) if now.duration_since(start) < REPORT_WINDOW => {}
+            _ => {
+                self.start = Some(now);
+                self.sent = 0;
+            }
+        }
+
+        if self.sent >= REPORT_LIMIT {
+            self.suppressed += 1;
+
+            return None;
+        }
+
+        self.sent += 1;
+
+        Some(mem::take(&mut self.suppressed))
+    }
+}
+
+/// Forward a command error to the configured error report channel.
+///
+/// Does nothing if no channel is configured or if too many errors were
+/// reported recently.
+pub(super) fn report_command_error(kind: &'static str, name: &str, err: &Report) {
+    let Some(channel) = BotConfig::get().error_report_channel else {
+        return;
+    };
+
+    let suppressed = match WINDOW.lock() {
+        Ok(mut window) => window.try_report(Instant::now()),
+        Err(_) => return,
+    };
+
+    let Some(suppressed) = suppressed else {
+        return;
+    };
+
+    let mut content = format!("**{kind} command `{name}` failed**\n```\n");
+
+    for cause in err.chain().take(CHAIN_LIMIT) {
+        let cause = cause.to_string().replace("```", "'''");
+
+        match cause.char_indices().nth(CAUSE_LEN_LIMIT) {
+            Some((idx, _)) => {
+                let _ = writeln!(content, "- {}...", &cause[..idx]);
+            }
+            None => {
+                let _ = writeln!(content, "- {cause}");
+            }
+        }
+    }
+
+    content.push_str("```");
+
+    if suppressed > 0 {
+        let _ = write!(
+            content,
+            "\n*{suppressed} more report(s) were suppressed due to rate limiting*"
+        );
+    }
+
+    tokio::spawn(async move {
+        if let Err(err) = channel.error(content).await {
+            warn!(?err, "Failed to send error report");
+        }
+    });
+}
@@ -1,5 +1,8 @@
 use std::{mem, time::Instant};
 
+use super::command::take_private_option;
+#[cfg(feature = "osutracking")]
+use crate::commands::tracking::slash_track;
 use crate::{
     commands::{
         help::slash_help,
@@ -15,12 +18,17 @@ pub async fn handle_autocomplete(mut command: InteractionCommand) {
     let name = mem::take(&mut command.data.name);
     EventKind::Autocomplete.log(&command, &name).await;
 
+    // Not relevant for autocompletion but would interfere with option parsing
+    take_private_option(&mut command.data.options);
+
     let res = match name.as_str() {
         "help" => slash_help(command).await,
         "badges" => slash_badges(command).await,
         "medal" => slash_medal(command).await,
         "cs" | "compare" | "score" => slash_cs(command).await,
         "regiontop" => slash_regiontop(command).await,
+        #[cfg(feature = "osutracking")]
+        "track" => slash_track(command).await,
         _ => return error!(name, "Unknown autocomplete command"),
     };
 
@@ -1,14 +1,19 @@
 use std::{mem, time::Instant};
 
 use eyre::Result;
+use twilight_model::application::interaction::application_command::{
+    CommandDataOption, CommandOptionValue,
+};
 
 use crate::{
     core::{
         commands::{
-            checks::check_authority,
-            interaction::{InteractionCommandKind, InteractionCommands, SlashCommand},
+            checks::{check_authority, check_channel_restriction, check_disabled},
+            interaction::{
+                InteractionCommandKind, InteractionCommands, SlashCommand, PRIVATE_OPTION,
+            },
         },
-        events::{EventKind, ProcessResult},
+        events::{error_report::report_command_error, EventKind, ProcessResult},
         BotConfig, BotMetrics, Context,
     },
     util::{interaction::InteractionCommand, Authored, InteractionCommandExt},
@@ -36,6 +41,7 @@ pub async fn handle_command(mut command: InteractionCommand) {
             }
 
             error!(name, ?err, "Failed to process interaction command");
+            report_command_error("Slash", &name, &err);
         }
     }
 
@@ -48,15 +54,20 @@ pub async fn handle_command(mut command: InteractionCommand) {
 }
 
 async fn process_command(
-    command: InteractionCommand,
+    mut command: InteractionCommand,
     cmd: InteractionCommandKind,
 ) -> Result<ProcessResult> {
     match cmd {
         InteractionCommandKind::Chat(cmd) => match pre_process_command(&command, cmd).await? {
             Some(result) => return Ok(result),
             None => {
+                // Must be removed before the command parses its options
+                let private =
+                    cmd.flags.privatable() && take_private_option(&mut command.data.options);
+
                 if cmd.flags.defer() {
-                    command.defer(cmd.flags.ephemeral()).await?;
+                    command.ephemeral = cmd.flags.ephemeral() || private;
+                    command.defer(command.ephemeral).await?;
                 }
 
                 (cmd.exec)(command).await?;
@@ -64,7 +75,8 @@ async fn process_command(
         },
         InteractionCommandKind::Message(cmd) => {
             if cmd.flags.defer() {
-                command.defer(cmd.flags.ephemeral()).await?;
+                command.ephemeral = cmd.flags.ephemeral();
+                command.defer(command.ephemeral).await?;
             }
 
             (cmd.exec)(command).await?;
@@ -88,9 +100,31 @@ async fn pre_process_command(
         return Ok(Some(ProcessResult::NoOwner));
     }
 
+    // Disabled in this guild?
+    if !slash.flags.only_owner() && check_disabled(command.guild_id, slash.name).await {
+        let content = "That command has been disabled in this server";
+        command.error_callback(content).await?;
+
+        return Ok(Some(ProcessResult::Disabled));
+    }
+
+    // Restricted to other channels?
+    if !slash.flags.only_owner() {
+        let restriction_fut =
+            check_channel_restriction(command.guild_id, command.channel_id, slash.name);
+
+        if let Some(content) = restriction_fut.await {
+            command.error_callback(content).await?;
+
+            return Ok(Some(ProcessResult::Restricted));
+        }
+    }
+
     // Ratelimited?
     if let Some(bucket) = slash.bucket {
-        if let Some(cooldown) = Context::check_ratelimit(user_id, bucket) {
+        if let Some(cooldown) =
+            Context::check_guild_ratelimit(user_id, command.guild_id, bucket).await
+        {
             trace!("Ratelimiting user {user_id} on bucket `{bucket:?}` for {cooldown} seconds");
 
             let content = format!("Command on cooldown, try again in {cooldown} seconds");
@@ -120,3 +154,24 @@ async fn pre_process_command(
 
     Ok(None)
 }
+
+/// Remove the [`PRIVATE_OPTION`] from the leaf options and return its value.
+pub(super) fn take_private_option(options: &mut Vec<CommandDataOption>) -> bool {
+    if let Some(idx) = options
+        .iter()
+        .position(|option| option.name == PRIVATE_OPTION)
+    {
+        return matches!(options.remove(idx).value, CommandOptionValue::Boolean(true));
+    }
+
+    options
+        .iter_mut()
+        .find_map(|option| match option.value {
+            CommandOptionValue::SubCommand(ref mut options)
+            | CommandOptionValue::SubCommandGroup(ref mut options) => {
+                Some(take_private_option(options))
+            }
+            _ => None,
+        })
+        .unwrap_or(false)
+}
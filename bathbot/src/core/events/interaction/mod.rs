@@ -34,6 +34,7 @@ pub async fn handle_interaction(interaction: Interaction) {
                 permissions,
                 channel_id,
                 data,
+                ephemeral: false,
                 guild_id,
                 id,
                 member,
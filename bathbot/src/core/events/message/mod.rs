@@ -9,11 +9,13 @@ use nom::{
 use twilight_model::{channel::Message, guild::Permissions};
 
 use self::parse::*;
-use super::{EventKind, ProcessResult};
+use super::{error_report::report_command_error, EventKind, ProcessResult};
 use crate::{
     core::{
         buckets::BucketName,
-        commands::checks::{check_authority, check_channel_permissions},
+        commands::checks::{
+            check_authority, check_channel_permissions, check_channel_restriction, check_disabled,
+        },
         BotMetrics, Context,
     },
     util::ChannelExt,
@@ -66,6 +68,7 @@ pub async fn handle_message(msg: Message) {
         Err(err) => {
             BotMetrics::inc_command_error("prefix", name);
             error!(name, ?err, "Failed to process prefix command");
+            report_command_error("Prefix", name, &err);
         }
     }
 
@@ -103,6 +106,27 @@ async fn process_command<'m>(invoke: Invoke<'m>, msg: &'m Message) -> Result<Pro
         _ => None,
     };
 
+    let only_owner = cmd.flags.only_owner();
+
+    // Disabled in this guild?
+    if !only_owner && check_disabled(msg.guild_id, cmd.config_name()).await {
+        let content = "That command has been disabled in this server";
+        msg.error(content).await?;
+
+        return Ok(ProcessResult::Disabled);
+    }
+
+    // Restricted to other channels?
+    if !only_owner {
+        let restriction_fut = check_channel_restriction(msg.guild_id, channel, cmd.config_name());
+
+        if let Some(content) = restriction_fut.await {
+            msg.error(content).await?;
+
+            return Ok(ProcessResult::Restricted);
+        }
+    }
+
     // Ratelimited?
     if let Some(cooldown) = Context::check_ratelimit(msg.author.id, BucketName::All) {
         trace!("Ratelimiting user {} for {cooldown} seconds", msg.author.id);
@@ -111,7 +135,9 @@ async fn process_command<'m>(invoke: Invoke<'m>, msg: &'m Message) -> Result<Pro
     }
 
     if let Some(bucket) = cmd.bucket {
-        if let Some(cooldown) = Context::check_ratelimit(msg.author.id, bucket) {
+        if let Some(cooldown) =
+            Context::check_guild_ratelimit(msg.author.id, msg.guild_id, bucket).await
+        {
             trace!(
                 "Ratelimiting user {} on bucket `{bucket:?}` for {cooldown} seconds",
                 msg.author.id,
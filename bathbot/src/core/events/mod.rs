@@ -1,4 +1,7 @@
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    time::Duration,
+};
 
 use bathbot_cache::model::CachedArchive;
 use bathbot_model::twilight_model::{channel::Channel, guild::Guild};
@@ -6,15 +9,19 @@ use bathbot_util::constants::MISS_ANALYZER_ID;
 use eyre::Result;
 use futures::StreamExt;
 use tokio::sync::mpsc::Receiver;
-use twilight_gateway::{error::ReceiveMessageErrorType, stream::ShardEventStream, Event, Shard};
+use twilight_gateway::{
+    error::ReceiveMessageErrorType, stream::ShardEventStream, ConnectionStatus, Event, Shard,
+};
 use twilight_model::{gateway::CloseCode, user::User};
 
-use self::{interaction::handle_interaction, message::handle_message};
+use self::{interaction::handle_interaction, message::handle_message, reaction::handle_reaction};
 use super::{buckets::BucketName, BotMetrics, Context};
 use crate::util::Authored;
 
+mod error_report;
 mod interaction;
 mod message;
+mod reaction;
 
 #[derive(Debug)]
 enum ProcessResult {
@@ -27,6 +34,8 @@ enum ProcessResult {
     ),
     NoOwner,
     NoAuthority,
+    Disabled,
+    Restricted,
 }
 
 pub enum EventKind {
@@ -118,6 +127,64 @@ impl Display for EventLocation {
     }
 }
 
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum ShardState {
+    Connected,
+    Disconnected,
+    FatallyClosed,
+    Identifying,
+    Resuming,
+}
+
+impl ShardState {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Connected => "Connected",
+            Self::Disconnected => "Disconnected",
+            Self::FatallyClosed => "Fatally closed",
+            Self::Identifying => "Identifying",
+            Self::Resuming => "Resuming",
+        }
+    }
+}
+
+/// Snapshot of a shard's connection, updated whenever it changed while the
+/// shard yields an event.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct ShardStatus {
+    pub state: ShardState,
+    pub latency: Option<Duration>,
+}
+
+impl ShardStatus {
+    fn new(shard: &Shard) -> Self {
+        let state = match shard.status() {
+            ConnectionStatus::Connected => ShardState::Connected,
+            ConnectionStatus::Disconnected { .. } => ShardState::Disconnected,
+            ConnectionStatus::FatallyClosed { .. } => ShardState::FatallyClosed,
+            ConnectionStatus::Identifying => ShardState::Identifying,
+            ConnectionStatus::Resuming => ShardState::Resuming,
+        };
+
+        Self {
+            state,
+            latency: shard.latency().average(),
+        }
+    }
+
+    /// Lock-free so that events of different shards don't contend on it.
+    fn update(shard: &Shard) {
+        let status = Self::new(shard);
+        let shard_id = shard.id().number();
+        let statuses = Context::get().shard_statuses.pin();
+
+        // Only write on change; most events don't alter state nor latency
+        if statuses.get(&shard_id) != Some(&status) {
+            statuses.insert(shard_id, status);
+        }
+    }
+}
+
 pub async fn event_loop(shards: &mut Vec<Shard>, mut reshard_rx: Receiver<()>) {
     let standby = Context::standby();
     let cache = Context::cache();
@@ -131,6 +198,7 @@ pub async fn event_loop(shards: &mut Vec<Shard>, mut reshard_rx: Receiver<()>) {
             let err = tokio::select!(
                  res = stream.next()  => match res {
                     Some((shard, Ok(event))) => {
+                        ShardStatus::update(&shard);
                         standby.process(&event);
                         let change = cache.update(&event).await;
                         BotMetrics::event(&event, change);
@@ -144,7 +212,11 @@ pub async fn event_loop(shards: &mut Vec<Shard>, mut reshard_rx: Receiver<()>) {
 
                         continue 'event_loop;
                     }
-                    Some((_, Err(err))) => Some(err),
+                    Some((shard, Err(err))) => {
+                        ShardStatus::update(&shard);
+
+                        Some(err)
+                    }
                     None => return,
                 },
                 _ = reshard_rx.recv() => None,
@@ -170,6 +242,7 @@ pub async fn event_loop(shards: &mut Vec<Shard>, mut reshard_rx: Receiver<()>) {
 
             if must_reshard {
                 drop(stream);
+                Context::get().shard_statuses.pin().clear();
 
                 if let Err(err) = Context::reshard(shards).await {
                     return error!("{err:?}");
@@ -212,13 +285,8 @@ async fn handle_event(event: Event, shard_id: u64) -> Result<()> {
             let ctx = Context::get();
 
             ctx.guild_shards().pin().insert(e.id, shard_id);
-            ctx.member_requests
-                .pending_guilds
-                .lock()
-                .unwrap()
-                .insert(e.id);
 
-            if let Err(err) = ctx.member_requests.tx.send((e.id, shard_id)) {
+            if let Err(err) = ctx.member_requests.enqueue(e.id, shard_id) {
                 warn!(?err, "Failed to forward member request");
             }
         }
@@ -246,6 +314,8 @@ async fn handle_event(event: Event, shard_id: u64) -> Result<()> {
                 Context::get().active_msgs.remove(id).await;
             }
         }
+        Event::ReactionAdd(e) => handle_reaction(&e.0, true).await?,
+        Event::ReactionRemove(e) => handle_reaction(&e.0, false).await?,
         Event::Ready(_) => info!(shard_id, "Shard is ready"),
         Event::Resumed => info!(shard_id, "Shard is resumed"),
         _ => {}
@@ -0,0 +1,44 @@
+use eyre::{Result, WrapErr};
+use twilight_model::gateway::GatewayReaction;
+
+use crate::core::Context;
+
+/// Add or remove the assigned role of a reaction on a role-assign message.
+pub async fn handle_reaction(reaction: &GatewayReaction, added: bool) -> Result<()> {
+    let Some(guild_id) = reaction.guild_id else {
+        return Ok(());
+    };
+
+    let Some(role_id) = Context::role_assigns().get(reaction.message_id, &reaction.emoji) else {
+        return Ok(());
+    };
+
+    let is_bot = match reaction.member {
+        Some(ref member) => member.user.bot,
+        None => false,
+    };
+
+    if is_bot {
+        return Ok(());
+    }
+
+    let user_id = reaction.user_id;
+
+    if added {
+        Context::http()
+            .add_guild_member_role(guild_id, user_id, role_id)
+            .await
+            .wrap_err("Failed to add role of role assign")?;
+
+        debug!(%guild_id, %user_id, %role_id, "Assigned role through reaction");
+    } else {
+        Context::http()
+            .remove_guild_member_role(guild_id, user_id, role_id)
+            .await
+            .wrap_err("Failed to remove role of role assign")?;
+
+        debug!(%guild_id, %user_id, %role_id, "Removed role through reaction");
+    }
+
+    Ok(())
+}
@@ -1,6 +1,8 @@
 use std::fmt::Result as FmtResult;
 
 use bathbot_util::datetime::NAIVE_DATETIME_FORMAT;
+use eyre::{ContextCompat, Result, WrapErr};
+use once_cell::sync::OnceCell;
 use time::format_description::FormatItem;
 use tracing::{Event, Subscriber};
 use tracing_appender::{
@@ -15,12 +17,20 @@ use tracing_subscriber::{
     },
     layer::SubscriberExt,
     registry::LookupSpan,
+    reload::{self, Handle},
     util::SubscriberInitExt,
-    EnvFilter, Layer as _,
+    EnvFilter, Layer as _, Registry,
 };
 
+/// Filter directives for stdout logs on startup
+const STDOUT_FILTER: &str = "bathbot=debug,sqlx=warn,info";
+
+static STDOUT_FILTER_HANDLE: OnceCell<Handle<EnvFilter, Registry>> = OnceCell::new();
+
 pub fn init() -> WorkerGuard {
-    let stdout_filter: EnvFilter = "bathbot=debug,sqlx=warn,info".parse().unwrap();
+    let stdout_filter: EnvFilter = STDOUT_FILTER.parse().unwrap();
+    let (stdout_filter, stdout_handle) = reload::Layer::new(stdout_filter);
+    let _ = STDOUT_FILTER_HANDLE.set(stdout_handle);
 
     let stdout_layer = Layer::default()
         .event_format(StdoutEventFormat::default())
@@ -67,6 +77,32 @@ pub fn init() -> WorkerGuard {
     guard
 }
 
+/// Replace the stdout log filter with the given directives or, if none are
+/// given, with the default directives.
+///
+/// The filter is not persisted so a restart always starts off with the
+/// default directives. Returns the previous and the new filter.
+pub fn set_stdout_filter(directives: Option<&str>) -> Result<(String, String)> {
+    let handle = STDOUT_FILTER_HANDLE
+        .get()
+        .wrap_err("Logging has not been initialized")?;
+
+    let filter: EnvFilter = directives
+        .unwrap_or(STDOUT_FILTER)
+        .parse()
+        .wrap_err("Invalid filter directives")?;
+
+    let new = filter.to_string();
+
+    let previous = handle
+        .with_current(ToString::to_string)
+        .wrap_err("Failed to get current filter")?;
+
+    handle.reload(filter).wrap_err("Failed to reload filter")?;
+
+    Ok((previous, new))
+}
+
 struct StdoutEventFormat {
     timer: UtcTime<&'static [FormatItem<'static>]>,
 }
@@ -12,6 +12,9 @@ const COMMANDS_PROCESS_TIME: &str = "commands_process_time";
 const COMMAND_ERRORS: &str = "command_errors";
 const CACHE_ENTRIES: &str = "cache_entries";
 const REDIS_CACHE_HITS: &str = "redis_cache_hits";
+const DIFFICULTY_CACHE: &str = "difficulty_cache";
+const DIFFICULTY_CACHE_ENTRIES: &str = "difficulty_cache_entries";
+const MEMBER_REQUEST_QUEUE: &str = "member_request_queue";
 
 pub struct BotMetrics;
 
@@ -34,12 +37,28 @@ impl BotMetrics {
             Unit::Count,
             "Number of times redis contained a cached value"
         );
+        describe_counter!(
+            DIFFICULTY_CACHE,
+            Unit::Count,
+            "Number of difficulty attribute lookups in memory"
+        );
+        describe_gauge!(
+            DIFFICULTY_CACHE_ENTRIES,
+            Unit::Count,
+            "Number of difficulty attributes held in memory"
+        );
+        describe_gauge!(
+            MEMBER_REQUEST_QUEUE,
+            Unit::Count,
+            "Number of guilds waiting for a member request"
+        );
 
         let stats = cache.stats();
 
         gauge!(CACHE_ENTRIES, "kind" => "Guilds").increment(stats.guilds as f64);
         gauge!(CACHE_ENTRIES, "kind" => "Channels").increment(stats.channels as f64);
         gauge!(CACHE_ENTRIES, "kind" => "Users").increment(stats.users as f64);
+        gauge!(CACHE_ENTRIES, "kind" => "Members").increment(stats.members as f64);
         gauge!(CACHE_ENTRIES, "kind" => "Roles").increment(stats.roles as f64);
         gauge!(CACHE_ENTRIES, "kind" => "Unavailable guilds")
             .increment(stats.unavailable_guilds as f64);
@@ -86,11 +105,25 @@ impl BotMetrics {
         counter!(REDIS_CACHE_HITS, "kind" => kind).increment(1);
     }
 
+    pub fn inc_difficulty_cache(hit: bool) {
+        let result = if hit { "hit" } else { "miss" };
+        counter!(DIFFICULTY_CACHE, "result" => result).increment(1);
+    }
+
+    pub fn set_difficulty_cache_entries(len: usize) {
+        gauge!(DIFFICULTY_CACHE_ENTRIES).set(len as f64);
+    }
+
+    pub fn set_member_request_queue(len: usize) {
+        gauge!(MEMBER_REQUEST_QUEUE).set(len as f64);
+    }
+
     pub fn event(event: &Event, change: Option<CacheChange>) {
         if let Some(change) = change {
             gauge!(CACHE_ENTRIES, "kind" => "Guilds").increment(change.guilds as f64);
             gauge!(CACHE_ENTRIES, "kind" => "Channels").increment(change.channels as f64);
             gauge!(CACHE_ENTRIES, "kind" => "Users").increment(change.users as f64);
+            gauge!(CACHE_ENTRIES, "kind" => "Members").increment(change.members as f64);
             gauge!(CACHE_ENTRIES, "kind" => "Roles").increment(change.roles as f64);
             gauge!(CACHE_ENTRIES, "kind" => "Unavailable guilds")
                 .increment(change.unavailable_guilds as f64);
@@ -22,15 +22,17 @@ struct BadgeEntry {
 }
 
 impl BWSEmbed {
+    /// If `what_if` is set, `global_rank` and `badges_curr` are user-specified
+    /// values instead of the user's actual ones.
     pub fn new(
         user: &RedisData<User>,
+        global_rank: u32,
         badges_curr: usize,
         badges_min: usize,
         badges_max: usize,
         rank: Option<u32>,
+        what_if: bool,
     ) -> Self {
-        let global_rank = user.stats().global_rank();
-
         let dist_badges = badges_max - badges_min;
         let step_dist = 2;
 
@@ -167,11 +169,20 @@ impl BWSEmbed {
             }
         };
 
-        let title = format!(
-            "Current BWS for {badges_curr} badge{}: {}",
-            if badges_curr == 1 { "" } else { "s" },
-            WithComma::new(bws(global_rank, badges_curr))
-        );
+        let title = if what_if {
+            format!(
+                "BWS at rank #{} with {badges_curr} badge{}: {}",
+                WithComma::new(global_rank),
+                if badges_curr == 1 { "" } else { "s" },
+                WithComma::new(bws(global_rank, badges_curr))
+            )
+        } else {
+            format!(
+                "Current BWS for {badges_curr} badge{}: {}",
+                if badges_curr == 1 { "" } else { "s" },
+                WithComma::new(bws(global_rank, badges_curr))
+            )
+        };
 
         Self {
             title,
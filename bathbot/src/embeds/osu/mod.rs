@@ -74,23 +74,37 @@ impl Display for ComboFormatter {
 pub struct PpFormatter {
     actual: Option<f32>,
     max: Option<f32>,
+    decimals: usize,
 }
 
 impl PpFormatter {
     pub fn new(actual: Option<f32>, max: Option<f32>) -> Self {
-        Self { actual, max }
+        Self {
+            actual,
+            max,
+            decimals: 2,
+        }
+    }
+
+    /// Amount of decimal places to display, defaults to 2.
+    pub fn decimals(mut self, decimals: u8) -> Self {
+        self.decimals = decimals as usize;
+
+        self
     }
 }
 
 impl Display for PpFormatter {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let prec = self.decimals;
+
         match (self.actual, self.max) {
             (Some(actual), Some(max)) => {
-                write!(f, "**{actual:.2}**/{max:.2}", max = max.max(actual))?
+                write!(f, "**{actual:.prec$}**/{max:.prec$}", max = max.max(actual))?
             }
-            (Some(actual), None) => write!(f, "**{actual:.2}**/-")?,
-            (None, Some(max)) => write!(f, "-/{max:.2}")?,
+            (Some(actual), None) => write!(f, "**{actual:.prec$}**/-")?,
+            (None, Some(max)) => write!(f, "-/{max:.prec$}")?,
             (None, None) => f.write_str("-/-")?,
         }
 
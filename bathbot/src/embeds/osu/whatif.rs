@@ -7,18 +7,28 @@ use bathbot_util::{
     AuthorBuilder, CowUtils,
 };
 
-use crate::{commands::osu::WhatIfData, manager::redis::RedisData};
+use crate::{
+    commands::osu::{WhatIfData, WhatIfProjection, PROJECTION_GRAPH_NAME},
+    embeds::attachment,
+    manager::redis::RedisData,
+};
 
 #[derive(EmbedData)]
 pub struct WhatIfEmbed {
     author: AuthorBuilder,
     description: String,
+    image: String,
     thumbnail: String,
     title: String,
 }
 
 impl WhatIfEmbed {
-    pub fn new(user: &RedisData<User>, pp: f32, data: WhatIfData) -> Self {
+    pub fn new(
+        user: &RedisData<User>,
+        pp: f32,
+        data: WhatIfData,
+        projection: WhatIfProjection,
+    ) -> Self {
         let (stats_pp, global_rank) = {
             let stats = user.stats();
 
@@ -50,7 +60,7 @@ impl WhatIfEmbed {
             )
         };
 
-        let description = match data {
+        let mut description = match data {
             WhatIfData::NonTop100 => {
                 format!(
                     "A {pp_given}pp play wouldn't even be in {username}'s top 100 plays.\n\
@@ -131,9 +141,23 @@ impl WhatIfEmbed {
             }
         };
 
+        let image = match projection {
+            WhatIfProjection::None => String::new(),
+            WhatIfProjection::Graph => attachment(PROJECTION_GRAPH_NAME),
+            WhatIfProjection::OutOfRange => {
+                description.push_str(
+                    "\nThe pp are outside of the rank approximation's range \
+                    so there is no rank projection.",
+                );
+
+                String::new()
+            }
+        };
+
         Self {
             author: user.author_builder(),
             description,
+            image,
             thumbnail: avatar_url.to_owned(),
             title,
         }
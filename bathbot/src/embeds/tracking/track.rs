@@ -2,9 +2,11 @@ use std::fmt::Write;
 
 use bathbot_macros::EmbedData;
 use bathbot_util::fields;
-use rosu_v2::{model::GameMode, prelude::Username};
+use rosu_v2::prelude::Username;
 use twilight_model::channel::message::embed::EmbedField;
 
+use crate::commands::tracking::TrackOptions;
+
 #[derive(EmbedData)]
 pub struct TrackEmbed {
     fields: Vec<EmbedField>,
@@ -13,13 +15,28 @@ pub struct TrackEmbed {
 
 impl TrackEmbed {
     pub fn new(
-        mode: GameMode,
+        options: TrackOptions,
         success: Vec<Username>,
         failure: Vec<Username>,
         failed: Option<Username>,
-        limit: u8,
     ) -> Self {
-        let title = format!("Top score tracking | mode={mode} | limit={limit}");
+        let TrackOptions {
+            mode,
+            limit,
+            snipes,
+            min_rank_gain,
+        } = options;
+
+        let mut title = format!("Top score tracking | mode={mode} | limit={limit}");
+
+        if snipes {
+            title.push_str(" | snipes");
+        }
+
+        if let Some(gain @ 1..) = min_rank_gain {
+            let _ = write!(title, " | min rank gain={gain}");
+        }
+
         let mut fields = Vec::with_capacity(3);
         let mut iter = success.iter();
 
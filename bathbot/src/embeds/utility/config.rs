@@ -110,6 +110,26 @@ impl ConfigEmbed {
             ),
         ];
 
+        let pagination_timeout = config
+            .pagination_timeout
+            .map_or_else(|| "default (60s)".to_owned(), |secs| format!("{secs}s"));
+
+        fields.push(EmbedField {
+            inline: false,
+            name: "Pagination timeout".to_owned(),
+            value: format!("```\n{pagination_timeout}\n```"),
+        });
+
+        let pp_decimals = config
+            .pp_decimals
+            .map_or_else(|| "default (2)".to_owned(), |decimals| decimals.to_string());
+
+        fields.push(EmbedField {
+            inline: false,
+            name: "PP decimals".to_owned(),
+            value: format!("```\n{pp_decimals}\n```"),
+        });
+
         if let Some(skin_url) = skin_url {
             fields.push(EmbedField {
                 inline: false,
@@ -3,11 +3,15 @@ use std::fmt::Write;
 use bathbot_cache::model::CachedArchive;
 use bathbot_macros::EmbedData;
 use bathbot_model::twilight_model::guild::Guild;
-use bathbot_psql::model::configs::{GuildConfig, HideSolutions, ListSize, Retries, ScoreData};
+use bathbot_psql::model::configs::{
+    Cooldown, GuildConfig, HideSolutions, ListSize, Retries, ScoreData,
+};
 use bathbot_util::AuthorBuilder;
+use rosu_v2::prelude::GameMode;
 use twilight_model::channel::message::embed::EmbedField;
 
 use super::config::create_field;
+use crate::core::buckets::BucketName;
 
 #[derive(EmbedData)]
 pub struct ServerConfigEmbed {
@@ -62,7 +66,31 @@ impl ServerConfigEmbed {
         }
 
         let track_limit = config.track_limit.unwrap_or(50);
-        let _ = writeln!(description, "\nDefault track limit: {track_limit}\n```");
+        let pagination_timeout = config.pagination_timeout.unwrap_or(60);
+        let _ = write!(
+            description,
+            "\nDefault track limit: {track_limit}\n\
+            Pagination timeout*: {pagination_timeout}s\nCooldowns: "
+        );
+
+        let mut cooldowns = config.cooldowns.iter().filter_map(|cooldown| {
+            BucketName::try_from(cooldown.bucket)
+                .ok()
+                .map(|bucket| (bucket, cooldown))
+        });
+
+        if let Some((bucket, cooldown)) = cooldowns.next() {
+            write_cooldown(&mut description, bucket, cooldown);
+
+            for (bucket, cooldown) in cooldowns {
+                description.push_str(", ");
+                write_cooldown(&mut description, bucket, cooldown);
+            }
+        } else {
+            description.push_str("Default");
+        }
+
+        description.push_str("\n```");
 
         let fields = vec![
             create_field(
@@ -116,6 +144,26 @@ impl ServerConfigEmbed {
                 config.render_button.unwrap_or(true),
                 &[(false, "hide"), (true, "let user decide")],
             ),
+            create_field(
+                "Button usage",
+                config.allow_foreign_interactions.unwrap_or(false),
+                &[(false, "invoker & authorities"), (true, "anyone")],
+            ),
+            create_field(
+                "Leaderboard links",
+                config.require_verification.unwrap_or(false),
+                &[(false, "any"), (true, "verified only")],
+            ),
+            create_field(
+                "Mode*",
+                config.mode.unwrap_or(GameMode::Osu),
+                &[
+                    (GameMode::Osu, "osu"),
+                    (GameMode::Taiko, "taiko"),
+                    (GameMode::Catch, "catch"),
+                    (GameMode::Mania, "mania"),
+                ],
+            ),
             create_field(
                 "Retries*",
                 config.retries.unwrap_or(Retries::ConsiderMods),
@@ -136,3 +184,13 @@ impl ServerConfigEmbed {
         }
     }
 }
+
+fn write_cooldown(description: &mut String, bucket: BucketName, cooldown: Cooldown) {
+    let _ = write!(description, "{} ({}s", bucket.name(), cooldown.seconds);
+
+    if let Some((timespan, uses)) = cooldown.limit {
+        let _ = write!(description, ", {uses}x per {timespan}s");
+    }
+
+    description.push(')');
+}
@@ -15,14 +15,12 @@ mod util;
 #[cfg(feature = "matchlive")]
 mod matchlive;
 
-use std::time::Duration;
-
 use bathbot_model::Countries;
 use eyre::{Report, Result, WrapErr};
 use tokio::{
     runtime::Builder as RuntimeBuilder,
     signal,
-    sync::mpsc,
+    sync::mpsc::{self, error::TryRecvError},
     time::{self, MissedTickBehavior},
 };
 use twilight_model::gateway::payload::outgoing::RequestGuildMembers;
@@ -30,6 +28,7 @@ use twilight_model::gateway::payload::outgoing::RequestGuildMembers;
 use crate::{
     commands::owner::RESHARD_TX,
     core::{commands::interaction::InteractionCommands, event_loop, logging, BotConfig, Context},
+    util::retry_with_backoff,
 };
 
 fn main() {
@@ -55,9 +54,10 @@ async fn async_main() -> Result<()> {
     BotConfig::init().context("failed to initialize config")?;
     Countries::init();
 
-    let (member_tx, mut member_rx) = mpsc::unbounded_channel();
+    let member_config = &BotConfig::get().member_requests;
+    let (member_tx, mut member_rx) = mpsc::channel(member_config.queue_capacity);
 
-    let res = Context::init(member_tx.clone())
+    let res = Context::init(member_tx)
         .await
         .context("Failed to create context")?;
 
@@ -73,7 +73,11 @@ async fn async_main() -> Result<()> {
 
     #[cfg(feature = "global_slash")]
     {
-        let cmds = Context::set_global_commands(slash_commands).await?;
+        let cmds = retry_with_backoff("Setting global commands", || {
+            Context::set_global_commands(slash_commands.clone())
+        })
+        .await?;
+
         InteractionCommands::set_ids(&cmds);
 
         if let Err(err) = Context::set_guild_commands(Vec::new()).await {
@@ -83,7 +87,11 @@ async fn async_main() -> Result<()> {
 
     #[cfg(not(feature = "global_slash"))]
     {
-        let cmds = Context::set_guild_commands(slash_commands).await?;
+        let cmds = retry_with_backoff("Setting guild commands", || {
+            Context::set_guild_commands(slash_commands.clone())
+        })
+        .await?;
+
         InteractionCommands::set_ids(&cmds);
 
         if let Err(err) = Context::set_global_commands(Vec::new()).await {
@@ -101,6 +109,9 @@ async fn async_main() -> Result<()> {
     {
         // Spawn osu tracking worker
         tokio::spawn(tracking::osu_tracking_loop());
+
+        // Spawn osu snipe tracking worker
+        tokio::spawn(tracking::osu_snipe_tracking_loop());
     }
 
     #[cfg(feature = "matchlive")]
@@ -109,31 +120,46 @@ async fn async_main() -> Result<()> {
         tokio::spawn(Context::match_live_loop());
     }
 
+    // Spawn worker to keep linked users' names up to date
+    tokio::spawn(Context::osu_user().refresh_linked_loop());
+
     // Request members
     tokio::spawn(async move {
         let ctx = Context::get();
 
-        let mut interval = time::interval(Duration::from_millis(600));
+        let mut interval = time::interval(member_config.interval);
         interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
         interval.tick().await;
         let mut counter = 1;
+        let mut batch_len = 0;
         info!("Processing member request queue...");
 
-        while let Some((guild_id, shard_id)) = member_rx.recv().await {
-            let removed_opt = ctx
-                .member_requests
-                .pending_guilds
-                .lock()
-                .unwrap()
-                .remove(&guild_id);
+        loop {
+            let (guild_id, shard_id) = match member_rx.try_recv() {
+                Ok(tuple) => tuple,
+                // Remaining pending guilds did not fit into the queue
+                Err(TryRecvError::Empty) => match ctx.member_requests.overflowed() {
+                    Some(tuple) => tuple,
+                    None => match member_rx.recv().await {
+                        Some(tuple) => tuple,
+                        None => break,
+                    },
+                },
+                Err(TryRecvError::Disconnected) => break,
+            };
 
             // If a guild is in the channel twice, only process the first and ignore the
             // second
-            if !removed_opt {
+            if !ctx.member_requests.take_pending(guild_id) {
                 continue;
             }
 
-            interval.tick().await;
+            if batch_len == member_config.batch_size {
+                interval.tick().await;
+                batch_len = 0;
+            }
+
+            batch_len += 1;
 
             let req = RequestGuildMembers::builder(guild_id).query("", None);
             trace!("Member request #{counter} for guild {guild_id}");
@@ -152,7 +178,7 @@ async fn async_main() -> Result<()> {
                 let wrap = format!("Failed to request members for guild {guild_id}");
                 warn!("{:?}", Report::new(err).wrap_err(wrap));
 
-                if let Err(err) = member_tx.send((guild_id, shard_id)) {
+                if let Err(err) = ctx.member_requests.enqueue(guild_id, shard_id) {
                     warn!("Failed to re-forward member request: {err}");
                 }
             }
@@ -0,0 +1,63 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use rosu_pp::any::DifficultyAttributes;
+use rosu_v2::prelude::GameMode;
+
+use crate::core::BotMetrics;
+
+/// In-memory cache for difficulty attributes so that repeated star
+/// calculations for the same map and mods don't need to hit the database or
+/// recalculate.
+///
+/// Only attributes without custom clock rate are cached.
+#[derive(Default)]
+pub struct DifficultyCache {
+    entries: Mutex<HashMap<DifficultyKey, DifficultyAttributes>>,
+}
+
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+struct DifficultyKey {
+    map_id: u32,
+    mode: GameMode,
+    mods: u32,
+}
+
+impl DifficultyCache {
+    /// Once this many entries are cached, the cache is cleared before inserting
+    /// the next entry.
+    const CAPACITY: usize = 50_000;
+
+    pub fn get(&self, map_id: u32, mode: GameMode, mods: u32) -> Option<DifficultyAttributes> {
+        let key = DifficultyKey { map_id, mode, mods };
+
+        let attrs = self
+            .entries
+            .lock()
+            .unwrap()
+            .get(&key)
+            .map(DifficultyAttributes::to_owned);
+
+        BotMetrics::inc_difficulty_cache(attrs.is_some());
+
+        attrs
+    }
+
+    pub fn insert(&self, map_id: u32, mode: GameMode, mods: u32, attrs: DifficultyAttributes) {
+        let key = DifficultyKey { map_id, mode, mods };
+        let mut entries = self.entries.lock().unwrap();
+
+        if entries.len() >= Self::CAPACITY {
+            entries.clear();
+        }
+
+        entries.insert(key, attrs);
+        BotMetrics::set_difficulty_cache_entries(entries.len());
+    }
+
+    /// Remove all entries of a map e.g. because its checksum changed.
+    pub fn invalidate(&self, map_id: u32) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|key, _| key.map_id != map_id);
+        BotMetrics::set_difficulty_cache_entries(entries.len());
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use bathbot_model::{BgGameScore, HlGameScore, HlVersion};
+use bathbot_model::{BgGameScore, HlGameScore, HlVersion, MapsetTags};
 use bathbot_psql::{
     model::games::{DbMapTagsParams, MapsetTagsEntries},
     Database,
@@ -56,9 +56,9 @@ impl GameManager {
             .wrap_err("Failed to upsert higherlower score")
     }
 
-    pub async fn bggame_leaderboard(self) -> Result<Vec<BgGameScore>> {
+    pub async fn bggame_leaderboard(self, mode: GameMode) -> Result<Vec<BgGameScore>> {
         self.psql
-            .select_bggame_scores()
+            .select_bggame_scores(mode)
             .await
             .wrap_err("failed to get bggame leaderboard")
     }
@@ -78,6 +78,7 @@ impl GameManager {
     pub async fn bggame_increment_scores(
         self,
         scores: &HashMap<Id<UserMarker>, u32, IntHasher>,
+        mode: GameMode,
     ) -> Result<()> {
         let mut user_ids = Vec::with_capacity(scores.len());
         let mut amounts = Vec::with_capacity(scores.len());
@@ -88,11 +89,44 @@ impl GameManager {
         }
 
         self.psql
-            .increment_bggame_scores(&user_ids, &amounts)
+            .increment_bggame_scores(&user_ids, &amounts, mode)
             .await
             .wrap_err("failed to increment score")
     }
 
+    /// Returns the filename of the stored background of the mapset, if any.
+    pub async fn bggame_mapset_filename(self, mapset_id: u32) -> Result<Option<String>> {
+        self.psql
+            .select_map_tag_entry(mapset_id)
+            .await
+            .map(|entry| entry.map(|entry| entry.image_filename))
+            .wrap_err("failed to get mapset entry")
+    }
+
+    /// Removes the mapset and returns its filename and mode if it was stored.
+    pub async fn bggame_remove_mapset(self, mapset_id: u32) -> Result<Option<(String, GameMode)>> {
+        self.psql
+            .delete_map_tag(mapset_id)
+            .await
+            .wrap_err("failed to remove mapset")
+    }
+
+    pub async fn bggame_mapset_tags(self, mapset_id: u32) -> Result<Option<MapsetTags>> {
+        self.psql
+            .select_mapset_tags(mapset_id)
+            .await
+            .wrap_err("failed to get mapset tags")
+    }
+
+    /// Overwrites the tags of the mapset. Returns `false` if the mapset is not
+    /// stored.
+    pub async fn bggame_update_mapset_tags(self, mapset_id: u32, tags: MapsetTags) -> Result<bool> {
+        self.psql
+            .update_mapset_tags(mapset_id, tags)
+            .await
+            .wrap_err("failed to update mapset tags")
+    }
+
     pub async fn bggame_upsert_mapset(
         self,
         mapset_id: u32,
@@ -43,6 +43,40 @@ impl GuildConfigManager {
         res
     }
 
+    /// Execute a function on the cached [`GuildConfig`] of a guild without
+    /// inserting a default config if there is none.
+    pub fn peek_cached<F, O>(self, guild_id: Id<GuildMarker>, f: F) -> Option<O>
+    where
+        F: FnOnce(&GuildConfig) -> O,
+    {
+        self.guild_configs.pin().get(&guild_id).map(f)
+    }
+
+    /// Replace the cached [`GuildConfig`] of a guild with the one stored in
+    /// the database. Returns whether the database contained a config.
+    pub async fn reload(self, guild_id: Id<GuildMarker>) -> Result<bool> {
+        let config_opt = self
+            .psql
+            .select_guild_config(guild_id)
+            .await
+            .wrap_err("failed to get guild config")?;
+
+        let guild_configs = self.guild_configs.pin();
+
+        match config_opt {
+            Some(config) => {
+                guild_configs.insert(guild_id, config);
+
+                Ok(true)
+            }
+            None => {
+                guild_configs.remove(&guild_id);
+
+                Ok(false)
+            }
+        }
+    }
+
     pub async fn first_prefix(self, guild_id: Option<Id<GuildMarker>>) -> Prefix {
         let prefix_opt = match guild_id {
             Some(guild_id) => {
@@ -0,0 +1,126 @@
+use bathbot_psql::{model::map_request::MapRequest, Database};
+use eyre::{Result, WrapErr};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::core::Context;
+
+/// Maximum amount of requests a host's queue can hold
+pub const MAX_MAP_REQUESTS: usize = 25;
+
+/// Maximum amount of requests a single user can have in a host's queue
+pub const MAX_MAP_REQUESTS_PER_USER: usize = 3;
+
+#[derive(Copy, Clone)]
+pub struct MapRequestManager {
+    psql: &'static Database,
+}
+
+impl MapRequestManager {
+    pub fn new() -> Self {
+        Self {
+            psql: Context::psql(),
+        }
+    }
+
+    /// Returns the requests of the host, oldest first
+    pub async fn get(self, host: Id<UserMarker>) -> Result<Vec<MapRequest>> {
+        self.psql
+            .select_map_requests(host)
+            .await
+            .wrap_err("Failed to get map requests")
+    }
+
+    pub async fn add(
+        self,
+        host: Id<UserMarker>,
+        requester: Id<UserMarker>,
+        map_id: u32,
+    ) -> Result<MapRequestAdd> {
+        let inserted = self
+            .psql
+            .insert_map_request(
+                host,
+                requester,
+                map_id,
+                MAX_MAP_REQUESTS,
+                MAX_MAP_REQUESTS_PER_USER,
+            )
+            .await
+            .wrap_err("Failed to insert map request")?;
+
+        let requests = self.get(host).await?;
+
+        if inserted {
+            let position = requests
+                .iter()
+                .position(|request| request.map_id == map_id)
+                .map_or(requests.len(), |idx| idx + 1);
+
+            return Ok(MapRequestAdd::Added { position });
+        }
+
+        // The insert was rejected so figure out why
+        if !self.is_open(host).await? {
+            Ok(MapRequestAdd::Closed)
+        } else if requests.iter().any(|request| request.map_id == map_id) {
+            Ok(MapRequestAdd::Duplicate)
+        } else if requests
+            .iter()
+            .filter(|request| request.requester_id == requester)
+            .count()
+            >= MAX_MAP_REQUESTS_PER_USER
+        {
+            Ok(MapRequestAdd::TooManyRequests)
+        } else {
+            Ok(MapRequestAdd::Full)
+        }
+    }
+
+    /// Remove and return the oldest request of the host
+    pub async fn pop(self, host: Id<UserMarker>) -> Result<Option<MapRequest>> {
+        self.psql
+            .pop_map_request(host)
+            .await
+            .wrap_err("Failed to pop map request")
+    }
+
+    /// Whether the host's queue accepts requests
+    pub async fn is_open(self, host: Id<UserMarker>) -> Result<bool> {
+        self.psql
+            .select_map_request_host(host)
+            .await
+            .wrap_err("Failed to check whether map requests are open")
+    }
+
+    /// Start accepting requests. Returns `false` if the queue was open already.
+    pub async fn open(self, host: Id<UserMarker>) -> Result<bool> {
+        self.psql
+            .insert_map_request_host(host)
+            .await
+            .wrap_err("Failed to open map requests")
+    }
+
+    /// Stop accepting requests. Returns `false` if the queue was not open.
+    pub async fn close(self, host: Id<UserMarker>) -> Result<bool> {
+        self.psql
+            .delete_map_request_host(host)
+            .await
+            .wrap_err("Failed to close map requests")
+    }
+
+    /// Returns the amount of removed requests
+    pub async fn clear(self, host: Id<UserMarker>) -> Result<u64> {
+        self.psql
+            .delete_map_requests(host)
+            .await
+            .wrap_err("Failed to delete map requests")
+    }
+}
+
+pub enum MapRequestAdd {
+    Added { position: usize },
+    Closed,
+    Duplicate,
+    Full,
+    TooManyRequests,
+}
@@ -4,32 +4,38 @@ pub use self::osu_tracking::OsuTrackingManager;
 pub use self::twitch::TwitchManager;
 pub use self::{
     bookmarks::BookmarkManager,
+    difficulty_cache::DifficultyCache,
     games::GameManager,
     github::GithubManager,
     guild_config::GuildConfigManager,
     huismetbenen_country::HuismetbenenCountryManager,
+    map_requests::{MapRequestAdd, MapRequestManager, MAX_MAP_REQUESTS, MAX_MAP_REQUESTS_PER_USER},
     osu_map::{MapError, MapManager, OsuMap, OsuMapSlim},
     osu_scores::ScoresManager,
     osu_user::OsuUserManager,
     pp::{Mods, PpManager},
     rank_pp_approx::ApproxManager,
     replay::{OwnedReplayScore, ReplayManager, ReplayScore, ReplaySettings},
+    role_assign::{RoleAssignManager, RoleAssigns},
     user_config::UserConfigManager,
 };
 
 pub mod redis;
 
 mod bookmarks;
+mod difficulty_cache;
 mod games;
 mod github;
 mod guild_config;
 mod huismetbenen_country;
+mod map_requests;
 mod osu_map;
 mod osu_scores;
 mod osu_user;
 mod pp;
 mod rank_pp_approx;
 mod replay;
+mod role_assign;
 mod user_config;
 
 #[cfg(feature = "osutracking")]
@@ -1,7 +1,9 @@
 use std::{collections::HashMap, fmt::Debug, io::Error as IoError, ops::Deref, path::PathBuf};
 
 use bathbot_client::ClientError;
-use bathbot_psql::model::osu::{ArtistTitle, DbBeatmap, DbBeatmapset, DbMapFilename, MapVersion};
+use bathbot_psql::model::osu::{
+    ArtistTitle, DbBeatmap, DbBeatmapset, DbFarmMap, DbMapFilename, MapVersion,
+};
 use bathbot_util::{ExponentialBackoff, IntHasher};
 use eyre::{ContextCompat, Report, WrapErr};
 use rosu_pp::{any::DifficultyAttributes, model::beatmap::BeatmapAttributesBuilder, Beatmap};
@@ -18,6 +20,9 @@ use crate::{
 
 type Result<T> = eyre::Result<T, MapError>;
 
+/// Maximum amount of maps considered when looking for farm maps
+const FARM_SAMPLE_SIZE: usize = 2000;
+
 #[derive(Copy, Clone)]
 pub struct MapManager;
 
@@ -80,11 +85,19 @@ impl MapManager {
             mode: GameMode,
             mods: Mods,
         ) -> Result<DifficultyAttributes> {
-            if mods.clock_rate.is_none() {
+            if mods.is_cacheable() {
+                let cache = Context::difficulty_cache();
+
+                if let Some(attrs) = cache.get(map_id, mode, mods.bits) {
+                    return Ok(attrs);
+                }
+
                 let attrs_fut =
                     Context::psql().select_map_difficulty_attrs(map_id, mode, mods.bits);
 
                 if let Some(attrs) = attrs_fut.await.wrap_err("Failed to get attributes")? {
+                    cache.insert(map_id, mode, mods.bits, attrs.to_owned());
+
                     return Ok(attrs);
                 }
             }
@@ -243,9 +256,55 @@ impl MapManager {
         Ok(versions)
     }
 
+    /// Stored ranked osu!standard maps within the star range that have
+    /// difficulty attributes for the mods.
+    pub async fn farm_maps(
+        self,
+        mods: u32,
+        min_stars: f32,
+        max_stars: f32,
+    ) -> eyre::Result<Vec<DbFarmMap>> {
+        Context::psql()
+            .select_farm_maps(
+                mods,
+                f64::from(min_stars),
+                f64::from(max_stars),
+                FARM_SAMPLE_SIZE,
+            )
+            .await
+            .wrap_err("Failed to get farm maps")
+    }
+
+    /// When the map was last stored, if it is stored at all.
+    pub async fn last_update(self, map_id: u32) -> eyre::Result<Option<OffsetDateTime>> {
+        Context::psql()
+            .select_osu_map_last_update(map_id)
+            .await
+            .wrap_err("Failed to get last update of map")
+    }
+
+    /// Remove the stored map so that it will be retrieved anew on the next
+    /// request.
+    pub async fn evict(self, map_id: u32) -> eyre::Result<()> {
+        Context::difficulty_cache().invalidate(map_id);
+
+        Context::psql()
+            .delete_osu_map(map_id)
+            .await
+            .wrap_err("Failed to delete map")
+    }
+
     pub async fn store(&self, mapset: &BeatmapsetExtended) {
         if let Err(err) = Context::psql().upsert_beatmapset(mapset).await {
             warn!(?err, "Failed to store mapset");
+
+            return;
+        }
+
+        // Stored attributes of changed maps were just deleted so cached ones
+        // must be dropped as well
+        for map in mapset.maps.iter().flatten() {
+            Context::difficulty_cache().invalidate(map.map_id);
         }
     }
 
@@ -313,6 +372,8 @@ impl MapManager {
             DbMapFilename::ChecksumMismatch => {
                 info!("Checksum mismatch for map {map_id}, re-downloading...");
 
+                Context::difficulty_cache().invalidate(map_id);
+
                 let map_fut = self.download_map_file(map_id);
                 let map_slim_fut = self.retrieve_map(map_id);
 
@@ -524,6 +585,13 @@ impl OsuMapSlim {
     pub fn cover(&self) -> &str {
         self.mapset.cover.as_str()
     }
+
+    pub fn card(&self) -> String {
+        format!(
+            "https://assets.ppy.sh/beatmaps/{}/covers/card.jpg",
+            self.mapset_id()
+        )
+    }
 }
 
 impl Searchable<RegularCriteria<'_>> for OsuMapSlim {
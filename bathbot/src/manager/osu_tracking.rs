@@ -1,4 +1,4 @@
-use std::{collections::HashMap, num::NonZeroU64};
+use std::collections::HashMap;
 
 use bathbot_psql::{
     model::osu::{TrackedOsuUserKey, TrackedOsuUserValue},
@@ -41,12 +41,17 @@ impl<'d> OsuTrackingManager<'d> {
     pub async fn update_channels(
         self,
         key: TrackedOsuUserKey,
-        channels: &HashMap<NonZeroU64, u8, IntHasher>,
+        value: &TrackedOsuUserValue<IntHasher>,
     ) -> Result<()> {
         let TrackedOsuUserKey { user_id, mode } = key;
+        let TrackedOsuUserValue {
+            channels,
+            min_rank_gains,
+            ..
+        } = value;
 
         self.psql
-            .update_tracked_osu_user_channels(user_id, mode, channels)
+            .update_tracked_osu_user_channels(user_id, mode, channels, min_rank_gains)
             .await
             .wrap_err("failed to update channels for user in osu tracking")
     }
@@ -73,4 +78,38 @@ impl<'d> OsuTrackingManager<'d> {
             .await
             .wrap_err("failed to insert tracked user")
     }
+
+    pub async fn get_snipe_tracks(self) -> Result<HashMap<u32, Vec<Id<ChannelMarker>>, IntHasher>> {
+        self.psql
+            .select_tracked_osu_snipes()
+            .await
+            .wrap_err("failed to get tracked osu snipes")
+    }
+
+    /// Returns whether the user was not yet snipe-tracked in the channel.
+    pub async fn add_snipe_track(self, channel: Id<ChannelMarker>, user_id: u32) -> Result<bool> {
+        self.psql
+            .insert_tracked_osu_snipe(channel, user_id)
+            .await
+            .wrap_err("failed to insert tracked snipe")
+    }
+
+    /// Returns whether the user was snipe-tracked in the channel.
+    pub async fn remove_snipe_track(
+        self,
+        channel: Id<ChannelMarker>,
+        user_id: u32,
+    ) -> Result<bool> {
+        self.psql
+            .delete_tracked_osu_snipe(channel, user_id)
+            .await
+            .wrap_err("failed to remove tracked snipe")
+    }
+
+    pub async fn remove_snipe_tracks(self, channel: Id<ChannelMarker>) -> Result<()> {
+        self.psql
+            .delete_tracked_osu_snipes(channel)
+            .await
+            .wrap_err("failed to remove tracked snipes of channel")
+    }
 }
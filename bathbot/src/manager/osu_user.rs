@@ -1,13 +1,27 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 use bathbot_model::{RankingEntries, UserModeStatsColumn, UserStatsColumn};
 use bathbot_psql::Database;
 use bathbot_util::{CowUtils, IntHasher};
 use eyre::{Result, WrapErr};
-use rosu_v2::prelude::{GameMode, UserExtended, Username};
+use rosu_v2::prelude::{GameMode, OsuError, UserExtended, Username};
+use time::OffsetDateTime;
+use tokio::time::{interval, MissedTickBehavior};
 
 use crate::core::Context;
 
+/// Stored data of linked users older than this will be refreshed
+const REFRESH_THRESHOLD: time::Duration = time::Duration::days(7);
+
+/// How many users are refreshed per batch
+const REFRESH_BATCH_SIZE: i64 = 50;
+
+/// Delay between each user request while refreshing
+const REFRESH_REQUEST_DELAY: Duration = Duration::from_secs(5);
+
+/// Delay between batches
+const REFRESH_BATCH_DELAY: Duration = Duration::from_secs(60 * 60);
+
 #[derive(Copy, Clone)]
 pub struct OsuUserManager {
     psql: &'static Database,
@@ -86,12 +100,71 @@ impl OsuUserManager {
             .wrap_err("Failed to get user mode stats")
     }
 
+    /// When the user's stats were last stored, if they are stored at all.
+    pub async fn last_update(self, user_id: u32) -> Result<Option<OffsetDateTime>> {
+        self.psql
+            .select_osu_user_last_update(user_id)
+            .await
+            .wrap_err("Failed to get last update of user")
+    }
+
     pub async fn store(self, user: &UserExtended, mode: GameMode) {
         if let Err(err) = self.psql.upsert_osu_user(user, mode).await {
             warn!(?err, "Failed to upsert osu user");
         }
     }
 
+    /// Periodically re-request linked users whose stored data became stale so
+    /// that their cached usernames stay up to date after namechanges.
+    pub async fn refresh_linked_loop(self) {
+        let mut batch_interval = interval(REFRESH_BATCH_DELAY);
+        batch_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            batch_interval.tick().await;
+
+            match self.refresh_stale_linked().await {
+                Ok(0) => {}
+                Ok(count) => debug!(count, "Refreshed stale linked users"),
+                Err(err) => warn!(?err, "Failed to refresh stale linked users"),
+            }
+        }
+    }
+
+    async fn refresh_stale_linked(self) -> Result<usize> {
+        let threshold = OffsetDateTime::now_utc() - REFRESH_THRESHOLD;
+
+        let user_ids = self
+            .psql
+            .select_stale_linked_osu_ids(threshold, REFRESH_BATCH_SIZE)
+            .await
+            .wrap_err("Failed to get stale linked users")?;
+
+        let mut request_interval = interval(REFRESH_REQUEST_DELAY);
+        let mut count = 0;
+
+        for user_id in user_ids {
+            request_interval.tick().await;
+
+            match Context::osu().user(user_id).await {
+                Ok(user) => {
+                    self.store(&user, user.mode).await;
+                    count += 1;
+                }
+                Err(OsuError::NotFound) => {
+                    // Restricted or deleted; remove the stats so the user
+                    // won't be picked up again on the next batch
+                    if let Err(err) = self.remove_stats_and_scores(user_id).await {
+                        warn!(user_id, ?err, "Failed to remove data of missing user");
+                    }
+                }
+                Err(err) => warn!(user_id, ?err, "Failed to refresh linked user"),
+            }
+        }
+
+        Ok(count)
+    }
+
     pub async fn remove_stats_and_scores(self, user_id: u32) -> Result<()> {
         self.psql
             .delete_osu_user_stats_and_scores(user_id)
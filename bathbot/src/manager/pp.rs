@@ -1,5 +1,6 @@
 use std::{
     borrow::Cow,
+    collections::HashMap,
     hash::{Hash, Hasher},
 };
 
@@ -11,7 +12,13 @@ use rosu_pp::{
     Beatmap, Difficulty,
 };
 use rosu_v2::{
-    model::mods::GameMods,
+    model::mods::{
+        generated_mods::{
+            DifficultyAdjustCatch, DifficultyAdjustMania, DifficultyAdjustOsu,
+            DifficultyAdjustTaiko,
+        },
+        GameMod, GameMods,
+    },
     prelude::{GameMode, Grade, Score},
 };
 
@@ -111,16 +118,38 @@ impl<'m> PpManager<'m> {
         inner(self, score.into())
     }
 
-    async fn lookup_attrs(&self) -> Result<Option<DifficultyAttributes>> {
-        if self.mods.clock_rate.is_some() {
+    async fn lookup_attrs(&self, mods: Mods) -> Result<Option<DifficultyAttributes>> {
+        if !mods.is_cacheable() {
             return Ok(None);
         }
 
         let mode = GameMode::from(self.map.mode as u8);
+        let cache = Context::difficulty_cache();
 
-        Context::psql()
-            .select_map_difficulty_attrs(self.map_id, mode, self.mods.bits)
-            .await
+        if let Some(attrs) = cache.get(self.map_id, mode, mods.bits) {
+            return Ok(Some(attrs));
+        }
+
+        let attrs_opt = Context::psql()
+            .select_map_difficulty_attrs(self.map_id, mode, mods.bits)
+            .await?;
+
+        if let Some(ref attrs) = attrs_opt {
+            cache.insert(self.map_id, mode, mods.bits, attrs.to_owned());
+        }
+
+        Ok(attrs_opt)
+    }
+
+    async fn store_attrs(&self, mods: Mods, attrs: &DifficultyAttributes) {
+        let mode = GameMode::from(self.map.mode as u8);
+        Context::difficulty_cache().insert(self.map_id, mode, mods.bits, attrs.to_owned());
+
+        let upsert_fut = Context::psql().upsert_map_difficulty(self.map_id, mods.bits, attrs);
+
+        if let Err(err) = upsert_fut.await {
+            warn!(?err, "Failed to upsert difficulty attrs");
+        }
     }
 
     /// Calculate difficulty attributes
@@ -128,7 +157,7 @@ impl<'m> PpManager<'m> {
         if !self.partial {
             match self.attrs {
                 Some(ref attrs) => return attrs,
-                None => match self.lookup_attrs().await {
+                None => match self.lookup_attrs(self.mods).await {
                     Ok(Some(attrs)) => return self.attrs.insert(attrs),
                     Ok(None) => {}
                     Err(err) => warn!(?err, "Failed to get difficulty attributes"),
@@ -136,11 +165,7 @@ impl<'m> PpManager<'m> {
             }
         }
 
-        let mut calc = Difficulty::new().mods(self.mods.bits);
-
-        if let Some(clock_rate) = self.mods.clock_rate {
-            calc = calc.clock_rate(f64::from(clock_rate));
-        }
+        let mut calc = self.mods.difficulty();
 
         if let Some(state) = self.state.as_ref().filter(|_| self.partial) {
             calc = calc.passed_objects(state.total_hits(self.map.mode));
@@ -148,16 +173,50 @@ impl<'m> PpManager<'m> {
 
         let attrs = calc.calculate(&self.map);
 
-        if !self.partial && self.mods.clock_rate.is_none() {
-            let upsert_fut =
-                Context::psql().upsert_map_difficulty(self.map_id, self.mods.bits, &attrs);
+        if !self.partial && self.mods.is_cacheable() {
+            self.store_attrs(self.mods, &attrs).await;
+        }
 
-            if let Err(err) = upsert_fut.await {
-                warn!(?err, "Failed to upsert difficulty attrs");
+        self.attrs.insert(attrs)
+    }
+
+    /// Calculate difficulty attributes for multiple mod combinations at once,
+    /// reusing the map instead of re-parsing it for each combination.
+    ///
+    /// The manager's own mods and score state are ignored.
+    pub async fn difficulties(
+        &self,
+        mods: impl IntoIterator<Item = Mods>,
+    ) -> HashMap<Mods, DifficultyAttributes> {
+        let mut attrs_map = HashMap::new();
+        let mut missing = Vec::new();
+
+        for mods in mods {
+            if attrs_map.contains_key(&mods) || missing.contains(&mods) {
+                continue;
+            }
+
+            match self.lookup_attrs(mods).await {
+                Ok(Some(attrs)) => {
+                    attrs_map.insert(mods, attrs);
+                }
+                Ok(None) => missing.push(mods),
+                Err(err) => {
+                    warn!(?err, "Failed to get difficulty attributes");
+                    missing.push(mods);
+                }
             }
         }
 
-        self.attrs.insert(attrs)
+        for (mods, attrs) in calculate_difficulties(&self.map, missing) {
+            if mods.is_cacheable() {
+                self.store_attrs(mods, &attrs).await;
+            }
+
+            attrs_map.insert(mods, attrs);
+        }
+
+        attrs_map
     }
 
     /// Calculate performance attributes
@@ -185,6 +244,15 @@ impl<'m> PpManager<'m> {
     }
 }
 
+/// Calculate difficulty attributes on the same map for each of the given mods.
+fn calculate_difficulties(
+    map: &Beatmap,
+    mods: impl IntoIterator<Item = Mods>,
+) -> impl Iterator<Item = (Mods, DifficultyAttributes)> + '_ {
+    mods.into_iter()
+        .map(|mods| (mods, mods.difficulty().calculate(map)))
+}
+
 pub struct ScoreData {
     state: ScoreState,
     mods: Mods,
@@ -293,11 +361,12 @@ impl<'s> From<&'s ScoreEmbedDataRaw> for ScoreData {
     }
 }
 
-/// Mods with an optional custom clock rate.
+/// Mods with an optional custom clock rate and difficulty adjustments.
 #[derive(Copy, Clone, Default, PartialEq)]
 pub struct Mods {
     pub bits: u32,
     pub clock_rate: Option<f32>,
+    pub adjust: DifficultyAdjust,
 }
 
 impl Mods {
@@ -306,8 +375,26 @@ impl Mods {
         Self {
             bits,
             clock_rate: None,
+            adjust: DifficultyAdjust::default(),
         }
     }
+
+    /// Whether difficulty attributes for these mods can be looked up from and
+    /// stored in the cache which is only keyed by mod bits.
+    pub fn is_cacheable(&self) -> bool {
+        self.clock_rate.is_none() && self.adjust.is_empty()
+    }
+
+    /// Difficulty calculator with these mods applied.
+    fn difficulty(&self) -> Difficulty {
+        let mut calc = Difficulty::new().mods(self.bits);
+
+        if let Some(clock_rate) = self.clock_rate {
+            calc = calc.clock_rate(f64::from(clock_rate));
+        }
+
+        self.adjust.apply(calc)
+    }
 }
 
 impl From<&GameMods> for Mods {
@@ -315,6 +402,7 @@ impl From<&GameMods> for Mods {
         Self {
             bits: mods.bits(),
             clock_rate: mods.clock_rate(),
+            adjust: DifficultyAdjust::from(mods),
         }
     }
 }
@@ -326,5 +414,235 @@ impl Hash for Mods {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.bits.hash(state);
         self.clock_rate.map(f32::to_bits).hash(state);
+        self.adjust.hash(state);
+    }
+}
+
+/// Beatmap attribute overrides of lazer's difficulty adjust mod.
+///
+/// The mod has no legacy bits so it would be ignored entirely otherwise.
+/// Stable scores can't contain it so their attributes remain unchanged.
+#[derive(Copy, Clone, Default, PartialEq)]
+pub struct DifficultyAdjust {
+    pub ar: Option<f32>,
+    pub cs: Option<f32>,
+    pub hp: Option<f32>,
+    pub od: Option<f32>,
+}
+
+impl DifficultyAdjust {
+    pub fn is_empty(&self) -> bool {
+        self.ar.is_none() && self.cs.is_none() && self.hp.is_none() && self.od.is_none()
+    }
+
+    fn apply(self, mut calc: Difficulty) -> Difficulty {
+        if let Some(ar) = self.ar {
+            calc = calc.ar(ar, false);
+        }
+
+        if let Some(cs) = self.cs {
+            calc = calc.cs(cs, false);
+        }
+
+        if let Some(hp) = self.hp {
+            calc = calc.hp(hp, false);
+        }
+
+        if let Some(od) = self.od {
+            calc = calc.od(od, false);
+        }
+
+        calc
+    }
+}
+
+impl From<&GameMods> for DifficultyAdjust {
+    fn from(mods: &GameMods) -> Self {
+        let mut adjust = Self::default();
+
+        for gamemod in mods.iter() {
+            match gamemod {
+                GameMod::DifficultyAdjustOsu(m) => {
+                    let DifficultyAdjustOsu {
+                        circle_size,
+                        approach_rate,
+                        drain_rate,
+                        overall_difficulty,
+                        ..
+                    } = m;
+
+                    adjust.cs = *circle_size;
+                    adjust.ar = *approach_rate;
+                    adjust.hp = *drain_rate;
+                    adjust.od = *overall_difficulty;
+                }
+                GameMod::DifficultyAdjustTaiko(m) => {
+                    let DifficultyAdjustTaiko {
+                        drain_rate,
+                        overall_difficulty,
+                        ..
+                    } = m;
+
+                    adjust.hp = *drain_rate;
+                    adjust.od = *overall_difficulty;
+                }
+                GameMod::DifficultyAdjustCatch(m) => {
+                    let DifficultyAdjustCatch {
+                        circle_size,
+                        approach_rate,
+                        drain_rate,
+                        overall_difficulty,
+                        ..
+                    } = m;
+
+                    adjust.cs = *circle_size;
+                    adjust.ar = *approach_rate;
+                    adjust.hp = *drain_rate;
+                    adjust.od = *overall_difficulty;
+                }
+                GameMod::DifficultyAdjustMania(m) => {
+                    let DifficultyAdjustMania {
+                        drain_rate,
+                        overall_difficulty,
+                        ..
+                    } = m;
+
+                    adjust.hp = *drain_rate;
+                    adjust.od = *overall_difficulty;
+                }
+                _ => {}
+            }
+        }
+
+        adjust
+    }
+}
+
+impl Hash for DifficultyAdjust {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ar.map(f32::to_bits).hash(state);
+        self.cs.map(f32::to_bits).hash(state);
+        self.hp.map(f32::to_bits).hash(state);
+        self.od.map(f32::to_bits).hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "osu file format v14
+
+[General]
+Mode: 0
+
+[Difficulty]
+HPDrainRate:5
+CircleSize:4
+OverallDifficulty:8
+ApproachRate:9
+SliderMultiplier:1.4
+SliderTickRate:1
+
+[TimingPoints]
+0,400,4,2,0,100,1,0
+
+[HitObjects]
+64,64,1000,1,0,0:0:0:0:
+448,64,1200,1,0,0:0:0:0:
+448,320,1400,1,0,0:0:0:0:
+64,320,1600,1,0,0:0:0:0:
+256,192,1800,2,0,L|256:32,1,140
+128,192,2400,1,0,0:0:0:0:
+384,192,2600,1,0,0:0:0:0:
+256,64,2800,1,0,0:0:0:0:
+256,320,3000,1,0,0:0:0:0:
+";
+
+    #[test]
+    fn batched_difficulties_match_single() {
+        let map = Beatmap::from_bytes(MAP.as_bytes()).unwrap();
+
+        let mods = [
+            Mods::new(0),
+            Mods::new(8),
+            Mods::new(16),
+            Mods::new(64),
+            Mods::new(8 + 16 + 64),
+            Mods {
+                bits: 0,
+                clock_rate: Some(1.2),
+                adjust: DifficultyAdjust::default(),
+            },
+        ];
+
+        let batched: Vec<_> = calculate_difficulties(&map, mods).collect();
+
+        assert_eq!(batched.len(), mods.len());
+
+        for (mods, attrs) in batched {
+            let single = mods.difficulty().calculate(&map);
+
+            assert_eq!(attrs.stars(), single.stars());
+            assert_eq!(attrs.max_combo(), single.max_combo());
+        }
+    }
+
+    fn lazer_mods() -> GameMods {
+        let mut mods = GameMods::new();
+
+        mods.insert(GameMod::DifficultyAdjustOsu(DifficultyAdjustOsu {
+            approach_rate: Some(10.5),
+            overall_difficulty: Some(10.0),
+            ..Default::default()
+        }));
+
+        mods
+    }
+
+    #[test]
+    fn lazer_difficulty_adjust_is_kept() {
+        let mods = Mods::from(&lazer_mods());
+
+        assert_eq!(mods.bits, 0);
+        assert_eq!(mods.adjust.ar, Some(10.5));
+        assert_eq!(mods.adjust.od, Some(10.0));
+        assert!(mods.adjust.cs.is_none());
+        assert!(!mods.is_cacheable());
+    }
+
+    #[test]
+    fn lazer_difficulty_adjust_affects_attributes() {
+        let map = Beatmap::from_bytes(MAP.as_bytes()).unwrap();
+
+        let lazer = Mods::from(&lazer_mods());
+        let classic = Mods::new(lazer.bits);
+
+        let mut attrs = calculate_difficulties(&map, [lazer, classic]).map(|(_, attrs)| attrs);
+
+        let (Some(DifficultyAttributes::Osu(lazer)), Some(DifficultyAttributes::Osu(classic))) =
+            (attrs.next(), attrs.next())
+        else {
+            panic!("expected osu!standard attributes");
+        };
+
+        assert_eq!(lazer.ar, 10.5);
+        assert_eq!(lazer.od, 10.0);
+        assert_eq!(classic.ar, 9.0);
+        assert_eq!(classic.od, 8.0);
+    }
+
+    #[test]
+    fn stable_mods_fall_back_to_classic() {
+        let mut game_mods = GameMods::new();
+        game_mods.insert(GameMod::HiddenOsu(Default::default()));
+        game_mods.insert(GameMod::HardRockOsu(Default::default()));
+
+        let mods = Mods::from(&game_mods);
+
+        assert_eq!(mods.bits, 8 + 16);
+        assert!(mods.adjust.is_empty());
+        assert!(mods.is_cacheable());
+        assert!(mods == Mods::new(8 + 16));
     }
 }
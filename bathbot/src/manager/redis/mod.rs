@@ -3,7 +3,7 @@ use std::{borrow::Cow, fmt::Write};
 use bathbot_cache::{Cache, CacheSerializer};
 use bathbot_model::{
     rosu_v2::ranking::Rankings, CountryRegions, OsekaiBadge, OsekaiMedal, OsekaiRanking,
-    OsuStatsBestScores, OsuStatsBestTimeframe, SnipeCountries,
+    OsuStatsBestScores, OsuStatsBestTimeframe, SnipeCountries, SnipeMapScore,
 };
 use bathbot_psql::model::osu::MapVersion;
 use bathbot_util::{matcher, osu::MapIdType};
@@ -233,6 +233,41 @@ impl RedisManager {
         Ok(RedisData::new(countries))
     }
 
+    pub async fn snipe_map_leaderboard(
+        self,
+        country_code: &str,
+        map_id: u32,
+    ) -> RedisResult<Vec<SnipeMapScore>> {
+        const EXPIRE: u64 = 600;
+        let key = format!("snipe_map_{country_code}_{map_id}");
+
+        let mut conn = match Context::cache().fetch(&key).await {
+            Ok(Ok(scores)) => {
+                BotMetrics::inc_redis_hit("Snipe map leaderboard");
+
+                return Ok(RedisData::Archive(scores));
+            }
+            Ok(Err(conn)) => Some(conn),
+            Err(err) => {
+                warn!("{err:?}");
+
+                None
+            }
+        };
+
+        let scores = Context::client()
+            .get_snipe_map_leaderboard(country_code, map_id)
+            .await?;
+
+        if let Some(ref mut conn) = conn {
+            if let Err(err) = Cache::store::<_, _, 1024>(conn, &key, &scores, EXPIRE).await {
+                warn!(?err, "Failed to store snipe map leaderboard");
+            }
+        }
+
+        Ok(RedisData::new(scores))
+    }
+
     pub async fn country_regions(self) -> RedisResult<CountryRegions> {
         const EXPIRE: u64 = 43_200; // 12 hours
         let key = "country_regions";
@@ -5,6 +5,7 @@ use bathbot_model::rosu_v2::user::{ArchivedUserHighestRank, StatsWrapper, User};
 use bathbot_util::{
     constants::OSU_BASE, numbers::WithComma, osu::flag_url, AuthorBuilder, CowUtils,
 };
+use eyre::Result;
 use rosu_v2::{
     prelude::{GameMode, OsuError, User as RosuUser},
     request::UserId,
@@ -209,6 +210,33 @@ impl RedisManager {
         Ok(RedisData::Original(user))
     }
 
+    /// Seconds since the osu! user was cached for the given mode, if it is
+    /// cached.
+    pub async fn osu_user_age(self, user_id: u32, mode: GameMode) -> Result<Option<u64>> {
+        let key = Self::osu_user_key(user_id, mode);
+        let ttl = Context::cache().ttl(&key).await?;
+
+        Ok(ttl.map(|ttl| EXPIRE.saturating_sub(ttl)))
+    }
+
+    /// Remove the cached osu! user for all modes and return how many entries
+    /// were removed.
+    pub async fn evict_osu_user(self, user_id: u32) -> Result<usize> {
+        let mut removed = 0;
+
+        for mode in [
+            GameMode::Osu,
+            GameMode::Taiko,
+            GameMode::Catch,
+            GameMode::Mania,
+        ] {
+            let key = Self::osu_user_key(user_id, mode);
+            removed += Context::cache().delete(&key).await? as usize;
+        }
+
+        Ok(removed)
+    }
+
     pub async fn osu_user(self, args: UserArgs) -> RedisResult<User, User, OsuError> {
         match args {
             UserArgs::Args(args) => self.osu_user_from_args(args).await,
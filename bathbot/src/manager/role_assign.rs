@@ -0,0 +1,327 @@
+use std::time::Duration;
+
+use bathbot_psql::{
+    model::role_assign::{RoleAssign, RoleAssignEmoji},
+    Database,
+};
+use bathbot_util::{constants::UNKNOWN_MEMBER, IntHasher};
+use eyre::{Report, Result, WrapErr};
+use papaya::{HashMap as PapayaMap, Operation};
+use tokio::time::{interval, MissedTickBehavior};
+use twilight_http::{
+    api_error::{ApiError, GeneralApiError},
+    error::ErrorType,
+    request::channel::reaction::RequestReactionType,
+    Error as TwilightError,
+};
+use twilight_model::{
+    channel::message::ReactionType,
+    id::{
+        marker::{GuildMarker, MessageMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+use crate::core::Context;
+
+/// Delay between each role edit while reconciling
+const RECONCILE_EDIT_DELAY: Duration = Duration::from_millis(500);
+
+/// Maximum amount of users per reactions request
+const REACTIONS_LIMIT: u16 = 100;
+
+pub type RoleAssigns = PapayaMap<Id<MessageMarker>, Vec<RoleAssign>, IntHasher>;
+
+#[derive(Copy, Clone)]
+pub struct RoleAssignManager {
+    psql: &'static Database,
+    role_assigns: &'static RoleAssigns,
+}
+
+impl RoleAssignManager {
+    pub fn new(psql: &'static Database, role_assigns: &'static RoleAssigns) -> Self {
+        Self { psql, role_assigns }
+    }
+
+    /// The role that should be assigned when reacting with the emoji on the
+    /// message.
+    pub fn get(
+        self,
+        message_id: Id<MessageMarker>,
+        emoji: &ReactionType,
+    ) -> Option<Id<RoleMarker>> {
+        self.role_assigns
+            .pin()
+            .get(&message_id)?
+            .iter()
+            .find(|role_assign| role_assign.emoji.matches(emoji))
+            .map(|role_assign| role_assign.role_id)
+    }
+
+    /// All role assigns of a guild.
+    pub fn list(self, guild_id: Id<GuildMarker>) -> Vec<RoleAssign> {
+        self.role_assigns
+            .pin()
+            .values()
+            .flatten()
+            .filter(|role_assign| role_assign.guild_id == guild_id)
+            .cloned()
+            .collect()
+    }
+
+    /// Store the role assign, replacing the role of a previous entry for the
+    /// same message and emoji.
+    pub async fn add(self, mut role_assign: RoleAssign) -> Result<()> {
+        // Keep the stored variant of the emoji so the entry is replaced
+        if let Some(emoji) = self.stored_emoji(
+            role_assign.guild_id,
+            role_assign.message_id,
+            &role_assign.emoji,
+        ) {
+            role_assign.emoji = emoji;
+        }
+
+        self.psql
+            .upsert_role_assign(&role_assign)
+            .await
+            .wrap_err("Failed to upsert role assign")?;
+
+        let message_id = role_assign.message_id;
+
+        self.role_assigns
+            .pin()
+            .compute(message_id, |entry| match entry {
+                Some((_, old)) => {
+                    let mut new = Vec::with_capacity(old.len() + 1);
+
+                    new.extend(
+                        old.iter()
+                            .filter(|old| old.emoji != role_assign.emoji)
+                            .cloned(),
+                    );
+
+                    new.push(role_assign.clone());
+
+                    Operation::Insert::<_, ()>(new)
+                }
+                None => Operation::Insert(vec![role_assign.clone()]),
+            });
+
+        Ok(())
+    }
+
+    /// Returns whether an entry of the guild was removed.
+    pub async fn remove(
+        self,
+        guild_id: Id<GuildMarker>,
+        message_id: Id<MessageMarker>,
+        emoji: &RoleAssignEmoji,
+    ) -> Result<bool> {
+        let stored = self.stored_emoji(guild_id, message_id, emoji);
+        let emoji = stored.as_ref().unwrap_or(emoji);
+
+        let removed = self
+            .psql
+            .delete_role_assign(guild_id, message_id, emoji)
+            .await
+            .wrap_err("Failed to delete role assign")?;
+
+        self.role_assigns
+            .pin()
+            .compute(message_id, |entry| match entry {
+                Some((_, old)) => {
+                    let new: Vec<_> = old
+                        .iter()
+                        .filter(|old| old.guild_id != guild_id || old.emoji != *emoji)
+                        .cloned()
+                        .collect();
+
+                    if new.is_empty() {
+                        Operation::Remove
+                    } else {
+                        Operation::Insert(new)
+                    }
+                }
+                None => Operation::Abort(()),
+            });
+
+        Ok(removed)
+    }
+
+    /// The emoji as it was stored for the message which may differ from the
+    /// given emoji in its variation selectors.
+    fn stored_emoji(
+        self,
+        guild_id: Id<GuildMarker>,
+        message_id: Id<MessageMarker>,
+        emoji: &RoleAssignEmoji,
+    ) -> Option<RoleAssignEmoji> {
+        self.role_assigns
+            .pin()
+            .get(&message_id)?
+            .iter()
+            .find(|role_assign| {
+                role_assign.guild_id == guild_id && role_assign.emoji.is_same(emoji)
+            })
+            .map(|role_assign| role_assign.emoji.clone())
+    }
+
+    /// Assign roles to all members that reacted on a role-assign message but
+    /// don't have the role, e.g. because they reacted while the bot was
+    /// offline.
+    ///
+    /// Returns the amount of assigned roles.
+    pub async fn reconcile(self) -> Result<usize> {
+        let role_assigns: Vec<_> = self
+            .role_assigns
+            .pin()
+            .values()
+            .flatten()
+            .cloned()
+            .collect();
+
+        let cache = Context::cache();
+        let mut edit_interval = interval(RECONCILE_EDIT_DELAY);
+        edit_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let mut reconciled = 0;
+
+        for role_assign in role_assigns {
+            let RoleAssign {
+                guild_id,
+                channel_id,
+                message_id,
+                ref emoji,
+                role_id,
+            } = role_assign;
+
+            let user_ids = match Self::reacted_users(&role_assign).await {
+                Ok(user_ids) => user_ids,
+                Err(err) => {
+                    warn!(%channel_id, %message_id, %emoji, ?err, "Failed to get reactions");
+
+                    continue;
+                }
+            };
+
+            for user_id in user_ids {
+                let cached = match cache.member(guild_id, user_id).await {
+                    Ok(member) => member.map(|member| member.roles().contains(&role_id)),
+                    Err(err) => {
+                        warn!(?err, "Failed to get member from cache");
+
+                        None
+                    }
+                };
+
+                // Members are likely not cached yet after downtime so they're
+                // requested instead
+                let has_role = match cached {
+                    Some(has_role) => has_role,
+                    None => match Self::fetch_has_role(guild_id, user_id, role_id).await {
+                        Ok(Some(has_role)) => has_role,
+                        // Reactions of users that are no longer members can be skipped
+                        Ok(None) => continue,
+                        Err(err) => {
+                            warn!(%guild_id, %user_id, ?err, "Failed to fetch member");
+
+                            continue;
+                        }
+                    },
+                };
+
+                if has_role {
+                    continue;
+                }
+
+                edit_interval.tick().await;
+
+                let add_fut = Context::http().add_guild_member_role(guild_id, user_id, role_id);
+
+                match add_fut.await {
+                    Ok(_) => reconciled += 1,
+                    Err(err) => {
+                        warn!(%guild_id, %user_id, %role_id, ?err, "Failed to reconcile role")
+                    }
+                }
+            }
+        }
+
+        Ok(reconciled)
+    }
+
+    /// Whether the member has the role.
+    ///
+    /// Returns `None` if the user is not a member of the guild.
+    async fn fetch_has_role(
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        role_id: Id<RoleMarker>,
+    ) -> Result<Option<bool>> {
+        let member = match Context::http().guild_member(guild_id, user_id).await {
+            Ok(response) => response
+                .model()
+                .await
+                .wrap_err("Failed to deserialize member")?,
+            Err(err) if is_unknown_member(&err) => return Ok(None),
+            Err(err) => return Err(Report::new(err).wrap_err("Failed to request member")),
+        };
+
+        Ok(Some(member.roles.contains(&role_id)))
+    }
+
+    /// All non-bot users that reacted with the role assign's emoji.
+    async fn reacted_users(role_assign: &RoleAssign) -> Result<Vec<Id<UserMarker>>> {
+        let reaction = match role_assign.emoji {
+            RoleAssignEmoji::Custom(id) => RequestReactionType::Custom { id, name: None },
+            RoleAssignEmoji::Unicode(ref name) => RequestReactionType::Unicode { name },
+        };
+
+        let mut user_ids = Vec::new();
+        let mut after = None;
+
+        loop {
+            let mut req = Context::http()
+                .reactions(role_assign.channel_id, role_assign.message_id, &reaction)
+                .limit(REACTIONS_LIMIT)
+                .unwrap();
+
+            if let Some(user_id) = after {
+                req = req.after(user_id);
+            }
+
+            let users = req
+                .await
+                .wrap_err("Failed to request reactions")?
+                .models()
+                .await
+                .wrap_err("Failed to deserialize reactions")?;
+
+            let done = users.len() < REACTIONS_LIMIT as usize;
+            after = users.last().map(|user| user.id);
+
+            user_ids.extend(
+                users
+                    .into_iter()
+                    .filter(|user| !user.bot)
+                    .map(|user| user.id),
+            );
+
+            if done {
+                return Ok(user_ids);
+            }
+        }
+    }
+}
+
+fn is_unknown_member(err: &TwilightError) -> bool {
+    matches!(
+        err.kind(),
+        ErrorType::Response {
+            error: ApiError::General(GeneralApiError {
+                code: UNKNOWN_MEMBER,
+                ..
+            }),
+            ..
+        }
+    )
+}
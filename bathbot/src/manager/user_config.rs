@@ -5,8 +5,11 @@ use bathbot_psql::{
 };
 use bathbot_util::CowUtils;
 use eyre::{Result, WrapErr};
-use rosu_v2::prelude::{GameMode, Username};
-use twilight_model::id::{marker::UserMarker, Id};
+use rosu_v2::prelude::{GameMode, GameModsIntermode, Username};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
 
 use crate::core::Context;
 
@@ -48,6 +51,14 @@ impl UserConfigManager {
             .wrap_err("Failed to get user mode from DB")
     }
 
+    /// Seconds until paginations of the user time out, if configured.
+    pub async fn pagination_timeout(self, user_id: Id<UserMarker>) -> Result<Option<u16>> {
+        self.psql
+            .select_pagination_timeout(user_id)
+            .await
+            .wrap_err("Failed to get pagination timeout from DB")
+    }
+
     pub async fn osu_id(self, user_id: Id<UserMarker>) -> Result<Option<u32>> {
         self.psql
             .select_osu_id_by_discord_id(user_id)
@@ -117,6 +128,78 @@ impl UserConfigManager {
             .wrap_err("failed to store user config")
     }
 
+    /// Link the user to the given osu! user id, keeping the rest of its
+    /// config as is, and mark the link as verified.
+    pub async fn link_osu(self, user_id: Id<UserMarker>, osu_id: u32) -> Result<()> {
+        let mut config = self.with_osu_id(user_id).await?;
+        config.osu = Some(osu_id);
+
+        self.store(user_id, &config).await?;
+        self.verify_osu(user_id, osu_id).await
+    }
+
+    /// Mark the user's link to the osu! user id as verified.
+    pub async fn verify_osu(self, user_id: Id<UserMarker>, osu_id: u32) -> Result<()> {
+        self.psql
+            .upsert_osu_link_verification(user_id, osu_id)
+            .await
+            .wrap_err("failed to store link verification")
+    }
+
+    /// Keep only those members whose link counts for the guild, i.e. all of
+    /// them unless the guild requires verified links.
+    pub async fn guild_members(
+        self,
+        guild_id: Id<GuildMarker>,
+        members: Vec<i64>,
+    ) -> Result<Vec<i64>> {
+        let require_verification = Context::guild_config()
+            .peek(guild_id, |config| config.require_verification)
+            .await
+            .unwrap_or(false);
+
+        if !require_verification {
+            return Ok(members);
+        }
+
+        self.psql
+            .select_verified_discord_ids(&members)
+            .await
+            .wrap_err("failed to get verified discord ids")
+    }
+
+    /// Remove the osu! link of the user and return the previously linked id.
+    ///
+    /// If `reset_preferences` is set, all other osu! related settings including
+    /// remembered simulate mods are reset too. The twitch link, pagination
+    /// timeout, and pp decimals are kept either way.
+    pub async fn unlink_osu(
+        self,
+        user_id: Id<UserMarker>,
+        reset_preferences: bool,
+    ) -> Result<Option<u32>> {
+        let mut config = self.with_osu_id(user_id).await?;
+        let prev = config.osu.take();
+
+        if reset_preferences {
+            config = UserConfig {
+                twitch_id: config.twitch_id,
+                pagination_timeout: config.pagination_timeout,
+                pp_decimals: config.pp_decimals,
+                ..Default::default()
+            };
+        }
+
+        self.store(user_id, &config).await?;
+
+        // Remembered simulate mods are stored separately from the config
+        if reset_preferences {
+            self.remember_simulate_mods(user_id, false).await?;
+        }
+
+        Ok(prev)
+    }
+
     pub async fn store_score_embed_settings(
         self,
         user_id: Id<UserMarker>,
@@ -127,4 +210,56 @@ impl UserConfigManager {
             .await
             .wrap_err("Failed to store score embed settings")
     }
+
+    /// Remembered simulate mods of the user for the given mode, if the user
+    /// enabled remembering them.
+    pub async fn simulate_mods(
+        self,
+        user_id: Id<UserMarker>,
+        mode: GameMode,
+    ) -> Result<Option<GameModsIntermode>> {
+        let mods_opt = self
+            .psql
+            .select_simulate_mods(user_id, mode)
+            .await
+            .wrap_err("Failed to get simulate mods")?;
+
+        Ok(mods_opt.and_then(|mods| GameModsIntermode::try_from_acronyms(&mods)))
+    }
+
+    /// Store the simulate mods of the user for the given mode. Does nothing if
+    /// the user did not enable remembering them.
+    pub async fn store_simulate_mods(
+        self,
+        user_id: Id<UserMarker>,
+        mode: GameMode,
+        mods: &GameModsIntermode,
+    ) -> Result<()> {
+        self.psql
+            .upsert_simulate_mods(user_id, mode, &mods.to_string())
+            .await
+            .wrap_err("Failed to store simulate mods")
+    }
+
+    /// Enable or disable remembering simulate mods. Disabling also forgets all
+    /// previously remembered mods.
+    pub async fn remember_simulate_mods(
+        self,
+        user_id: Id<UserMarker>,
+        remember: bool,
+    ) -> Result<()> {
+        self.psql
+            .update_remember_simulate_mods(user_id, remember)
+            .await
+            .wrap_err("Failed to update simulate mods toggle")?;
+
+        if !remember {
+            self.psql
+                .delete_simulate_mods(user_id)
+                .await
+                .wrap_err("Failed to delete simulate mods")?;
+        }
+
+        Ok(())
+    }
 }
@@ -3,6 +3,7 @@ pub use self::ordr::{Ordr, OrdrReceivers};
 pub use self::osu::{
     osu_loop::{osu_tracking_loop, process_osu_tracking},
     osu_queue::*,
+    snipe_loop::osu_snipe_tracking_loop,
 };
 #[cfg(feature = "twitch")]
 pub use self::twitch::online_streams::OnlineTwitchStreams;
@@ -1,2 +1,3 @@
 pub mod osu_loop;
 pub mod osu_queue;
+pub mod snipe_loop;
@@ -3,7 +3,7 @@ use std::{borrow::Cow, collections::HashMap, num::NonZeroU64, slice};
 use bathbot_model::{
     embed_builder::{
         ComboValue, HitresultsValue, ScoreEmbedSettings, SettingValue, SettingsButtons,
-        SettingsImage, Value,
+        SettingsImage, SettingsThumbnail, Value,
     },
     rosu_v2::user::User,
 };
@@ -100,11 +100,12 @@ pub async fn process_osu_tracking(scores: &[Score], user: Option<&RedisData<User
     };
 
     // Make sure the user is being tracked in general
-    let (channels, last) = match tracking.get_tracked(key).await {
+    let (channels, last, min_rank_gains) = match tracking.get_tracked(key).await {
         Some(TrackedOsuUserValue {
             channels,
             last_update,
-        }) => (channels, last_update),
+            min_rank_gains,
+        }) => (channels, last_update, min_rank_gains),
         None => return,
     };
 
@@ -114,8 +115,10 @@ pub async fn process_osu_tracking(scores: &[Score], user: Option<&RedisData<User
         None => return,
     };
 
+    let has_new_scores = new_last > last;
+
     // If new top score, update the date
-    if new_last > last {
+    if has_new_scores {
         let update_fut = tracking.update_last_date(key, new_last);
 
         if let Err(err) = update_fut.await {
@@ -127,8 +130,20 @@ pub async fn process_osu_tracking(scores: &[Score], user: Option<&RedisData<User
 
     let mut user = TrackUser::new(key, user);
 
+    if !has_new_scores {
+        // Keep the rank snapshot up to date so that the rank gain of the next
+        // new top score is measured from right before it was set
+        if !min_rank_gains.is_empty() {
+            if let Some(rank) = user.cached_rank() {
+                tracking.swap_rank_snapshot(key, rank);
+            }
+        }
+
+        return;
+    }
+
     // Process scores
-    match score_loop(&mut user, max, last, scores, &channels).await {
+    match score_loop(&mut user, max, last, scores, &channels, &min_rank_gains).await {
         Ok(_) => {}
         Err(OsuError::NotFound) => {
             if let Err(err) = tracking.remove_user_all(key.user_id).await {
@@ -148,7 +163,14 @@ async fn score_loop(
     last: OffsetDateTime,
     scores: &[Score],
     channels: &HashMap<NonZeroU64, u8, IntHasher>,
+    min_rank_gains: &HashMap<NonZeroU64, u32, IntHasher>,
 ) -> OsuResult<()> {
+    let rank_gain = if min_rank_gains.is_empty() {
+        None
+    } else {
+        user.rank_gain().await?
+    };
+
     for (idx, score) in (1..).zip(scores.iter()).take(max as usize) {
         // Skip if its an older score
         if score.ended_at <= last {
@@ -176,6 +198,13 @@ async fn score_loop(
                 continue;
             }
 
+            // If the rank gain is unknown, notifications are not suppressed
+            if let (Some(min_gain), Some(gain)) = (min_rank_gains.get(&channel), rank_gain) {
+                if gain < *min_gain {
+                    continue;
+                }
+            }
+
             let channel = Id::new(channel.get());
             let embeds = slice::from_ref(&embed);
 
@@ -230,21 +259,48 @@ impl<'u> TrackUser<'u> {
         }
     }
 
-    async fn embed(&mut self, score: &Score, map: OsuMap, idx: u8) -> OsuResult<EmbedBuilder> {
-        let user = match self.user.as_deref() {
-            Some(user) => user,
+    async fn user(&mut self) -> OsuResult<&RedisData<User>> {
+        match self.user {
+            Some(ref user) => Ok(user.as_ref()),
             None => {
                 let TrackedOsuUserKey { user_id, mode } = self.key;
                 let args = UserArgs::user_id(user_id, mode);
                 let user = Context::redis().osu_user(args).await?;
 
                 match self.user.get_or_insert(Cow::Owned(user)) {
-                    Cow::Owned(user) => &*user,
-                    Cow::Borrowed(user) => *user,
+                    Cow::Owned(user) => Ok(&*user),
+                    Cow::Borrowed(user) => Ok(*user),
                 }
             }
+        }
+    }
+
+    /// The user's global rank if the user is already available
+    fn cached_rank(&self) -> Option<u32> {
+        self.user.as_deref().and_then(global_rank)
+    }
+
+    /// Snapshot the user's current global rank and return by how much it
+    /// improved since the previous snapshot.
+    ///
+    /// Returns `None` if there is no previous snapshot or the user is unranked.
+    async fn rank_gain(&mut self) -> OsuResult<Option<u32>> {
+        let key = self.key;
+
+        let Some(rank) = global_rank(self.user().await?) else {
+            return Ok(None);
         };
 
+        let gain = Context::tracking()
+            .swap_rank_snapshot(key, rank)
+            .map(|prev_rank| prev_rank.saturating_sub(rank));
+
+        Ok(gain)
+    }
+
+    async fn embed(&mut self, score: &Score, map: OsuMap, idx: u8) -> OsuResult<EmbedBuilder> {
+        let user = self.user().await?;
+
         let settings = match score.mode {
             GameMode::Mania => create_mania_settings(),
             _ => create_settings(),
@@ -277,6 +333,13 @@ impl<'u> TrackUser<'u> {
     }
 }
 
+fn global_rank(user: &RedisData<User>) -> Option<u32> {
+    match user.stats().global_rank() {
+        0 => None,
+        rank => Some(rank),
+    }
+}
+
 fn create_settings() -> ScoreEmbedSettings {
     ScoreEmbedSettings {
         values: vec![
@@ -343,7 +406,9 @@ fn create_settings() -> ScoreEmbedSettings {
         ],
         show_artist: true,
         show_sr_in_title: true,
+        show_score_version: false,
         image: SettingsImage::Thumbnail,
+        thumbnail: SettingsThumbnail::ListCover,
         buttons: SettingsButtons {
             pagination: false,
             render: false,
@@ -418,7 +483,9 @@ fn create_mania_settings() -> ScoreEmbedSettings {
         ],
         show_artist: true,
         show_sr_in_title: true,
+        show_score_version: false,
         image: SettingsImage::Thumbnail,
+        thumbnail: SettingsThumbnail::ListCover,
         buttons: SettingsButtons {
             pagination: false,
             render: false,
@@ -3,7 +3,7 @@ use std::{
     collections::HashMap as StdHashMap,
     sync::{
         atomic::{AtomicBool, Ordering},
-        RwLock,
+        Mutex as StdMutex, RwLock,
     },
     time::Duration as StdDuration,
 };
@@ -24,7 +24,10 @@ use rosu_v2::model::GameMode;
 use tokio::{sync::Mutex, time};
 use twilight_model::id::{marker::ChannelMarker, Id};
 
-use crate::{core::Context, manager::OsuTrackingManager};
+use crate::{
+    core::{BotConfig, Context},
+    manager::OsuTrackingManager,
+};
 
 static OSU_TRACKING_INTERVAL: OnceCell<Duration> = OnceCell::with_value(Duration::minutes(210));
 
@@ -35,6 +38,10 @@ pub fn default_tracking_interval() -> Duration {
 type TrackingQueue =
     Mutex<PriorityQueue<TrackedOsuUserKey, Reverse<OffsetDateTime>, DefaultHashBuilder>>;
 
+/// Lower bound for the delay between two checks so that the osu!api is not
+/// flooded when many users are tracked
+const MIN_MS_PER_TRACK: f32 = 250.0;
+
 pub struct TrackingStats {
     pub next_pop: Option<TrackedOsuUserKey>,
     pub users: usize,
@@ -44,6 +51,29 @@ pub struct TrackingStats {
     pub tracking: bool,
     pub wait_interval: i64,
     pub ms_per_track: i64,
+    /// Approximate duration in seconds to check all queued users once
+    pub cycle: i64,
+    pub target_cycle: Option<i64>,
+}
+
+pub enum TrackedUserStatus {
+    NotTracked,
+    /// The user was popped from the queue and is currently being checked
+    Processing {
+        last_top_score: OffsetDateTime,
+    },
+    Queued(TrackedUserStats),
+}
+
+pub struct TrackedUserStats {
+    /// Date of the user's latest top score that was processed
+    pub last_top_score: OffsetDateTime,
+    /// When the user was last checked or added to the queue
+    pub last_check: OffsetDateTime,
+    /// Amount of users that will be checked before this one
+    pub position: usize,
+    pub queue: usize,
+    pub next_check: OffsetDateTime,
 }
 
 pub struct OsuTracking {
@@ -152,9 +182,7 @@ impl OsuTracking {
                 let guard = self.queue.users.lock(&remove_entry.key).await;
 
                 if let Some(user) = guard.get() {
-                    manager
-                        .update_channels(remove_entry.key, &user.channels)
-                        .await?;
+                    manager.update_channels(remove_entry.key, user).await?;
                 }
             }
         }
@@ -181,7 +209,7 @@ impl OsuTracking {
                 let guard = self.queue.users.lock(&key).await;
 
                 if let Some(user) = guard.get() {
-                    manager.update_channels(key, &user.channels).await?;
+                    manager.update_channels(key, user).await?;
                 } else {
                     return Ok(false);
                 }
@@ -191,20 +219,62 @@ impl OsuTracking {
         Ok(true)
     }
 
+    /// Set the minimum rank gain of a user tracked in the channel.
+    ///
+    /// Returns whether the value changed. Users that are not tracked in the
+    /// channel are ignored.
+    pub async fn set_min_rank_gain(
+        &self,
+        key: TrackedOsuUserKey,
+        channel: Id<ChannelMarker>,
+        min_rank_gain: Option<u32>,
+    ) -> Result<bool> {
+        let set_fut = self.queue.set_min_rank_gain(key, channel, min_rank_gain);
+
+        let Some(user) = set_fut.await else {
+            return Ok(false);
+        };
+
+        Context::osu_tracking().update_channels(key, &user).await?;
+
+        Ok(true)
+    }
+
+    /// Store the user's current global rank and return the previously stored
+    /// one.
+    ///
+    /// Ranks are only stored for users with a minimum rank gain in some
+    /// channel.
+    pub fn swap_rank_snapshot(&self, key: TrackedOsuUserKey, rank: u32) -> Option<u32> {
+        self.queue.rank_snapshots.lock().unwrap().insert(key, rank)
+    }
+
     pub async fn list(&self, channel: Id<ChannelMarker>) -> Vec<(TrackedOsuUserKey, u8)> {
         self.queue.list(channel).await
     }
 
+    pub async fn list_all(&self) -> Vec<(TrackedOsuUserKey, Id<ChannelMarker>, u8, Option<u32>)> {
+        self.queue.list_all().await
+    }
+
     pub async fn stats(&self) -> TrackingStats {
         self.queue.stats().await
     }
+
+    pub async fn user_status(&self, key: TrackedOsuUserKey) -> TrackedUserStatus {
+        self.queue.user_status(key).await
+    }
 }
 
 pub struct OsuTrackingQueue {
     queue: TrackingQueue,
     users: TokioMutexMap<TrackedOsuUserKey, TrackedOsuUserValue<IntHasher>>,
     last_date: Mutex<OffsetDateTime>,
+    /// Global ranks of users at their last check, only stored for users with
+    /// a minimum rank gain
+    rank_snapshots: StdMutex<StdHashMap<TrackedOsuUserKey, u32>>,
     pub interval: RwLock<Duration>,
+    target_cycle: Option<StdDuration>,
     pub stop_tracking: AtomicBool,
 }
 
@@ -227,7 +297,9 @@ impl OsuTrackingQueue {
             queue: Mutex::new(queue),
             users,
             last_date: Mutex::new(now),
+            rank_snapshots: StdMutex::default(),
             interval: RwLock::new(default_tracking_interval()),
+            target_cycle: BotConfig::get().tracking_target_cycle,
             stop_tracking: AtomicBool::new(false),
         })
     }
@@ -275,8 +347,7 @@ impl OsuTrackingQueue {
         }
 
         let last_date = *self.last_date.lock().await;
-        let interval = last_date + *self.interval.read().unwrap() - OffsetDateTime::now_utc();
-        let ms_per_track = interval.whole_milliseconds() as f32 / len as f32;
+        let ms_per_track = self.ms_per_track(last_date, len);
         time::sleep(StdDuration::from_millis(ms_per_track as u64)).await;
 
         // Pop user and return them
@@ -306,6 +377,7 @@ impl OsuTrackingQueue {
 
             self.queue.lock().await.remove(&key);
             self.users.lock(&key).await.remove();
+            self.rank_snapshots.lock().unwrap().remove(&key);
         }
 
         to_remove
@@ -324,11 +396,7 @@ impl OsuTrackingQueue {
         while let Some(mut guard) = stream.next().await {
             if guard.key().user_id == user_id
                 && mode.map_or(true, |m| guard.key().mode == m)
-                && guard
-                    .value_mut()
-                    .channels
-                    .remove(&channel.into_nonzero())
-                    .is_some()
+                && guard.value_mut().remove_channel(channel.into_nonzero())
             {
                 removed.push(RemoveEntry::from(guard.key()));
             }
@@ -342,6 +410,7 @@ impl OsuTrackingQueue {
                     user_remove.no_longer_tracked = true;
                     entry.remove();
                     self.queue.lock().await.remove(&user_remove.key);
+                    self.rank_snapshots.lock().unwrap().remove(&user_remove.key);
                 }
             }
         }
@@ -361,11 +430,7 @@ impl OsuTrackingQueue {
 
         while let Some(mut guard) = stream.next().await {
             if mode.map_or(true, |m| guard.key().mode == m)
-                && guard
-                    .value_mut()
-                    .channels
-                    .remove(&channel.into_nonzero())
-                    .is_some()
+                && guard.value_mut().remove_channel(channel.into_nonzero())
             {
                 removed.push(RemoveEntry::from(guard.key()));
             }
@@ -379,6 +444,10 @@ impl OsuTrackingQueue {
                     channel_remove.no_longer_tracked = true;
                     entry.remove();
                     self.queue.lock().await.remove(&channel_remove.key);
+                    self.rank_snapshots
+                        .lock()
+                        .unwrap()
+                        .remove(&channel_remove.key);
                 }
             }
         }
@@ -421,6 +490,7 @@ impl OsuTrackingQueue {
                 let value = TrackedOsuUserValue {
                     channels,
                     last_update: last_top_score,
+                    min_rank_gains: StdHashMap::default(),
                 };
 
                 entry.insert(value);
@@ -434,6 +504,37 @@ impl OsuTrackingQueue {
         }
     }
 
+    /// Returns the updated value if it changed
+    async fn set_min_rank_gain(
+        &self,
+        key: TrackedOsuUserKey,
+        channel: Id<ChannelMarker>,
+        min_rank_gain: Option<u32>,
+    ) -> Option<TrackedOsuUserValue<IntHasher>> {
+        let channel = channel.into_nonzero();
+        let mut guard = self.users.lock(&key).await;
+        let value = guard.get_mut()?;
+
+        if !value.channels.contains_key(&channel) {
+            return None;
+        }
+
+        let prev = match min_rank_gain {
+            Some(gain) => value.min_rank_gains.insert(channel, gain),
+            None => value.min_rank_gains.remove(&channel),
+        };
+
+        if prev == min_rank_gain {
+            return None;
+        }
+
+        if value.min_rank_gains.is_empty() {
+            self.rank_snapshots.lock().unwrap().remove(&key);
+        }
+
+        Some(value.to_owned())
+    }
+
     /// Returns all entries that are tracked in the channel
     async fn list(&self, channel: Id<ChannelMarker>) -> Vec<(TrackedOsuUserKey, u8)> {
         self.users
@@ -448,6 +549,27 @@ impl OsuTrackingQueue {
             .await
     }
 
+    /// Returns all entries of all channels alongside their minimum rank gain
+    async fn list_all(&self) -> Vec<(TrackedOsuUserKey, Id<ChannelMarker>, u8, Option<u32>)> {
+        let mut entries = Vec::new();
+        let mut stream = self.users.iter();
+
+        while let Some(guard) = stream.next().await {
+            let key = *guard.key();
+            let value = guard.value();
+
+            let iter = value.channels.iter().map(|(&channel, &limit)| {
+                let min_rank_gain = value.min_rank_gains.get(&channel).copied();
+
+                (key, Id::from(channel), limit, min_rank_gain)
+            });
+
+            entries.extend(iter);
+        }
+
+        entries
+    }
+
     async fn stats(&self) -> TrackingStats {
         let (next_pop, queue) = {
             let guard = self.queue.lock().await;
@@ -461,7 +583,8 @@ impl OsuTrackingQueue {
         let tracking = !self.stop_tracking.load(Ordering::Acquire);
 
         let wait_interval = last_pop + interval - OffsetDateTime::now_utc();
-        let ms_per_track = wait_interval.whole_milliseconds() as f32 / queue as f32;
+        let ms_per_track = self.ms_per_track(last_pop, queue);
+        let cycle = ms_per_track * queue as f32 / 1000.0;
 
         TrackingStats {
             next_pop,
@@ -472,7 +595,70 @@ impl OsuTrackingQueue {
             tracking,
             wait_interval: wait_interval.whole_seconds(),
             ms_per_track: ms_per_track as i64,
+            cycle: cycle as i64,
+            target_cycle: self.target_cycle.map(|target| target.as_secs() as i64),
+        }
+    }
+
+    /// Delay between two pops.
+    ///
+    /// The queue is spread across the remaining interval. If a target cycle
+    /// is configured and a full cycle with that delay would take longer than
+    /// the target, the delay is tightened so that the cycle fits the target.
+    fn ms_per_track(&self, last_pop: OffsetDateTime, len: usize) -> f32 {
+        let len = len.max(1) as f32;
+
+        let interval = last_pop + *self.interval.read().unwrap() - OffsetDateTime::now_utc();
+        let base_ms = interval.whole_milliseconds() as f32 / len;
+
+        let Some(target_cycle) = self.target_cycle else {
+            return base_ms;
+        };
+
+        let target_cycle_ms = target_cycle.as_millis() as f32;
+
+        if base_ms * len <= target_cycle_ms {
+            return base_ms;
         }
+
+        (target_cycle_ms / len).max(MIN_MS_PER_TRACK)
+    }
+
+    /// Returns when the user was last checked and approximately when the
+    /// user will be checked next
+    async fn user_status(&self, key: TrackedOsuUserKey) -> TrackedUserStatus {
+        let last_top_score = match self.users.lock(&key).await.get() {
+            Some(value) => value.last_update,
+            None => return TrackedUserStatus::NotTracked,
+        };
+
+        let (last_check, position, queue) = {
+            let guard = self.queue.lock().await;
+
+            let Some(&Reverse(last_check)) = guard.get_priority(&key) else {
+                return TrackedUserStatus::Processing { last_top_score };
+            };
+
+            let position = guard
+                .iter()
+                .filter(|(_, Reverse(date))| *date < last_check)
+                .count();
+
+            (last_check, position, guard.len())
+        };
+
+        let last_pop = *self.last_date.lock().await;
+        let ms_per_track = self.ms_per_track(last_pop, queue);
+        let until_check = Duration::milliseconds((ms_per_track * (position + 1) as f32) as i64);
+        let next_check = OffsetDateTime::now_utc() + until_check;
+
+        TrackedUserStatus::Queued(TrackedUserStats {
+            last_top_score,
+            last_check,
+            position,
+            queue,
+            next_check,
+        })
     }
 }
 
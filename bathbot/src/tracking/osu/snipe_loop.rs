@@ -0,0 +1,301 @@
+use std::{collections::HashMap, fmt::Write, slice, time::Duration};
+
+use bathbot_model::SnipeRecent;
+use bathbot_util::{
+    constants::{OSU_BASE, UNKNOWN_CHANNEL},
+    numbers::round,
+    CowUtils, EmbedBuilder, FooterBuilder, IntHasher,
+};
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use time::OffsetDateTime;
+use tokio::time::{interval, Interval, MissedTickBehavior};
+use twilight_http::{
+    api_error::{ApiError, GeneralApiError},
+    error::ErrorType as TwilightErrorType,
+};
+use twilight_model::{
+    channel::message::Embed,
+    id::{marker::ChannelMarker, Id},
+};
+
+use crate::{manager::redis::osu::UserArgs, Context};
+
+/// Time between two checks of the same user
+const SNIPE_ROUND_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Delay between two requests to huismetbenen
+const SNIPE_REQUEST_DELAY: Duration = Duration::from_secs(2);
+
+/// Maximum amount of listed snipes per notification
+const SNIPE_LIST_LIMIT: usize = 10;
+
+/// How far back each request reaches beyond the previous round since
+/// huismetbenen may provide snipes with a delay
+const SNIPE_OVERLAP: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// Snipe tracking progress of a single user.
+struct SnipeState {
+    /// Snipes up to this point have been processed successfully
+    since: OffsetDateTime,
+    /// Snipes that were already notified about within the overlap, alongside
+    /// the time they were notified at
+    notified: HashMap<SnipeKey, OffsetDateTime, IntHasher>,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+struct SnipeKey {
+    map_id: u32,
+    gained: bool,
+    date: Option<i64>,
+}
+
+impl SnipeKey {
+    fn new(snipe: &SnipeRecent, gained: bool) -> Self {
+        Self {
+            map_id: snipe.map_id,
+            gained,
+            date: snipe.date.map(OffsetDateTime::unix_timestamp),
+        }
+    }
+}
+
+impl SnipeState {
+    fn new(since: OffsetDateTime) -> Self {
+        Self {
+            since,
+            notified: HashMap::default(),
+        }
+    }
+
+    /// Returns `true` if the snipe has not been notified about yet.
+    fn notify(&mut self, snipe: &SnipeRecent, gained: bool, now: OffsetDateTime) -> bool {
+        self.notified
+            .insert(SnipeKey::new(snipe, gained), now)
+            .is_none()
+    }
+
+    /// Forget notified snipes that are no longer within the overlap.
+    fn prune(&mut self, now: OffsetDateTime) {
+        self.notified
+            .retain(|_, notified_at| now - *notified_at < SNIPE_OVERLAP * 2);
+    }
+}
+
+#[cold]
+pub async fn osu_snipe_tracking_loop() {
+    let mut round_interval = interval(SNIPE_ROUND_INTERVAL);
+    round_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    round_interval.tick().await;
+
+    let mut request_interval = interval(SNIPE_REQUEST_DELAY);
+    request_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    // Starting point for users that were not tracked before
+    let mut default_since = OffsetDateTime::now_utc();
+    let mut states: HashMap<u32, SnipeState, IntHasher> = HashMap::default();
+
+    loop {
+        round_interval.tick().await;
+        let until = OffsetDateTime::now_utc();
+
+        if Context::tracking().stop_tracking() {
+            default_since = until;
+            states.clear();
+
+            continue;
+        }
+
+        let tracks = match Context::osu_tracking().get_snipe_tracks().await {
+            Ok(tracks) => tracks,
+            Err(err) => {
+                warn!(?err, "Failed to get snipe tracks");
+
+                continue;
+            }
+        };
+
+        states.retain(|user_id, _| tracks.contains_key(user_id));
+
+        for (user_id, channels) in tracks {
+            let state = states
+                .entry(user_id)
+                .or_insert_with(|| SnipeState::new(default_since));
+
+            let process_fut =
+                process_snipe_tracking(user_id, &channels, state, until, &mut request_interval);
+
+            // On failure, the next round retries from the same point
+            match process_fut.await {
+                Ok(_) => state.since = until,
+                Err(err) => warn!(user_id, ?err, "Failed to process snipe tracking"),
+            }
+
+            state.prune(until);
+        }
+
+        default_since = until;
+    }
+}
+
+async fn process_snipe_tracking(
+    user_id: u32,
+    channels: &[Id<ChannelMarker>],
+    state: &mut SnipeState,
+    until: OffsetDateTime,
+    request_interval: &mut Interval,
+) -> Result<()> {
+    let args = UserArgs::user_id(user_id, GameMode::Osu);
+
+    let user = Context::redis()
+        .osu_user(args)
+        .await
+        .wrap_err("Failed to get user")?;
+
+    // Users of countries without snipe data are skipped
+    if !Context::huismetbenen()
+        .is_supported(user.country_code(), GameMode::Osu)
+        .await
+    {
+        return Ok(());
+    }
+
+    let client = Context::client();
+    let since = state.since - SNIPE_OVERLAP;
+
+    request_interval.tick().await;
+
+    let mut gained = client
+        .get_national_snipes(user_id, true, since, GameMode::Osu)
+        .await
+        .wrap_err("Failed to get gained national #1s")?;
+
+    request_interval.tick().await;
+
+    let mut lost = client
+        .get_national_snipes(user_id, false, since, GameMode::Osu)
+        .await
+        .wrap_err("Failed to get lost national #1s")?;
+
+    // Snipes after `until` will be handled in the next round and snipes within
+    // the overlap might have been notified about already
+    let in_range = |snipe: &SnipeRecent| snipe.date.map_or(true, |date| date <= until);
+    gained.retain(|snipe| in_range(snipe) && state.notify(snipe, true, until));
+    lost.retain(|snipe| in_range(snipe) && state.notify(snipe, false, until));
+
+    if gained.is_empty() && lost.is_empty() {
+        return Ok(());
+    }
+
+    let mut description = String::with_capacity(512);
+
+    if !gained.is_empty() {
+        let _ = writeln!(description, "**Gained national #1s: {}**", gained.len());
+        write_snipes(&mut description, &gained, |snipe| match snipe.sniped {
+            Some(ref victim) => format!("from `{victim}`"),
+            None => "unclaimed".to_owned(),
+        });
+    }
+
+    if !lost.is_empty() {
+        if !description.is_empty() {
+            description.push('\n');
+        }
+
+        let _ = writeln!(description, "**Lost national #1s: {}**", lost.len());
+        write_snipes(&mut description, &lost, |snipe| match snipe.sniper {
+            Some(ref sniper) => format!("to `{sniper}`"),
+            None => format!("to user {}", snipe.sniper_id),
+        });
+    }
+
+    let embed = EmbedBuilder::new()
+        .author(user.author_builder())
+        .description(description)
+        .footer(FooterBuilder::new(
+            "Snipe tracking • Data from huismetbenen",
+        ))
+        .thumbnail(user.avatar_url())
+        .timestamp(until)
+        .build();
+
+    for &channel in channels {
+        send_snipe_notif(channel, &embed).await;
+    }
+
+    Ok(())
+}
+
+fn write_snipes(
+    description: &mut String,
+    snipes: &[SnipeRecent],
+    other_user: impl Fn(&SnipeRecent) -> String,
+) {
+    for snipe in snipes.iter().take(SNIPE_LIST_LIMIT) {
+        let _ = write!(
+            description,
+            "- [{artist} - {title} [{version}]]({OSU_BASE}b/{map_id})",
+            artist = snipe.artist.cow_escape_markdown(),
+            title = snipe.title.cow_escape_markdown(),
+            version = snipe.version.cow_escape_markdown(),
+            map_id = snipe.map_id,
+        );
+
+        if let Some(ref mods) = snipe.mods {
+            if !mods.is_empty() {
+                let _ = write!(description, " +{mods}");
+            }
+        }
+
+        let _ = write!(description, " • {}%", round(snipe.accuracy));
+
+        if let Some(stars) = snipe.stars {
+            let _ = write!(description, " • {}★", round(stars));
+        }
+
+        let _ = writeln!(description, " • {}", other_user(snipe));
+    }
+
+    if snipes.len() > SNIPE_LIST_LIMIT {
+        let _ = writeln!(
+            description,
+            "*and {} more*",
+            snipes.len() - SNIPE_LIST_LIMIT
+        );
+    }
+}
+
+async fn send_snipe_notif(channel: Id<ChannelMarker>, embed: &Embed) {
+    let embeds = slice::from_ref(embed);
+
+    let msg_fut = match Context::http().create_message(channel).embeds(embeds) {
+        Ok(msg_fut) => msg_fut,
+        Err(err) => {
+            warn!(?err, "Invalid embed for snipe tracking notification");
+
+            return;
+        }
+    };
+
+    let Err(err) = msg_fut.await else { return };
+
+    if let TwilightErrorType::Response { error, .. } = err.kind() {
+        if let ApiError::General(GeneralApiError {
+            code: UNKNOWN_CHANNEL,
+            ..
+        }) = error
+        {
+            if let Err(err) = Context::osu_tracking().remove_snipe_tracks(channel).await {
+                warn!(
+                    ?channel,
+                    ?err,
+                    "Failed to remove snipe tracks from unknown channel"
+                );
+            }
+        } else {
+            warn!(%channel, ?error, "Error from API while sending snipe notif")
+        }
+    } else {
+        warn!(%channel, ?err, "Error while sending snipe notif");
+    }
+}
@@ -3,7 +3,7 @@ use std::{borrow::Cow, future::IntoFuture, slice};
 use bathbot_util::{modal::ModalBuilder, MessageBuilder};
 use twilight_http::response::{marker::EmptyBody, ResponseFuture};
 use twilight_model::{
-    channel::Message,
+    channel::{message::MessageFlags, Message},
     guild::Permissions,
     http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
 };
@@ -24,6 +24,10 @@ pub trait ComponentExt {
 
     /// Acknowledge a component by responding with a modal.
     fn modal(&self, modal: ModalBuilder) -> ResponseFuture<EmptyBody>;
+
+    /// Acknowledge the component by responding with a new ephemeral message
+    /// while leaving the component's message as is.
+    fn reply_ephemeral(&self, content: &str) -> ResponseFuture<EmptyBody>;
 }
 
 impl ComponentExt for InteractionComponent {
@@ -109,4 +113,21 @@ impl ComponentExt for InteractionComponent {
             .create_response(self.id, &self.token, &response)
             .into_future()
     }
+
+    fn reply_ephemeral(&self, content: &str) -> ResponseFuture<EmptyBody> {
+        let data = InteractionResponseData {
+            content: Some(content.to_owned()),
+            flags: Some(MessageFlags::EPHEMERAL),
+            ..Default::default()
+        };
+
+        let response = InteractionResponse {
+            kind: InteractionResponseType::ChannelMessageWithSource,
+            data: Some(data),
+        };
+
+        Context::interaction()
+            .create_response(self.id, &self.token, &response)
+            .into_future()
+    }
 }
@@ -20,6 +20,8 @@ pub struct InteractionCommand {
     pub permissions: Option<Permissions>,
     pub channel_id: Id<ChannelMarker>,
     pub data: Box<CommandData>,
+    /// Whether the command was deferred with an ephemeral response
+    pub ephemeral: bool,
     pub guild_id: Option<Id<GuildMarker>>,
     pub id: Id<InteractionMarker>,
     pub member: Option<PartialMember>,
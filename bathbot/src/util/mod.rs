@@ -3,6 +3,7 @@ pub use self::{
     emote::{CustomEmote, Emote},
     ext::*,
     monthly::Monthly,
+    retry::retry_with_backoff,
 };
 
 pub mod interaction;
@@ -13,3 +14,4 @@ mod check_permissions;
 mod emote;
 mod ext;
 mod monthly;
+mod retry;
@@ -89,33 +89,41 @@ impl<'a> GradeCompletionFormatter<'a> {
     }
 }
 
+/// The percentage of the map that a failed score completed.
+///
+/// Returns `None` if the score is not a fail or the completion cannot be
+/// determined.
+pub fn fail_completion(
+    grade: Grade,
+    mode: GameMode,
+    score_hits: u32,
+    n_objects: u32,
+) -> Option<u32> {
+    // The completion is very hard to calculate for `Catch` because
+    // `n_objects` is not correct due to juicestreams so we won't
+    // show it for that mode.
+    if grade != Grade::F || mode == GameMode::Catch || n_objects == 0 {
+        return None;
+    }
+
+    Some((100 * score_hits / n_objects).min(100))
+}
+
 impl Display for GradeCompletionFormatter<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        let completion = || {
-            if self.n_objects != 0 {
-                100 * self.score_hits / self.n_objects
-            } else {
-                100
-            }
-        };
-
         let grade_fmt = GradeFormatter {
             grade: self.grade,
             score_id: self.score_id,
         };
 
         let mods_fmt = ModsFormatter::new(self.mods);
+        let completion = fail_completion(self.grade, self.mode, self.score_hits, self.n_objects);
 
-        // The completion is very hard to calculate for `Catch` because
-        // `n_objects` is not correct due to juicestreams so we won't
-        // show it for that mode.
-        let is_fail = self.grade == Grade::F && self.mode != GameMode::Catch;
-
-        match (self.mods.is_empty(), is_fail) {
-            (true, true) => write!(f, "{grade_fmt}@{}%", completion()),
-            (false, true) => write!(f, "{grade_fmt}@{}% +{mods_fmt}", completion()),
-            (true, false) => Display::fmt(&grade_fmt, f),
-            (false, false) => write!(f, "{grade_fmt} +{mods_fmt}"),
+        match (self.mods.is_empty(), completion) {
+            (true, Some(completion)) => write!(f, "{grade_fmt}@{completion}%"),
+            (false, Some(completion)) => write!(f, "{grade_fmt}@{completion}% +{mods_fmt}"),
+            (true, None) => Display::fmt(&grade_fmt, f),
+            (false, None) => write!(f, "{grade_fmt} +{mods_fmt}"),
         }
     }
 }
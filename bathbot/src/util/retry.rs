@@ -0,0 +1,122 @@
+use std::future::Future;
+
+use bathbot_util::ExponentialBackoff;
+use eyre::{Report, Result};
+use tokio::time::sleep;
+use twilight_http::error::ErrorType;
+
+const ATTEMPTS: usize = 6;
+
+/// Run the given operation and retry it with exponential backoff if it fails
+/// with a [transient](is_transient) error, logging each failed attempt.
+///
+/// Other errors, or the error of the last attempt, are returned immediately.
+pub async fn retry_with_backoff<F, Fut, T>(operation: &str, mut f: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let backoff = ExponentialBackoff::new(2).factor(1000).max_delay(30_000);
+
+    for (delay, attempt) in backoff.take(ATTEMPTS - 1).zip(1..) {
+        match f().await {
+            Ok(value) => return Ok(value),
+            Err(err) if is_transient(&err) => warn!(
+                ?err,
+                "{operation} failed on attempt #{attempt}/{ATTEMPTS}; retrying in {delay:?}"
+            ),
+            Err(err) => return Err(err),
+        }
+
+        sleep(delay).await;
+    }
+
+    f().await
+}
+
+/// Whether the error stems from a discord request that could not reach discord,
+/// timed out, hit a ratelimit, or failed due to a server error.
+fn is_transient(err: &Report) -> bool {
+    err.chain()
+        .find_map(|err| err.downcast_ref::<twilight_http::Error>())
+        .is_some_and(|err| is_transient_kind(err.kind()))
+}
+
+fn is_transient_kind(kind: &ErrorType) -> bool {
+    match kind {
+        ErrorType::RequestError
+        | ErrorType::RequestTimedOut
+        | ErrorType::ServiceUnavailable { .. } => true,
+        ErrorType::Response { status, .. } => status.get() == 429 || status.is_server_error(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_http::{
+        api_error::{ApiError, GeneralApiError},
+        response::StatusCode,
+    };
+
+    use super::*;
+
+    fn response(status: u16) -> ErrorType {
+        ErrorType::Response {
+            body: Vec::new(),
+            error: ApiError::General(GeneralApiError {
+                code: 0,
+                message: String::new(),
+            }),
+            status: StatusCode::new(status),
+        }
+    }
+
+    #[test]
+    fn transient_kinds() {
+        let transient = [
+            ErrorType::RequestError,
+            ErrorType::RequestTimedOut,
+            ErrorType::ServiceUnavailable {
+                response: hyper::Response::new(hyper::Body::empty()),
+            },
+            response(429),
+            response(500),
+            response(502),
+        ];
+
+        for kind in transient {
+            assert!(is_transient_kind(&kind), "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn non_transient_kinds() {
+        let non_transient = [
+            ErrorType::BuildingRequest,
+            ErrorType::ChunkingResponse,
+            ErrorType::CreatingHeader {
+                name: String::new(),
+            },
+            ErrorType::Json,
+            ErrorType::Parsing { body: Vec::new() },
+            ErrorType::RequestCanceled,
+            ErrorType::Unauthorized,
+            ErrorType::Validation,
+            response(400),
+            response(403),
+            response(404),
+        ];
+
+        for kind in non_transient {
+            assert!(!is_transient_kind(&kind), "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn non_http_error() {
+        assert!(!is_transient(&eyre::eyre!(
+            "Failed to deserialize current user"
+        )));
+    }
+}